@@ -1,5 +1,6 @@
 from .kurbopy import Affine
 from .kurbopy import Arc
+from .kurbopy import ArcLengths
 from .kurbopy import BezPath
 from .kurbopy import Circle
 from .kurbopy import CircleSegment
@@ -8,19 +9,26 @@ from .kurbopy import CubicBez
 # CubicOffset XXX
 # CurveFitSample XXX
 from .kurbopy import Ellipse
+from .kurbopy import FrozenBezPath
 from .kurbopy import Insets
 from .kurbopy import Line
+from .kurbopy import Line2
 from .kurbopy import LineIntersection
 from .kurbopy import MinDistance
 from .kurbopy import Nearest
 from .kurbopy import PathEl
+from .kurbopy import PathIntersection
+from .kurbopy import PathLineIntersection
 from .kurbopy import PathSeg
 from .kurbopy import Point
 from .kurbopy import QuadBez
 from .kurbopy import QuadSpline
+from .kurbopy import QuadSplineConversion
+from .kurbopy import Ray
+from .kurbopy import RayHit
 from .kurbopy import Rect
-# RoundedRect XXX
-# RoundedRectRadii XXX
+from .kurbopy import RoundedRect
+from .kurbopy import RoundedRectRadii
 # Segments XXX
 from .kurbopy import Size
 # Stroke XXX
@@ -28,8 +36,29 @@ from .kurbopy import Size
 # SVGArc XXX
 from .kurbopy import TranslateScale
 from .kurbopy import Vec2
+from .kurbopy import cubics_to_quadratic_splines
+from .kurbopy import sweep
+from .kurbopy import simplify_polyline
+from .kurbopy import points_bbox
+from .kurbopy import points_centroid
+from .kurbopy import points_principal_axes
+from .kurbopy import min_bounding_ellipse
+from .kurbopy import union_bounding_box
+from .kurbopy import distribute_along_path
+from .kurbopy import match_paths
+from .kurbopy import minkowski_sum
+from .kurbopy import intersection_area
+from .kurbopy import iou
+from .kurbopy import split_at_intersections
+from .kurbopy import fit_euler
+from .kurbopy import set_default_accuracy
+from .kurbopy import get_default_accuracy
+from .kurbopy import _set_thread_accuracy
+from .kurbopy import DEFAULT_ACCURACY
+from .kurbopy import DEFAULT_TOLERANCE
 from fontTools.pens.basePen import BasePen
 from kurbopy.magic import magic_mul, magic_add, magic_sub
+from contextlib import contextmanager
 import re
 
 
@@ -141,6 +170,28 @@ def plot(self, ax, **kwargs):
 setattr(BezPath, "plot", plot)
 
 
+@contextmanager
+def accuracy(value):
+    """Use ``value`` as the default accuracy of measurements within a block.
+
+    ::
+
+        with kurbopy.accuracy(1e-3):
+            length = path.perimeter()
+
+    The setting only applies to the current thread, and takes precedence
+    over ``set_default_accuracy``. The previous setting is restored when the
+    block ends, even if it raises.
+
+    Note that this function is not in original kurbo
+    """
+    previous = _set_thread_accuracy(value)
+    try:
+        yield
+    finally:
+        _set_thread_accuracy(previous)
+
+
 class BezPathCreatingPen(BasePen):
     def __init__(self, *args, **kwargs):
         super(BezPathCreatingPen, self).__init__(*args, **kwargs)
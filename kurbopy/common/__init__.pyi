@@ -0,0 +1,220 @@
+# This file is generated from the built extension module and the Rust
+# sources by tools/generate_stubs.py. Do not edit it by hand: rebuild
+# kurbopy and run the script again.
+from typing import Any, List, Optional, Tuple
+from kurbopy.kurbopy import Point, PointLike
+
+GAUSS_LEGENDRE_COEFFS_16: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_24: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_3: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_32: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_4: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_5: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_6: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_7: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_8: List[Tuple[float, float]]
+GAUSS_LEGENDRE_COEFFS_9: List[Tuple[float, float]]
+
+
+def bernstein_basis(degree: int, t: float) -> List[float]:
+    """
+    The Bernstein basis polynomials of the given degree, evaluated at `t`.
+
+    Returns a list of `degree + 1` values, where the `i`th is
+    C(degree, i) tⁱ (1 - t)^(degree - i). A Bézier curve's point at `t` is
+    the sum of its control points weighted by these values.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
+
+
+def elevate_degree(points: List[PointLike], times: int = 1) -> List[Point]:
+    """
+    Raise the degree of a Bézier curve with any number of control points.
+
+    Returns the control points of a curve of degree one higher (applied
+    `times` times) which traces exactly the same path; for example, raising
+    the three points of a quadratic gives the four points of the equivalent
+    cubic.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
+
+
+def factor_quartic_inner(
+    a: float,
+    b: float,
+    c: float,
+    d: float,
+    rescale: bool,
+) -> Optional[List[Tuple[float, float]]]:
+    """
+    Factor a quartic into two quadratics.
+
+    Attempt to factor a quartic equation into two quadratic equations. Returns `None` either if there
+    is overflow (in which case rescaling might succeed) or the factorization would result in
+    complex coefficients.
+
+    Discussion question: distinguish the two cases in return value?
+    """
+    ...
+
+
+def gauss_legendre_coeffs(n: int) -> List[Tuple[float, float]]:
+    """
+    The Gauss-Legendre coefficients of order `n`.
+
+    Returns a list of `(weight, abscissa)` pairs over the interval [-1, 1].
+    The available orders are 3 to 9, 16, 24 and 32; each table is also
+    available as a module constant such as `GAUSS_LEGENDRE_COEFFS_8`.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
+
+
+def gauss_quadrature(fun: Any, a: float, b: float, n: int = 8) -> float:
+    """
+    Integrate a function over the interval [a, b] using Gauss-Legendre
+    quadrature of order `n`.
+
+    `fun` is called with a single float and must return a float. Any
+    exception it raises is propagated.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
+
+
+def solve_cubic(c0: float, c1: float, c2: float, c3: float) -> List[float]:
+    """
+    Find real roots of cubic equation.
+
+    The implementation is not (yet) fully robust, but it does handle the case
+    where `c3` is zero (in that case, solving the quadratic equation).
+
+    See: <https://momentsingraphics.de/CubicRoots.html>
+
+    That implementation is in turn based on Jim Blinn's "How to Solve a Cubic
+    Equation", which is masterful.
+
+    Return values of x for which c0 + c1 x + c2 x² + c3 x³ = 0.
+    """
+    ...
+
+
+def solve_itp(
+    fun: Any,
+    a: float,
+    b: float,
+    epsilon: float,
+    n0: int,
+    k1: float,
+    ya: float,
+    yb: float,
+) -> float:
+    """
+    Solve an arbitrary function for a zero-crossing.
+
+    This uses the [ITP method], as described in the paper
+    [An Enhancement of the Bisection Method Average Performance Preserving Minmax Optimality].
+
+    The values of `ya` and `yb` are given as arguments rather than
+    computed from `f`, as the values may already be known, or they may
+    be less expensive to compute as special cases.
+
+    It is assumed that `ya < 0.0` and `yb > 0.0`, otherwise unexpected
+    results may occur.
+
+    The value of `epsilon` must be larger than 2^-63 times `b - a`,
+    otherwise integer overflow may occur. The `a` and `b` parameters
+    represent the lower and upper bounds of the bracket searched for a
+    solution.
+
+    The ITP method has tuning parameters. This implementation hardwires
+    k2 to 2, both because it avoids an expensive floating point
+    exponentiation, and because this value has been tested to work well
+    with curve fitting problems.
+
+    The `n0` parameter controls the relative impact of the bisection and
+    secant components. When it is 0, the number of iterations is
+    guaranteed to be no more than the number required by bisection (thus,
+    this method is strictly superior to bisection). However, when the
+    function is smooth, a value of 1 gives the secant method more of a
+    chance to engage, so the average number of iterations is likely
+    lower, though there can be one more iteration than bisection in the
+    worst case.
+
+    The `k1` parameter is harder to characterize, and interested users
+    are referred to the paper, as well as encouraged to do empirical
+    testing. To match the paper, a value of `0.2 / (b - a)` is
+    suggested, and this is confirmed to give good results.
+
+    When the function is monotonic, the returned result is guaranteed to
+    be within `epsilon` of the zero crossing. For more detailed analysis,
+    again see the paper.
+
+    [ITP method]: https://en.wikipedia.org/wiki/ITP_Method
+    [An Enhancement of the Bisection Method Average Performance Preserving Minmax Optimality]: https://dl.acm.org/doi/10.1145/3423597
+    """
+    ...
+
+
+def solve_poly(coeffs: List[float]) -> List[float]:
+    """
+    Find real roots of a polynomial of arbitrary degree.
+
+    Return values of x for which c0 + c1 x + c2 x² + ... + cn xⁿ = 0, where
+    `coeffs` is `[c0, c1, ..., cn]`. Roots are returned in ascending order.
+
+    Polynomials of degree four or less are handed to the corresponding
+    solver in this module. Higher degree polynomials are solved by finding
+    the turning points (recursively, from the derivative) and bisecting
+    between them, which finds every real root including repeated ones.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
+
+
+def solve_quadratic(c0: float, c1: float, c2: float) -> List[float]:
+    """
+    Find real roots of quadratic equation.
+
+    Return values of x for which c0 + c1 x + c2 x² = 0.
+
+    This function tries to be quite numerically robust. If the equation
+    is nearly linear, it will return the root ignoring the quadratic term;
+    the other root might be out of representable range. In the degenerate
+    case where all coefficients are zero, so that all values of x satisfy
+    the equation, a single `0.0` is returned.
+    """
+    ...
+
+
+def solve_quartic(c0: float, c1: float, c2: float, c3: float, c4: float) -> List[float]:
+    """
+    Find real roots of a quartic equation.
+
+    This is a fairly literal implementation of the method described in:
+    Algorithm 1010: Boosting Efficiency in Solving Quartic Equations with
+    No Compromise in Accuracy, Orellana and De Michele, ACM
+    Transactions on Mathematical Software, Vol. 46, No. 2, May 2020.
+    """
+    ...
+
+
+def split_bezier(points: List[PointLike], t: float) -> Tuple[List[Point], List[Point]]:
+    """
+    Split a Bézier curve with any number of control points at `t`, using
+    de Casteljau's algorithm.
+
+    Returns a tuple of the control points of the two halves, covering the
+    ranges 0..t and t..1 of the original; the last point of the first half
+    is the first point of the second, and is the point on the curve at `t`.
+
+    Note that this function is not in original kurbo.
+    """
+    ...
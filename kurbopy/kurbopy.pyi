@@ -0,0 +1,6771 @@
+# This file is generated from the built extension module and the Rust
+# sources by tools/generate_stubs.py. Do not edit it by hand: rebuild
+# kurbopy and run the script again.
+from typing import Any, ClassVar, Iterator, List, Optional, Sequence, Set, Tuple, Union, overload
+from typing_extensions import Self
+
+from . import common as common
+
+DEFAULT_ACCURACY: float
+DEFAULT_TOLERANCE: float
+
+PointLike = Union[Point, Tuple[float, float]]
+Vec2Like = Union[Vec2, Tuple[float, float]]
+AffineLike = Union[Affine, TranslateScale]
+RadiiLike = Union[RoundedRectRadii, float, Tuple[float, float, float, float]]
+PointArray = Union[Sequence[PointLike], Any]
+Region = Union[BezPath, Rect]
+
+
+class Affine:
+    """A 2D affine transform."""
+
+    def __init__(
+        self,
+        a: float,
+        b: float,
+        c: float,
+        d: float,
+        e: float,
+        f: float,
+    ) -> None:
+        """
+        Construct an affine transform from coefficients.
+
+        If the coefficients are `(a, b, c, d, e, f)`, then the resulting
+        transformation represents this augmented matrix:
+
+        ```text
+        | a c e |
+        | b d f |
+        | 0 0 1 |
+        ```
+
+        Note that this convention is transposed from PostScript and
+        Direct2D, but is consistent with the
+        [Wikipedia](https://en.wikipedia.org/wiki/Affine_transformation)
+        formulation of affine transformation as augmented matrix. The
+        idea is that `(A * B) * v == A * (B * v)`, where `*` is the
+        [`Mul`](std::ops::Mul) trait.
+        """
+        ...
+
+    @classmethod
+    def FLIP_X(cls) -> Self:
+        """A transform that is flipped on the x-axis."""
+        ...
+
+    @classmethod
+    def FLIP_Y(cls) -> Self:
+        """
+        A transform that is flipped on the y-axis. Useful for converting between
+        y-up and y-down spaces.
+        """
+        ...
+
+    @classmethod
+    def IDENTITY(cls) -> Self:
+        """The identity transform."""
+        ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    __hash__: ClassVar[None]
+
+    @overload
+    def __mul__(self, rhs: Point) -> Point: ...
+
+    @overload
+    def __mul__(self, rhs: Affine) -> Self: ...
+
+    @overload
+    def __mul__(self, rhs: Arc) -> Arc: ...
+
+    @overload
+    def __mul__(self, rhs: Circle) -> Ellipse: ...
+
+    @overload
+    def __mul__(self, rhs: CubicBez) -> CubicBez: ...
+
+    @overload
+    def __mul__(self, rhs: Line) -> Line: ...
+
+    @overload
+    def __mul__(self, rhs: PathEl) -> PathEl: ...
+
+    @overload
+    def __mul__(self, rhs: PathSeg) -> PathSeg: ...
+
+    @overload
+    def __mul__(self, rhs: QuadBez) -> QuadBez: ...
+
+    @overload
+    def __mul__(self, rhs: Ellipse) -> Ellipse: ...
+
+    def __ne__(self, other: object) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def __rmul__(self, rhs: float) -> Self: ...
+
+    def approx_eq(self, other: Affine, epsilon: float = 1e-9) -> bool:
+        """
+        Returns true if each coefficient of this transform is within
+        ``epsilon`` of the corresponding coefficient of ``other``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def as_coeffs(self) -> List[float]:
+        """Get the coefficients of the transform."""
+        ...
+
+    def as_translate_scale(self, epsilon: float = 1e-9) -> Optional[TranslateScale]:
+        """
+        Convert this transform into a `TranslateScale`, if it consists only
+        of uniform scaling and translation (to within ``epsilon`` in each
+        coefficient). Returns ``None`` otherwise.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def determinant(self) -> float:
+        """Compute the determinant of this transform."""
+        ...
+
+    @classmethod
+    def fit(
+        cls,
+        src_points: List[PointLike],
+        dst_points: List[PointLike],
+        kind: str = "affine",
+    ) -> Self:
+        """
+        Find the transform which best maps ``src_points`` onto ``dst_points``.
+
+        The transform minimizes the sum of squared distances between the
+        transformed source points and the corresponding destination points.
+        ``kind`` restricts the transforms considered:
+
+        - ``"affine"``: any affine transform (needs three or more points,
+          not all on a line);
+        - ``"similarity"``: rotation, uniform scaling and translation;
+        - ``"rigid"``: rotation and translation only.
+
+        Reflections are only produced by ``"affine"``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def inverse(self) -> Self:
+        """
+        Compute the inverse transform.
+
+        Produces NaN values when the determinant is zero; use
+        ``is_invertible`` to check first.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_invertible(self, epsilon: float = ...) -> bool:
+        """
+        Returns true if the transform can be inverted, that is, if the
+        magnitude of its determinant is greater than ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    @classmethod
+    def map_unit_square(cls, rect: Rect) -> Self:
+        """
+        Creates an affine transformation that takes the unit square to the given rectangle.
+
+        Useful when you want to draw into the unit square but have your output fill any rectangle.
+        In this case push the `Affine` onto the transform stack.
+        """
+        ...
+
+    def pre_rotate(self, th: float) -> Self:
+        """
+        A rotation by `th` followed by `self`.
+
+        Equivalent to `self * Affine::rotate(th)`
+        """
+        ...
+
+    def pre_rotate_about(self, th: float, center: PointLike) -> Self:
+        """
+        A rotation by `th` about `center` followed by `self`.
+
+        Equivalent to `self * Affine::rotate_about(th)`
+        """
+        ...
+
+    def pre_scale(self, scale: float) -> Self:
+        """
+        A scale by `scale` followed by `self`.
+
+        Equivalent to `self * Affine::scale(scale)`
+        """
+        ...
+
+    def pre_scale_non_uniform(self, scale_x: float, scale_y: float) -> Self:
+        """
+        A scale by `(scale_x, scale_y)` followed by `self`.
+
+        Equivalent to `self * Affine::scale_non_uniform(scale_x, scale_y)`
+        """
+        ...
+
+    def pre_translate(self, trans: Vec2Like) -> Self:
+        """
+        A translation of `trans` followed by `self`.
+
+        Equivalent to `self * Affine::translate(trans)`
+        """
+        ...
+
+    @classmethod
+    def reflect(cls, point: PointLike, direction: Vec2Like) -> Self:
+        """
+        Create an affine transform that represents reflection about the line `point + direction * t, t in (-infty, infty)`
+
+        # Examples
+
+        ```
+        # use kurbo::{Point, Vec2, Affine};
+        # fn assert_near(p0: Point, p1: Point) {
+        #     assert!((p1 - p0).hypot() < 1e-9, "{p0:?} != {p1:?}");
+        # }
+        let point = Point::new(1., 0.);
+        let vec = Vec2::new(1., 1.);
+        let map = Affine::reflect(point, vec);
+        assert_near(map * Point::new(1., 0.), Point::new(1., 0.));
+        assert_near(map * Point::new(2., 1.), Point::new(2., 1.));
+        assert_near(map * Point::new(2., 2.), Point::new(3., 1.));
+        ```
+        """
+        ...
+
+    @classmethod
+    def rotate(cls, th: float) -> Self:
+        """
+        An affine transform representing rotation.
+
+        The convention for rotation is that a positive angle rotates a
+        positive X direction into positive Y. Thus, in a Y-down coordinate
+        system (as is common for graphics), it is a clockwise rotation, and
+        in Y-up (traditional for math), it is anti-clockwise.
+
+        The angle, `th`, is expressed in radians.
+        """
+        ...
+
+    @classmethod
+    def rotate_about(cls, th: float, center: PointLike) -> Self:
+        """
+        An affine transform representing a rotation of `th` radians about `center`.
+
+        See [`Affine::rotate()`] for more info.
+        """
+        ...
+
+    @classmethod
+    def scale(cls, s: float) -> Self:
+        """An affine transform representing uniform scaling."""
+        ...
+
+    @classmethod
+    def scale_non_uniform(cls, sx: float, sy: float) -> Self:
+        """
+        An affine transform representing non-uniform scaling
+        with different scale values for x and y
+        """
+        ...
+
+    @classmethod
+    def skew(cls, skew_x: float, skew_y: float) -> Self:
+        """
+        An affine transformation representing a skew.
+
+        The `skew_x` and `skew_y` parameters represent skew factors for the
+        horizontal and vertical directions, respectively.
+
+        This is commonly used to generate a faux oblique transform for
+        font rendering. In this case, you can slant the glyph 20 degrees
+        clockwise in the horizontal direction (assuming a Y-up coordinate
+        system):
+
+        ```
+        let oblique_transform = kurbo::Affine::skew(20f64.to_radians().tan(), 0.0);
+        ```
+        """
+        ...
+
+    def then_rotate(self, th: float) -> Self:
+        """
+        `self` followed by a rotation of `th`.
+
+        Equivalent to `Affine::rotate(th) * self`
+        """
+        ...
+
+    def then_rotate_about(self, th: float, center: PointLike) -> Self:
+        """
+        `self` followed by a rotation of `th` about `center`.
+
+        Equivalent to `Affine::rotate_about(th, center) * self`
+        """
+        ...
+
+    def then_scale(self, scale: float) -> Self:
+        """
+        `self` followed by a scale of `scale`.
+
+        Equivalent to `Affine::scale(scale) * self`
+        """
+        ...
+
+    def then_scale_non_uniform(self, scale_x: float, scale_y: float) -> Self:
+        """
+        `self` followed by a scale of `(scale_x, scale_y)`.
+
+        Equivalent to `Affine::scale_non_uniform(scale_x, scale_y) * self`
+        """
+        ...
+
+    def then_translate(self, trans: Vec2Like) -> Self:
+        """
+        `self` followed by a translation of `trans`.
+
+        Equivalent to `Affine::translate(trans) * self`
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def transform_points(self, points: PointArray) -> Any:
+        """
+        Apply the transform to many points at once.
+
+        ``points`` may be a NumPy array of shape ``(n, 2)``, in which case an
+        array of the same shape is returned, or a list of points, in which
+        case a list of :py:class:`Point` is returned.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def transform_rect_bbox(self, rect: Rect) -> Rect:
+        """
+        Compute the bounding box of a transformed rectangle.
+
+        Returns the minimal `Rect` that encloses the given `Rect` after affine transformation.
+        If the transform is axis-aligned, then this bounding box is "tight", in other words the
+        returned `Rect` is the transformed rectangle.
+
+        The returned rectangle always has non-negative width and height.
+        """
+        ...
+
+    @classmethod
+    def translate(cls, p: PointLike) -> Self:
+        """An affine transform representing translation."""
+        ...
+
+    def translation(self) -> Vec2:
+        """Returns the translation part of this affine map (`(self.0[4], self.0[5])`)."""
+        ...
+
+    def with_translation(self, trans: Vec2Like) -> Self:
+        """
+        Replaces the translation portion of this affine map
+
+        The translation can be seen as being applied after the linear part of the map.
+        """
+        ...
+
+
+class Arc:
+    """A single arc segment."""
+
+    def __init__(
+        self,
+        center: PointLike,
+        radii: Vec2Like,
+        start_angle: float,
+        sweep_angle: float,
+        x_rotation: float,
+    ) -> None:
+        """Create a new `Arc`."""
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    @property
+    def center(self) -> Point: ...
+
+    @center.setter
+    def center(self, center: PointLike) -> None: ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    @classmethod
+    def from_center_endpoints(
+        cls,
+        center: PointLike,
+        start: PointLike,
+        end: PointLike,
+        direction: str = "anticlockwise",
+    ) -> Self:
+        """
+        Create a circular `Arc` around ``center``, from ``start`` to the
+        direction of ``end``.
+
+        The radius is the distance from ``center`` to ``start``; ``end`` only
+        sets the angle at which the arc finishes. ``direction`` is
+        ``"anticlockwise"`` (the direction of increasing angles, which is
+        anticlockwise in a y-up coordinate system) or ``"clockwise"``. If
+        ``start`` and ``end`` are in the same direction from ``center``, the
+        arc is a full circle.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_svg_arc(
+        cls,
+        start: PointLike,
+        rx: float,
+        ry: float,
+        x_rotation: float,
+        large_arc: bool,
+        sweep: bool,
+        end: PointLike,
+    ) -> Optional[Self]:
+        """
+        Create an `Arc` from the SVG "endpoint" arc parameterization.
+
+        The arguments follow the order of the SVG ``A`` path command:
+        the start point, the two radii, the rotation of the ellipse's
+        x-axis (in radians), the large-arc and sweep flags, and the end point.
+
+        Returns ``None`` if the arc degenerates to a straight line (for
+        example, if either radius is zero or the endpoints coincide).
+        """
+        ...
+
+    @classmethod
+    def from_three_points(
+        cls,
+        p0: PointLike,
+        p1: PointLike,
+        p2: PointLike,
+    ) -> Optional[Self]:
+        """
+        Create a circular `Arc` which starts at ``p0``, passes through ``p1``
+        and ends at ``p2``.
+
+        Returns ``None`` if the points are collinear, so that no circle passes
+        through them.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    @property
+    def radii(self) -> Vec2: ...
+
+    @radii.setter
+    def radii(self, radii: Vec2Like) -> None: ...
+
+    @property
+    def start_angle(self) -> float: ...
+
+    @start_angle.setter
+    def start_angle(self, start_angle: float) -> None: ...
+
+    @property
+    def sweep_angle(self) -> float: ...
+
+    @sweep_angle.setter
+    def sweep_angle(self, sweep_angle: float) -> None: ...
+
+    def to_cubic_beziers(self, tolerance: float, fun: Any) -> None:
+        """
+        Converts an Arc into a series of cubic bezier segments.
+
+        Closure will be invoked for each segment.
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+    @property
+    def x_rotation(self) -> float: ...
+
+    @x_rotation.setter
+    def x_rotation(self, x_rotation: float) -> None: ...
+
+
+class ArcLengths:
+    """
+    The arc lengths of the segments of a path, measured once so that
+    positions along the path can be found quickly.
+
+    Measuring a position along a path with ``inv_arclen`` integrates along
+    every segment before it, each time. An ``ArcLengths`` object measures
+    each segment when it is created, and then finds the segment holding any
+    distance by binary search, so that only that one segment is integrated.
+
+    Segments are numbered as yielded by ``BezPath.segments``. The object
+    takes a copy of the path, so later changes to the path do not affect it.
+
+    ::
+
+       from kurbopy import ArcLengths, Circle
+       lengths = ArcLengths(Circle((0, 0), 10).to_path(0.1))
+       points = [lengths.point_at_length(d) for d in range(0, 60, 5)]
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(self, path: BezPath, accuracy: float = ...) -> None: ...
+
+    def __len__(self) -> int: ...
+
+    def __repr__(self) -> str: ...
+
+    def length_at(self, segment_index: int, t: float) -> float:
+        """
+        Returns the distance along the path of the point at parameter value
+        ``t`` on the given segment.
+        """
+        ...
+
+    def point_at_length(self, length: float) -> Point:
+        """
+        Returns the point ``length`` along the path. Lengths beyond either end
+        of the path are clamped to it.
+
+        Raises ``ValueError`` if the path has no segments.
+        """
+        ...
+
+    def t_at_length(self, length: float) -> Tuple[int, float]:
+        """
+        Returns the ``(segment_index, t)`` of the point ``length`` along the
+        path. Lengths beyond either end of the path are clamped to it.
+
+        Raises ``ValueError`` if the path has no segments.
+        """
+        ...
+
+    @property
+    def total(self) -> float:
+        """The total length of the path."""
+        ...
+
+
+class BezPath:
+    """
+    A Bézier path.
+
+    These docs assume basic familiarity with Bézier curves; for an
+    introduction, see Pomax's wonderful `A Primer on Bézier Curves`_.
+
+    This path can contain lines, quadratics ([``QuadBez``]) and cubics
+    ([``CubicBez``]), and may contain multiple subpaths.
+
+    Elements and Segments
+    =====================
+
+    A Bézier path can be represented in terms of either 'elements'
+    ([``PathEl``]) or 'segments' ([``PathSeg``]). Elements map closely to
+    how Béziers are generally used in PostScript-style drawing APIs; they
+    can be thought of as instructions for drawing the path. Segments more
+    directly describe the path itself, with each segment being an
+    independent line or curve.
+
+    These different representations are useful in different contexts. For
+    tasks like drawing, elements are a natural fit, but when doing
+    hit-testing or subdividing, we need to have access to the segments.
+
+    ::
+
+       from kurbopy import BezPath, Rect, Shape, Vec2, Point
+       accuracy = 0.1
+       rect = Rect(Point(0, 0), Point(10, 10))
+       path1 = rect.to_path(accuracy)
+
+       # extend a path with another path:
+       path = rect.to_path(accuracy)
+       shifted_rect = rect + Vec2(5.0, 10.0)
+       path.extend(shifted_rect.to_path(accuracy))
+
+
+    Advanced functionality
+    ======================
+
+    In addition to the basic API, there are several useful pieces of
+    advanced functionality available on ``BezPath``:
+
+    -  ```flatten```_ does Bézier flattening, converting a curve to a series
+       of line segments
+    -  ```intersections```_ computes intersections of a path with a line,
+       useful for things like subdividing
+
+    .. _A Primer on Bézier Curves: https://pomax.github.io/bezierinfo/
+    .. _``intersections``: PathSeg::intersections
+    """
+
+    def __init__(self) -> None: ...
+
+    def __add__(self, other: BezPath) -> Self: ...
+
+    def __bool__(self) -> bool: ...
+
+    def __contains__(self, pt: PointLike) -> bool: ...
+
+    def __iadd__(self, other: Any) -> None: ...
+
+    def __imul__(self, rhs: AffineLike) -> None: ...
+
+    def __len__(self) -> int: ...
+
+    def __mul__(self, rhs: AffineLike) -> Self: ...
+
+    def __repr__(self) -> str: ...
+
+    def append_arc(self, arc: Any, tolerance: float = ...) -> None:
+        """
+        Append an arc to the path, converted to cubic Béziers to within
+        ``tolerance``.
+
+        The arc continues the current subpath: if the path does not already
+        end at the start of the arc, a line is drawn to it. If the path is
+        empty or its last subpath is closed, a new subpath is started at the
+        start of the arc instead.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def apply_affine(self, affine: Affine) -> None:
+        """Apply an affine transform to the path."""
+        ...
+
+    def approx_eq(self, other: BezPath, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another path, allowing each coordinate to differ by up
+        to ``epsilon``. The paths must have the same elements in the same
+        order; use ``canonicalize`` first to compare paths which draw the
+        same shape in a different order.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def arc_to(
+        self,
+        rx: float,
+        ry: float,
+        x_rotation: float,
+        large_arc: bool,
+        sweep: bool,
+        end: PointLike,
+        tolerance: float = ...,
+    ) -> None:
+        """
+        Draw an elliptical arc from the current point to ``end``, as with the
+        SVG ``A`` path command.
+
+        ``rx`` and ``ry`` are the radii of the ellipse, ``x_rotation`` the
+        rotation of its x-axis in radians, and ``large_arc`` and ``sweep``
+        choose which of the four possible arcs is drawn. As in SVG, radii
+        which are too small are scaled up, and an arc which degenerates
+        (for example, with a zero radius) is drawn as a straight line. The
+        arc is converted to cubic Béziers to within ``tolerance``.
+
+        Raises ``ValueError`` if the path has no current point.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    def bounding_ellipse(self, tolerance: float = ...) -> Ellipse:
+        """
+        Returns the smallest ellipse enclosing the path, as found by
+        :py:func:`min_bounding_ellipse`.
+
+        The path is flattened to within ``tolerance``, so it may stray beyond
+        the ellipse by up to that distance. Raises ``ValueError`` if the path
+        has no points, or unless ``tolerance`` is positive.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def buffer(
+        self,
+        distance: float,
+        join: str = "round",
+        tolerance: float = ...,
+    ) -> Self:
+        """
+        Grow or shrink the region enclosed by the path.
+
+        With a positive ``distance``, the result covers every point within
+        ``distance`` of the shape; with a negative one, it covers the points
+        of the shape which are further than ``-distance`` from its outline.
+        Unlike offsetting the segments, overlaps and self-intersections are
+        resolved, so the result is always a valid set of closed outlines,
+        and parts of the shape which are too thin disappear when shrinking.
+
+        ``join`` is ``"round"``, ``"miter"`` or ``"bevel"``, and determines
+        the shape of the buffered corners. Miters longer than four times the
+        distance are beveled. The shape is filled with the nonzero rule and
+        open subpaths are treated as closed.
+
+        The path is flattened to within ``tolerance``, so the result is made
+        of straight lines, with outer contours running anticlockwise in a
+        y-up coordinate system and holes running clockwise.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def canonicalize(self) -> Self:
+        """
+        Put the path into a canonical form, so that paths which draw the
+        same segments compare equal however their elements are ordered.
+
+        Each closed subpath is made to run anticlockwise in a y-up coordinate
+        system (unless it encloses no area), and to start from whichever of
+        its segments gives the least sequence of coordinates; each open
+        subpath runs whichever way gives the lesser sequence; and the
+        subpaths are sorted. A closing line is always left to the
+        ``ClosePath``. Note that directions are not kept, which changes
+        how overlapping subpaths are filled; use ``correct_directions``
+        afterwards to restore a font format's convention.
+
+        Paths are only made equal if they have the same segments, so run
+        ``cleanup`` or ``round_coordinates`` first to remove small
+        differences.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def cleanup(self, tolerance: float = ...) -> Self:
+        """
+        Remove degenerate geometry from the path.
+
+        Segments shorter than ``tolerance`` are removed, curves whose control
+        points lie within ``tolerance`` of the line between their ends are
+        replaced by lines, consecutive lines which carry on in the same
+        direction are merged into one, and subpaths which are left with
+        nothing to draw are dropped.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def clip_lines(self, rect: Rect, tolerance: float = ...) -> Self:
+        """
+        Flatten the path and clip it to a rectangle.
+
+        The result is made of straight lines, with a new subpath starting
+        wherever the path leaves and re-enters the rectangle. Closed subpaths
+        are clipped as outlines, not as areas, so the result is suitable for
+        plotting strokes rather than filling. Raises ``ValueError`` unless
+        ``tolerance`` is positive.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def clip_to_rect(self, rect: Rect, fill: bool = True) -> Self:
+        """
+        Clip the path to a rectangle, keeping its curves.
+
+        With ``fill=True``, the path is treated as a filled shape, and the
+        result encloses the part of the shape inside the rectangle, with
+        straight lines along the edges of the rectangle where the shape was
+        cut. Open subpaths are treated as closed, and the fill rule of the
+        shape is respected whichever it is. With ``fill=False``, the path is
+        treated as an outline and trimmed to the parts inside the rectangle,
+        with a new subpath starting wherever it re-enters.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def close_path(self) -> None:
+        """Push a "close path" element onto the path."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def contains_points(self, points: PointArray, parallel: bool = False) -> Any:
+        """
+        Returns whether each of many points is inside this shape.
+
+        ``points`` may be a NumPy array of shape ``(n, 2)``, in which case a
+        NumPy array of booleans is returned, or a list of points, in which
+        case a list is returned. If ``parallel`` is true, the points are
+        divided among several threads. The lock on the interpreter is
+        released while testing.
+
+        This is only meaningful for closed shapes.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def control_box(self) -> Rect:
+        """
+        Returns a rectangle that conservatively encloses the path.
+
+        Unlike the `bounding_box` method, this uses control points directly
+        rather than computing tight bounds for curve elements.
+        """
+        ...
+
+    def control_points(self, grouped: bool = False) -> Any:
+        """
+        Returns the points of the path, including off-curve control points,
+        in order.
+
+        If ``grouped`` is true, returns a list for each element instead, as
+        given by ``PathEl.points``; a ``ClosePath`` element has an empty list.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def control_polygon(self) -> Self:
+        """
+        Returns the control polygon of the path: a path of straight lines
+        joining each point to the next, including the off-curve control
+        points. Subpaths are kept separate, and closed subpaths stay closed.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def corners(self, angle_threshold: float) -> List[Tuple[int, float, Point, float]]:
+        """
+        Find the corners of the path.
+
+        A corner is a join between two segments where the direction of the
+        path turns by more than ``angle_threshold`` radians, or a cusp
+        inside a cubic segment, where the curve stops and reverses. Joins
+        between the last and first segments of closed subpaths are included.
+
+        Returns a list of ``(index, t, point, angle)`` tuples, where
+        ``index`` is the index of the segment (as yielded by ``segments``)
+        and ``t`` the parameter value on it: 0 for a join at the start of
+        the segment, or the position of a cusp. ``angle`` is the signed
+        turning angle in radians, which is π for a cusp.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def correct_directions(self, convention: str = "postscript") -> Self:
+        """
+        Reverse subpaths as needed so that their directions follow a font
+        format's convention.
+
+        With ``convention="postscript"`` (as used by CFF and Type 1 fonts),
+        outer contours run anticlockwise and holes clockwise, in a y-up
+        coordinate system. With ``convention="truetype"``, it is the other
+        way round. Whether a subpath is an outer contour or a hole is found
+        from its depth, as given by ``subpath_directions``. Subpaths which
+        enclose no area are left alone.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def curvature_comb(
+        self,
+        samples_per_segment: int = 16,
+        scale: float = 1.0,
+        as_numpy: bool = False,
+    ) -> Any:
+        """
+        Sample the curvature of the path for drawing a curvature comb.
+
+        Each segment is sampled at ``samples_per_segment`` evenly spaced
+        parameter values, including both of its ends. Returns a tuple of
+        three lists: the points on the path, the unit normals at those
+        points, and the curvature at each point multiplied by ``scale``.
+        The normals point away from the center of curvature, so the teeth of
+        the comb run from each point to ``point + normal * magnitude``.
+
+        If ``as_numpy`` is true, NumPy arrays of shape ``(n, 2)``,
+        ``(n, 2)`` and ``(n,)`` are returned instead of lists; this requires
+        NumPy to be installed.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def curve_to(self, pt1: PointLike, pt2: PointLike, pt3: PointLike) -> None:
+        """Push a "curve to" element onto the path."""
+        ...
+
+    def dash_spans(
+        self,
+        pattern: List[float],
+        offset: float = 0.0,
+        accuracy: float = ...,
+    ) -> List[Tuple[float, float]]:
+        """
+        Computes the arc length spans which would be drawn by a dash pattern.
+
+        ``pattern`` alternates between "on" and "off" lengths, and ``offset``
+        is how far into the pattern to start, as for ``dash``. A pattern with
+        an odd number of entries is repeated to make it even. Returns a list
+        of ``(start, end)`` tuples giving the "on" spans as distances along
+        the path. As with ``dash``, the pattern restarts at each subpath, and
+        spans never cross from one subpath into the next.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def elements(self) -> ElementIterator: ...
+
+    def extend(self, other: Any) -> None:
+        """
+        Append the elements of another path, or of any iterable of
+        [``PathEl``], to this path.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def extrema(self, global_only: bool = False) -> List[Tuple[int, float, Point]]:
+        """
+        Find the extreme points of the path.
+
+        Returns a list of ``(index, t, point)`` tuples, where ``index`` is the
+        index of a segment (as yielded by ``segments``) and ``t`` a parameter
+        value strictly inside it at which the segment is horizontal or
+        vertical; these are the places where font editors expect on-curve
+        points.
+
+        If ``global_only`` is true, only the points where the path reaches
+        its overall minimum or maximum x or y coordinate are returned; these
+        may include the ends of segments (``t`` of 0 or 1).
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def find_mirror_axes(self, tolerance: float = ...) -> List[Tuple[Line, float]]:
+        """
+        Find the axes of mirror symmetry of the path.
+
+        Returns a list of ``(axis, error)`` tuples, most symmetric first.
+        Each axis is a :py:class:`Line` across the path's bounding box, and
+        ``error`` is the Hausdorff distance between the path and its
+        reflection in that axis, which is at most ``tolerance``. Contours
+        which the reflection reverses still count as symmetric.
+
+        ::
+
+           axes = glyph.find_mirror_axes(1.0)
+           if axes:
+               axis, error = axes[0]
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def flatten(
+        self,
+        tolerance: float = ...,
+        callback: Optional[Any] = None,
+    ) -> Optional[List[Point]]:
+        """
+        Flatten the path, returning a list of points.
+
+        The points of all subpaths are returned in a single list; use
+        ``flatten_subpaths`` to keep the subpaths apart.
+
+        If ``callback`` is given, it is instead called with each
+        :py:class:`PathEl` of the flattened path (``MoveTo``, ``LineTo`` and
+        ``ClosePath`` elements only), as in kurbo, and ``None`` is returned.
+        If the callback raises an exception, flattening stops and the
+        exception is propagated.
+        """
+        ...
+
+    def flatten_simplified(
+        self,
+        tolerance: float = ...,
+        epsilon: float = ...,
+    ) -> List[List[Point]]:
+        """
+        Flatten the path into a list of points for each subpath, as for
+        ``flatten_subpaths``, then simplify each polyline so that it stays
+        within ``epsilon`` of the flattened path.
+
+        This produces far fewer points than flattening at a tighter
+        tolerance, which suits plotters and cutters. See
+        :py:func:`simplify_polyline`. Raises ``ValueError`` if ``epsilon`` is
+        negative.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def flatten_subpaths(self, tolerance: float = ...) -> List[List[Point]]:
+        """
+        Flatten the path, returning a list of points for each subpath.
+
+        The polyline for a closed subpath ends with its starting point, so
+        each polyline can be drawn as it is. Raises ``ValueError`` unless
+        ``tolerance`` is positive.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def flatten_with_t(self, tolerance: float = ...) -> List[Tuple[Point, int, float]]:
+        """
+        Flatten the path, returning a list of ``(point, segment_index, t)``
+        tuples which record where each point lies on the original path.
+
+        ``segment_index`` is the index of a segment as yielded by
+        ``segments``, and ``t`` the parameter value on it, so that
+        ``segment.eval(t)`` gives the point. Each subpath begins with a point
+        at ``t == 0`` on its first segment; every other point ends a piece of
+        some segment, so the end of each segment has ``t == 1``. Curves are
+        subdivided until each piece lies within ``tolerance`` of a line.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def freeze(self) -> FrozenBezPath:
+        """
+        Returns an immutable, hashable copy of the path.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build a path from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @staticmethod
+    def from_drawable(drawable: Any, *penArgs: Any, **penKwargs: Any) -> List[BezPath]:
+        """Returns an *array of BezPath* from any object conforming to the pen protocol."""
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize a path from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_polygon(cls, points: PointArray, closed: bool = True) -> Self:
+        """
+        Build a path of straight lines through a list of points.
+
+        ``points`` may be a list of points or a NumPy array of shape
+        ``(n, 2)``. The path starts with a ``MoveTo`` to the first point and
+        has a ``LineTo`` to each of the others; if ``closed`` is true, it is
+        finished with a ``ClosePath``. An empty list gives an empty path.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_svg(cls, data: str) -> Self:
+        """
+        Try to parse a path from an SVG path string.
+
+        All SVG path commands are supported, in both absolute and relative
+        forms. Elliptical arc (``A``) commands are converted to cubic Béziers,
+        as a ``BezPath`` has no native arc element; this means that ``to_svg``
+        will not round-trip them as arcs.
+
+        Raises ``ValueError`` if the string is not a valid path.
+        """
+        ...
+
+    def get_seg(self, ix: int) -> Optional[PathSeg]:
+        """
+        Get the segment at the given element index.
+
+        If you need to access all segments, [`segments`] provides a better
+        API. This is intended for random access of specific elements, for clients
+        that require this specifically.
+
+        **note**: This returns the segment that ends at the provided element
+        index. In effect this means it is *1-indexed*: since no segment ends at
+        the first element (which is presumed to be a `MoveTo`) `get_seg(0)` will
+        always return `None`.
+        """
+        ...
+
+    def grid_fit(self, upm_grid: float, preserve_smooth: bool = True) -> Self:
+        """
+        Round the on-curve points of the path to a grid, adjusting the
+        off-curve points so that the character of the curves is kept.
+
+        Each on-curve point is rounded to the nearest multiple of ``upm_grid``.
+        Cubic handles move together with the on-curve point they belong to, so
+        their directions are unchanged: smooth connections stay smooth, and
+        extrema (on-curve points with horizontal or vertical handles) stay on
+        the on-curve points. The control point of a quadratic segment is
+        placed where the original tangents meet at the new end points.
+
+        If ``preserve_smooth`` is false, the off-curve points are then rounded
+        to the grid as well, at the cost of small changes in tangent direction.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def harmonize(self, level: str = "g1", angle_threshold: float = ...) -> Self:
+        """
+        Adjust the control points of the path so that its smooth joins are
+        truly smooth.
+
+        With ``level="g1"``, the handles on either side of each join are
+        rotated to share a tangent direction, keeping their lengths. Where
+        one side is a straight line, the handle of the curve on the other
+        side is aligned with the line. With ``level="g2"``, joins between two
+        cubic segments are then made curvature-continuous as well, by
+        sliding the on-curve point along its handles.
+
+        Joins which turn by more than ``angle_threshold`` radians (10° by
+        default) are considered corners and are left alone, as are joins
+        next to quadratic segments.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def hausdorff_distance(self, other: BezPath, accuracy: float = ...) -> float:
+        """
+        Computes the Hausdorff distance between this ``BezPath`` and another:
+        the greatest distance from a point on either path to the nearest
+        point on the other.
+
+        This is the usual measure of how far an approximation strays from
+        the original, such as a conversion of cubics to quadratics. Each path
+        is bisected only where the distance could exceed the greatest found so
+        far, and the result is within ``accuracy`` of the true distance.
+        Returns ``0.0`` if both paths are empty, and the largest float if only
+        one of them is. Raises ``ValueError`` if either path has non-finite
+        coordinates.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersect_line(
+        self,
+        line: Line,
+        sort: bool = True,
+        dedup: bool = True,
+    ) -> List[PathLineIntersection]:
+        """
+        Find the points where a line crosses the path.
+
+        Returns a list of :py:class:`PathLineIntersection` objects, giving
+        the index of the segment crossed, the parameter values on the segment
+        and on the line, and the point. If ``sort`` is true they are ordered
+        along the line, as for slicing a shape with a scanline; otherwise
+        they are in the order of the path's segments.
+
+        A line through a point where two segments join hits both of them;
+        if ``dedup`` is true, only the first of these hits is kept.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersect_path(
+        self,
+        other: BezPath,
+        accuracy: float = ...,
+    ) -> List[PathIntersection]:
+        """
+        Find the points where this path crosses or touches another.
+
+        Returns a list of :py:class:`PathIntersection` objects, sorted by
+        their position along this path. The meeting points are found as for
+        ``intersects``, to within ``accuracy``, which returns the same
+        information as plain tuples.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersections(self, line: Line) -> List[Point]:
+        """
+        Computes the intersections with a line as a list of ``Point`` objects.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersections_with_lines(self, lines: List[Line]) -> List[List[Point]]:
+        """
+        Computes the intersections with each of a list of lines.
+
+        Returns a list with one entry per input line, each a list of
+        ``Point`` objects ordered along that line. The path's segments are
+        prepared once and shared by all the lines, so this is much faster
+        than calling ``intersections`` repeatedly, e.g. for hatching.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersects(
+        self,
+        other: BezPath,
+        accuracy: float = ...,
+    ) -> List[Tuple[int, float, int, float, Point]]:
+        """
+        Find the points where this path crosses or touches another.
+
+        Returns a list of ``(index_a, t_a, index_b, t_b, point)`` tuples, one
+        for each meeting point, where ``index_a`` is the index of a segment of
+        this path (as yielded by ``segments``) and ``t_a`` the parameter value
+        on it, and likewise ``index_b`` and ``t_b`` for the other path. The
+        tuples are sorted by ``index_a`` and ``t_a``.
+
+        Crossings with lines are found exactly, and crossings between curves
+        by subdivision to within ``accuracy``. A crossing at a point where
+        segments join is only reported once.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_compatible(self, other: BezPath) -> bool:
+        """
+        Returns true if this path can be interpolated with ``other``.
+
+        Paths are compatible when they have the same number of elements and
+        each pair of corresponding elements is of the same kind.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_empty(self) -> bool:
+        """Returns `true` if the path contains no segments."""
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this path finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this path NaN?"""
+        ...
+
+    def lerp(self, other: BezPath, t: float) -> Self:
+        """
+        Interpolate between this path and ``other``, which must be
+        compatible (see ``is_compatible``).
+
+        Each point of the result is ``t`` of the way from the corresponding
+        point of this path to that of ``other``; ``t`` outside 0 to 1
+        extrapolates. Raises ``ValueError`` describing the first difference
+        if the paths are not compatible.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def line_to(self, pt: PointLike) -> None:
+        """Push a "line to" element onto the path."""
+        ...
+
+    def medial_axis(self, tolerance: float = ...) -> List[List[Point]]:
+        """
+        Approximate the medial axis (skeleton) of a closed shape.
+
+        The medial axis is the set of centers of the circles which touch
+        the outline in two or more places; for a stroked shape, it runs
+        down the middle of the strokes. It is returned as a list of
+        polylines, each a list of points, which run between the ends of the
+        axis and the points where it branches.
+
+        The outline is sampled at points at most ``tolerance`` apart (but
+        no more than 4000 points in all), and the result is accurate to
+        roughly the same degree. Small wiggles in the outline produce small
+        spurious branches.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def min_distance(self, other: BezPath, accuracy: float = ...) -> float:
+        """
+        Computes the minimum distance between this ``BezPath`` and another.
+
+        Every pair of segments whose bounding boxes are close enough to hold
+        a nearer pair of points than any found so far is measured with
+        ``PathSeg.min_dist``, so the result is within ``accuracy`` of the
+        true distance. Returns the largest float if either path is empty.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def move_to(self, pt: PointLike) -> None:
+        """Push a "move to" element onto the path."""
+        ...
+
+    def nearest(
+        self,
+        pt: PointLike,
+        accuracy: float = ...,
+        filter: Optional[Any] = None,
+    ) -> Optional[Tuple[int, Nearest]]:
+        """
+        Find the position on the path that is nearest to the given point.
+
+        Returns a tuple of the index of the nearest segment (as yielded by
+        ``segments``) and a :py:class:`Nearest` describing the position on
+        that segment, or ``None`` if no segment was considered.
+
+        If ``filter`` is given, it is called with each :py:class:`PathSeg`
+        and only segments for which it returns a true value are considered;
+        for example, ``filter=lambda s: s.as_line() is not None`` snaps only
+        to straight segments.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def nearest_many(
+        self,
+        points: PointArray,
+        accuracy: float = ...,
+        parallel: bool = False,
+    ) -> List[Optional[Tuple[int, Nearest]]]:
+        """
+        Find the positions on the path nearest to each of many points.
+
+        ``points`` may be a list of points or a NumPy array of shape
+        ``(n, 2)``. Returns a list with an entry for each point, as returned
+        by ``nearest``. Segments are visited in order of the distance to
+        their bounding boxes, and those which cannot be nearer than the best
+        found so far are skipped. If ``parallel`` is true, the points are
+        divided among several threads. The lock on the interpreter is
+        released while searching.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def nearest_on_subpath(
+        self,
+        pt: PointLike,
+        subpath_index: int,
+        accuracy: float = ...,
+        filter: Optional[Any] = None,
+    ) -> Optional[Tuple[int, Nearest]]:
+        """
+        Find the position on a single subpath that is nearest to the given point.
+
+        Subpaths are numbered from zero in the order they appear in the path.
+        The return value is as for ``nearest``; the segment index counts
+        segments across the whole path, not within the subpath.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def plot(self, ax: Any, **kwargs: Any) -> None:
+        """
+        Plot the path on a Matplot subplot which you supply
+
+        ::
+
+              import matplotlib.pyplot as plt
+              fig, ax = plt.subplots()
+              path.plot(ax)
+        """
+        ...
+
+    def pop(self) -> Optional[PathEl]:
+        """Removes the last [`PathEl`] from the path and returns it, or `None` if the path is empty."""
+        ...
+
+    def push(self, el: PathEl) -> None:
+        """Push a generic path element onto the path."""
+        ...
+
+    def quad_to(self, pt1: PointLike, pt2: PointLike) -> None:
+        """Push a "quad to" element onto the path."""
+        ...
+
+    def raycast(self, ray: Ray) -> Optional[RayHit]:
+        """
+        Cast a :py:class:`Ray` at the path, returning the first place it hits
+        as a :py:class:`RayHit`, or ``None`` if it misses.
+
+        Only segments whose bounding boxes the ray passes through are
+        examined, nearest first, and the search stops once no nearer hit is
+        possible.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def raycast_all(self, ray: Ray) -> List[RayHit]:
+        """
+        Cast a :py:class:`Ray` at the path, returning every place it hits as
+        a list of :py:class:`RayHit` objects, ordered by distance. A ray
+        through the join between two segments hits only the first of them.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def regular_polygon(
+        cls,
+        center: PointLike,
+        n: int,
+        radius: float,
+        rotation: float = 0.0,
+    ) -> Self:
+        """
+        Build a closed regular polygon with ``n`` sides.
+
+        The vertices lie on a circle of the given ``radius`` around
+        ``center``. The first vertex is at angle ``rotation`` (in radians,
+        measured from the positive x axis, like the angles of an ``Arc``),
+        and the others follow in the direction of increasing angle.
+
+        Raises ``ValueError`` if ``n`` is less than 3.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def remove_overlaps(self) -> Self:
+        """
+        Merge the overlapping subpaths of the path into their union.
+
+        The path is filled with the nonzero rule, and open subpaths are
+        treated as closed. Segments are split where they cross and at their
+        extrema, and the pieces on the outline of the union are joined back
+        into closed subpaths. Curves are kept as curves, and segments which
+        are not crossed come through unchanged. Subpaths which lie inside
+        others with the same direction are removed.
+
+        The result keeps the overall direction of the path: if its area is
+        positive, outer contours run anticlockwise in a y-up coordinate
+        system and holes clockwise; otherwise the other way round.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def resample(self, n: int, accuracy: float = ...) -> List[Point]:
+        """
+        Returns ``n`` points spaced equally by arc length along the whole
+        path, including its start and end points.
+
+        Distances are measured across all subpaths as if they were joined
+        end to end, so samples are not aligned to the start of each
+        subpath. Closing segments count towards the length.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def resample_by_distance(self, step: float, accuracy: float = ...) -> List[Point]:
+        """
+        Returns points spaced ``step`` apart by arc length along the whole
+        path, starting at its start point.
+
+        As for ``resample``, distances are measured across all subpaths.
+        The end point of the path is only included if the length of the
+        path is a multiple of ``step``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def reverse_subpaths(self) -> Self:
+        """Returns a new path with the winding direction of all subpaths reversed."""
+        ...
+
+    def rotational_symmetry(self, tolerance: float = ...) -> Tuple[int, Point]:
+        """
+        Find the rotational symmetry of the path.
+
+        Returns an ``(order, center)`` tuple, where ``order`` is the largest
+        ``n`` such that rotating the path by a ``1/n`` turn about ``center``
+        leaves it unchanged to within ``tolerance`` (as measured by the
+        Hausdorff distance), and ``center`` is the length-weighted centroid
+        of the outline. The order is 1 if the path has no rotational
+        symmetry. Raises ``ValueError`` if the path has no length.
+
+        ::
+
+           order, center = ornament.rotational_symmetry(0.5)
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates of the path, either to ``ndigits`` decimal
+        places (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers, as font
+        formats require.
+
+        Every point is rounded independently and the elements of the path are
+        kept as they are. To keep the directions of curve handles while
+        snapping, use ``grid_fit`` instead.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_corners_smooth(self, radius: float, smoothness: float = 0.6) -> Self:
+        """
+        Round the corners between straight lines with curvature-continuous
+        ("squircle") corners.
+
+        Each corner is a circular arc of the given ``radius``, joined to the
+        lines on either side by transition curves whose curvature rises
+        smoothly from zero, as in the continuous corners used by iOS and
+        Figma. ``smoothness`` runs from 0, which gives plain circular fillets,
+        to 1, where the corner has no circular part at all; larger values
+        extend the corner further along the lines. The radius is reduced
+        where a corner would not fit in half the length of its lines.
+        Corners next to curved segments are left as they are.
+
+        To make a smoothly rounded rectangle, use
+        ``rect.to_path().round_corners_smooth(radius, smoothness)``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def scale_path(self, scale_factor: float) -> Self: ...
+
+    def segments(self) -> SegmentIterator: ...
+
+    @classmethod
+    def star(
+        cls,
+        center: PointLike,
+        n: int,
+        r_outer: float,
+        r_inner: float,
+        rotation: float = 0.0,
+    ) -> Self:
+        """
+        Build a closed star with ``n`` points.
+
+        The path alternates between ``n`` outer vertices on a circle of
+        radius ``r_outer`` around ``center`` and ``n`` inner vertices on a
+        circle of radius ``r_inner``, halfway between them in angle. The
+        first outer vertex is at angle ``rotation``, as for
+        ``regular_polygon``.
+
+        Raises ``ValueError`` if ``n`` is less than 2.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def stroke_variable(self, width: Any, tolerance: float = ...) -> Self:
+        """
+        Stroke the path with a width which varies along its length,
+        returning the outline of the stroke.
+
+        ``width`` may be a function, which is called with the position along
+        each subpath as a fraction of its length (0 at the start, 1 at the
+        end) and returns the full width of the stroke there; a list of
+        widths, which are spaced evenly along each subpath and interpolated
+        linearly; or a single number for a constant width.
+
+        Open subpaths produce a single closed outline with square-cut ends;
+        closed subpaths produce an outer and an inner outline. The result is
+        made of straight line segments, flattened and simplified to within
+        ``tolerance``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def subpath_directions(self) -> List[Tuple[Optional[str], int]]:
+        """
+        The direction and nesting depth of each subpath.
+
+        Returns a ``(direction, depth)`` tuple for each subpath. The direction
+        is ``"anticlockwise"`` or ``"clockwise"`` in a y-up coordinate system,
+        as used by font formats (on a y-down screen they appear the other way
+        round), or ``None`` if the subpath encloses no area. Open subpaths are
+        treated as closed. The depth is the number of other subpaths which
+        enclose the subpath, so outer contours have even depths and holes odd
+        depths.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def tessellate(self, tolerance: float = ...) -> Tuple[Any, Any, Any]:
+        """
+        Triangulate the region enclosed by the path, for rendering on a GPU.
+
+        The path is flattened to within ``tolerance`` and filled with the
+        nonzero rule, with open subpaths treated as closed. Overlapping
+        subpaths are merged first, so no point is covered by two triangles.
+
+        Returns a tuple of three ``array.array`` objects, which support the
+        buffer protocol and can be uploaded directly: the vertices as
+        ``float32`` values ``x0, y0, x1, y1, ...``; the triangles as
+        ``uint32`` vertex indices, three per triangle, each wound
+        anticlockwise in a y-up coordinate system; and for each vertex, the
+        ``uint32`` ID of the contour of the merged outline it lies on.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_arcs(self, tolerance: float = ...) -> List[List[Union[Arc, Line]]]:
+        """
+        Approximate the path by circular arcs and lines.
+
+        Returns a list with an entry for each subpath, which is a list of
+        ``Arc`` and ``Line`` objects joined end to end, including the line
+        which closes a closed subpath. Lines are kept as they are, and curves
+        are replaced by arcs as described in ``CubicBez.to_biarcs``, staying
+        within ``tolerance`` of the original path.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert the path to plain Python data with the same structure as the
+        JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize the path to a JSON string: a list of path elements, as
+        described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_matplot(self) -> Any: ...
+
+    def to_monotonic(self) -> Self:
+        """
+        Split every curve in the path at its extrema.
+
+        Returns a new path with the same subpaths, in which every quadratic
+        and cubic segment is monotonic in both x and y. The split points
+        become on-curve points; lines are already monotonic and are left
+        alone.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_svg(
+        self,
+        precision: Optional[int] = None,
+        relative: bool = False,
+        shorthand: bool = False,
+    ) -> str:
+        """
+        Convert the path to an SVG path string representation.
+
+        By default, coordinates are written at full precision with absolute
+        commands. To produce a shorter string, ``precision`` gives the number
+        of decimal places to write, ``relative`` uses relative (lowercase)
+        commands, and ``shorthand`` uses the ``S`` and ``T`` commands where a
+        control point is the reflection of the one before it. With relative
+        commands, coordinates are rounded before the offsets between them are
+        taken, so rounding errors do not build up along the path.
+
+        Note that the ``precision``, ``relative`` and ``shorthand`` arguments
+        are not in original kurbo
+        """
+        ...
+
+    def truncate(self, len: int) -> None:
+        """Shorten the path, keeping the first `len`` elements."""
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class Circle:
+    """A circle."""
+
+    def __init__(self, center: PointLike, radius: float) -> None:
+        """Create a new `Circle`."""
+        ...
+
+    def __add__(self, v: Vec2Like) -> Self: ...
+
+    def __contains__(self, pt: PointLike) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, v: Vec2Like) -> Self: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    @property
+    def center(self) -> Point: ...
+
+    @center.setter
+    def center(self, center: PointLike) -> None: ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersect_circle(
+        self,
+        other: Circle,
+        accuracy: float = ...,
+    ) -> Tuple[str, List[Point]]:
+        """
+        Find where this circle meets another.
+
+        Returns a tuple of a string describing how the circles meet and a
+        list of the points where they do. The string is ``"disjoint"`` if
+        each circle is outside the other, ``"contained"`` if one is inside
+        the other, ``"tangent"`` if they touch at one point, ``"intersecting"``
+        if they cross at two points, and ``"coincident"`` if they are the same
+        circle. Only tangent and intersecting circles have points. Distances
+        within ``accuracy`` of each other are treated as equal, so that
+        nearly tangent circles are reported as tangent.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersect_line(self, line: Line) -> List[Tuple[Point, float, float]]:
+        """
+        Find where a line crosses the circle.
+
+        Returns a list of up to two ``(point, t, angle)`` tuples, in order
+        along the line, where ``t`` is the parameter of the point on the line
+        and ``angle`` is its angle around the circle in radians, measured from
+        the positive x axis as in :py:meth:`segment`. Only the points within
+        the line segment are returned; to intersect an infinite line, extend
+        the line first. A line which just touches the circle gives a single
+        point.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    @property
+    def radius(self) -> float: ...
+
+    @radius.setter
+    def radius(self, radius: float) -> None: ...
+
+    def segment(
+        self,
+        inner_radius: float,
+        start_angle: float,
+        sweep_angle: float,
+    ) -> CircleSegment:
+        """Create a [`CircleSegment`] by cutting out parts of this circle."""
+        ...
+
+    def tangent_lines(self, pt: PointLike) -> List[Tuple[Line, Point]]:
+        """
+        The lines from ``pt`` which touch the circle.
+
+        Returns a list of ``(line, point)`` tuples, where ``line`` runs from
+        ``pt`` to ``point``, the point at which it touches the circle. There are
+        two tangents from a point outside the circle, none from a point inside it,
+        and a single zero-length line from a point on it.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class CircleSegment:
+    """
+    A segment of a circle.
+
+    If `inner_radius > 0`, then the shape will be a doughnut segment.
+    """
+
+    def __init__(
+        self,
+        center: PointLike,
+        outer_radius: float,
+        inner_radius: float,
+        start_angle: float,
+        sweep_angle: float,
+    ) -> None:
+        """Create a `CircleSegment` out of its constituent parts."""
+        ...
+
+    def __add__(self, v: Vec2Like) -> CircleSegment: ...
+
+    def __contains__(self, pt: PointLike) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, v: Vec2Like) -> CircleSegment: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    @property
+    def center(self) -> Point: ...
+
+    @center.setter
+    def center(self, center: PointLike) -> None: ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @property
+    def inner_radius(self) -> float: ...
+
+    @inner_radius.setter
+    def inner_radius(self, inner_radius: float) -> None: ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    @property
+    def outer_radius(self) -> float: ...
+
+    @outer_radius.setter
+    def outer_radius(self, outer_radius: float) -> None: ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    @property
+    def start_angle(self) -> float: ...
+
+    @start_angle.setter
+    def start_angle(self, start_angle: float) -> None: ...
+
+    @property
+    def sweep_angle(self) -> float: ...
+
+    @sweep_angle.setter
+    def sweep_angle(self, sweep_angle: float) -> None: ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class ConstPoint:
+    """A single ConstPoint segment."""
+
+    def __init__(self, p: PointLike) -> None:
+        """
+        Create a `ConstPoint` which is always at the given point.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def arclen(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        The arc length of the curve.
+
+        The result is accurate to the given accuracy (subject to
+        roundoff errors for ridiculously low values). Compute time
+        may vary with accuracy, if the curve needs to be subdivided.
+
+        If ``return_error`` is true, returns a ``(length, error)`` tuple,
+        where ``error`` estimates the error actually achieved.
+        """
+        ...
+
+    def deriv(self) -> ConstPoint:
+        """
+        The derivative of the curve.
+
+        Note that the type of the return value is somewhat inaccurate, as
+        the derivative of a curve (mapping of param to point) is a mapping
+        of param to vector. We choose to accept this rather than have a
+        more complex type scheme.
+        """
+        ...
+
+    def end(self) -> Point:
+        """The end point."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """
+        Evaluate the curve at parameter `t`.
+
+        Generally `t` is in the range [0..1].
+        """
+        ...
+
+    def inv_arclen(self, arclen: float, accuracy: float = ...) -> float:
+        """
+        Solve for the parameter that has the given arc length from the start.
+
+        This implementation uses the IPT method, as provided by
+        [`common::solve_itp`]. This is as robust as bisection but
+        typically converges faster. In addition, the method takes
+        care to compute arc lengths of increasingly smaller segments
+        of the curve, as that is likely faster than repeatedly
+        computing the arc length of the segment starting at t=0.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def start(self) -> Point:
+        """The start point."""
+        ...
+
+    def subdivide(self) -> Tuple[Self, Self]:
+        """Subdivide into (roughly) halves."""
+        ...
+
+    def subsegment(self, t0_t1: Tuple[float, float]) -> Self:
+        """Get a subsegment of the curve for the given parameter range."""
+        ...
+
+
+class CubicBez:
+    """A single cubic Bézier segment."""
+
+    def __init__(
+        self,
+        p0: PointLike,
+        p1: PointLike,
+        p2: PointLike,
+        p3: PointLike,
+    ) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    def approx_eq(self, other: CubicBez, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def arclen(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        The arc length of the curve.
+
+        The result is accurate to the given accuracy (subject to
+        roundoff errors for ridiculously low values). Compute time
+        may vary with accuracy, if the curve needs to be subdivided.
+
+        If ``return_error`` is true, returns a ``(length, error)`` tuple,
+        where ``error`` estimates the error actually achieved.
+        """
+        ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the curve in the range (0..1)."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def curvature(self, t: float) -> float: ...
+
+    def curvature_extrema(self) -> List[float]:
+        """
+        Find the parameter values strictly inside the curve at which
+        the absolute curvature is locally greatest, in increasing
+        order.
+
+        These are the tightest points of bends, and the positions of
+        cusps, where the curvature is infinite.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def deriv(self) -> QuadBez:
+        """
+        The derivative of the curve.
+
+        Note that the type of the return value is somewhat inaccurate, as
+        the derivative of a curve (mapping of param to point) is a mapping
+        of param to vector. We choose to accept this rather than have a
+        more complex type scheme.
+        """
+        ...
+
+    def end(self) -> Point:
+        """The end point."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """
+        Evaluate the curve at parameter `t`.
+
+        Generally `t` is in the range [0..1].
+        """
+        ...
+
+    def extrema(self) -> List[float]:
+        """
+        Compute the extrema of the curve.
+
+        Only extrema within the interior of the curve count.
+
+        The extrema should be reported in increasing parameter order.
+        """
+        ...
+
+    def extrema_ranges(self) -> List[Tuple[float, float]]:
+        """Return parameter ranges, each of which is monotonic within the range."""
+        ...
+
+    def flatness(self) -> float:
+        """
+        The greatest distance of the control points from the chord (the line
+        segment from ``p0`` to ``p3``).
+
+        The curve lies entirely within this distance of the chord, so this
+        is an upper bound on how far it strays from a straight line.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def inflections(self) -> List[float]:
+        """
+        Determine the inflection points.
+
+        Return value is t parameter for the inflection points of the curve segment.
+        There are a maximum of two for a cubic Bézier.
+
+        See <https://www.caffeineowl.com/graphics/2d/vectorial/cubic-inflexion.html>
+        for the theory.
+        """
+        ...
+
+    def inv_arclen(self, arclen: float, accuracy: float = ...) -> float:
+        """
+        Solve for the parameter that has the given arc length from the start.
+
+        This implementation uses the IPT method, as provided by
+        [`common::solve_itp`]. This is as robust as bisection but
+        typically converges faster. In addition, the method takes
+        care to compute arc lengths of increasingly smaller segments
+        of the curve, as that is likely faster than repeatedly
+        computing the arc length of the segment starting at t=0.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_linear(self, tolerance: float = ...) -> bool:
+        """
+        Whether the cubic is a straight line to within ``tolerance``, that is,
+        whether it could be replaced by a ``Line`` from ``p0`` to ``p3``
+        without moving any part of it further than ``tolerance``.
+
+        A curve whose control points lie on the line through its ends but
+        beyond them doubles back on itself, and is not considered linear.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def nearest(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+        return_error: bool = False,
+    ) -> Any:
+        """
+        Find the position on the curve that is nearest to the given point.
+
+        This returns a [`Nearest`] struct that contains information about the position.
+
+        If ``return_error`` is true, returns a ``(nearest, error)`` tuple,
+        where ``error`` estimates the error in the distance to the curve.
+        """
+        ...
+
+    @property
+    def p0(self) -> Point: ...
+
+    @p0.setter
+    def p0(self, p0: PointLike) -> None: ...
+
+    @property
+    def p1(self) -> Point: ...
+
+    @p1.setter
+    def p1(self, p1: PointLike) -> None: ...
+
+    @property
+    def p2(self) -> Point: ...
+
+    @p2.setter
+    def p2(self, p2: PointLike) -> None: ...
+
+    @property
+    def p3(self) -> Point: ...
+
+    @p3.setter
+    def p3(self, p3: PointLike) -> None: ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def project(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+    ) -> Tuple[float, Point, float]:
+        """
+        Project a point onto the curve.
+
+        Returns a ``(t, point, distance)`` tuple of the parameter value of
+        the nearest position on the curve, the point there, and its
+        distance from the given point.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def signed_area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def split_at_extrema(self) -> List[CubicBez]:
+        """
+        Split the curve at its extrema.
+
+        Returns a list of pieces, one for each of the ranges given by
+        ``extrema_ranges``, each of which is monotonic in both x and y.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def start(self) -> Point:
+        """The start point."""
+        ...
+
+    def subdivide(self) -> Tuple[Self, Self]:
+        """Subdivide into (roughly) halves."""
+        ...
+
+    def subsegment(self, t0_t1: Tuple[float, float]) -> Self:
+        """Get a subsegment of the curve for the given parameter range."""
+        ...
+
+    def to_biarcs(self, tolerance: float = ...) -> List[Union[Arc, Line]]:
+        """
+        Approximate the cubic by circular arcs.
+
+        Returns a list of ``Arc`` objects, joined end to end with matching
+        tangents, which stay within ``tolerance`` of the cubic. The cubic is
+        split at its inflections and fitted with pairs of arcs ("biarcs"),
+        which are subdivided further until they are close enough. Parts of
+        the cubic which are straight to within ``tolerance`` become ``Line``
+        objects instead, as an arc cannot have an infinite radius.
+
+        This is useful for output formats which only support lines and arcs,
+        such as G-code for CNC machines.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def to_quads(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to quadratic Béziers.
+
+        Returns a list of tuples containing the start and end parameter in the cubic of each quadratic
+        segment, along with the quadratic.
+
+        Note that the resulting quadratic Béziers are not in general G1 continuous;
+        they are optimized for minimizing distance error.
+
+        This iterator will always produce at least one :py:class:`QuadBez`.
+
+        If ``return_error`` is true, returns a ``(quads, error)`` tuple, where
+        ``error`` estimates the greatest distance between the quadratics and
+        the cubic.
+        """
+        ...
+
+    def try_approx_quad(self, tolerance: float = ...) -> Optional[QuadBez]:
+        """
+        Approximate the cubic with a single quadratic Bézier, if that is
+        possible to within ``tolerance``.
+
+        The control point of the quadratic is the average of the points where
+        the cubic's tangents at either end would put it. Returns ``None`` if
+        the distance between the two curves might exceed ``tolerance``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class Ellipse:
+    """A Ellipse."""
+
+    def __init__(self, center: PointLike, radii: Vec2Like, x_rotation: float) -> None:
+        """Create a new `Ellipse`."""
+        ...
+
+    def __add__(self, rhs: Vec2Like) -> Self: ...
+
+    def __contains__(self, pt: PointLike) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, rhs: Vec2Like) -> Self: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    @property
+    def center(self) -> Point: ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    @classmethod
+    def from_affine(cls, affine: Affine) -> Self:
+        """Create an ellipse from an affine transformation of the unit circle."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_rect(cls, rect: Rect) -> Self:
+        """
+        Returns the largest ellipse that can be bounded by this [`Rect`].
+
+        This uses the absolute width and height of the rectangle.
+
+        This ellipse is always axis-aligned; to apply rotation you can call
+        [`with_rotation`] with the result.
+
+        [`with_rotation`]: Ellipse::with_rotation    #[pyo3(text_signature = "(cls, p0, p1)")]
+        """
+        ...
+
+    def intersect_line(self, line: Line) -> List[Tuple[Point, float, float]]:
+        """
+        Find where a line crosses the ellipse.
+
+        Returns a list of up to two ``(point, t, angle)`` tuples, in order
+        along the line, where ``t`` is the parameter of the point on the line
+        and ``angle`` is its parameter on the ellipse: the point is at
+        ``(rx * cos(angle), ry * sin(angle))``, rotated by the ellipse's
+        rotation and moved to its center, as for :py:class:`Arc` angles.
+        Only the points within the line segment are returned. The line is
+        transformed so that the ellipse becomes the unit circle, intersected
+        with it, and the results transformed back.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    @property
+    def radii(self) -> Vec2: ...
+
+    def radii_and_rotation(self) -> Tuple[Vec2, float]:
+        """
+        Returns the radii and the rotation of this ellipse.
+
+        Equivalent to `(self.radii(), self.rotation())` but more efficient.
+        """
+        ...
+
+    @property
+    def rotation(self) -> float: ...
+
+    def tangent_lines(self, pt: PointLike) -> List[Tuple[Line, Point]]:
+        """
+        The lines from ``pt`` which touch the ellipse.
+
+        Returns a list of ``(line, point)`` tuples, where ``line`` runs from
+        ``pt`` to ``point``, the point at which it touches the ellipse. There are
+        two tangents from a point outside the ellipse, none from a point inside it,
+        and a single zero-length line from a point on it.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+    def with_center(self, new_center: PointLike) -> Self:
+        """Create a new `Ellipse` centered on the provided point."""
+        ...
+
+    def with_radii(self, new_radii: Vec2Like) -> Self:
+        """Create a new `Ellipse` with the provided radii."""
+        ...
+
+    def with_rotation(self, rotation: float) -> Self:
+        """
+        Create a new `Ellipse`, with the rotation replaced by `rotation`
+        radians.
+
+        The rotation is clockwise, for a y-down coordinate system. For more
+        on rotation, See [`Affine::rotate`].
+        """
+        ...
+
+
+class FrozenBezPath:
+    """
+    An immutable Bézier path.
+
+    A ``BezPath`` can be modified in place, so it cannot be hashed, and
+    sharing it between threads means locking it. A ``FrozenBezPath`` holds
+    a snapshot of a path which never changes: it is hashable, compares equal
+    to another frozen path with the same elements, and so can be used as a
+    dictionary or cache key.
+
+    ::
+
+       from kurbopy import BezPath, FrozenBezPath
+       path = BezPath.from_svg("M0,0 L10,0 L10,10 Z")
+       frozen = path.freeze()  # or FrozenBezPath(path)
+       cache = {frozen: frozen.area()}
+       editable = frozen.thaw()
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(self, path: Optional[BezPath] = None) -> None: ...
+
+    def __bool__(self) -> bool: ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __hash__(self) -> int: ...
+
+    def __len__(self) -> int: ...
+
+    def __ne__(self, other: object) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the path."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def control_box(self) -> Rect:
+        """Returns a rectangle that conservatively encloses the path."""
+        ...
+
+    def elements(self) -> ElementIterator: ...
+
+    def get_seg(self, ix: int) -> Optional[PathSeg]:
+        """
+        Returns the segment at the given element index, as with
+        ``BezPath.get_seg``.
+        """
+        ...
+
+    def is_empty(self) -> bool:
+        """Returns `true` if the path contains no segments."""
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this path finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this path NaN?"""
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def segments(self) -> SegmentIterator: ...
+
+    def thaw(self) -> BezPath:
+        """Returns a new, mutable ``BezPath`` with the same elements."""
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def to_svg(
+        self,
+        precision: Optional[int] = None,
+        relative: bool = False,
+        shorthand: bool = False,
+    ) -> str:
+        """
+        Convert the path to an SVG path string representation, with the same
+        options as ``BezPath.to_svg``.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class Insets:
+    """
+    Insets from the edges of a rectangle.
+
+
+    The inset value for each edge can be thought of as a delta computed from
+    the center of the rect to that edge. For instance, with an inset of `2.0` on
+    the x-axis, a rectangle with the origin `(0.0, 0.0)` with that inset added
+    will have the new origin at `(-2.0, 0.0)`.
+
+    Put alternatively, a positive inset represents increased distance from center,
+    and a negative inset represents decreased distance from center.
+
+    ## Examples
+
+    Positive insets added to a [`Rect`] produce a larger [`Rect`]:
+    ```
+    rect = Rect.from_origin_size(Point(0., 0.,), Size(10., 10.,))
+    insets = Insets.uniform_xy(3., 0.)
+
+    inset_rect = rect + insets
+    assert inset_rect.width() == 16.0, "10.0 + 3.0 × 2"
+    assert inset_rect.x0 == -3.0
+    ```
+
+    Negative insets added to a [`Rect`] produce a smaller [`Rect`]:
+
+    ```
+    rect = Rect.from_origin_size(Point(0., 0.,), Size(10., 10.,))
+    insets = Insets.uniform_xy(-3., 0.)
+
+    inset_rect = rect + insets
+    assert inset_rect.width() == 4.0, "10.0 - 3.0 × 2"
+    assert inset_rect.x0 == 3.0
+    ```
+
+    [`Insets`] operate on the absolute rectangle [`Rect.abs`], and so ignore
+    existing negative widths and heights.
+
+    ```
+    rect = Rect(7., 11., 0., 0.)
+    insets = Insets.uniform_xy(0., 1.)
+
+    assert rect.width() == -7.0
+
+    inset_rect = rect + insets
+    assert inset_rect.width() == 7.0
+    assert inset_rect.x0 == 0.0
+    assert inset_rect.height() == 13.0
+    ```
+
+    The width and height of an inset operation can still be negative if the
+    [`Insets`]' dimensions are greater than the dimensions of the original [`Rect`].
+
+    ```
+    rect = Rect(Point(0., 0.), Point(3., 5.))
+    insets = Insets.uniform_xy(0., 7.)
+
+    inset_rect = rect - insets
+    assert inset_rect.height() == -9., "5 - 7 × 2"
+    ```
+
+    `Rect - Rect = Insets`:
+
+
+    ```
+    rect = Rect(Point(0., 0.), Point(5., 11.))
+    insets = Insets.uniform_xy(1., 7.,)
+
+    inset_rect = rect + insets
+    insets2 = inset_rect - rect
+
+    assert insets2.x0 == insets.x0
+    assert insets2.y1 == insets.y1
+    assert insets2.x_value() == insets.x_value()
+    assert insets2.y_value() == insets.y_value()
+    ```
+
+    `Insets` can also be added to and subtracted from each other, scaled by
+    a number, and added to a [`Size`] to give the size of a rectangle of that
+    size once the insets are applied (these are not in original kurbo):
+
+    ```
+    padding = Insets.uniform(2.) + Insets(1., 0., 1., 0.) * 2
+    assert padding.x0 == 4.0
+    assert (padding + Size(10., 10.)).width == 18.0
+    ```
+    """
+
+    def __init__(self, x0: float, y0: float, x1: float, y1: float) -> None:
+        """Create a new `Insets`."""
+        ...
+
+    @classmethod
+    def ZERO(cls) -> Self:
+        """Zeroed insets"""
+        ...
+
+    def __add__(self, rhs: Any) -> Any: ...
+
+    def __mul__(self, rhs: float) -> Self: ...
+
+    def __neg__(self) -> Self: ...
+
+    def __repr__(self) -> str: ...
+
+    def __rmul__(self, lhs: float) -> Self: ...
+
+    def __sub__(self, rhs: Any) -> Any: ...
+
+    def __truediv__(self, rhs: float) -> Self: ...
+
+    def are_nonnegative(self) -> bool:
+        """Return `true` iff all values are nonnegative."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def nonnegative(self) -> Self:
+        """
+        Return new `Insets` with all negative values replaced with `0.0`.
+
+        This is provided as a convenience for applications where negative insets
+        are not meaningful.
+        """
+        ...
+
+    def size(self) -> Size:
+        """
+        Returns the total delta represented by these insets as a [`Size`].
+
+        This is equivalent to creating a [`Size`] from the values returned by
+        [`x_value`] and [`y_value`].
+
+        This function may return a size with negative values.
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def uniform(cls, value: float) -> Self:
+        """New uniform insets."""
+        ...
+
+    @classmethod
+    def uniform_xy(cls, x_value: float, y_value: float) -> Self:
+        """New insets with uniform values along each axis."""
+        ...
+
+    @property
+    def x0(self) -> float: ...
+
+    @x0.setter
+    def x0(self, x0: float) -> None: ...
+
+    @property
+    def x1(self) -> float: ...
+
+    @x1.setter
+    def x1(self, x1: float) -> None: ...
+
+    def x_value(self) -> float:
+        """
+        The total delta on the x-axis represented by these insets.
+
+        # Examples
+
+        ```
+        insets = Insets.uniform_xy(3., 8.)
+        assert insets.x_value() == 6
+
+        insets = Insets(5., 0., -12., 0.,)
+        assert insets.x_value() == -7
+        ```
+        """
+        ...
+
+    @property
+    def y0(self) -> float: ...
+
+    @y0.setter
+    def y0(self, y0: float) -> None: ...
+
+    @property
+    def y1(self) -> float: ...
+
+    @y1.setter
+    def y1(self, y1: float) -> None: ...
+
+    def y_value(self) -> float:
+        """
+        The total delta on the y-axis represented by these insets.
+
+        # Examples
+
+        ```
+        insets = Insets.uniform_xy(3., 7.)
+        assert insets.y_value() == 14
+
+        insets = Insets(5., 10., -12., 4.,)
+        assert insets.y_value() == 14
+        ```
+        """
+        ...
+
+
+class Line:
+    """A single line."""
+
+    def __init__(self, p0: PointLike, p1: PointLike) -> None: ...
+
+    def __add__(self, rhs: Vec2Like) -> Line: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, rhs: Vec2Like) -> Line: ...
+
+    def approx_eq(self, other: Line, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def arclen(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        The arc length of the curve.
+
+        The result is accurate to the given accuracy (subject to
+        roundoff errors for ridiculously low values). Compute time
+        may vary with accuracy, if the curve needs to be subdivided.
+
+        If ``return_error`` is true, returns a ``(length, error)`` tuple,
+        where ``error`` estimates the error actually achieved.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the curve in the range (0..1)."""
+        ...
+
+    def clip(self, rect: Rect) -> Optional[Line]:
+        """
+        Clip the line to a rectangle, returning the part of the line inside
+        the rectangle (including its edges), or ``None`` if the line lies
+        entirely outside it.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def crossing_point(self, other: Line) -> Optional[Point]:
+        """Computes the point where two lines, if extended to infinity, would cross"""
+        ...
+
+    def curvature(self, t: float) -> float: ...
+
+    def curvature_extrema(self) -> List[float]:
+        """
+        Find the parameter values strictly inside the curve at which
+        the absolute curvature is locally greatest, in increasing
+        order.
+
+        These are the tightest points of bends, and the positions of
+        cusps, where the curvature is infinite.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def deriv(self) -> ConstPoint:
+        """
+        The derivative of the curve.
+
+        Note that the type of the return value is somewhat inaccurate, as
+        the derivative of a curve (mapping of param to point) is a mapping
+        of param to vector. We choose to accept this rather than have a
+        more complex type scheme.
+        """
+        ...
+
+    def end(self) -> Point:
+        """The end point."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """
+        Evaluate the curve at parameter `t`.
+
+        Generally `t` is in the range [0..1].
+        """
+        ...
+
+    def extend(self, amount: float) -> Self:
+        """
+        A line lengthened by ``amount`` at each end, keeping its direction.
+        Negative amounts shorten the line.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def extrema(self) -> List[float]:
+        """
+        Compute the extrema of the curve.
+
+        Only extrema within the interior of the curve count.
+
+        The extrema should be reported in increasing parameter order.
+        """
+        ...
+
+    def extrema_ranges(self) -> List[Tuple[float, float]]:
+        """Return parameter ranges, each of which is monotonic within the range."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def inv_arclen(self, arclen: float, accuracy: float = ...) -> float:
+        """
+        Solve for the parameter that has the given arc length from the start.
+
+        This implementation uses the IPT method, as provided by
+        [`common::solve_itp`]. This is as robust as bisection but
+        typically converges faster. In addition, the method takes
+        care to compute arc lengths of increasingly smaller segments
+        of the curve, as that is likely faster than repeatedly
+        computing the arc length of the segment starting at t=0.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def length(self) -> float:
+        """The length of the line."""
+        ...
+
+    def nearest(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+        return_error: bool = False,
+    ) -> Any:
+        """
+        Find the position on the curve that is nearest to the given point.
+
+        This returns a [`Nearest`] struct that contains information about the position.
+
+        If ``return_error`` is true, returns a ``(nearest, error)`` tuple,
+        where ``error`` estimates the error in the distance to the curve.
+        """
+        ...
+
+    def offset(self, d: float) -> Self:
+        """
+        A line parallel to this one, at a signed distance ``d``. Positive
+        distances are to the left of the line's direction in a y-up
+        coordinate system (to the right in y-down).
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @property
+    def p0(self) -> Point: ...
+
+    @p0.setter
+    def p0(self, p0: PointLike) -> None: ...
+
+    @property
+    def p1(self) -> Point: ...
+
+    @p1.setter
+    def p1(self, p1: PointLike) -> None: ...
+
+    def perpendicular_at(self, t: float, length: Optional[float] = None) -> Self:
+        """
+        A line perpendicular to this one, starting at the point at parameter
+        ``t`` and heading in the direction of a positive ``offset``. Its length
+        defaults to the length of this line.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def project(
+        self,
+        point: PointLike,
+        clamp: bool = True,
+    ) -> Tuple[float, Point, float]:
+        """
+        Project a point onto the line.
+
+        Returns a ``(t, point, distance)`` tuple of the parameter value of
+        the nearest position on the line, the point there, and its distance
+        from the given point. If ``clamp`` is false, the line is treated as
+        extending for ever in both directions, so ``t`` may fall outside
+        ``[0, 1]``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def signed_area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def split_at_extrema(self) -> List[Line]:
+        """
+        Split the curve at its extrema.
+
+        Returns a list of pieces, one for each of the ranges given by
+        ``extrema_ranges``, each of which is monotonic in both x and y.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def start(self) -> Point:
+        """The start point."""
+        ...
+
+    def subdivide(self) -> Tuple[Self, Self]:
+        """Subdivide into (roughly) halves."""
+        ...
+
+    def subsegment(self, t0_t1: Tuple[float, float]) -> Self:
+        """Get a subsegment of the curve for the given parameter range."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+
+class Line2:
+    """
+    An infinite straight line, through ``point`` in the direction of
+    ``direction``.
+
+    Unlike a :py:class:`Line`, which is a segment between two points, a
+    ``Line2`` goes on for ever both ways. Positions along it are given by a
+    parameter ``t``, so that ``eval(t)`` is ``point + direction * t``.
+
+    ::
+
+       from kurbopy import Line2, Rect
+       axis = Line2.from_points((0, 0), (1, 1))
+       axis.side_of((0, 5))  # 1: to the left
+       axis.intersect(Rect(2, 0, 4, 10).to_path())  # [Point(2, 2), Point(4, 4)]
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(self, point: PointLike, direction: Vec2Like) -> None:
+        """
+        Create a line through ``point`` in the direction of ``direction``.
+        Raises ``ValueError`` if the direction is zero.
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def direction(self) -> Vec2:
+        """The direction of the line, as given when it was created."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """The point on the line at parameter value ``t``."""
+        ...
+
+    @classmethod
+    def from_line(cls, line: Line) -> Self:
+        """
+        Create the line which extends a :py:class:`Line` segment for ever in
+        both directions. Raises ``ValueError`` if the segment has no length.
+        """
+        ...
+
+    @classmethod
+    def from_points(cls, p0: PointLike, p1: PointLike) -> Self:
+        """
+        Create the line through two points, with ``p0`` at ``t == 0`` and
+        ``p1`` at ``t == 1``. Raises ``ValueError`` if they are the same.
+        """
+        ...
+
+    def intersect(self, other: Any) -> List[Point]:
+        """
+        Find the points where the line meets ``other``, which may be another
+        ``Line2``, a ``Line``, ``QuadBez``, ``CubicBez`` or ``PathSeg``, or a
+        ``BezPath`` or ``FrozenBezPath``.
+
+        Returns a list of points ordered along the line. Where the line
+        passes through the join between two segments of a path, the point is
+        only reported once. Parallel lines, and segments lying along the
+        line, do not cross it at a single point and are not reported.
+        """
+        ...
+
+    def param_of(self, pt: PointLike) -> float:
+        """The parameter value of the point on the line nearest to ``pt``."""
+        ...
+
+    @property
+    def point(self) -> Point:
+        """The point on the line at ``t == 0``."""
+        ...
+
+    def project(self, pt: PointLike) -> Point:
+        """The point on the line nearest to ``pt``."""
+        ...
+
+    def side_of(self, pt: PointLike) -> int:
+        """
+        Which side of the line ``pt`` is on: ``1`` for the left (looking
+        along its direction in a y-up coordinate system), ``-1`` for the
+        right, and ``0`` if it is on the line.
+        """
+        ...
+
+    def signed_distance(self, pt: PointLike) -> float:
+        """
+        The distance from the line to ``pt``, positive if the point is to the
+        left of the line (looking along its direction in a y-up coordinate
+        system) and negative if it is to the right.
+        """
+        ...
+
+
+class LineIntersection:
+    def __init__(self, line_t: float, segment_t: float) -> None:
+        """
+        Create a `LineIntersection` from its fields.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    @property
+    def line_t(self) -> float: ...
+
+    @property
+    def segment_t(self) -> float: ...
+
+
+class MinDistance:
+    def __init__(
+        self,
+        distance: float,
+        t1: float,
+        t2: float,
+        p1: PointLike,
+        p2: PointLike,
+    ) -> None:
+        """
+        Create a `MinDistance` from its fields.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def distance(self) -> float: ...
+
+    @distance.setter
+    def distance(self, value: float) -> None: ...
+
+    @property
+    def p1(self) -> Point:
+        """
+        The closest point on the first curve, at parameter ``t1``.
+
+        Note that this property is not in original kurbo
+        """
+        ...
+
+    @p1.setter
+    def p1(self, value: PointLike) -> None: ...
+
+    @property
+    def p2(self) -> Point:
+        """
+        The closest point on the second curve, at parameter ``t2``.
+
+        Note that this property is not in original kurbo
+        """
+        ...
+
+    @p2.setter
+    def p2(self, value: PointLike) -> None: ...
+
+    @property
+    def t1(self) -> float: ...
+
+    @t1.setter
+    def t1(self, value: float) -> None: ...
+
+    @property
+    def t2(self) -> float: ...
+
+    @t2.setter
+    def t2(self, value: float) -> None: ...
+
+
+class Nearest:
+    def __init__(
+        self,
+        distance_sq: float,
+        t: float,
+        point: Optional[PointLike] = None,
+    ) -> None:
+        """
+        Create a `Nearest` from its fields. ``point`` may be omitted, in
+        which case the ``point`` property is ``None``.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def distance(self) -> float:
+        """
+        The distance to the nearest point: the square root of
+        ``distance_sq``.
+
+        Note that this property is not in original kurbo
+        """
+        ...
+
+    @property
+    def distance_sq(self) -> float: ...
+
+    @distance_sq.setter
+    def distance_sq(self, value: float) -> None: ...
+
+    def get_distance_sq(self) -> float:
+        """Deprecated: use the ``distance_sq`` property."""
+        ...
+
+    def get_t(self) -> float:
+        """Deprecated: use the ``t`` property."""
+        ...
+
+    @property
+    def point(self) -> Optional[Point]:
+        """
+        The nearest point on the curve, at parameter ``t``, or ``None`` if
+        this `Nearest` was constructed without one.
+
+        Note that this property is not in original kurbo
+        """
+        ...
+
+    @point.setter
+    def point(self, value: Optional[PointLike]) -> None: ...
+
+    def set_distance_sq(self, value: float) -> None:
+        """Deprecated: assign to the ``distance_sq`` property."""
+        ...
+
+    def set_t(self, value: float) -> None:
+        """Deprecated: assign to the ``t`` property."""
+        ...
+
+    @property
+    def t(self) -> float: ...
+
+    @t.setter
+    def t(self, value: float) -> None: ...
+
+
+class PathEl:
+    def __init__(self, kind: str, *points: Any) -> None:
+        """
+        Create a `PathEl` from its kind (as returned by ``kind``) and points,
+        for example ``PathEl("QuadTo", (1, 2), (3, 4))``.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def end_point(self) -> Optional[Point]:
+        """Get the end point of the path element, if it exists."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    @property
+    def kind(self) -> str:
+        """
+        The kind of element, as a string: one of ``"MoveTo"``, ``"LineTo"``,
+        ``"QuadTo"``, ``"CurveTo"`` or ``"ClosePath"``.
+
+        Note that this property is not in original kurbo
+        """
+        ...
+
+    def points(self) -> List[Point]:
+        """
+        The points of the element, in order: the control points (if any)
+        followed by the end point. A ``ClosePath`` has no points.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+
+class PathIntersection:
+    """
+    A point where two paths meet, as found by :py:meth:`BezPath.intersect_path`.
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(
+        self,
+        segment_index: int,
+        segment_t: float,
+        other_index: int,
+        other_t: float,
+        point: PointLike,
+    ) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def other_index(self) -> int:
+        """The index of the segment of the other path."""
+        ...
+
+    @property
+    def other_t(self) -> float:
+        """The parameter value of the meeting point on the other path's segment."""
+        ...
+
+    @property
+    def point(self) -> Point:
+        """The meeting point itself."""
+        ...
+
+    @property
+    def segment_index(self) -> int:
+        """
+        The index of the segment of the first path, as yielded by
+        ``BezPath.segments``.
+        """
+        ...
+
+    @property
+    def segment_t(self) -> float:
+        """The parameter value of the meeting point on that segment."""
+        ...
+
+
+class PathLineIntersection:
+    """
+    A point where a line crosses a path, as found by
+    :py:meth:`BezPath.intersect_line`.
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(
+        self,
+        segment_index: int,
+        segment_t: float,
+        line_t: float,
+        point: PointLike,
+    ) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def line_t(self) -> float:
+        """The parameter value of the crossing on the line."""
+        ...
+
+    @property
+    def point(self) -> Point:
+        """The crossing point itself."""
+        ...
+
+    @property
+    def segment_index(self) -> int:
+        """The index of the segment crossed, as yielded by ``BezPath.segments``."""
+        ...
+
+    @property
+    def segment_t(self) -> float:
+        """The parameter value of the crossing on the segment."""
+        ...
+
+
+class PathSeg:
+    def __init__(self, segment: Any) -> None:
+        """
+        Create a `PathSeg` from a `Line`, `QuadBez` or `CubicBez`.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def approx_eq(self, other: PathSeg, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def arclen(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        The arc length of the curve.
+
+        The result is accurate to the given accuracy (subject to
+        roundoff errors for ridiculously low values). Compute time
+        may vary with accuracy, if the curve needs to be subdivided.
+
+        If ``return_error`` is true, returns a ``(length, error)`` tuple,
+        where ``error`` estimates the error actually achieved.
+        """
+        ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def as_cubic(self) -> Optional[CubicBez]: ...
+
+    def as_line(self) -> Optional[Line]: ...
+
+    def as_path_el(self) -> PathEl:
+        """Get the [`PathEl`] that is equivalent to discarding the segment start point."""
+        ...
+
+    def as_quad(self) -> Optional[QuadBez]: ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the curve in the range (0..1)."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def curvature(self, t: float) -> float: ...
+
+    def curvature_extrema(self) -> List[float]:
+        """
+        Find the parameter values strictly inside the segment at which the
+        absolute curvature is locally greatest, in increasing order.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def deriv(self) -> Any: ...
+
+    def end(self) -> Point:
+        """The end point."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """
+        Evaluate the curve at parameter `t`.
+
+        Generally `t` is in the range [0..1].
+        """
+        ...
+
+    def extrema(self) -> List[float]:
+        """
+        Compute the extrema of the curve.
+
+        Only extrema within the interior of the curve count.
+
+        The extrema should be reported in increasing parameter order.
+        """
+        ...
+
+    def extrema_ranges(self) -> List[Tuple[float, float]]:
+        """Return parameter ranges, each of which is monotonic within the range."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def intersect_line(self, line: Line) -> List[LineIntersection]:
+        """
+        Compute intersections against a line.
+
+        Returns a vector of the intersections. For each intersection,
+        the `t` value of the segment and line are given.
+
+        Note: This test is designed to be inclusive of points near the endpoints
+        of the segment. This is so that testing a line against multiple
+        contiguous segments of a path will be guaranteed to catch at least one
+        of them. In such cases, use higher level logic to coalesce the hits
+        (the `t` value may be slightly outside the range of 0..1).
+        """
+        ...
+
+    def inv_arclen(self, arclen: float, accuracy: float = ...) -> float:
+        """
+        Solve for the parameter that has the given arc length from the start.
+
+        This implementation uses the IPT method, as provided by
+        [`common::solve_itp`]. This is as robust as bisection but
+        typically converges faster. In addition, the method takes
+        care to compute arc lengths of increasingly smaller segments
+        of the curve, as that is likely faster than repeatedly
+        computing the arc length of the segment starting at t=0.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def min_dist(self, other: PathSeg, accuracy: float = ...) -> MinDistance:
+        """
+        Minimum distance between two [`PathSeg`]s.
+
+        Returns a tuple of the distance, the path time `t1` of the closest point
+        on the first `PathSeg`, and the path time `t2` of the closest point on the
+        second `PathSeg`. The closest points themselves are available as ``p1``
+        and ``p2``.
+        """
+        ...
+
+    def nearest(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+        return_error: bool = False,
+    ) -> Any:
+        """
+        Find the position on the curve that is nearest to the given point.
+
+        This returns a [`Nearest`] struct that contains information about the position.
+
+        If ``return_error`` is true, returns a ``(nearest, error)`` tuple,
+        where ``error`` estimates the error in the distance to the curve.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def project(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+    ) -> Tuple[float, Point, float]:
+        """
+        Project a point onto the curve.
+
+        Returns a ``(t, point, distance)`` tuple of the parameter value of
+        the nearest position on the curve, the point there, and its
+        distance from the given point.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def reverse(self) -> Self:
+        """
+        Returns a new `PathSeg` describing the same path as `self`, but with
+        the points reversed.
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def signed_area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def split_at_extrema(self) -> List[PathSeg]:
+        """
+        Split the curve at its extrema.
+
+        Returns a list of pieces, one for each of the ranges given by
+        ``extrema_ranges``, each of which is monotonic in both x and y.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def start(self) -> Point:
+        """The start point."""
+        ...
+
+    def subdivide(self) -> Tuple[Self, Self]:
+        """Subdivide into (roughly) halves."""
+        ...
+
+    def subsegment(self, t0_t1: Tuple[float, float]) -> Self:
+        """Get a subsegment of the curve for the given parameter range."""
+        ...
+
+    def to_cubic(self) -> CubicBez:
+        """Convert this segment to a cubic bezier."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class Point:
+    """
+    A 2D point.
+
+    Anywhere a `Point` is expected as an argument, an ``(x, y)`` tuple
+    may be given instead. A `Point` is also a sequence of its two
+    coordinates, so it can be unpacked with ``x, y = pt``.
+    """
+
+    def __init__(self, x: float, y: float) -> None:
+        """Create a new `Point` with the provided `x` and `y` coordinates."""
+        ...
+
+    def __add__(self, rhs: Vec2) -> Self: ...
+
+    def __getitem__(self, ix: int) -> float: ...
+
+    def __iadd__(self, other: Vec2Like) -> None: ...
+
+    def __isub__(self, other: Vec2Like) -> None: ...
+
+    def __iter__(self) -> Iterator[Any]: ...
+
+    def __len__(self) -> int: ...
+
+    def __repr__(self) -> str: ...
+
+    @overload
+    def __sub__(self, rhs: Vec2) -> Self: ...
+
+    @overload
+    def __sub__(self, rhs: Point) -> Vec2: ...
+
+    def approx_eq(self, other: Point, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def ceil(self) -> Self:
+        """
+        Returns a new `Point`,
+        with `x` and `y` rounded up to the nearest integer,
+        unless they are already an integer.
+
+        Examples::
+
+            from kurbopy import Point
+            a = Point(3.3, 3.6).ceil()
+            b = Point(3.0, -3.1).ceil()
+            assert a.x == 4.0
+            assert a.y == 4.0
+            assert b.x == 3.0
+            assert b.y == -3.0
+        """
+        ...
+
+    def distance(self, other: PointLike) -> float:
+        """Euclidean distance."""
+        ...
+
+    def expand(self) -> Self:
+        """
+        Returns a new `Point`,
+        with `x` and `y` rounded away from zero to the nearest integer,
+        unless they are already an integer.
+
+        Examples::
+
+            from kurbopy import Point
+            a = Point(3.3, 3.6).expand()
+            b = Point(3.0, -3.1).expand()
+            assert a.x == 4.0
+            assert a.y == 4.0
+            assert b.x == 3.0
+            assert b.y == -4.0
+        """
+        ...
+
+    def floor(self) -> Self:
+        """
+        Returns a new `Point`,
+        with `x` and `y` rounded down to the nearest integer,
+        unless they are already an integer.
+
+        Examples::
+
+            from kurbopy import Point
+            a = Point(3.3, 3.6).floor()
+            b = Point(3.0, -3.1).floor()
+            assert a.x == 3.0
+            assert a.y == 3.0
+            assert b.x == 3.0
+            assert b.y == -4.0
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this point finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this point NaN?"""
+        ...
+
+    def lerp(self, other: PointLike, t: float) -> Self:
+        """Linearly interpolate between two points."""
+        ...
+
+    def midpoint(self, other: PointLike) -> Self:
+        """Determine the midpoint of two points."""
+        ...
+
+    def round(self) -> Self:
+        """
+        Returns a new `Point`,
+        with `x` and `y` rounded to the nearest integer.
+
+        Examples::
+
+            from kurbopy import Point
+            a = Point(3.3, 3.6).round()
+            b = Point(3.0, -3.1).round()
+            assert a.x == 3.0
+            assert a.y == 4.0
+            assert b.x == 3.0
+            assert b.y == -3.0
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_vec2(self) -> Vec2:
+        """Convert this point into a `Vec2`."""
+        ...
+
+    def trunc(self) -> Self:
+        """
+        Returns a new `Point`,
+        with `x` and `y` rounded towards zero to the nearest integer,
+        unless they are already an integer.
+
+        Examples::
+
+            from kurbopy import Point
+            a = Point(3.3, 3.6).trunc()
+            b = Point(3.0, -3.1).trunc()
+            assert a.x == 3.0
+            assert a.y == 3.0
+            assert b.x == 3.0
+            assert b.y == -3.0
+        """
+        ...
+
+    @property
+    def x(self) -> float: ...
+
+    @x.setter
+    def x(self, x: float) -> None: ...
+
+    @property
+    def y(self) -> float: ...
+
+    @y.setter
+    def y(self, y: float) -> None: ...
+
+
+class QuadBez:
+    """A single quadratic Bézier segment."""
+
+    def __init__(self, p0: PointLike, p1: PointLike, p2: PointLike) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    def approx_eq(self, other: QuadBez, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def arclen(self, accuracy: float = ..., return_error: bool = False) -> Any:
+        """
+        The arc length of the curve.
+
+        The result is accurate to the given accuracy (subject to
+        roundoff errors for ridiculously low values). Compute time
+        may vary with accuracy, if the curve needs to be subdivided.
+
+        If ``return_error`` is true, returns a ``(length, error)`` tuple,
+        where ``error`` estimates the error actually achieved.
+        """
+        ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the curve in the range (0..1)."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def curvature(self, t: float) -> float: ...
+
+    def curvature_extrema(self) -> List[float]:
+        """
+        Find the parameter values strictly inside the curve at which
+        the absolute curvature is locally greatest, in increasing
+        order.
+
+        These are the tightest points of bends, and the positions of
+        cusps, where the curvature is infinite.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def deriv(self) -> Line:
+        """
+        The derivative of the curve.
+
+        Note that the type of the return value is somewhat inaccurate, as
+        the derivative of a curve (mapping of param to point) is a mapping
+        of param to vector. We choose to accept this rather than have a
+        more complex type scheme.
+        """
+        ...
+
+    def end(self) -> Point:
+        """The end point."""
+        ...
+
+    def eval(self, t: float) -> Point:
+        """
+        Evaluate the curve at parameter `t`.
+
+        Generally `t` is in the range [0..1].
+        """
+        ...
+
+    def extrema(self) -> List[float]:
+        """
+        Compute the extrema of the curve.
+
+        Only extrema within the interior of the curve count.
+
+        The extrema should be reported in increasing parameter order.
+        """
+        ...
+
+    def extrema_ranges(self) -> List[Tuple[float, float]]:
+        """Return parameter ranges, each of which is monotonic within the range."""
+        ...
+
+    def flatness(self) -> float:
+        """
+        The greatest distance of the control points from the chord (the line
+        segment from ``p0`` to ``p2``).
+
+        The curve lies entirely within this distance of the chord, so this
+        is an upper bound on how far it strays from a straight line.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def inv_arclen(self, arclen: float, accuracy: float = ...) -> float:
+        """
+        Solve for the parameter that has the given arc length from the start.
+
+        This implementation uses the IPT method, as provided by
+        [`common::solve_itp`]. This is as robust as bisection but
+        typically converges faster. In addition, the method takes
+        care to compute arc lengths of increasingly smaller segments
+        of the curve, as that is likely faster than repeatedly
+        computing the arc length of the segment starting at t=0.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_linear(self, tolerance: float = ...) -> bool:
+        """
+        Whether the quadratic is a straight line to within ``tolerance``, that is,
+        whether it could be replaced by a ``Line`` from ``p0`` to ``p2``
+        without moving any part of it further than ``tolerance``.
+
+        A curve whose control points lie on the line through its ends but
+        beyond them doubles back on itself, and is not considered linear.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def nearest(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+        return_error: bool = False,
+    ) -> Any:
+        """
+        Find the position on the curve that is nearest to the given point.
+
+        This returns a [`Nearest`] struct that contains information about the position.
+
+        If ``return_error`` is true, returns a ``(nearest, error)`` tuple,
+        where ``error`` estimates the error in the distance to the curve.
+        """
+        ...
+
+    @property
+    def p0(self) -> Point: ...
+
+    @p0.setter
+    def p0(self, p0: PointLike) -> None: ...
+
+    @property
+    def p1(self) -> Point: ...
+
+    @p1.setter
+    def p1(self, p1: PointLike) -> None: ...
+
+    @property
+    def p2(self) -> Point: ...
+
+    @p2.setter
+    def p2(self, p2: PointLike) -> None: ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def project(
+        self,
+        point: PointLike,
+        accuracy: float = ...,
+    ) -> Tuple[float, Point, float]:
+        """
+        Project a point onto the curve.
+
+        Returns a ``(t, point, distance)`` tuple of the parameter value of
+        the nearest position on the curve, the point there, and its
+        distance from the given point.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def signed_area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def split_at_extrema(self) -> List[QuadBez]:
+        """
+        Split the curve at its extrema.
+
+        Returns a list of pieces, one for each of the ranges given by
+        ``extrema_ranges``, each of which is monotonic in both x and y.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def start(self) -> Point:
+        """The start point."""
+        ...
+
+    def subdivide(self) -> Tuple[Self, Self]:
+        """Subdivide into (roughly) halves."""
+        ...
+
+    def subsegment(self, t0_t1: Tuple[float, float]) -> Self:
+        """Get a subsegment of the curve for the given parameter range."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class QuadSpline:
+    """A quadratic Bézier spline in B-spline format."""
+
+    def __init__(self, points: List[PointLike]) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def points(self) -> List[Point]:
+        """Return the spline’s control `Point`s."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with the same
+        structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_quads(self) -> List[QuadBez]:
+        """Return an iterator over the implied `QuadBez`` sequence."""
+        ...
+
+
+class QuadSplineConversion:
+    """
+    The result of converting a batch of cubics to quadratic splines.
+
+    ``splines`` has one entry per input curve, which is ``None`` for
+    curves that could not be converted. ``failures`` lists the index of
+    each such curve together with the reason it failed.
+
+    When ``compatible`` is true, all converted splines have the same number
+    of points and can be interpolated against each other.
+    """
+
+    def __init__(
+        self,
+        splines: List[Optional[QuadSpline]],
+        failures: List[Tuple[int, str]],
+        compatible: bool,
+    ) -> None:
+        """
+        Create a `QuadSplineConversion` from its fields.
+
+        Note that this constructor is not in original kurbo
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def compatible(self) -> bool: ...
+
+    @property
+    def failures(self) -> List[Tuple[int, str]]: ...
+
+    def is_complete(self) -> bool:
+        """Returns `true` if every curve was converted."""
+        ...
+
+    @property
+    def splines(self) -> List[Optional[QuadSpline]]: ...
+
+    def succeeded(self) -> List[int]:
+        """The indices of the curves which were successfully converted."""
+        ...
+
+
+class Ray:
+    """
+    A half-line, starting at ``origin`` and going on for ever in the
+    direction of ``direction``.
+
+    ::
+
+       from kurbopy import Ray, Rect
+       ray = Ray((5, 5), (1, 0))
+       hit = Rect(0, 0, 10, 10).to_path().raycast(ray)
+       # hit.distance == 5, hit.point == Point(10, 5)
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(self, origin: PointLike, direction: Vec2Like) -> None:
+        """
+        Create a ray from its origin and direction. Raises ``ValueError`` if
+        the direction is zero or not finite.
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def direction(self) -> Vec2:
+        """The direction of the ray, as given when it was created."""
+        ...
+
+    @property
+    def origin(self) -> Point:
+        """The point the ray starts from."""
+        ...
+
+    def point_at(self, distance: float) -> Point:
+        """The point ``distance`` along the ray from its origin."""
+        ...
+
+
+class RayHit:
+    """
+    A place where a :py:class:`Ray` meets a path, as found by
+    :py:meth:`BezPath.raycast` and :py:meth:`BezPath.raycast_all`.
+
+    Note that this class is not in original kurbo
+    """
+
+    def __init__(
+        self,
+        distance: float,
+        point: PointLike,
+        segment_index: int,
+        segment_t: float,
+    ) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+    @property
+    def distance(self) -> float:
+        """The distance from the origin of the ray to the hit."""
+        ...
+
+    @property
+    def point(self) -> Point:
+        """The point hit."""
+        ...
+
+    @property
+    def segment_index(self) -> int:
+        """The index of the segment hit, as yielded by ``BezPath.segments``."""
+        ...
+
+    @property
+    def segment_t(self) -> float:
+        """The parameter value of the hit on the segment."""
+        ...
+
+
+class Rect:
+    """A rectangle."""
+
+    def __init__(self, p0: float, p1: float, p2: float, p3: float) -> None: ...
+
+    @overload
+    def __add__(self, rhs: Vec2) -> Self: ...
+
+    @overload
+    def __add__(self, rhs: Insets) -> Self: ...
+
+    def __contains__(self, item: Any) -> bool: ...
+
+    def __mul__(self, rhs: float) -> Self: ...
+
+    def __repr__(self) -> str: ...
+
+    def __rmul__(self, lhs: float) -> Self: ...
+
+    @overload
+    def __sub__(self, rhs: Vec2) -> Self: ...
+
+    @overload
+    def __sub__(self, rhs: Insets) -> Self: ...
+
+    @overload
+    def __sub__(self, rhs: Rect) -> Insets: ...
+
+    def __truediv__(self, rhs: float) -> Self: ...
+
+    def abs(self) -> Self:
+        """
+        Take absolute value of width and height.
+
+        The resulting rect has the same extents as the original, but is
+        guaranteed to have non-negative width and height.
+        """
+        ...
+
+    def approx_eq(self, other: Rect, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def aspect_ratio(self) -> float:
+        """
+        The aspect ratio of the ``Rect``.
+
+        This is defined as the height divided by the width. It measures the
+        "squareness" of the rectangle (a value of `1` is square).
+
+        If the width is `0` the output will be ``sign(y1 - y0) * infinity``.
+
+        If the width and height are `0`, the result will be `NaN`.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    def ceil(self) -> Self:
+        """
+        Returns a new `Rect`,
+        with each coordinate value rounded up to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    def center(self) -> Point:
+        """The center point of the rectangle."""
+        ...
+
+    def contained_rect_with_aspect_ratio(self, aspect_ratio: float) -> Self:
+        """
+        Returns the largest possible ``Rect`` that is fully contained in ``self``
+        with the given ``aspect_ratio``.
+
+        The aspect ratio is specified fractionally, as ``height / width``.
+
+        The resulting rectangle will be centered if it is smaller than the
+        input rectangle.
+
+        For the special case where the aspect ratio is ``1.0``, the resulting
+        ``Rect`` will be square.
+        """
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    def contains_rect(self, other: Rect) -> bool:
+        """
+        Returns true if ``other`` lies entirely within this rectangle,
+        including the case where they share edges.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def expand(self) -> Self:
+        """
+        Returns a new `Rect`,
+        with each coordinate value rounded away from the center of the `Rect`
+        to the nearest integer, unless they are already an integer.
+        That is to say this function will return the smallest possible `Rect`
+        with integer coordinates that is a superset of `self`.
+        """
+        ...
+
+    def floor(self) -> Self:
+        """
+        Returns a new `Rect`,
+        with each coordinate value rounded down to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    @classmethod
+    def from_center_size(cls, p0: PointLike, p1: Size) -> Self: ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_origin_size(cls, p0: PointLike, p1: Size) -> Self: ...
+
+    @classmethod
+    def from_points(cls, p0: PointLike, p1: PointLike) -> Self:
+        """
+        A new rectangle from two points.
+
+        The result will have non-negative width and height.
+        """
+        ...
+
+    def height(self) -> float:
+        """
+        The height of the rectangle.
+
+        Note: nothing forbids negative height.
+        """
+        ...
+
+    def inflate(self, width: float, height: float) -> Self:
+        """
+        Expand a rectangle by a constant amount in both directions.
+
+        The logic simply applies the amount in each direction. If rectangle
+        area or added dimensions are negative, this could give odd results.
+        """
+        ...
+
+    def inset(self, inset: Insets) -> Self: ...
+
+    def intersect(self, other: Rect) -> Self:
+        """
+        The intersection of two rectangles.
+
+        The result is zero-area if either input has negative width or
+        height. The result always has non-negative width and height.
+        """
+        ...
+
+    def is_empty(self) -> bool:
+        """
+        Whether this rectangle has zero area.
+
+        Note: a rectangle with negative area is not considered empty.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def max_x(self) -> float:
+        """Returns the maximum value for the x-coordinate of the rectangle."""
+        ...
+
+    def max_y(self) -> float:
+        """Returns the maximum value for the y-coordinate of the rectangle."""
+        ...
+
+    def min_x(self) -> float:
+        """Returns the minimum value for the x-coordinate of the rectangle."""
+        ...
+
+    def min_y(self) -> float:
+        """Returns the minimum value for the y-coordinate of the rectangle."""
+        ...
+
+    def origin(self) -> Point:
+        """
+        The origin of the rectangle.
+
+        This is the top left corner in a y-down space and with
+        non-negative width and height.
+        """
+        ...
+
+    def overlaps(self, other: Rect) -> bool:
+        """
+        Returns true if the two rectangles have any point in common.
+
+        Rectangles which only touch along an edge or at a corner count as
+        overlapping, as do zero-width or zero-height rectangles lying within
+        the other, even though their intersection has no area.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def round(self) -> Self:
+        """
+        Returns a new `Rect`,
+        with each coordinate value rounded to the nearest integer.
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def scale_from_origin(self, factor: float) -> Self:
+        """
+        Scales the ``Rect`` by ``factor`` with respect to the origin (the point ``(0, 0)``).
+
+        Examples::
+
+            from kurbopy import Rect
+            rect = Rect(2, 2, 4, 6).scale_from_origin(2)
+            assert rect.x0 == 4
+            assert rect.x1 == 8
+        """
+        ...
+
+    def size(self) -> Size:
+        """The size of the rectangle."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def to_rounded_rect(self, radii: RadiiLike) -> RoundedRect:
+        """
+        Create a :py:class:`RoundedRect` from this `Rect` with the provided
+        corner radii, given either as a single radius, a tuple of four radii
+        or a :py:class:`RoundedRectRadii`.
+        """
+        ...
+
+    def trunc(self) -> Self:
+        """
+        Returns a new `Rect`,
+        with each coordinate value rounded towards the center of the `Rect`
+        to the nearest integer, unless they are already an integer.
+        That is to say this function will return the biggest possible `Rect`
+        with integer coordinates that is a subset of `self`.
+        """
+        ...
+
+    def union(self, other: Rect) -> Self:
+        """
+        The smallest rectangle enclosing two rectangles.
+
+        Results are valid only if width and height are non-negative.
+        """
+        ...
+
+    def union_pt(self, pt: PointLike) -> Self:
+        """
+        Compute the union with one point.
+
+        This method includes the perimeter of zero-area rectangles.
+        Thus, a succession of ``union_pt`` operations on a series of
+        points yields their enclosing rectangle.
+
+        Results are valid only if width and height are non-negative.
+        """
+        ...
+
+    def width(self) -> float:
+        """
+        The width of the rectangle.
+
+        Note: nothing forbids negative width.
+        """
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+    def with_origin(self, origin: PointLike) -> Self:
+        """Create a new `Rect` with the same size as `self` and a new origin."""
+        ...
+
+    def with_size(self, size: Size) -> Self: ...
+
+    @property
+    def x0(self) -> float: ...
+
+    @x0.setter
+    def x0(self, x0: float) -> None: ...
+
+    @property
+    def x1(self) -> float: ...
+
+    @x1.setter
+    def x1(self, x1: float) -> None: ...
+
+    @property
+    def y0(self) -> float: ...
+
+    @y0.setter
+    def y0(self, y0: float) -> None: ...
+
+
+class RoundedRect:
+    """
+    A rectangle with equally sized rounded corners.
+
+    Wherever radii are expected, a single radius for all four corners or a
+    ``(top_left, top_right, bottom_right, bottom_left)`` tuple may be given
+    instead of a :py:class:`RoundedRectRadii`.
+    """
+
+    def __init__(
+        self,
+        x0: float,
+        y0: float,
+        x1: float,
+        y1: float,
+        radii: RadiiLike,
+    ) -> None:
+        """
+        A new rectangle from minimum and maximum coordinates.
+
+        The result will have non-negative width, height and radii.
+        """
+        ...
+
+    def __add__(self, v: Vec2Like) -> Self: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, v: Vec2Like) -> Self: ...
+
+    def area(self) -> float:
+        """
+        Compute the signed area under the curve.
+
+        For a closed path, the signed area of the path is the sum of signed
+        areas of the segments. This is a variant of the "shoelace formula."
+        See:
+        <https://github.com/Pomax/bezierinfo/issues/44> and
+        <http://ich.deanmcnamee.com/graphics/2016/03/30/CurveArea.html>
+
+        This can be computed exactly for Béziers thanks to Green's theorem,
+        and also for simple curves such as circular arcs. For more exotic
+        curves, it's probably best to subdivide to cubics. We leave that
+        to the caller, which is why we don't give an accuracy param here.
+        """
+        ...
+
+    def bounding_box(self) -> Rect:
+        """The smallest rectangle that encloses the shape."""
+        ...
+
+    def center(self) -> Point:
+        """The center point of the rectangle."""
+        ...
+
+    def contains(self, pt: PointLike) -> bool:
+        """
+        Returns `true` if the [`Point`] is inside this shape.
+
+        This is only meaningful for closed shapes.
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_origin_size(cls, origin: PointLike, size: Size, radii: RadiiLike) -> Self:
+        """
+        A new rectangle from origin and size.
+
+        The result will have non-negative width, height and radius.
+        """
+        ...
+
+    @classmethod
+    def from_points(cls, p0: PointLike, p1: PointLike, radii: RadiiLike) -> Self:
+        """
+        A new rectangle from two `Point`s.
+
+        The result will have non-negative width, height and radius.
+        """
+        ...
+
+    @classmethod
+    def from_rect(cls, rect: Rect, radii: RadiiLike) -> Self:
+        """A new rounded rectangle from a rectangle and corner radii."""
+        ...
+
+    def height(self) -> float:
+        """The height of the rectangle."""
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def origin(self) -> Point:
+        """
+        The origin of the rectangle.
+
+        This is the top left corner in a y-down space.
+        """
+        ...
+
+    def path_elements(self, tolerance: float = ...) -> PathElIterator:
+        """
+        Returns an iterator over this shape's path elements.
+
+        The elements are generated lazily, which avoids building a full
+        :py:class:`BezPath` when they only need to be visited once.
+        """
+        ...
+
+    def perimeter(self, accuracy: float = ...) -> float:
+        """Total length of perimeter."""
+        ...
+
+    def radii(self) -> RoundedRectRadii:
+        """Radii of the rounded corners."""
+        ...
+
+    def rect(self) -> Rect:
+        """The (non-rounded) rectangle."""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_path(self, tolerance: float = ..., return_error: bool = False) -> Any:
+        """
+        Convert to a Bézier path.
+
+        If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        where ``error`` estimates the greatest distance between the path
+        and the shape.
+        """
+        ...
+
+    def width(self) -> float:
+        """The width of the rectangle."""
+        ...
+
+    def winding(self, pt: PointLike) -> int:
+        """
+        The winding number of a point.
+
+        This method only produces meaningful results with closed shapes.
+
+        The sign of the winding number is consistent with that of ``area``,
+        meaning it is +1 when the point is inside a positive area shape
+        and -1 when it is inside a negative area shape. Of course, greater
+        magnitude values are also possible when the shape is more complex.
+        """
+        ...
+
+
+class RoundedRectRadii:
+    """
+    Radii for each corner of a rounded rectangle.
+
+    The use of `top` as in `top_left` assumes a y-down coordinate space. Piet
+    (and Druid by extension) uses a y-down coordinate space, but Kurbo also
+    supports a y-up coordinate space, in which case `top_left` would actually
+    refer to the bottom-left corner, and vice versa. Top may not always
+    actually be the top, but `top` corners will always have a smaller y-value
+    than `bottom` corners.
+    """
+
+    def __init__(
+        self,
+        top_left: float,
+        top_right: float,
+        bottom_right: float,
+        bottom_left: float,
+    ) -> None:
+        """
+        Create a new `RoundedRectRadii`. This function takes radius values for
+        the four corners. The argument order is `top_left`, `top_right`,
+        `bottom_right`, `bottom_left`, or clockwise starting from `top_left`.
+        """
+        ...
+
+    def __repr__(self) -> str: ...
+
+    def abs(self) -> Self:
+        """Takes the absolute value of all corner radii."""
+        ...
+
+    def as_single_radius(self) -> Optional[float]:
+        """
+        If all radii are equal, returns the value of the radii. Otherwise,
+        returns `None`.
+        """
+        ...
+
+    @property
+    def bottom_left(self) -> float: ...
+
+    @property
+    def bottom_right(self) -> float: ...
+
+    def clamp(self, max: float) -> Self:
+        """For each corner, takes the min of that value and the given `max`."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_single_radius(cls, radius: float) -> Self:
+        """Create a new `RoundedRectRadii` from a single radius."""
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this value finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this value NaN?"""
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @property
+    def top_left(self) -> float: ...
+
+    @property
+    def top_right(self) -> float: ...
+
+
+class Size:
+    """A 2D size."""
+
+    def __init__(self, width: float, height: float) -> None:
+        """Create a new `Size` with the provided `width` and `height`."""
+        ...
+
+    @classmethod
+    def ZERO(cls) -> Self:
+        """A size with zero width or height."""
+        ...
+
+    def __add__(self, other: Size) -> Self: ...
+
+    def __eq__(self, other: object) -> bool: ...
+
+    def __ge__(self, other: object) -> bool: ...
+
+    def __gt__(self, other: object) -> bool: ...
+
+    __hash__: ClassVar[None]
+
+    def __iadd__(self, other: Size) -> None: ...
+
+    def __imul__(self, other: float) -> None: ...
+
+    def __isub__(self, other: Size) -> None: ...
+
+    def __itruediv__(self, other: float) -> None: ...
+
+    def __le__(self, other: object) -> bool: ...
+
+    def __lt__(self, other: object) -> bool: ...
+
+    def __mul__(self, other: Any) -> Self: ...
+
+    def __ne__(self, other: object) -> bool: ...
+
+    def __repr__(self) -> str: ...
+
+    def __rmul__(self, other: float) -> Self: ...
+
+    def __sub__(self, other: Size) -> Self: ...
+
+    def __truediv__(self, other: float) -> Self: ...
+
+    def area(self) -> float:
+        """The area covered by this size."""
+        ...
+
+    def aspect_ratio(self) -> float:
+        """
+        Returns the aspect ratio of a rectangle with the given size.
+
+        If the width is `0`, the output will be `sign(self.height) * infinity`. If The width and
+        height are `0`, then the output will be `NaN`.
+        """
+        ...
+
+    def ceil(self) -> Self:
+        """
+        Returns a new `Size`,
+        with `width` and `height` rounded up to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    def clamp(self, min: Size, max: Size) -> Self:
+        """
+        Returns a new size bounded by `min` and `max.`
+
+        # Examples
+
+        ```
+        this = Size(0., 100.)
+        min = Size(10., 10.)
+        max = Size(50., 50.)
+        assert this.clamp(min, max) == Size(10., 50.))
+        ```
+        """
+        ...
+
+    def expand(self) -> Self:
+        """
+        Returns a new `Size`,
+        with `width` and `height` rounded away from zero to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    def floor(self) -> Self:
+        """
+        Returns a new `Size`,
+        with `width` and `height` rounded down to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @property
+    def height(self) -> float: ...
+
+    @height.setter
+    def height(self, height: float) -> None: ...
+
+    def is_empty(self) -> bool:
+        """
+        Whether this size has zero area.
+
+        Note: a size with negative area is not considered empty.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this size finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this size NaN?"""
+        ...
+
+    def max(self, other: Size) -> Self:
+        """
+        Returns a new size with the larger of each of the widths and heights.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def max_side(self) -> float:
+        """
+        Returns the max of `width` and `height`.
+
+        # Examples
+
+        ```
+        size = Size(-10.5, 42.0)
+        assert size.max_side() == 42.0
+        ```
+        """
+        ...
+
+    def min(self, other: Size) -> Self:
+        """
+        Returns a new size with the smaller of each of the widths and heights.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def min_side(self) -> float:
+        """
+        Returns the min of `width` and `height`.
+
+        # Examples
+
+        ```
+        size = Size(-10.5, 42.0)
+        assert size.min_side() == -10.5
+        ```
+        """
+        ...
+
+    def round(self) -> Self:
+        """
+        Returns a new `Size`,
+        with `width` and `height` rounded to the nearest integer.
+
+        # Examples
+
+        ```
+        size_pos = Size(3.3, 3.6).round()
+        assert size_pos.width == 3.0)
+        assert size_pos.height == 4.0)
+        size_neg = Size(-3.3, -3.6).round()
+        assert size_neg.width == -3.0)
+        assert size_neg.height == -4.0)
+        ```
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_rect(self) -> Rect:
+        """Convert this `Size` into a [`Rect`] with origin `(0.0, 0.0)`."""
+        ...
+
+    def to_vec2(self) -> Vec2:
+        """
+        Convert this size into a [`Vec2`], with `width` mapped to `x` and `height`
+        mapped to `y`.
+        """
+        ...
+
+    def trunc(self) -> Self:
+        """
+        Returns a new `Size`,
+        with `width` and `height` rounded down towards zero the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    @property
+    def width(self) -> float: ...
+
+    @width.setter
+    def width(self, width: float) -> None: ...
+
+
+class TranslateScale:
+    """
+    A transformation including scaling and translation.
+
+    If the translation is `(x, y)` and the scale is `s`, then this
+    transformation represents this augmented matrix::
+
+        | s 0 x |
+        | 0 s y |
+        | 0 0 1 |
+
+    See [`Affine`] for more details about the
+    equivalence with augmented matrices.
+
+    Various multiplication ops are defined, and these are all defined
+    to be consistent with matrix multiplication. Therefore,
+    ``TranslateScale * Point`` is defined but not the other way around.
+
+    Also note that multiplication is not commutative. Thus,
+    ``TranslateScale.scale(2.0) * TranslateScale.translate(Vec2(1.0, 0.0))``
+    has a translation of (2, 0), while
+    ``TranslateScale.translate(Vec2(1.0, 0.0)) * TranslateScale.scale(2.0)``
+    has a translation of (1, 0). (Both have a scale of 2).
+
+    This transformation is less powerful than `Affine`, but can be applied
+    to more primitives, especially including :py:class:`Rect`.
+    """
+
+    def __init__(self, translation: Vec2Like, scale: float) -> None:
+        """Create a new transformation from translation and scale."""
+        ...
+
+    def __add__(self, rhs: Vec2Like) -> Self: ...
+
+    @overload
+    def __mul__(self, rhs: Point) -> Point: ...
+
+    @overload
+    def __mul__(self, rhs: TranslateScale) -> Self: ...
+
+    @overload
+    def __mul__(self, rhs: Line) -> Line: ...
+
+    @overload
+    def __mul__(self, rhs: Rect) -> Rect: ...
+
+    @overload
+    def __mul__(self, rhs: CubicBez) -> CubicBez: ...
+
+    def __repr__(self) -> str: ...
+
+    def as_tuple(self) -> Tuple[Vec2, float]:
+        """Decompose transformation into translation and scale."""
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_scale_about(cls, scale: float, center: PointLike) -> Self:
+        """
+        Create a new transformation which scales about the given center
+        point, leaving that point where it is.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def inverse(self) -> Self:
+        """
+        Compute the inverse transform.
+
+        Multiplying a transform with its inverse (either on the
+        left or right) results in the identity transform
+        (modulo floating point rounding errors).
+
+        Produces NaN values when scale is zero.
+        """
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this translate/scale finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this translate/scale NaN?"""
+        ...
+
+    @classmethod
+    def scale(cls, scale: float) -> Self:
+        """Create a new transformation with scale only."""
+        ...
+
+    def to_affine(self) -> Affine:
+        """
+        Convert this transformation into an equivalent `Affine`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def transform_points(self, points: PointArray) -> Any:
+        """
+        Apply the transform to many points at once.
+
+        ``points`` may be a NumPy array of shape ``(n, 2)``, in which case an
+        array of the same shape is returned, or a list of points, in which
+        case a list of :py:class:`Point` is returned.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def translate(cls, vec2: Vec2Like) -> Self:
+        """Create a new transformation with translation only."""
+        ...
+
+
+class Vec2:
+    """
+    A 2D vector.
+
+    This is intended primarily for a vector in the mathematical sense,
+    but it can be interpreted as a translation, and converted to and
+    from a point (vector relative to the origin) and size.
+
+    Anywhere a `Vec2` is expected as an argument, an ``(x, y)`` tuple
+    may be given instead. A `Vec2` is also a sequence of its two
+    coordinates, so it can be unpacked with ``x, y = vec``.
+    """
+
+    def __init__(self, x: float, y: float) -> None: ...
+
+    @classmethod
+    def ZERO(cls) -> Self:
+        """The vector (0, 0)."""
+        ...
+
+    def __add__(self, rhs: Vec2Like) -> Self: ...
+
+    def __getitem__(self, ix: int) -> float: ...
+
+    def __iadd__(self, other: Vec2Like) -> None: ...
+
+    def __imul__(self, rhs: float) -> None: ...
+
+    def __isub__(self, other: Vec2Like) -> None: ...
+
+    def __iter__(self) -> Iterator[Any]: ...
+
+    def __itruediv__(self, rhs: float) -> None: ...
+
+    def __len__(self) -> int: ...
+
+    def __mul__(self, rhs: float) -> Self: ...
+
+    def __neg__(self) -> Self: ...
+
+    def __repr__(self) -> str: ...
+
+    def __sub__(self, rhs: Vec2Like) -> Self: ...
+
+    def __truediv__(self, rhs: float) -> Self: ...
+
+    def approx_eq(self, other: Vec2, epsilon: float = 1e-9) -> bool:
+        """
+        Compare with another value of the same type, allowing each
+        coordinate to differ by up to ``epsilon``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def atan2(self) -> float:
+        """
+        Angle of vector.
+
+        If the vector is interpreted as a complex number, this is the argument.
+        The angle is expressed in radians.
+        """
+        ...
+
+    def ceil(self) -> Self:
+        """
+        Returns a new `Vec2`,
+        with `x` and `y` rounded up to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    def cross(self, other: Vec2Like) -> float:
+        """
+        Cross product of two vectors.
+
+        This is signed so that (0, 1) × (1, 0) = 1.
+        """
+        ...
+
+    def dot(self, other: Vec2Like) -> float:
+        """Dot product of two vectors."""
+        ...
+
+    def expand(self) -> Self:
+        """
+        Returns a new `Vec2`,
+        with `x` and `y` rounded away from zero to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    def floor(self) -> Self:
+        """
+        Returns a new `Vec2`,
+        with `x` and `y` rounded down to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    @classmethod
+    def from_angle(cls, th: float) -> Self:
+        """
+        A unit vector of the given angle.
+
+        With `th` at zero, the result is the positive X unit vector, and
+        at π/2, it is the positive Y unit vector. The angle is expressed
+        in radians.
+
+        Thus, in a Y-down coordinate system (as is common for graphics),
+        it is a clockwise rotation, and in Y-up (traditional for math), it
+        is anti-clockwise. This convention is consistent with
+        _`Affine.rotate`.
+        """
+        ...
+
+    @classmethod
+    def from_dict(cls, data: Any) -> Self:
+        """
+        Build from plain Python data, as produced by ``to_dict``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    @classmethod
+    def from_json(cls, json: str) -> Self:
+        """
+        Deserialize from a JSON string, as produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def hypot(self) -> float:
+        """Magnitude of vector."""
+        ...
+
+    def hypot2(self) -> float:
+        """Magnitude squared of vector."""
+        ...
+
+    def is_finite(self) -> bool:
+        """Is this Vec2 finite?"""
+        ...
+
+    def is_nan(self) -> bool:
+        """Is this Vec2 NaN?"""
+        ...
+
+    def lerp(self, other: Vec2Like, t: float) -> Self:
+        """Linearly interpolate between two vectors."""
+        ...
+
+    def normalize(self) -> Self:
+        """
+        Returns a vector of magnitude 1.0 with the same angle as `self`; i.e.
+        a unit/direction vector.
+
+        This produces `NaN` values when the magnitutde is `0`.
+        """
+        ...
+
+    def round(self) -> Self:
+        """
+        Returns a new `Vec2`,
+        with `x` and `y` rounded to the nearest integer.
+        """
+        ...
+
+    def round_coordinates(
+        self,
+        ndigits: Optional[int] = None,
+        grid: Optional[float] = None,
+    ) -> Self:
+        """
+        Round all coordinates, either to ``ndigits`` decimal places
+        (as with Python's ``round``) or to the nearest multiple of
+        ``grid``. With neither, coordinates are rounded to integers.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_dict(self) -> Any:
+        """
+        Convert to plain Python data (dicts, lists and floats) with
+        the same structure as the JSON produced by ``to_json``.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_json(self) -> str:
+        """
+        Serialize to a JSON string, as described in :doc:`serialization`.
+
+        Note that this method is not in original kurbo
+        """
+        ...
+
+    def to_point(self) -> Point:
+        """Convert this vector into a :py:class:`Point`."""
+        ...
+
+    def trunc(self) -> Self:
+        """
+        Returns a new `Vec2`,
+        with `x` and `y` rounded towards zero to the nearest integer,
+        unless they are already an integer.
+        """
+        ...
+
+    @property
+    def x(self) -> float: ...
+
+    @x.setter
+    def x(self, x: float) -> None: ...
+
+    @property
+    def y(self) -> float: ...
+
+    @y.setter
+    def y(self, y: float) -> None: ...
+
+
+class ElementIterator:
+    def __iter__(self) -> Self: ...
+
+    def __len__(self) -> int: ...
+
+    def __next__(self) -> Any: ...
+
+
+class PathElIterator:
+    """A lazy iterator over the elements of a shape."""
+
+    def __iter__(self) -> Self: ...
+
+    def __next__(self) -> PathEl: ...
+
+
+class SegmentIterator:
+    def __getitem__(self, ix: int) -> PathSeg: ...
+
+    def __iter__(self) -> Self: ...
+
+    def __len__(self) -> int: ...
+
+    def __next__(self) -> Any: ...
+
+
+def cubics_to_quadratic_splines(
+    curves: List[CubicBez],
+    accuracy: float,
+    fallback: bool = False,
+) -> QuadSplineConversion:
+    """
+    Convert multiple cubic Bézier curves to quadratic splines.
+
+    Ensures that the resulting splines have the same number of control points,
+    so that they remain interpolation compatible.
+
+    Rather than failing outright when one curve cannot be converted, this
+    returns a :py:class:`QuadSplineConversion` describing which curves
+    succeeded and which failed (and why); the remaining curves are still
+    converted to compatible splines. If ``fallback`` is true and the
+    remaining curves cannot share a spline order, each is instead converted
+    independently at whatever order it needs, at the cost of compatibility.
+    """
+    ...
+
+
+def distribute_along_path(
+    path: BezPath,
+    stamp: Optional[BezPath],
+    spacing: float,
+    align_to_tangent: bool = True,
+    accuracy: float = ...,
+) -> List[Any]:
+    """
+    Place copies of a shape at regular intervals along a path.
+
+    Positions are ``spacing`` apart by arc length, starting at the start of
+    each subpath. At each position, the transform moves the origin to the
+    point on the path and, if ``align_to_tangent`` is true, rotates the
+    positive x axis to follow the path's direction.
+
+    If ``stamp`` is given, a list of transformed copies of it is returned;
+    otherwise the list of :py:class:`Affine` transforms is returned.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def fit_euler(
+    samples: List[PointLike],
+    tolerance: float = ...,
+    closed: bool = False,
+) -> BezPath:
+    """
+    Interpolate a list of points with a smooth curve made of Euler spirals.
+
+    An Euler spiral (or clothoid) is a curve whose curvature changes
+    linearly along its length, which makes it the smoothest way of joining
+    two directions; they are used to lay out roads and railway tracks. The
+    curve passes through each point in ``samples`` in order, with a tangent
+    taken from the circle through the point and its neighbours, and each
+    pair of consecutive points is joined by the Euler spiral segment which
+    matches those tangents. The segments are then converted to cubic Béziers
+    staying within ``tolerance`` of the spirals.
+
+    If ``closed`` is true, the last point is joined back to the first and the
+    path is closed.
+
+    Returns a ``BezPath``. Raises ``ValueError`` if ``tolerance`` is not
+    positive.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def get_default_accuracy() -> float:
+    """
+    The accuracy used by measurements when no ``accuracy`` argument is
+    given. This is ``DEFAULT_ACCURACY`` unless changed with
+    ``set_default_accuracy`` or ``kurbopy.accuracy``.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def intersection_area(a: BezPath, b: BezPath, tolerance: float = ...) -> float:
+    """
+    The area of the region enclosed by both ``a`` and ``b``.
+
+    This measures how much two shapes overlap without building the path of
+    their intersection. Both paths are flattened to within ``tolerance`` and
+    filled with the nonzero rule, with open subpaths treated as closed, so
+    the result is exact for polygons and otherwise within about ``tolerance``
+    times the length of the overlapping outline.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def iou(a: Region, b: Region, tolerance: float = ...) -> float:
+    """
+    The intersection over union of two shapes: the area enclosed by both,
+    divided by the area enclosed by either. This is ``1.0`` for identical
+    shapes and ``0.0`` for shapes which do not overlap (or which are both
+    empty).
+
+    Each shape may be a ``BezPath`` or a ``Rect``. Paths are flattened to
+    within ``tolerance`` and filled with the nonzero rule, as with
+    ``intersection_area``; the result for two ``Rect`` objects is exact.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def match_paths(
+    a: BezPath,
+    b: BezPath,
+    samples: int = 64,
+    accuracy: float = ...,
+) -> Tuple[BezPath, BezPath]:
+    """
+    Resample two paths to a common structure so that they can be
+    interpolated with ``BezPath.lerp``.
+
+    Corresponding subpaths of the two paths are each split into ``samples``
+    cubic segments of equal arc length, so that the n-th segment of one
+    path corresponds to the same fraction of the length of the other.
+    Pieces which span a corner or a join between segments are approximated.
+    Where one path has more subpaths than the other, the extra subpaths are
+    matched with a subpath shrunk to a point at the center of their
+    bounding box. A subpath of the result is closed if both of the subpaths
+    it comes from are closed, in which case the subpath from ``b`` is
+    rotated to start at whichever piece best lines up with the start of the
+    subpath from ``a``.
+
+    Returns a tuple of the two resampled paths.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def min_bounding_ellipse(points: PointArray) -> Ellipse:
+    """
+    Find the smallest ellipse enclosing a set of points (the Löwner–John
+    ellipse).
+
+    The result is found to within a tiny fraction of the smallest area, and
+    always encloses every point. If all the points lie on a line, the
+    ellipse is degenerate, with a second radius of zero. Non-finite points
+    are ignored; raises ``ValueError`` if there are no finite points.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def minkowski_sum(a: BezPath, b: BezPath, tolerance: float = ...) -> BezPath:
+    """
+    The Minkowski sum of the regions enclosed by two paths: the shape
+    covered by ``b`` as its origin is moved over every point of ``a``.
+
+    Growing a shape by a circle of radius ``r`` centered on the origin is
+    the same as buffering it by ``r``, and the sum of a shape with another
+    shape reflected through the origin gives the region in which the two
+    overlap, which is useful for collision margins.
+
+    Both paths are flattened to within ``tolerance`` and filled with the
+    nonzero rule, with open subpaths treated as closed. The sum of two
+    convex shapes is quick to compute; otherwise the time taken grows with
+    the product of the number of flattened edges in each.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def points_bbox(points: PointArray) -> Rect:
+    """
+    Find the bounding box of a set of points.
+
+    ``points`` may be a list of points or a NumPy array of shape ``(n, 2)``.
+    Raises ``ValueError`` if there are no points.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def points_centroid(points: PointArray) -> Point:
+    """
+    Find the centroid (mean) of a set of points.
+
+    ``points`` may be a list of points or a NumPy array of shape ``(n, 2)``.
+    Raises ``ValueError`` if there are no points.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def points_principal_axes(points: PointArray) -> List[Tuple[Vec2, float]]:
+    """
+    Find the principal axes of a set of points: the directions in which
+    they are most and least spread out about their centroid.
+
+    Returns ``[(major, variance), (minor, variance)]``, where ``major`` and
+    ``minor`` are perpendicular unit ``Vec2`` objects and each ``variance``
+    is the variance of the points along that axis. ``points`` may be a list
+    of points or a NumPy array of shape ``(n, 2)``. Raises ``ValueError`` if
+    there are no points.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def set_default_accuracy(value: float) -> None:
+    """
+    Set the accuracy used by measurements (arc length, nearest point, inverse
+    arc length and so on) when no ``accuracy`` argument is given. The setting
+    is shared by all threads, except within a ``kurbopy.accuracy`` block.
+
+    To change the accuracy for a block of code in the current thread, use the
+    ``kurbopy.accuracy`` context manager::
+
+       with kurbopy.accuracy(1e-3):
+           length = path.perimeter()
+
+    Raises ``ValueError`` unless ``value`` is positive and finite.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def simplify_polyline(points: List[PointLike], epsilon: float) -> List[Point]:
+    """
+    Simplify a polyline with the Douglas–Peucker algorithm.
+
+    Returns the subset of ``points`` needed for the simplified polyline to
+    stay within ``epsilon`` of the original. The first and last points are
+    always kept. Raises ``ValueError`` if ``epsilon`` is negative.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def split_at_intersections(
+    a: BezPath,
+    b: BezPath,
+    accuracy: float = ...,
+) -> Tuple[BezPath, BezPath]:
+    """
+    Split two paths where they cross each other.
+
+    Returns a tuple of copies of ``a`` and ``b`` in which every point where
+    the two paths meet (found as for ``BezPath.intersect_path``, to within
+    ``accuracy``) is the end of a segment, with the same coordinates in both
+    paths. The shapes of the paths are otherwise unchanged, so the pieces
+    between crossings can be picked out with ``segments`` to build boolean
+    operations or to trim one path against another.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def sweep(profile: BezPath, spine: BezPath, tolerance: float = ...) -> BezPath:
+    """
+    Sweep a profile along a spine, returning the outline of the swept area.
+
+    The profile is placed with its origin on the spine and rotated so that
+    its positive x axis follows the spine's tangent, as if it were the nib of
+    a pen being drawn along the spine. The sides of the outline are traced by
+    the points of the profile which are furthest from the spine on either side,
+    and the ends are capped with the leading and trailing halves of the
+    profile. Open spine subpaths produce a single closed outline; closed
+    spine subpaths produce an outer and an inner outline.
+
+    The convex hull of the profile is used, and the result is made of straight
+    line segments, with ``tolerance`` used both to flatten the inputs and to
+    subdivide the rotation of the profile around corners. Profiles which
+    extend far along the tangent may be clipped where the spine turns sharply.
+    Raises ``ValueError`` unless ``tolerance`` is positive.
+
+    Note that this function is not in original kurbo
+    """
+    ...
+
+
+def union_bounding_box(shapes: Any) -> Rect:
+    """
+    Find the smallest rectangle enclosing all of the given shapes.
+
+    ``shapes`` may mix any of ``BezPath``, ``FrozenBezPath``, ``Rect``,
+    ``RoundedRect``, ``Circle``, ``CircleSegment``, ``Ellipse``, ``Arc``,
+    ``Line``, ``QuadBez``, ``CubicBez``, ``PathSeg`` and ``Point``. Raises
+    ``ValueError`` if there are no shapes, and ``TypeError`` for anything
+    else.
+
+    Note that this function is not in original kurbo
+    """
+    ...
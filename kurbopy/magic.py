@@ -6,8 +6,9 @@ import sys
 # different classes without driving Rust's type system mad. When a magic method
 # (eg. __add__) is called on a Rust object, the Rust object will call
 # `kurbopy.magic.magic_add(self, rhs)`. This will in turn look up a method on
-# the Rust object, `_add_Rhs`, where `Rhs` is the class name of the rhs object.
-# If this method is not found, a TypeError is raised.
+# the Rust object, `_add_Rhs`, where `Rhs` is the class name of the rhs object
+# or of one of its base classes. If this method is not found, a TypeError is
+# raised.
 
 
 def get_magic_name(obj):
@@ -17,12 +18,20 @@ def get_magic_name(obj):
     raise ValueError("No magic name for " + str(obj))
 
 
+OPERATORS = {"mul": "*", "add": "+", "sub": "-", "isub": "-=", "iadd": "+="}
+
+
 def do_magic(self, rhs, methodname):
     other_type = get_magic_name(rhs)
-    if hasattr(self, "_" + methodname + "_" + other_type):
-        return getattr(self, "_" + methodname + "_" + other_type)(rhs)
+    for cls in type(rhs).__mro__:
+        method = getattr(self, "_" + methodname + "_" + cls.__name__, None)
+        if method is not None:
+            return method(rhs)
     mytype = get_magic_name(self)
-    raise TypeError("Cannot %s %s by %s" % (methodname, mytype, other_type))
+    raise TypeError(
+        "unsupported operand type(s) for %s: '%s' and '%s'"
+        % (OPERATORS[methodname], mytype, other_type)
+    )
 
 
 for method in ["mul", "add", "sub", "isub", "iadd"]:
@@ -0,0 +1,241 @@
+"""Reading and writing SVG documents.
+
+Note that this module is not in original kurbo.
+"""
+import math
+import os
+import re
+import xml.etree.ElementTree as ET
+from xml.sax.saxutils import quoteattr
+
+from kurbopy import Affine, BezPath, Circle, Ellipse, Line, Rect, RoundedRect, Size
+
+
+def _format(value):
+    if isinstance(value, float) and value.is_integer():
+        value = int(value)
+    return str(value)
+
+
+def _attributes(attributes):
+    return "".join(
+        " %s=%s" % (key, quoteattr(_format(value))) for key, value in attributes.items()
+    )
+
+
+def write(file, paths, size=None, attributes=None):
+    """Write a list of BezPaths to a minimal SVG document.
+
+    ``file`` is a filename or a file-like object opened for writing text.
+
+    ``size`` gives the area of the document. It may be a ``Rect``, which
+    becomes the ``viewBox``; a ``Size`` or a ``(width, height)`` tuple, for
+    a view box with its origin at ``(0, 0)``; or ``None``, in which case the
+    view box is the bounding box of all the paths.
+
+    ``attributes`` gives the attributes of the ``<path>`` elements, such as
+    ``{"fill": "none", "stroke": "red"}``. It may be a single dict applied to
+    every path, or a list with one dict (or ``None``) for each path.
+
+    ::
+
+        from kurbopy import Circle, svg
+        circle = Circle((50, 50), 40).to_path(0.1)
+        svg.write("circle.svg", [circle], size=(100, 100),
+                  attributes={"fill": "none", "stroke": "black"})
+    """
+    paths = list(paths)
+    if attributes is None or isinstance(attributes, dict):
+        attributes = [attributes] * len(paths)
+    else:
+        attributes = list(attributes)
+        if len(attributes) != len(paths):
+            raise ValueError("need one set of attributes for each path")
+
+    if size is None:
+        view = None
+        for path in paths:
+            bounds = path.bounding_box()
+            view = bounds if view is None else view.union(bounds)
+        if view is None:
+            view = Rect(0, 0, 0, 0)
+    elif isinstance(size, Rect):
+        view = size
+    elif isinstance(size, Size):
+        view = Rect(0, 0, size.width, size.height)
+    else:
+        width, height = size
+        view = Rect(0, 0, width, height)
+
+    lines = [
+        '<svg xmlns="http://www.w3.org/2000/svg" width="%s" height="%s" viewBox="%s">'
+        % (
+            _format(view.width()),
+            _format(view.height()),
+            " ".join(_format(v) for v in (view.x0, view.y0, view.width(), view.height())),
+        )
+    ]
+    for path, attrs in zip(paths, attributes):
+        lines.append(
+            "  <path d=%s%s/>" % (quoteattr(path.to_svg()), _attributes(attrs or {}))
+        )
+    lines.append("</svg>")
+    text = "\n".join(lines) + "\n"
+
+    if isinstance(file, (str, os.PathLike)):
+        with open(file, "w", encoding="utf-8") as f:
+            f.write(text)
+    else:
+        file.write(text)
+
+
+_NUMBER = re.compile(r"[-+]?(?:\d+\.?\d*|\.\d+)(?:[eE][-+]?\d+)?")
+_TRANSFORM = re.compile(r"([A-Za-z]+)\s*\(([^)]*)\)")
+# Elements whose contents are not drawn directly.
+_NOT_DRAWN = {"defs", "symbol", "clipPath", "mask", "pattern", "marker"}
+
+
+def _numbers(text):
+    return [float(n) for n in _NUMBER.findall(text or "")]
+
+
+def _length(element, name, default=0.0):
+    value = element.get(name)
+    if value is None:
+        return default
+    value = value.strip()
+    if value.endswith("px"):
+        value = value[:-2]
+    try:
+        return float(value)
+    except ValueError:
+        raise ValueError("unsupported length %s=%r" % (name, element.get(name)))
+
+
+def parse_transform(text):
+    """Parse an SVG ``transform`` attribute into an ``Affine``.
+
+    ``matrix``, ``translate``, ``scale``, ``rotate``, ``skewX`` and
+    ``skewY`` are supported; a list of transforms is applied right to left,
+    as in SVG.
+    """
+    result = Affine.IDENTITY()
+    for name, args in _TRANSFORM.findall(text or ""):
+        args = _numbers(args)
+        if name == "matrix" and len(args) == 6:
+            transform = Affine(*args)
+        elif name == "translate" and len(args) in (1, 2):
+            transform = Affine.translate((args[0], args[1] if len(args) == 2 else 0.0))
+        elif name == "scale" and len(args) in (1, 2):
+            transform = Affine.scale_non_uniform(args[0], args[-1])
+        elif name == "rotate" and len(args) == 1:
+            transform = Affine.rotate(math.radians(args[0]))
+        elif name == "rotate" and len(args) == 3:
+            transform = Affine.rotate_about(math.radians(args[0]), (args[1], args[2]))
+        elif name == "skewX" and len(args) == 1:
+            transform = Affine(1, 0, math.tan(math.radians(args[0])), 1, 0, 0)
+        elif name == "skewY" and len(args) == 1:
+            transform = Affine(1, math.tan(math.radians(args[0])), 0, 1, 0, 0)
+        else:
+            raise ValueError("invalid transform %s(%s)" % (name, ", ".join(map(str, args))))
+        result = result * transform
+    return result
+
+
+def _polyline(element, closed):
+    coords = _numbers(element.get("points"))
+    path = BezPath()
+    for i, point in enumerate(zip(coords[0::2], coords[1::2])):
+        if i == 0:
+            path.move_to(point)
+        else:
+            path.line_to(point)
+    if closed and len(coords) >= 2:
+        path.close_path()
+    return path
+
+
+def _rect(element):
+    x, y = _length(element, "x"), _length(element, "y")
+    width, height = _length(element, "width"), _length(element, "height")
+    rx, ry = element.get("rx"), element.get("ry")
+    rx = _length(element, "rx") if rx is not None else None
+    ry = _length(element, "ry") if ry is not None else None
+    if rx is None and ry is None:
+        return Rect(x, y, x + width, y + height)
+    rx = min(rx if rx is not None else ry, width / 2)
+    ry = min(ry if ry is not None else rx, height / 2)
+    if rx == ry:
+        return RoundedRect(x, y, x + width, y + height, rx)
+    # Elliptical corners, which RoundedRect cannot represent.
+    return BezPath.from_svg(
+        "M%r,%r H%r A%r,%r 0 0 1 %r,%r V%r A%r,%r 0 0 1 %r,%r H%r "
+        "A%r,%r 0 0 1 %r,%r V%r A%r,%r 0 0 1 %r,%r Z"
+        % (
+            x + rx, y, x + width - rx, rx, ry, x + width, y + ry,
+            y + height - ry, rx, ry, x + width - rx, y + height, x + rx,
+            rx, ry, x, y + height - ry, y + ry, rx, ry, x + rx, y,
+        )
+    )
+
+
+def _shape(tag, element):
+    if tag == "path":
+        return BezPath.from_svg(element.get("d", ""))
+    if tag == "rect":
+        return _rect(element)
+    if tag == "circle":
+        center = (_length(element, "cx"), _length(element, "cy"))
+        return Circle(center, _length(element, "r"))
+    if tag == "ellipse":
+        center = (_length(element, "cx"), _length(element, "cy"))
+        return Ellipse(center, (_length(element, "rx"), _length(element, "ry")), 0.0)
+    if tag == "line":
+        return Line(
+            (_length(element, "x1"), _length(element, "y1")),
+            (_length(element, "x2"), _length(element, "y2")),
+        )
+    if tag == "polygon":
+        return _polyline(element, True)
+    if tag == "polyline":
+        return _polyline(element, False)
+    return None
+
+
+def read(file):
+    """Read the shapes from an SVG document.
+
+    ``file`` is a filename or a file-like object. Returns a list of
+    ``(shape, transform)`` tuples, in document order, where ``transform`` is
+    an ``Affine`` combining the ``transform`` attributes of the element and
+    all of its ancestors.
+
+    ``<path>``, ``<polygon>`` and ``<polyline>`` elements become ``BezPath``
+    objects, ``<rect>`` a ``Rect`` (or a ``RoundedRect`` if it has rounded
+    corners), ``<circle>`` a ``Circle``, ``<ellipse>`` an ``Ellipse`` and
+    ``<line>`` a ``Line``. Other elements are ignored, as is anything
+    inside ``<defs>`` and similar elements which are not drawn directly.
+
+    ::
+
+        from kurbopy import svg
+        for shape, transform in svg.read("drawing.svg"):
+            path = transform * shape.to_path(0.1)
+    """
+    result = []
+
+    def visit(element, parent_transform):
+        tag = element.tag.rsplit("}", 1)[-1]
+        if tag in _NOT_DRAWN:
+            return
+        transform = parent_transform
+        if element.get("transform"):
+            transform = parent_transform * parse_transform(element.get("transform"))
+        shape = _shape(tag, element)
+        if shape is not None:
+            result.append((shape, transform))
+        for child in element:
+            visit(child, transform)
+
+    visit(ET.parse(file).getroot(), Affine.IDENTITY())
+    return result
@@ -0,0 +1,46 @@
+// Helpers for the `return_error` option of methods which take an accuracy.
+// The achieved error is estimated by comparing the result against one
+// computed to a much tighter accuracy.
+use kurbo::{ParamCurve, ParamCurveNearest, PathSeg as KPathSeg};
+use pyo3::prelude::*;
+
+/// How much tighter the reference computation is than the requested accuracy.
+pub(crate) const REFINEMENT: f64 = 0.01;
+
+/// The largest distance from points sampled along `coarse` to the nearest
+/// of the `fine` segments.
+pub(crate) fn max_deviation(
+    coarse: impl Iterator<Item = KPathSeg>,
+    fine: &[KPathSeg],
+    accuracy: f64,
+) -> f64 {
+    const SAMPLES: usize = 8;
+    let mut worst: f64 = 0.0;
+    for seg in coarse {
+        for i in 0..=SAMPLES {
+            let pt = seg.eval(i as f64 / SAMPLES as f64);
+            let dist_sq = fine
+                .iter()
+                .map(|f| f.nearest(pt, accuracy).distance_sq)
+                .fold(f64::INFINITY, f64::min);
+            if dist_sq.is_finite() {
+                worst = worst.max(dist_sq.sqrt());
+            }
+        }
+    }
+    worst
+}
+
+/// Return `value` on its own, or as a `(value, error)` tuple if requested.
+pub(crate) fn with_error(
+    py: Python<'_>,
+    value: impl IntoPy<PyObject>,
+    error: impl FnOnce() -> f64,
+    return_error: bool,
+) -> PyObject {
+    if return_error {
+        (value.into_py(py), error()).into_py(py)
+    } else {
+        value.into_py(py)
+    }
+}
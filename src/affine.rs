@@ -1,3 +1,5 @@
+use crate::approx::ApproxEq;
+use crate::impl_serialize;
 use crate::arc::Arc;
 use crate::bezpath::BezPath;
 use crate::circle::Circle;
@@ -6,14 +8,17 @@ use crate::ellipse::Ellipse;
 use crate::line::Line;
 use crate::pathel::PathEl;
 use crate::pathseg::PathSeg;
-use crate::point::Point;
+use crate::point::{Point, PointArray, PointLike};
 use crate::quadbez::QuadBez;
 use crate::rect::Rect;
-use crate::vec2::Vec2;
-use kurbo::Affine as KAffine;
+use crate::subclass::{new_like, new_of_type};
+use crate::translatescale::TranslateScale;
+use crate::vec2::{Vec2, Vec2Like};
+use kurbo::{Affine as KAffine, Point as KPoint, TranslateScale as KTranslateScale, Vec2 as KVec2};
 use std::ops::Mul;
 use crate::{impl_isfinitenan, polymorphic};
 
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -27,28 +32,44 @@ impl From<KAffine> for Affine {
         Self(p)
     }
 }
+/// A transform argument, which may be given as an `Affine` or a
+/// `TranslateScale`.
+#[derive(Clone, Copy, Debug)]
+pub struct AffineLike(pub KAffine);
+
+impl<'py> FromPyObject<'py> for AffineLike {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(a) = ob.downcast::<Affine>() {
+            return Ok(AffineLike(a.borrow().0));
+        }
+        ob.downcast::<TranslateScale>()
+            .map(|ts| AffineLike(ts.borrow().0.into()))
+            .map_err(|_| PyTypeError::new_err("expected Affine or TranslateScale"))
+    }
+}
+
 #[pymethods]
 impl Affine {
     #[classmethod]
     #[allow(non_snake_case)]
     /// The identity transform.
-    fn IDENTITY(_cls: &Bound<'_, PyType>) -> Self {
-        Affine(KAffine::IDENTITY)
+    fn IDENTITY(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::IDENTITY))
     }
 
     #[classmethod]
     #[allow(non_snake_case)]
     /// A transform that is flipped on the y-axis. Useful for converting between
     /// y-up and y-down spaces.
-    fn FLIP_Y(_cls: &Bound<'_, PyType>) -> Self {
-        Affine(KAffine::FLIP_Y)
+    fn FLIP_Y(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::FLIP_Y))
     }
 
     #[classmethod]
     #[allow(non_snake_case)]
     /// A transform that is flipped on the x-axis.
-    fn FLIP_X(_cls: &Bound<'_, PyType>) -> Self {
-        Affine(KAffine::FLIP_X)
+    fn FLIP_X(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::FLIP_X))
     }
 
     /// Construct an affine transform from coefficients.
@@ -75,15 +96,15 @@ impl Affine {
 
     /// An affine transform representing uniform scaling.
     #[classmethod]
-    pub const fn scale(_cls: &Bound<'_, PyType>, s: f64) -> Affine {
-        Affine(KAffine::scale(s))
+    pub fn scale(cls: &Bound<'_, PyType>, s: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::scale(s)))
     }
 
     /// An affine transform representing non-uniform scaling
     /// with different scale values for x and y
     #[classmethod]
-    pub fn scale_non_uniform(_cls: &Bound<'_, PyType>, sx: f64, sy: f64) -> Affine {
-        Affine(KAffine::scale_non_uniform(sx, sy))
+    pub fn scale_non_uniform(cls: &Bound<'_, PyType>, sx: f64, sy: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::scale_non_uniform(sx, sy)))
     }
 
     /// An affine transform representing rotation.
@@ -95,22 +116,22 @@ impl Affine {
     ///
     /// The angle, `th`, is expressed in radians.
     #[classmethod]
-    pub fn rotate(_cls: &Bound<'_, PyType>, th: f64) -> Affine {
-        Affine(KAffine::rotate(th))
+    pub fn rotate(cls: &Bound<'_, PyType>, th: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::rotate(th)))
     }
 
     /// An affine transform representing a rotation of `th` radians about `center`.
     ///
     /// See [`Affine::rotate()`] for more info.
     #[classmethod]
-    pub fn rotate_about(_cls: &Bound<'_, PyType>, th: f64, center: &Point) -> Affine {
-        Affine(KAffine::rotate_about(th, center.0))
+    pub fn rotate_about(cls: &Bound<'_, PyType>, th: f64, center: PointLike) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::rotate_about(th, center.0)))
     }
 
     /// An affine transform representing translation.
     #[classmethod]
-    pub fn translate(_cls: &Bound<'_, PyType>, p: &Point) -> Affine {
-        Affine(KAffine::translate(p.to_vec2().0))
+    pub fn translate(cls: &Bound<'_, PyType>, p: PointLike) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::translate(p.0.to_vec2())))
     }
 
     /// An affine transformation representing a skew.
@@ -127,8 +148,8 @@ impl Affine {
     /// let oblique_transform = kurbo::Affine::skew(20f64.to_radians().tan(), 0.0);
     /// ```
     #[classmethod]
-    pub fn skew(_cls: &Bound<'_, PyType>, skew_x: f64, skew_y: f64) -> Affine {
-        Affine(KAffine::skew(skew_x, skew_y))
+    pub fn skew(cls: &Bound<'_, PyType>, skew_x: f64, skew_y: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::skew(skew_x, skew_y)))
     }
 
     /// Create an affine transform that represents reflection about the line `point + direction * t, t in (-infty, infty)`
@@ -148,78 +169,78 @@ impl Affine {
     /// assert_near(map * Point::new(2., 2.), Point::new(3., 1.));
     /// ```
     #[classmethod]
-    pub fn reflect(_cls: &Bound<'_, PyType>, point: &Point, direction: &Vec2) -> Affine {
-        Affine(KAffine::reflect(point.0, direction.0))
+    pub fn reflect(cls: &Bound<'_, PyType>, point: PointLike, direction: Vec2Like) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::reflect(point.0, direction.0)))
     }
 
     /// A rotation by `th` followed by `self`.
     ///
     /// Equivalent to `self * Affine::rotate(th)`
-    pub fn pre_rotate(&self, th: f64) -> Self {
-        Affine(self.0 * KAffine::rotate(th))
+    pub fn pre_rotate(slf: &Bound<'_, Self>, th: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0 * KAffine::rotate(th)))
     }
 
     /// A rotation by `th` about `center` followed by `self`.
     ///
     /// Equivalent to `self * Affine::rotate_about(th)`
-    fn pre_rotate_about(&self, th: f64, center: &Point) -> Self {
-        Affine(self.0 * KAffine::rotate_about(th, center.0))
+    fn pre_rotate_about(slf: &Bound<'_, Self>, th: f64, center: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0 * KAffine::rotate_about(th, center.0)))
     }
 
     /// A scale by `scale` followed by `self`.
     ///
     /// Equivalent to `self * Affine::scale(scale)`
-    pub fn pre_scale(&self, scale: f64) -> Self {
-        Affine(self.0 * KAffine::scale(scale))
+    pub fn pre_scale(slf: &Bound<'_, Self>, scale: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0 * KAffine::scale(scale)))
     }
 
     /// A scale by `(scale_x, scale_y)` followed by `self`.
     ///
     /// Equivalent to `self * Affine::scale_non_uniform(scale_x, scale_y)`
-    pub fn pre_scale_non_uniform(&self, scale_x: f64, scale_y: f64) -> Self {
-        Affine(self.0 * KAffine::scale_non_uniform(scale_x, scale_y))
+    pub fn pre_scale_non_uniform(slf: &Bound<'_, Self>, scale_x: f64, scale_y: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0 * KAffine::scale_non_uniform(scale_x, scale_y)))
     }
 
     /// A translation of `trans` followed by `self`.
     ///
     /// Equivalent to `self * Affine::translate(trans)`
-    pub fn pre_translate(&self, trans: &Vec2) -> Self {
-        Affine(self.0 * KAffine::translate(trans.0))
+    pub fn pre_translate(slf: &Bound<'_, Self>, trans: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0 * KAffine::translate(trans.0)))
     }
 
     /// `self` followed by a rotation of `th`.
     ///
     /// Equivalent to `Affine::rotate(th) * self`
-    pub fn then_rotate(&self, th: f64) -> Self {
-        Affine(KAffine::rotate(th) * self.0)
+    pub fn then_rotate(slf: &Bound<'_, Self>, th: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(KAffine::rotate(th) * slf.borrow().0))
     }
 
     /// `self` followed by a rotation of `th` about `center`.
     ///
     /// Equivalent to `Affine::rotate_about(th, center) * self`
-    pub fn then_rotate_about(&self, th: f64, center: &Point) -> Self {
-        Affine(KAffine::rotate_about(th, center.0) * self.0)
+    pub fn then_rotate_about(slf: &Bound<'_, Self>, th: f64, center: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(KAffine::rotate_about(th, center.0) * slf.borrow().0))
     }
 
     /// `self` followed by a scale of `scale`.
     ///
     /// Equivalent to `Affine::scale(scale) * self`
-    pub fn then_scale(&self, scale: f64) -> Self {
-        Affine(KAffine::scale(scale) * self.0)
+    pub fn then_scale(slf: &Bound<'_, Self>, scale: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(KAffine::scale(scale) * slf.borrow().0))
     }
 
     /// `self` followed by a scale of `(scale_x, scale_y)`.
     ///
     /// Equivalent to `Affine::scale_non_uniform(scale_x, scale_y) * self`
-    pub fn then_scale_non_uniform(&self, scale_x: f64, scale_y: f64) -> Self {
-        Affine(KAffine::scale_non_uniform(scale_x, scale_y) * self.0)
+    pub fn then_scale_non_uniform(slf: &Bound<'_, Self>, scale_x: f64, scale_y: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(KAffine::scale_non_uniform(scale_x, scale_y) * slf.borrow().0))
     }
 
     /// `self` followed by a translation of `trans`.
     ///
     /// Equivalent to `Affine::translate(trans) * self`
-    pub fn then_translate(&self, trans: &Vec2) -> Self {
-        Affine(KAffine::translate(trans.0) * self.0)
+    pub fn then_translate(slf: &Bound<'_, Self>, trans: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(KAffine::translate(trans.0) * slf.borrow().0))
     }
 
     /// Creates an affine transformation that takes the unit square to the given rectangle.
@@ -227,8 +248,52 @@ impl Affine {
     /// Useful when you want to draw into the unit square but have your output fill any rectangle.
     /// In this case push the `Affine` onto the transform stack.
     #[classmethod]
-    pub fn map_unit_square(_cls: &Bound<'_, PyType>, rect: &Rect) -> Affine {
-        Affine(KAffine::map_unit_square(rect.0))
+    pub fn map_unit_square(cls: &Bound<'_, PyType>, rect: &Rect) -> PyResult<Py<Self>> {
+        new_of_type(cls, Affine(KAffine::map_unit_square(rect.0)))
+    }
+
+    /// Find the transform which best maps ``src_points`` onto ``dst_points``.
+    ///
+    /// The transform minimizes the sum of squared distances between the
+    /// transformed source points and the corresponding destination points.
+    /// ``kind`` restricts the transforms considered:
+    ///
+    /// - ``"affine"``: any affine transform (needs three or more points,
+    ///   not all on a line);
+    /// - ``"similarity"``: rotation, uniform scaling and translation;
+    /// - ``"rigid"``: rotation and translation only.
+    ///
+    /// Reflections are only produced by ``"affine"``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    #[pyo3(signature = (src_points, dst_points, kind="affine"))]
+    pub fn fit(
+        cls: &Bound<'_, PyType>,
+        src_points: Vec<PointLike>,
+        dst_points: Vec<PointLike>,
+        kind: &str,
+    ) -> PyResult<Py<Self>> {
+        if src_points.len() != dst_points.len() {
+            return Err(PyValueError::new_err(
+                "source and destination must have the same number of points",
+            ));
+        }
+        let pairs: Vec<(KPoint, KPoint)> = src_points
+            .iter()
+            .zip(dst_points.iter())
+            .map(|(s, d)| (s.0, d.0))
+            .collect();
+        let fitted = match kind {
+            "affine" => fit_affine(&pairs),
+            "similarity" => fit_similarity(&pairs, true),
+            "rigid" => fit_similarity(&pairs, false),
+            _ => Err(PyValueError::new_err(format!(
+                "unknown kind {:?}; expected \"affine\", \"similarity\" or \"rigid\"",
+                kind
+            ))),
+        }?;
+        new_of_type(cls, Affine(fitted))
     }
 
     /// Get the coefficients of the transform.
@@ -241,11 +306,61 @@ impl Affine {
         self.0.determinant()
     }
 
+    /// Apply the transform to many points at once.
+    ///
+    /// ``points`` may be a NumPy array of shape ``(n, 2)``, in which case an
+    /// array of the same shape is returned, or a list of points, in which
+    /// case a list of :py:class:`Point` is returned.
+    ///
+    /// Note that this method is not in original kurbo
+    fn transform_points(&self, py: Python, points: PointArray) -> PyResult<PyObject> {
+        let result = points.points.iter().map(|&p| self.0 * p).collect();
+        points.points_in_kind(py, result)
+    }
+
     /// Compute the inverse transform.
     ///
-    /// Produces NaN values when the determinant is zero.
-    pub fn inverse(&self) -> Affine {
-        Affine(self.0.inverse())
+    /// Produces NaN values when the determinant is zero; use
+    /// ``is_invertible`` to check first.
+    pub fn inverse(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0.inverse()))
+    }
+
+    /// Returns true if the transform can be inverted, that is, if the
+    /// magnitude of its determinant is greater than ``epsilon``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (epsilon=f64::EPSILON))]
+    pub fn is_invertible(&self, epsilon: f64) -> bool {
+        let det = self.0.determinant();
+        det.is_finite() && det.abs() > epsilon
+    }
+
+    /// Returns true if each coefficient of this transform is within
+    /// ``epsilon`` of the corresponding coefficient of ``other``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, epsilon=1e-9))]
+    pub fn approx_eq(&self, other: &Affine, epsilon: f64) -> bool {
+        self.0.approx_eq(&other.0, epsilon)
+    }
+
+    /// Convert this transform into a `TranslateScale`, if it consists only
+    /// of uniform scaling and translation (to within ``epsilon`` in each
+    /// coefficient). Returns ``None`` otherwise.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (epsilon=1e-9))]
+    pub fn as_translate_scale(&self, epsilon: f64) -> Option<TranslateScale> {
+        let [a, b, c, d, e, f] = self.0.as_coeffs();
+        if b.abs() <= epsilon && c.abs() <= epsilon && (a - d).abs() <= epsilon {
+            Some(TranslateScale(KTranslateScale::new(
+                KVec2::new(e, f),
+                (a + d) / 2.0,
+            )))
+        } else {
+            None
+        }
     }
 
     /// Compute the bounding box of a transformed rectangle.
@@ -267,8 +382,8 @@ impl Affine {
     /// Replaces the translation portion of this affine map
     ///
     /// The translation can be seen as being applied after the linear part of the map.
-    pub fn with_translation(&self, trans: &Vec2) -> Affine {
-        Affine(self.0.with_translation(trans.0))
+    pub fn with_translation(slf: &Bound<'_, Self>, trans: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(slf.borrow().0.with_translation(trans.0)))
     }
 
     #[allow(non_snake_case)]
@@ -282,12 +397,95 @@ impl Affine {
     }
 
     #[allow(non_snake_case)]
-    fn __rmul__(&self, rhs: f64) -> Affine {
-        Affine(rhs * self.0)
+    fn __rmul__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Affine(rhs * slf.borrow().0))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Affine({})", crate::repr::floats(&self.0.as_coeffs()))
     }
 }
 
+/// Least-squares fit of a general affine transform.
+fn fit_affine(pairs: &[(KPoint, KPoint)]) -> PyResult<KAffine> {
+    // Normal equations: m * (a, c, e) = rx and m * (b, d, f) = ry.
+    let mut m = [[0.0; 3]; 3];
+    let mut rx = [0.0; 3];
+    let mut ry = [0.0; 3];
+    for &(s, d) in pairs {
+        let v = [s.x, s.y, 1.0];
+        for i in 0..3 {
+            for j in 0..3 {
+                m[i][j] += v[i] * v[j];
+            }
+            rx[i] += v[i] * d.x;
+            ry[i] += v[i] * d.y;
+        }
+    }
+    let det3 = |m: &[[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let det = det3(&m);
+    let scale = m.iter().flatten().fold(0.0_f64, |a, b| a.max(b.abs()));
+    if pairs.len() < 3 || det.is_nan() || det.abs() <= 1e-12 * scale.powi(3) {
+        return Err(PyValueError::new_err(
+            "an affine fit needs at least three points which are not collinear",
+        ));
+    }
+    // Cramer's rule
+    let solve = |r: &[f64; 3]| -> [f64; 3] {
+        let mut x = [0.0; 3];
+        for (k, xk) in x.iter_mut().enumerate() {
+            let mut mk = m;
+            for i in 0..3 {
+                mk[i][k] = r[i];
+            }
+            *xk = det3(&mk) / det;
+        }
+        x
+    };
+    let [a, c, e] = solve(&rx);
+    let [b, d, f] = solve(&ry);
+    Ok(KAffine::new([a, b, c, d, e, f]))
+}
+
+/// Least-squares fit of a rotation and translation, with uniform scaling if
+/// `scale` is true.
+fn fit_similarity(pairs: &[(KPoint, KPoint)], scale: bool) -> PyResult<KAffine> {
+    if pairs.is_empty() {
+        return Err(PyValueError::new_err("no points to fit"));
+    }
+    let n = pairs.len() as f64;
+    let src_center = pairs.iter().fold(KVec2::ZERO, |acc, (s, _)| acc + s.to_vec2()) / n;
+    let dst_center = pairs.iter().fold(KVec2::ZERO, |acc, (_, d)| acc + d.to_vec2()) / n;
+    let (mut dot, mut cross, mut norm) = (0.0, 0.0, 0.0);
+    for &(s, d) in pairs {
+        let p = s.to_vec2() - src_center;
+        let q = d.to_vec2() - dst_center;
+        dot += p.x * q.x + p.y * q.y;
+        cross += p.x * q.y - p.y * q.x;
+        norm += p.hypot2();
+    }
+    let angle = cross.atan2(dot);
+    let factor = if !scale {
+        1.0
+    } else if norm > 0.0 {
+        dot.hypot(cross) / norm
+    } else {
+        return Err(PyValueError::new_err(
+            "a similarity fit needs at least two distinct points",
+        ));
+    };
+    Ok(KAffine::translate(dst_center)
+        * KAffine::rotate(angle)
+        * KAffine::scale(factor)
+        * KAffine::translate(-src_center))
+}
+
 impl_isfinitenan!(Affine);
+impl_serialize!(Affine);
 polymorphic!(mul Affine =>
     (_mul_Point, Point, Point),
     (_mul_Affine, Affine, Affine),
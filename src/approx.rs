@@ -0,0 +1,125 @@
+// Comparison of values to within a tolerance, coordinate by coordinate.
+use kurbo::{
+    Affine as KAffine, BezPath as KBezPath, CubicBez as KCubicBez, Line as KLine,
+    PathEl as KPathEl, PathSeg as KPathSeg, Point as KPoint, QuadBez as KQuadBez, Rect as KRect,
+    Vec2 as KVec2,
+};
+
+fn close(a: f64, b: f64, epsilon: f64) -> bool {
+    a == b || (a - b).abs() <= epsilon
+}
+
+fn all_close(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| close(*x, *y, epsilon))
+}
+
+/// Values which can be compared to within a tolerance. Two values are
+/// approximately equal if they have the same structure and each pair of
+/// corresponding coordinates differs by at most `epsilon`.
+pub(crate) trait ApproxEq {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool;
+}
+
+impl ApproxEq for KPoint {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        close(self.x, other.x, epsilon) && close(self.y, other.y, epsilon)
+    }
+}
+
+impl ApproxEq for KVec2 {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        close(self.x, other.x, epsilon) && close(self.y, other.y, epsilon)
+    }
+}
+
+impl ApproxEq for KRect {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        all_close(
+            &[self.x0, self.y0, self.x1, self.y1],
+            &[other.x0, other.y0, other.x1, other.y1],
+            epsilon,
+        )
+    }
+}
+
+impl ApproxEq for KAffine {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        all_close(&self.as_coeffs(), &other.as_coeffs(), epsilon)
+    }
+}
+
+impl ApproxEq for KLine {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.p0.approx_eq(&other.p0, epsilon) && self.p1.approx_eq(&other.p1, epsilon)
+    }
+}
+
+impl ApproxEq for KQuadBez {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.p0.approx_eq(&other.p0, epsilon)
+            && self.p1.approx_eq(&other.p1, epsilon)
+            && self.p2.approx_eq(&other.p2, epsilon)
+    }
+}
+
+impl ApproxEq for KCubicBez {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.p0.approx_eq(&other.p0, epsilon)
+            && self.p1.approx_eq(&other.p1, epsilon)
+            && self.p2.approx_eq(&other.p2, epsilon)
+            && self.p3.approx_eq(&other.p3, epsilon)
+    }
+}
+
+impl ApproxEq for KPathSeg {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (KPathSeg::Line(a), KPathSeg::Line(b)) => a.approx_eq(b, epsilon),
+            (KPathSeg::Quad(a), KPathSeg::Quad(b)) => a.approx_eq(b, epsilon),
+            (KPathSeg::Cubic(a), KPathSeg::Cubic(b)) => a.approx_eq(b, epsilon),
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for KPathEl {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        match (self, other) {
+            (KPathEl::MoveTo(a), KPathEl::MoveTo(b)) => a.approx_eq(b, epsilon),
+            (KPathEl::LineTo(a), KPathEl::LineTo(b)) => a.approx_eq(b, epsilon),
+            (KPathEl::QuadTo(a1, a2), KPathEl::QuadTo(b1, b2)) => {
+                a1.approx_eq(b1, epsilon) && a2.approx_eq(b2, epsilon)
+            }
+            (KPathEl::CurveTo(a1, a2, a3), KPathEl::CurveTo(b1, b2, b3)) => {
+                a1.approx_eq(b1, epsilon) && a2.approx_eq(b2, epsilon) && a3.approx_eq(b3, epsilon)
+            }
+            (KPathEl::ClosePath, KPathEl::ClosePath) => true,
+            _ => false,
+        }
+    }
+}
+
+impl ApproxEq for KBezPath {
+    fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        let (a, b) = (self.elements(), other.elements());
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.approx_eq(y, epsilon))
+    }
+}
+
+#[macro_export]
+macro_rules! impl_approx_eq {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// Compare with another value of the same type, allowing each
+            /// coordinate to differ by up to ``epsilon``.
+            ///
+            /// Note that this method is not in original kurbo
+            #[pyo3(signature = (other, epsilon=1e-9))]
+            fn approx_eq(&self, other: &$name, epsilon: f64) -> bool {
+                use $crate::approx::ApproxEq;
+                self.0.approx_eq(&other.0, epsilon)
+            }
+        }
+    };
+}
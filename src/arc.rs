@@ -1,10 +1,14 @@
+use crate::impl_serialize;
 use crate::impl_shape;
-use crate::point::Point;
+use crate::point::{Point, PointLike};
 use crate::rect::Rect;
-use crate::vec2::Vec2;
+use crate::subclass::new_of_type;
+use crate::vec2::{Vec2, Vec2Like};
 
-use kurbo::{Arc as KArc, Point as KPoint, Shape};
+use kurbo::{Arc as KArc, Point as KPoint, Shape, SvgArc};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
+use pyo3::types::PyType;
 
 #[derive(Clone, Debug)]
 #[pyclass(subclass, module = "kurbopy")]
@@ -22,8 +26,8 @@ impl Arc {
     /// Create a new `Arc`.
     #[new]
     pub fn __new__(
-        center: Point,
-        radii: Vec2,
+        center: PointLike,
+        radii: Vec2Like,
         start_angle: f64,
         sweep_angle: f64,
         x_rotation: f64,
@@ -37,12 +41,140 @@ impl Arc {
         ))
     }
 
+    /// Create an `Arc` from the SVG "endpoint" arc parameterization.
+    ///
+    /// The arguments follow the order of the SVG ``A`` path command:
+    /// the start point, the two radii, the rotation of the ellipse's
+    /// x-axis (in radians), the large-arc and sweep flags, and the end point.
+    ///
+    /// Returns ``None`` if the arc degenerates to a straight line (for
+    /// example, if either radius is zero or the endpoints coincide).
+    #[classmethod]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(text_signature = "(cls, start, rx, ry, x_rotation, large_arc, sweep, end)")]
+    fn from_svg_arc(
+        cls: &Bound<'_, PyType>,
+        start: PointLike,
+        rx: f64,
+        ry: f64,
+        x_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        end: PointLike,
+    ) -> PyResult<Option<Py<Self>>> {
+        let arc = SvgArc {
+            from: start.0,
+            to: end.0,
+            radii: kurbo::Vec2::new(rx, ry),
+            x_rotation,
+            large_arc,
+            sweep,
+        };
+        KArc::from_svg_arc(&arc)
+            .map(|arc| new_of_type(cls, Arc(arc)))
+            .transpose()
+    }
+
+    /// Create a circular `Arc` which starts at ``p0``, passes through ``p1``
+    /// and ends at ``p2``.
+    ///
+    /// Returns ``None`` if the points are collinear, so that no circle passes
+    /// through them.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    #[pyo3(text_signature = "(cls, p0, p1, p2)")]
+    fn from_three_points(
+        cls: &Bound<'_, PyType>,
+        p0: PointLike,
+        p1: PointLike,
+        p2: PointLike,
+    ) -> PyResult<Option<Py<Self>>> {
+        let (p0, p1, p2) = (p0.0, p1.0, p2.0);
+        let (b, c) = (p1 - p0, p2 - p0);
+        let det = 2.0 * b.cross(c);
+        if det == 0.0 || !det.is_finite() {
+            return Ok(None);
+        }
+        // The circumcenter, relative to p0.
+        let offset = kurbo::Vec2::new(
+            c.y * b.hypot2() - b.y * c.hypot2(),
+            b.x * c.hypot2() - c.x * b.hypot2(),
+        ) / det;
+        let center = p0 + offset;
+        let radius = offset.hypot();
+        let angle = |p: KPoint| (p - center).atan2();
+        let start_angle = angle(p0);
+        let tau = std::f64::consts::TAU;
+        let sweep = (angle(p2) - start_angle).rem_euclid(tau);
+        let through = (angle(p1) - start_angle).rem_euclid(tau);
+        // Go whichever way round passes through p1.
+        let sweep_angle = if through <= sweep { sweep } else { sweep - tau };
+        let arc = KArc::new(
+            center,
+            kurbo::Vec2::new(radius, radius),
+            start_angle,
+            sweep_angle,
+            0.0,
+        );
+        new_of_type(cls, Arc(arc)).map(Some)
+    }
+
+    /// Create a circular `Arc` around ``center``, from ``start`` to the
+    /// direction of ``end``.
+    ///
+    /// The radius is the distance from ``center`` to ``start``; ``end`` only
+    /// sets the angle at which the arc finishes. ``direction`` is
+    /// ``"anticlockwise"`` (the direction of increasing angles, which is
+    /// anticlockwise in a y-up coordinate system) or ``"clockwise"``. If
+    /// ``start`` and ``end`` are in the same direction from ``center``, the
+    /// arc is a full circle.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    #[pyo3(signature = (center, start, end, direction="anticlockwise"))]
+    fn from_center_endpoints(
+        cls: &Bound<'_, PyType>,
+        center: PointLike,
+        start: PointLike,
+        end: PointLike,
+        direction: &str,
+    ) -> PyResult<Py<Self>> {
+        let anticlockwise = match direction {
+            "anticlockwise" => true,
+            "clockwise" => false,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "direction must be 'anticlockwise' or 'clockwise'",
+                ))
+            }
+        };
+        let center = center.0;
+        let radius = (start.0 - center).hypot();
+        let start_angle = (start.0 - center).atan2();
+        let tau = std::f64::consts::TAU;
+        let sweep = ((end.0 - center).atan2() - start_angle).rem_euclid(tau);
+        let sweep_angle = match (anticlockwise, sweep == 0.0) {
+            (true, true) => tau,
+            (true, false) => sweep,
+            (false, _) => sweep - tau,
+        };
+        let arc = KArc::new(
+            center,
+            kurbo::Vec2::new(radius, radius),
+            start_angle,
+            sweep_angle,
+            0.0,
+        );
+        new_of_type(cls, Arc(arc))
+    }
+
     #[getter]
     pub fn get_center(&self) -> Point {
         self.0.center.into()
     }
     #[setter]
-    pub fn set_center(&mut self, center: Point) {
+    pub fn set_center(&mut self, center: PointLike) {
         self.0.center = center.0
     }
     #[getter]
@@ -50,7 +182,7 @@ impl Arc {
         self.0.radii.into()
     }
     #[setter]
-    pub fn set_radii(&mut self, radii: Vec2) {
+    pub fn set_radii(&mut self, radii: Vec2Like) {
         self.0.radii = radii.0
     }
     #[getter]
@@ -89,6 +221,17 @@ impl Arc {
         };
         self.0.to_cubic_beziers(tolerance, callback)
     }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Arc({}, {}, {})",
+            crate::repr::point(self.0.center),
+            crate::repr::vec2(self.0.radii),
+            crate::repr::floats(&[self.0.start_angle, self.0.sweep_angle, self.0.x_rotation])
+        )
+    }
 }
 
 impl_shape!(Arc);
+
+impl_serialize!(Arc);
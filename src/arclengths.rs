@@ -0,0 +1,135 @@
+use crate::bezpath::{subpath_segments, BezPath};
+use crate::point::Point;
+use kurbo::{ParamCurve, ParamCurveArclen, PathSeg as KPathSeg};
+use pyo3::exceptions::{PyIndexError, PyValueError};
+use pyo3::prelude::*;
+
+/// The arc lengths of the segments of a path, measured once so that
+/// positions along the path can be found quickly.
+///
+/// Measuring a position along a path with ``inv_arclen`` integrates along
+/// every segment before it, each time. An ``ArcLengths`` object measures
+/// each segment when it is created, and then finds the segment holding any
+/// distance by binary search, so that only that one segment is integrated.
+///
+/// Segments are numbered as yielded by ``BezPath.segments``. The object
+/// takes a copy of the path, so later changes to the path do not affect it.
+///
+/// ::
+///
+///    from kurbopy import ArcLengths, Circle
+///    lengths = ArcLengths(Circle((0, 0), 10).to_path(0.1))
+///    points = [lengths.point_at_length(d) for d in range(0, 60, 5)]
+///
+/// Note that this class is not in original kurbo
+#[pyclass(frozen, subclass, module = "kurbopy")]
+pub struct ArcLengths {
+    segs: Vec<KPathSeg>,
+    // The length of the path up to the start of each segment, followed by
+    // the total length.
+    cumulative: Vec<f64>,
+    accuracy: f64,
+}
+
+impl ArcLengths {
+    /// The index of the segment containing the point `length` along the
+    /// path, and the parameter value of that point on it.
+    fn locate(&self, length: f64) -> PyResult<(usize, f64)> {
+        if self.segs.is_empty() {
+            return Err(PyValueError::new_err("path has no segments"));
+        }
+        let ix = self
+            .cumulative
+            .partition_point(|&c| c <= length)
+            .clamp(1, self.segs.len())
+            - 1;
+        let seg_len = self.cumulative[ix + 1] - self.cumulative[ix];
+        let local = length - self.cumulative[ix];
+        let t = if local <= 0.0 {
+            0.0
+        } else if local >= seg_len {
+            1.0
+        } else {
+            self.segs[ix].inv_arclen(local, self.accuracy)
+        };
+        Ok((ix, t))
+    }
+}
+
+#[pymethods]
+impl ArcLengths {
+    #[new]
+    #[pyo3(signature = (path, accuracy=crate::default_accuracy()))]
+    fn __new__(path: &BezPath, accuracy: f64) -> PyResult<Self> {
+        if !(accuracy.is_finite() && accuracy > 0.0) {
+            return Err(PyValueError::new_err("accuracy must be positive"));
+        }
+        let segs: Vec<KPathSeg> = subpath_segments(&path.path())
+            .into_iter()
+            .map(|(_, seg)| seg)
+            .collect();
+        let mut cumulative = vec![0.0];
+        let mut total = 0.0;
+        for seg in &segs {
+            total += seg.arclen(accuracy);
+            cumulative.push(total);
+        }
+        Ok(ArcLengths {
+            segs,
+            cumulative,
+            accuracy,
+        })
+    }
+
+    /// The total length of the path.
+    #[getter]
+    fn total(&self) -> f64 {
+        self.cumulative[self.segs.len()]
+    }
+
+    /// The number of segments in the path.
+    fn __len__(&self) -> usize {
+        self.segs.len()
+    }
+
+    /// Returns the ``(segment_index, t)`` of the point ``length`` along the
+    /// path. Lengths beyond either end of the path are clamped to it.
+    ///
+    /// Raises ``ValueError`` if the path has no segments.
+    fn t_at_length(&self, length: f64) -> PyResult<(usize, f64)> {
+        self.locate(length)
+    }
+
+    /// Returns the distance along the path of the point at parameter value
+    /// ``t`` on the given segment.
+    fn length_at(&self, segment_index: usize, t: f64) -> PyResult<f64> {
+        let seg = self
+            .segs
+            .get(segment_index)
+            .ok_or_else(|| PyIndexError::new_err("segment index out of range"))?;
+        let t = t.clamp(0.0, 1.0);
+        let within = if t == 1.0 {
+            self.cumulative[segment_index + 1] - self.cumulative[segment_index]
+        } else {
+            seg.subsegment(0.0..t).arclen(self.accuracy)
+        };
+        Ok(self.cumulative[segment_index] + within)
+    }
+
+    /// Returns the point ``length`` along the path. Lengths beyond either end
+    /// of the path are clamped to it.
+    ///
+    /// Raises ``ValueError`` if the path has no segments.
+    fn point_at_length(&self, length: f64) -> PyResult<Point> {
+        let (ix, t) = self.locate(length)?;
+        Ok(Point(self.segs[ix].eval(t)))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "<ArcLengths of {} segments, total {}>",
+            self.segs.len(),
+            crate::repr::float(self.total())
+        )
+    }
+}
@@ -1,21 +1,43 @@
-use crate::affine::Affine;
+use crate::affine::{Affine, AffineLike};
+use crate::approx::ApproxEq;
+use crate::arc::Arc as PyArc;
+use crate::boolean::{boolean, Op};
+use crate::buffer::{polygon_area, polygons};
+use crate::cleanup::{control_points, flatness};
 use crate::cubicbez::CubicBez;
+use crate::ellipse::Ellipse;
+use crate::impl_shape_no_bounding_box;
 use crate::line::Line;
-use crate::pathel::PathEl;
+use crate::nearest::Nearest;
+use crate::pathel::{el_points, PathEl};
 use crate::pathseg::PathSeg;
-use crate::point::Point;
+use crate::point::{Point, PointArray, PointLike};
+use crate::polyline::{clip_segment, cross, flatten_subpaths, push_polygon, simplify};
 use crate::quadbez::QuadBez;
+use crate::quantize::{Quantize, Rounding};
+use crate::ray::{Ray, RayHit};
 use crate::rect::Rect;
-use core::cmp::Ordering;
-use itertools::Itertools;
+use crate::subclass::{new_like, new_of_type};
+use crate::svg::SvgOptions;
+use crate::vec2::Vec2 as PyVec2;
 use kurbo::{
-    Affine as KAffine, BezPath as KBezPath, CubicBez as KCubicBez, ParamCurve, PathEl as KPathEl,
-    PathSeg as KPathSeg, Shape, Vec2,
+    Affine as KAffine, Arc as KArc, BezPath as KBezPath, CubicBez as KCubicBez, ParamCurve,
+    ParamCurveArclen, ParamCurveCurvature, ParamCurveDeriv, ParamCurveNearest,
+    PathEl as KPathEl, PathSeg as KPathSeg, Point as KPoint, QuadBez as KQuadBez, Rect as KRect,
+    Shape, SvgArc, Vec2,
 };
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyType;
 use std::borrow::BorrowMut;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::sync::{Arc, Mutex, MutexGuard};
 
+/// A meeting point of two paths: the index of a segment of each path and
+/// the parameter value on it, and the point itself.
+type Crossing<P> = (usize, f64, usize, f64, P);
+
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
 /// A Bézier path.
@@ -86,6 +108,320 @@ impl BezPath {
     pub(crate) fn path(&self) -> MutexGuard<KBezPath> {
         self._path.lock().unwrap()
     }
+
+    /// The segments of the path with their arc lengths, and the total length.
+    fn segment_lengths(&self, accuracy: f64) -> (Vec<(KPathSeg, f64)>, f64) {
+        let segs: Vec<(KPathSeg, f64)> = subpath_segments(&self.path())
+            .into_iter()
+            .map(|(_, seg)| (seg, seg.arclen(accuracy)))
+            .collect();
+        let total = segs.iter().map(|(_, len)| len).sum();
+        (segs, total)
+    }
+
+    /// The segment indices and parameter values at the given (ascending)
+    /// distances along a sequence of segments.
+    pub(crate) fn locate_distances(
+        segs: &[(KPathSeg, f64)],
+        distances: impl Iterator<Item = f64>,
+        accuracy: f64,
+    ) -> Vec<(usize, f64)> {
+        let mut result = vec![];
+        let mut ix = 0;
+        let mut base = 0.0;
+        for d in distances {
+            while ix + 1 < segs.len() && d > base + segs[ix].1 {
+                base += segs[ix].1;
+                ix += 1;
+            }
+            let Some((seg, len)) = segs.get(ix) else {
+                break;
+            };
+            let t = if d - base >= *len {
+                1.0
+            } else {
+                seg.inv_arclen((d - base).max(0.0), accuracy)
+            };
+            result.push((ix, t));
+        }
+        result
+    }
+
+    /// The points at the given (ascending) distances along the path.
+    fn points_at_distances(
+        segs: &[(KPathSeg, f64)],
+        distances: impl Iterator<Item = f64>,
+        accuracy: f64,
+    ) -> Vec<Point> {
+        Self::locate_distances(segs, distances, accuracy)
+            .into_iter()
+            .map(|(ix, t)| segs[ix].0.eval(t).into())
+            .collect()
+    }
+
+    fn nearest_filtered(
+        &self,
+        pt: PointLike,
+        accuracy: f64,
+        subpath: Option<usize>,
+        filter: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<(usize, Nearest)>> {
+        // Clone so that the filter callback is free to use this path.
+        let path = self.path().clone();
+        let mut best: Option<(usize, kurbo::Nearest, KPoint)> = None;
+        for (ix, (sp, seg)) in subpath_segments(&path).into_iter().enumerate() {
+            if matches!(subpath, Some(s) if s != sp) {
+                continue;
+            }
+            if let Some(filter) = filter {
+                if !filter.call1((PathSeg(seg),))?.is_truthy()? {
+                    continue;
+                }
+            }
+            let n = seg.nearest(pt.0, accuracy);
+            if best.map_or(true, |(_, b, _)| n.distance_sq < b.distance_sq) {
+                best = Some((ix, n, seg.eval(n.t)));
+            }
+        }
+        Ok(best.map(|(ix, n, p)| (ix, Nearest::new(n, p))))
+    }
+}
+
+/// Split a path into segments, tagging each with the index of its subpath.
+pub(crate) fn subpath_segments(path: &KBezPath) -> Vec<(usize, KPathSeg)> {
+    let mut result = vec![];
+    let mut subpath: Option<usize> = None;
+    let mut closed = false;
+    let mut start = kurbo::Point::ZERO;
+    let mut last = kurbo::Point::ZERO;
+    for el in path.elements() {
+        if closed && !matches!(el, KPathEl::MoveTo(_)) {
+            subpath = subpath.map(|s| s + 1);
+        }
+        closed = false;
+        let seg = match *el {
+            KPathEl::MoveTo(p) => {
+                subpath = Some(subpath.map_or(0, |s| s + 1));
+                start = p;
+                last = p;
+                None
+            }
+            KPathEl::LineTo(p) => Some(KPathSeg::Line(kurbo::Line::new(last, p))),
+            KPathEl::QuadTo(p1, p2) => Some(KPathSeg::Quad(kurbo::QuadBez::new(last, p1, p2))),
+            KPathEl::CurveTo(p1, p2, p3) => {
+                Some(KPathSeg::Cubic(KCubicBez::new(last, p1, p2, p3)))
+            }
+            KPathEl::ClosePath => {
+                closed = true;
+                if last != start {
+                    Some(KPathSeg::Line(kurbo::Line::new(last, start)))
+                } else {
+                    last = start;
+                    None
+                }
+            }
+        };
+        if let Some(seg) = seg {
+            last = seg.end();
+            result.push((subpath.unwrap_or(0), seg));
+        }
+    }
+    result
+}
+
+/// Subdivide the part of `seg` from `t0` to `t1` until each piece lies
+/// within `tolerance` of its chord, pushing the end of each piece.
+fn flatten_segment(
+    seg: KPathSeg,
+    t0: f64,
+    t1: f64,
+    tolerance: f64,
+    depth: usize,
+    out: &mut Vec<(f64, KPoint)>,
+) {
+    let piece = seg.subsegment(t0..t1);
+    if depth >= 32 || flatness(&control_points(&piece)) <= tolerance {
+        out.push((t1, seg.eval(t1)));
+        return;
+    }
+    let t = (t0 + t1) / 2.0;
+    flatten_segment(seg, t0, t, tolerance, depth + 1, out);
+    flatten_segment(seg, t, t1, tolerance, depth + 1, out);
+}
+
+/// A path of straight lines through `points`, closed if `closed` is true.
+fn polygon(points: impl IntoIterator<Item = KPoint>, closed: bool) -> KBezPath {
+    let mut path = KBezPath::new();
+    let mut iter = points.into_iter();
+    if let Some(first) = iter.next() {
+        path.move_to(first);
+        for p in iter {
+            path.line_to(p);
+        }
+        if closed {
+            path.close_path();
+        }
+    }
+    path
+}
+
+/// The meeting points of two paths, sorted by position along the first and
+/// with those found more than once where segments join removed.
+fn crossings(a: &KBezPath, b: &KBezPath, accuracy: f64) -> Vec<Crossing<KPoint>> {
+    let segs1: Vec<(KPathSeg, KRect)> = a.segments().map(|s| (s, s.bounding_box())).collect();
+    let segs2: Vec<(KPathSeg, KRect)> = b.segments().map(|s| (s, s.bounding_box())).collect();
+    let mut hits: Vec<Crossing<KPoint>> = vec![];
+    for (i, (s1, r1)) in segs1.iter().enumerate() {
+        for (j, (s2, r2)) in segs2.iter().enumerate() {
+            if rect_distance(*r1, *r2) > accuracy {
+                continue;
+            }
+            for (t1, t2) in crate::overlaps::segment_intersections(s1, s2, accuracy) {
+                hits.push((i, t1, j, t2, s1.eval(t1)));
+            }
+        }
+    }
+    hits.sort_by(|x, y| x.0.cmp(&y.0).then(x.1.total_cmp(&y.1)));
+    let mut result: Vec<Crossing<KPoint>> = vec![];
+    for hit in hits {
+        // Where segments join, the neighbours on both sides find the
+        // same point.
+        if !result.iter().any(|h| h.4.distance(hit.4) <= 4.0 * accuracy) {
+            result.push(hit);
+        }
+    }
+    result
+}
+
+/// A segment in the form used for `min_dist`, with lines raised to cubics.
+fn as_curve(seg: KPathSeg) -> KPathSeg {
+    match seg {
+        KPathSeg::Line(_) => KPathSeg::Cubic(KCubicBez::new(
+            seg.eval(0.0),
+            seg.eval(1.0 / 3.0),
+            seg.eval(2.0 / 3.0),
+            seg.eval(1.0),
+        )),
+        _ => seg,
+    }
+}
+
+/// The distance between the nearest points of two rectangles, which is zero
+/// if they overlap.
+fn rect_distance(a: KRect, b: KRect) -> f64 {
+    let dx = (a.x0 - b.x1).max(b.x0 - a.x1).max(0.0);
+    let dy = (a.y0 - b.y1).max(b.y0 - a.y1).max(0.0);
+    dx.hypot(dy)
+}
+
+/// The point at which the next element of the path would start, if any.
+fn current_point(path: &KBezPath) -> Option<KPoint> {
+    let mut start = None;
+    let mut last = None;
+    for el in path.elements() {
+        match el {
+            KPathEl::MoveTo(p) => {
+                start = Some(*p);
+                last = start;
+            }
+            KPathEl::ClosePath => last = start,
+            _ => last = el.end_point(),
+        }
+    }
+    last
+}
+
+/// Each subpath of a path as a path of its own, with its signed area (as if
+/// it were closed) and the number of other subpaths which enclose it.
+fn subpath_nesting(path: &KBezPath) -> Vec<(KBezPath, f64, usize)> {
+    let mut pieces: Vec<KBezPath> = vec![];
+    for el in path.elements() {
+        if pieces.is_empty() || matches!(el, KPathEl::MoveTo(_)) {
+            pieces.push(KBezPath::new());
+        }
+        if let Some(piece) = pieces.last_mut() {
+            piece.push(*el);
+        }
+    }
+    // Each subpath closed, with a point on its outline to test for
+    // containment in the others.
+    let loops: Vec<Option<(KBezPath, KPoint)>> = pieces
+        .iter()
+        .map(|piece| {
+            let sp = crate::corners::subpaths(piece).into_iter().next()?;
+            let probe = sp.segs.first()?.eval(0.5);
+            let mut closed = KBezPath::new();
+            closed.move_to(sp.start);
+            for seg in &sp.segs {
+                closed.push(seg.as_path_el());
+            }
+            closed.close_path();
+            Some((closed, probe))
+        })
+        .collect();
+    pieces
+        .into_iter()
+        .enumerate()
+        .map(|(ix, piece)| {
+            let Some((closed, probe)) = &loops[ix] else {
+                return (piece, 0.0, 0);
+            };
+            let depth = loops
+                .iter()
+                .enumerate()
+                .filter(|(other, lp)| {
+                    *other != ix && lp.as_ref().map_or(false, |(p, _)| p.winding(*probe) != 0)
+                })
+                .count();
+            (piece, closed.area(), depth)
+        })
+        .collect()
+}
+
+/// Describe why two paths cannot be interpolated, or `None` if they can:
+/// they must have the same number of elements, of the same kinds.
+fn incompatibility(a: &KBezPath, b: &KBezPath) -> Option<String> {
+    if a.elements().len() != b.elements().len() {
+        return Some(format!(
+            "paths have different numbers of elements ({} and {})",
+            a.elements().len(),
+            b.elements().len()
+        ));
+    }
+    a.elements()
+        .iter()
+        .zip(b.elements())
+        .position(|(x, y)| std::mem::discriminant(x) != std::mem::discriminant(y))
+        .map(|ix| {
+            format!(
+                "element {} differs: {:?} and {:?}",
+                ix,
+                a.elements()[ix],
+                b.elements()[ix]
+            )
+        })
+}
+
+/// Place the control point of a quadratic segment whose end points have moved
+/// from `p0` and `p1` to `q0` and `q1`, keeping the tangent directions which
+/// the original control point `c` gave at each end.
+fn refit_quad_control(p0: KPoint, c: KPoint, p1: KPoint, q0: KPoint, q1: KPoint) -> KPoint {
+    let fallback = c + ((q0 - p0) + (q1 - p1)) * 0.5;
+    let u = c - p0;
+    let v = c - p1;
+    let det = cross(u, v);
+    if det.abs() <= 1e-12 * u.hypot2().max(v.hypot2()) {
+        return fallback;
+    }
+    // Solve q0 + s * u == q1 + t * v.
+    let w = q1 - q0;
+    let s = cross(w, v) / det;
+    let t = cross(w, u) / det;
+    if s > 0.0 && t > 0.0 {
+        q0 + u * s
+    } else {
+        fallback
+    }
 }
 
 #[pymethods]
@@ -109,22 +445,22 @@ impl BezPath {
 
     /// Push a "move to" element onto the path.
     #[pyo3(text_signature = "($self, pt)")]
-    fn move_to(&mut self, p: Point) {
+    fn move_to(&mut self, p: PointLike) {
         self.path_mut().move_to(p.0);
     }
     /// Push a "line to" element onto the path.
     #[pyo3(text_signature = "($self, pt)")]
-    fn line_to(&mut self, p: Point) {
+    fn line_to(&mut self, p: PointLike) {
         self.path_mut().line_to(p.0)
     }
     /// Push a "quad to" element onto the path.
     #[pyo3(text_signature = "($self, pt1, pt2)")]
-    fn quad_to(&mut self, p1: Point, p2: Point) {
+    fn quad_to(&mut self, p1: PointLike, p2: PointLike) {
         self.path_mut().quad_to(p1.0, p2.0)
     }
     /// Push a "curve to" element onto the path.
     #[pyo3(text_signature = "($self, pt1, pt2, pt3)")]
-    fn curve_to(&mut self, p1: Point, p2: Point, p3: Point) {
+    fn curve_to(&mut self, p1: PointLike, p2: PointLike, p3: PointLike) {
         self.path_mut().curve_to(p1.0, p2.0, p3.0)
     }
     /// Push a "close path" element onto the path.
@@ -132,20 +468,233 @@ impl BezPath {
         self.path_mut().close_path();
     }
 
+    /// Append the elements of another path, or of any iterable of
+    /// [``PathEl``], to this path.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(text_signature = "($self, other)")]
+    fn extend(mut slf: PyRefMut<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        // Copy the elements out first: `other` may be this very path.
+        let els: Vec<KPathEl> = if other.as_ptr() == slf.as_ptr() {
+            slf.path().elements().to_vec()
+        } else if let Ok(path) = other.downcast::<BezPath>() {
+            path.borrow().path().elements().to_vec()
+        } else {
+            other
+                .iter()?
+                .map(|el| el.and_then(|el| el.extract::<PathEl>()).map(|el| el.0))
+                .collect::<PyResult<_>>()?
+        };
+        slf.path_mut().extend(els);
+        Ok(())
+    }
+
+    /// ``path1 + path2`` returns a new path with the elements of both.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __add__(slf: &Bound<'_, Self>, other: &BezPath) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let mut path = this.path().clone();
+        path.extend(other.path().elements().iter().copied());
+        new_like(slf, path.into())
+    }
+
+    /// ``path += other`` extends the path in place, as with ``extend``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __iadd__(slf: PyRefMut<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<()> {
+        Self::extend(slf, other)
+    }
+
+    /// Append an arc to the path, converted to cubic Béziers to within
+    /// ``tolerance``.
+    ///
+    /// The arc continues the current subpath: if the path does not already
+    /// end at the start of the arc, a line is drawn to it. If the path is
+    /// empty or its last subpath is closed, a new subpath is started at the
+    /// start of the arc instead.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (arc, tolerance=crate::DEFAULT_TOLERANCE))]
+    fn append_arc(&mut self, arc: &PyArc, tolerance: f64) {
+        // The elements of an arc start by moving to its start point.
+        let mut els = arc.0.path_elements(tolerance);
+        let Some(KPathEl::MoveTo(start)) = els.next() else {
+            return;
+        };
+        let mut path = self.path_mut();
+        match path.elements().last() {
+            None | Some(KPathEl::ClosePath) => path.move_to(start),
+            Some(el) if el.end_point() != Some(start) => path.line_to(start),
+            _ => {}
+        }
+        path.extend(els);
+    }
+
+    /// Draw an elliptical arc from the current point to ``end``, as with the
+    /// SVG ``A`` path command.
+    ///
+    /// ``rx`` and ``ry`` are the radii of the ellipse, ``x_rotation`` the
+    /// rotation of its x-axis in radians, and ``large_arc`` and ``sweep``
+    /// choose which of the four possible arcs is drawn. As in SVG, radii
+    /// which are too small are scaled up, and an arc which degenerates
+    /// (for example, with a zero radius) is drawn as a straight line. The
+    /// arc is converted to cubic Béziers to within ``tolerance``.
+    ///
+    /// Raises ``ValueError`` if the path has no current point.
+    ///
+    /// Note that this method is not in original kurbo
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (rx, ry, x_rotation, large_arc, sweep, end, tolerance=crate::DEFAULT_TOLERANCE))]
+    fn arc_to(
+        &mut self,
+        rx: f64,
+        ry: f64,
+        x_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        end: PointLike,
+        tolerance: f64,
+    ) -> PyResult<()> {
+        let mut path = self.path_mut();
+        let from = current_point(&path)
+            .ok_or_else(|| PyValueError::new_err("path has no current point"))?;
+        let arc = SvgArc {
+            from,
+            to: end.0,
+            radii: Vec2::new(rx, ry),
+            x_rotation,
+            large_arc,
+            sweep,
+        };
+        match KArc::from_svg_arc(&arc) {
+            Some(arc) => path.extend(arc.append_iter(tolerance)),
+            None if from != end.0 => path.line_to(end.0),
+            None => {}
+        }
+        Ok(())
+    }
+
     /// Shorten the path, keeping the first `len`` elements.
     fn truncate(&mut self, len: usize) {
         self.path_mut().truncate(len);
     }
 
     /// Flatten the path, returning a list of points.
-    fn flatten(&mut self, tolerance: f64) -> Vec<Point> {
+    ///
+    /// The points of all subpaths are returned in a single list; use
+    /// ``flatten_subpaths`` to keep the subpaths apart.
+    ///
+    /// If ``callback`` is given, it is instead called with each
+    /// :py:class:`PathEl` of the flattened path (``MoveTo``, ``LineTo`` and
+    /// ``ClosePath`` elements only), as in kurbo, and ``None`` is returned.
+    /// If the callback raises an exception, flattening stops and the
+    /// exception is propagated.
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE, callback=None))]
+    fn flatten(
+        &self,
+        py: Python,
+        tolerance: f64,
+        callback: Option<PyObject>,
+    ) -> PyResult<Option<Vec<Point>>> {
+        if let Some(callback) = callback {
+            // Release the lock before calling back into Python, which may
+            // use this path.
+            let path = self.path().clone();
+            let mut result = Ok(());
+            kurbo::flatten(&path, tolerance, |el| {
+                if result.is_ok() {
+                    result = callback.call1(py, (PathEl(el),)).map(|_| ());
+                }
+            });
+            return result.map(|_| None);
+        }
         let mut v = vec![];
-        self.path().flatten(tolerance, |l| match l {
+        kurbo::flatten(&*self.path(), tolerance, |l| match l {
             KPathEl::MoveTo(p) => v.push(p.into()),
             KPathEl::LineTo(p) => v.push(p.into()),
             _ => {}
         });
-        v
+        Ok(Some(v))
+    }
+
+    /// Flatten the path, returning a list of points for each subpath.
+    ///
+    /// The polyline for a closed subpath ends with its starting point, so
+    /// each polyline can be drawn as it is. Raises ``ValueError`` unless
+    /// ``tolerance`` is positive.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn flatten_subpaths(&self, tolerance: f64) -> PyResult<Vec<Vec<Point>>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        Ok(flatten_subpaths(&self.path(), tolerance)
+            .into_iter()
+            .map(|line| {
+                let mut points = line.points;
+                if line.closed && points.len() > 1 && points.last() != points.first() {
+                    points.push(points[0]);
+                }
+                points.into_iter().map(Point).collect()
+            })
+            .collect())
+    }
+
+    /// Flatten the path into a list of points for each subpath, as for
+    /// ``flatten_subpaths``, then simplify each polyline so that it stays
+    /// within ``epsilon`` of the flattened path.
+    ///
+    /// This produces far fewer points than flattening at a tighter
+    /// tolerance, which suits plotters and cutters. See
+    /// :py:func:`simplify_polyline`. Raises ``ValueError`` if ``epsilon`` is
+    /// negative.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE, epsilon=crate::DEFAULT_TOLERANCE))]
+    fn flatten_simplified(&self, tolerance: f64, epsilon: f64) -> PyResult<Vec<Vec<Point>>> {
+        if !(epsilon.is_finite() && epsilon >= 0.0) {
+            return Err(PyValueError::new_err("epsilon must not be negative"));
+        }
+        Ok(self
+            .flatten_subpaths(tolerance)?
+            .into_iter()
+            .map(|line| {
+                let points: Vec<KPoint> = line.into_iter().map(|p| p.0).collect();
+                simplify(&points, epsilon).into_iter().map(Point).collect()
+            })
+            .collect())
+    }
+
+    /// Flatten the path, returning a list of ``(point, segment_index, t)``
+    /// tuples which record where each point lies on the original path.
+    ///
+    /// ``segment_index`` is the index of a segment as yielded by
+    /// ``segments``, and ``t`` the parameter value on it, so that
+    /// ``segment.eval(t)`` gives the point. Each subpath begins with a point
+    /// at ``t == 0`` on its first segment; every other point ends a piece of
+    /// some segment, so the end of each segment has ``t == 1``. Curves are
+    /// subdivided until each piece lies within ``tolerance`` of a line.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn flatten_with_t(&self, tolerance: f64) -> PyResult<Vec<(Point, usize, f64)>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let mut result = vec![];
+        let mut last_subpath = None;
+        for (ix, (sp, seg)) in subpath_segments(&self.path()).into_iter().enumerate() {
+            if last_subpath != Some(sp) {
+                result.push((Point(seg.start()), ix, 0.0));
+                last_subpath = Some(sp);
+            }
+            let mut pieces = vec![];
+            flatten_segment(seg, 0.0, 1.0, tolerance, 0, &mut pieces);
+            result.extend(pieces.into_iter().map(|(t, p)| (Point(p), ix, t)));
+        }
+        Ok(result)
     }
 
     /// Get the segment at the given element index.
@@ -167,11 +716,50 @@ impl BezPath {
         self.path().is_empty()
     }
 
+    /// The number of elements in the path, as yielded by ``elements``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __len__(&self) -> usize {
+        self.path().elements().len()
+    }
+
+    /// A path is true if it has any elements, like a non-empty list. Note
+    /// that a path consisting only of a ``MoveTo`` is true, although
+    /// ``is_empty`` also returns true for it, as it has no segments.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __bool__(&self) -> bool {
+        !self.path().elements().is_empty()
+    }
+
+    /// Returns an immutable, hashable copy of the path.
+    ///
+    /// Note that this method is not in original kurbo
+    fn freeze(&self) -> FrozenBezPath {
+        self.path().clone().into()
+    }
+
     /// Apply an affine transform to the path.
     fn apply_affine(&mut self, affine: Affine) {
         self.path_mut().apply_affine(affine.0)
     }
 
+    /// ``path * transform`` returns a new path with an ``Affine`` or
+    /// ``TranslateScale`` applied, like ``transform * path``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __mul__(slf: &Bound<'_, Self>, rhs: AffineLike) -> PyResult<Py<Self>> {
+        new_like(slf, (rhs.0 * slf.borrow().path().clone()).into())
+    }
+
+    /// ``path *= transform`` applies an ``Affine`` or ``TranslateScale`` to
+    /// the path in place, as with ``apply_affine``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __imul__(&mut self, rhs: AffineLike) {
+        self.path_mut().apply_affine(rhs.0)
+    }
+
     /// Is this path finite?
     fn is_finite(&self) -> bool {
         self.path().is_finite()
@@ -181,6 +769,30 @@ impl BezPath {
         self.path().is_nan()
     }
 
+    /// Returns the smallest ellipse enclosing the path, as found by
+    /// :py:func:`min_bounding_ellipse`.
+    ///
+    /// The path is flattened to within ``tolerance``, so it may stray beyond
+    /// the ellipse by up to that distance. Raises ``ValueError`` if the path
+    /// has no points, or unless ``tolerance`` is positive.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn bounding_ellipse(&self, tolerance: f64) -> PyResult<Ellipse> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let mut points = vec![];
+        kurbo::flatten(&*self.path(), tolerance, |el| {
+            if let KPathEl::MoveTo(p) | KPathEl::LineTo(p) = el {
+                points.push(p);
+            }
+        });
+        crate::boundingellipse::min_bounding_ellipse(&points)
+            .map(Ellipse)
+            .ok_or_else(|| PyValueError::new_err("path has no points"))
+    }
+
     /// Returns a rectangle that conservatively encloses the path.
     ///
     /// Unlike the `bounding_box` method, this uses control points directly
@@ -189,17 +801,212 @@ impl BezPath {
         self.path().control_box().into()
     }
 
+    /// Returns the points of the path, including off-curve control points,
+    /// in order.
+    ///
+    /// If ``grouped`` is true, returns a list for each element instead, as
+    /// given by ``PathEl.points``; a ``ClosePath`` element has an empty list.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (grouped=false))]
+    fn control_points(&self, py: Python<'_>, grouped: bool) -> PyObject {
+        let path = self.path();
+        let groups = path
+            .elements()
+            .iter()
+            .map(|&el| el_points(el).into_iter().map(Point).collect::<Vec<_>>());
+        if grouped {
+            groups.collect::<Vec<_>>().into_py(py)
+        } else {
+            groups.flatten().collect::<Vec<_>>().into_py(py)
+        }
+    }
+
+    /// Returns the control polygon of the path: a path of straight lines
+    /// joining each point to the next, including the off-curve control
+    /// points. Subpaths are kept separate, and closed subpaths stay closed.
+    ///
+    /// Note that this method is not in original kurbo
+    fn control_polygon(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let mut result = KBezPath::new();
+        for &el in this.path().elements() {
+            match el {
+                KPathEl::MoveTo(p) => result.move_to(p),
+                KPathEl::ClosePath => result.close_path(),
+                _ => {
+                    for p in el_points(el) {
+                        result.line_to(p);
+                    }
+                }
+            }
+        }
+        new_like(slf, result.into())
+    }
+
     /// Returns a new path with the winding direction of all subpaths reversed.
-    pub fn reverse_subpaths(&self) -> BezPath {
-        self.path().reverse_subpaths().into()
+    pub fn reverse_subpaths(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().path().reverse_subpaths().into())
     }
 
     /// Convert the path to an SVG path string representation.
     ///
-    /// The current implementation doesn't take any special care to produce a
-    /// short string (reducing precision, using relative movement).
-    fn to_svg(&self) -> String {
-        self.path().to_svg()
+    /// By default, coordinates are written at full precision with absolute
+    /// commands. To produce a shorter string, ``precision`` gives the number
+    /// of decimal places to write, ``relative`` uses relative (lowercase)
+    /// commands, and ``shorthand`` uses the ``S`` and ``T`` commands where a
+    /// control point is the reflection of the one before it. With relative
+    /// commands, coordinates are rounded before the offsets between them are
+    /// taken, so rounding errors do not build up along the path.
+    ///
+    /// Note that the ``precision``, ``relative`` and ``shorthand`` arguments
+    /// are not in original kurbo
+    #[pyo3(signature = (precision=None, relative=false, shorthand=false))]
+    fn to_svg(&self, precision: Option<usize>, relative: bool, shorthand: bool) -> String {
+        let options = SvgOptions {
+            precision,
+            relative,
+            shorthand,
+        };
+        if precision.is_none() && !relative && !shorthand {
+            return self.path().to_svg();
+        }
+        crate::svg::to_svg(&self.path(), options)
+    }
+
+    fn __repr__(&self) -> String {
+        if self.path().elements().is_empty() {
+            "BezPath()".to_string()
+        } else {
+            format!("BezPath.from_svg({:?})", self.path().to_svg())
+        }
+    }
+
+    /// Try to parse a path from an SVG path string.
+    ///
+    /// All SVG path commands are supported, in both absolute and relative
+    /// forms. Elliptical arc (``A``) commands are converted to cubic Béziers,
+    /// as a ``BezPath`` has no native arc element; this means that ``to_svg``
+    /// will not round-trip them as arcs.
+    ///
+    /// Raises ``ValueError`` if the string is not a valid path.
+    #[classmethod]
+    #[pyo3(text_signature = "(cls, data)")]
+    fn from_svg(cls: &Bound<'_, PyType>, data: &str) -> PyResult<Py<Self>> {
+        let path = KBezPath::from_svg(data).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        new_of_type(cls, path.into())
+    }
+
+    /// Build a path of straight lines through a list of points.
+    ///
+    /// ``points`` may be a list of points or a NumPy array of shape
+    /// ``(n, 2)``. The path starts with a ``MoveTo`` to the first point and
+    /// has a ``LineTo`` to each of the others; if ``closed`` is true, it is
+    /// finished with a ``ClosePath``. An empty list gives an empty path.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[classmethod]
+    #[pyo3(signature = (points, closed=true))]
+    fn from_polygon(cls: &Bound<'_, PyType>, points: PointArray, closed: bool) -> PyResult<Py<Self>> {
+        new_of_type(cls, polygon(points.points, closed).into())
+    }
+
+    /// Build a closed regular polygon with ``n`` sides.
+    ///
+    /// The vertices lie on a circle of the given ``radius`` around
+    /// ``center``. The first vertex is at angle ``rotation`` (in radians,
+    /// measured from the positive x axis, like the angles of an ``Arc``),
+    /// and the others follow in the direction of increasing angle.
+    ///
+    /// Raises ``ValueError`` if ``n`` is less than 3.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[classmethod]
+    #[pyo3(signature = (center, n, radius, rotation=0.0))]
+    fn regular_polygon(
+        cls: &Bound<'_, PyType>,
+        center: PointLike,
+        n: usize,
+        radius: f64,
+        rotation: f64,
+    ) -> PyResult<Py<Self>> {
+        if n < 3 {
+            return Err(PyValueError::new_err("a polygon needs at least 3 sides"));
+        }
+        let points = (0..n).map(|i| {
+            let angle = rotation + std::f64::consts::TAU * i as f64 / n as f64;
+            center.0 + Vec2::from_angle(angle) * radius
+        });
+        new_of_type(cls, polygon(points, true).into())
+    }
+
+    /// Build a closed star with ``n`` points.
+    ///
+    /// The path alternates between ``n`` outer vertices on a circle of
+    /// radius ``r_outer`` around ``center`` and ``n`` inner vertices on a
+    /// circle of radius ``r_inner``, halfway between them in angle. The
+    /// first outer vertex is at angle ``rotation``, as for
+    /// ``regular_polygon``.
+    ///
+    /// Raises ``ValueError`` if ``n`` is less than 2.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[classmethod]
+    #[pyo3(signature = (center, n, r_outer, r_inner, rotation=0.0))]
+    fn star(
+        cls: &Bound<'_, PyType>,
+        center: PointLike,
+        n: usize,
+        r_outer: f64,
+        r_inner: f64,
+        rotation: f64,
+    ) -> PyResult<Py<Self>> {
+        if n < 2 {
+            return Err(PyValueError::new_err("a star needs at least 2 points"));
+        }
+        let points = (0..2 * n).map(|i| {
+            let angle = rotation + std::f64::consts::PI * i as f64 / n as f64;
+            let radius = if i % 2 == 0 { r_outer } else { r_inner };
+            center.0 + Vec2::from_angle(angle) * radius
+        });
+        new_of_type(cls, polygon(points, true).into())
+    }
+
+    /// Serialize the path to a JSON string: a list of path elements, as
+    /// described in :doc:`serialization`.
+    ///
+    /// Note that this method is not in original kurbo
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(&*self.path()).map_err(crate::serialize::json_error)
+    }
+
+    /// Deserialize a path from a JSON string, as produced by ``to_json``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    fn from_json(cls: &Bound<'_, PyType>, json: &str) -> PyResult<Py<Self>> {
+        let path = serde_json::from_str::<KBezPath>(json).map_err(crate::serialize::json_error)?;
+        new_of_type(cls, path.into())
+    }
+
+    /// Convert the path to plain Python data with the same structure as the
+    /// JSON produced by ``to_json``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        crate::serialize::json_to_py(py, &self.to_json()?)
+    }
+
+    /// Build a path from plain Python data, as produced by ``to_dict``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    fn from_dict(
+        cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<Self>> {
+        BezPath::from_json(cls, &crate::serialize::py_to_json(py, data)?)
     }
 
     /// Compute the signed area under the curve.
@@ -219,11 +1026,125 @@ impl BezPath {
     }
 
     /// Total length of perimeter.
-    #[pyo3(text_signature = "($self, accuracy)")]
+    #[pyo3(signature = (accuracy=crate::default_accuracy()))]
     fn perimeter(&self, accuracy: f64) -> f64 {
         self.path().perimeter(accuracy)
     }
 
+    /// Returns ``n`` points spaced equally by arc length along the whole
+    /// path, including its start and end points.
+    ///
+    /// Distances are measured across all subpaths as if they were joined
+    /// end to end, so samples are not aligned to the start of each
+    /// subpath. Closing segments count towards the length.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (n, accuracy=crate::default_accuracy()))]
+    fn resample(&self, n: usize, accuracy: f64) -> Vec<Point> {
+        let (segs, total) = self.segment_lengths(accuracy);
+        let step = if n > 1 { total / (n - 1) as f64 } else { 0.0 };
+        Self::points_at_distances(&segs, (0..n).map(|i| i as f64 * step), accuracy)
+    }
+
+    /// Returns points spaced ``step`` apart by arc length along the whole
+    /// path, starting at its start point.
+    ///
+    /// As for ``resample``, distances are measured across all subpaths.
+    /// The end point of the path is only included if the length of the
+    /// path is a multiple of ``step``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (step, accuracy=crate::default_accuracy()))]
+    fn resample_by_distance(&self, step: f64, accuracy: f64) -> PyResult<Vec<Point>> {
+        if !(step.is_finite() && step > 0.0) {
+            return Err(PyValueError::new_err("step must be finite and positive"));
+        }
+        let (segs, total) = self.segment_lengths(accuracy);
+        // Allow for rounding error when the length is a multiple of the step.
+        let n = (total / step + 1e-9).floor() as usize + 1;
+        Ok(Self::points_at_distances(
+            &segs,
+            (0..n).map(|i| i as f64 * step),
+            accuracy,
+        ))
+    }
+
+    /// Computes the arc length spans which would be drawn by a dash pattern.
+    ///
+    /// ``pattern`` alternates between "on" and "off" lengths, and ``offset``
+    /// is how far into the pattern to start, as for ``dash``. A pattern with
+    /// an odd number of entries is repeated to make it even. Returns a list
+    /// of ``(start, end)`` tuples giving the "on" spans as distances along
+    /// the path. As with ``dash``, the pattern restarts at each subpath, and
+    /// spans never cross from one subpath into the next.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (pattern, offset=0.0, accuracy=crate::default_accuracy()))]
+    fn dash_spans(
+        &self,
+        pattern: Vec<f64>,
+        offset: f64,
+        accuracy: f64,
+    ) -> PyResult<Vec<(f64, f64)>> {
+        if pattern.iter().any(|&d| !(d.is_finite() && d >= 0.0)) || !offset.is_finite() {
+            return Err(PyValueError::new_err("dash lengths must be finite and non-negative"));
+        }
+        let pattern = if pattern.len() % 2 == 1 {
+            pattern.repeat(2)
+        } else {
+            pattern
+        };
+        let period: f64 = pattern.iter().sum();
+        if period <= 0.0 {
+            return Err(PyValueError::new_err(
+                "dash pattern must have a positive length",
+            ));
+        }
+        // Find where in the pattern each subpath starts.
+        let mut start_ix = 0;
+        let mut start_remaining = pattern[0];
+        let mut phase = offset.rem_euclid(period);
+        while phase >= start_remaining && phase > 0.0 {
+            phase -= start_remaining;
+            start_ix = (start_ix + 1) % pattern.len();
+            start_remaining = pattern[start_ix];
+        }
+        start_remaining -= phase;
+
+        let mut subpath_lengths: Vec<f64> = vec![];
+        for (subpath, seg) in subpath_segments(&self.path()) {
+            if subpath_lengths.len() <= subpath {
+                subpath_lengths.resize(subpath + 1, 0.0);
+            }
+            subpath_lengths[subpath] += seg.arclen(accuracy);
+        }
+
+        let mut spans: Vec<(f64, f64)> = vec![];
+        let mut base = 0.0;
+        for length in subpath_lengths {
+            let first_span = spans.len();
+            let mut ix = start_ix;
+            let mut remaining = start_remaining;
+            let mut pos = 0.0;
+            while pos < length {
+                let end = (pos + remaining).min(length);
+                if ix % 2 == 0 && end > pos {
+                    let in_subpath = spans.len() > first_span;
+                    match spans.last_mut() {
+                        // Merge with the previous span across a zero-length gap
+                        Some(last) if in_subpath && last.1 == base + pos => last.1 = base + end,
+                        _ => spans.push((base + pos, base + end)),
+                    }
+                }
+                pos = end;
+                ix = (ix + 1) % pattern.len();
+                remaining = pattern[ix];
+            }
+            base += length;
+        }
+        Ok(spans)
+    }
+
     /// The winding number of a point.
     ///
     /// This method only produces meaningful results with closed shapes.
@@ -233,7 +1154,7 @@ impl BezPath {
     /// and -1 when it is inside a negative area shape. Of course, greater
     /// magnitude values are also possible when the shape is more complex.
     #[pyo3(text_signature = "($self, pt)")]
-    fn winding(&self, pt: Point) -> i32 {
+    fn winding(&self, pt: PointLike) -> i32 {
         self.path().winding(pt.0)
     }
 
@@ -245,138 +1166,1242 @@ impl BezPath {
     /// Returns `true` if the [`Point`] is inside this shape.
     ///
     /// This is only meaningful for closed shapes.
-    fn contains(&self, pt: Point) -> bool {
+    fn contains(&self, pt: PointLike) -> bool {
         self.winding(pt) != 0
     }
 
-    /// Computes the intersections with a line as a list of ``Point`` objects.
+    /// Support ``point in path``, which is the same as ``contains``.
     ///
     /// Note that this method is not in original kurbo
-    #[pyo3(text_signature = "($self, line)")]
-    fn intersections(&self, line: &Line) -> Vec<Point> {
-        // XXX Not in original kurbo
-        let mut intersections: Vec<Point> = vec![];
-        for seg in self.path().segments() {
-            for intersect in seg.intersect_line(line.0) {
-                intersections.push(line.0.eval(intersect.line_t).into())
-            }
-        }
-        intersections
+    fn __contains__(&self, pt: PointLike) -> bool {
+        self.contains(pt)
     }
 
-    /// Computes the minimum distance between this ``BezPath`` and another.
+    /// Returns whether each of many points is inside this shape.
+    ///
+    /// ``points`` may be a NumPy array of shape ``(n, 2)``, in which case a
+    /// NumPy array of booleans is returned, or a list of points, in which
+    /// case a list is returned. If ``parallel`` is true, the points are
+    /// divided among several threads. The lock on the interpreter is
+    /// released while testing.
+    ///
+    /// This is only meaningful for closed shapes.
     ///
     /// Note that this method is not in original kurbo
-    #[pyo3(text_signature = "($self, other)")]
-    fn min_distance(&self, other: &BezPath) -> f64 {
-        // XXX Not in original kurbo
-        let path = self.path();
-        let segs1 = path.segments();
-        let mut best_pair: Option<(f64, kurbo::PathSeg, kurbo::PathSeg)> = None;
-        for s1 in segs1 {
-            let p1 = [
-                s1.eval(0.0),
-                s1.eval(0.25),
-                s1.eval(0.5),
-                s1.eval(0.75),
-                s1.eval(1.0),
-            ];
-            for s2 in other.path().segments() {
-                let p2 = [
-                    s2.eval(0.0),
-                    s2.eval(0.25),
-                    s2.eval(0.5),
-                    s2.eval(0.75),
-                    s2.eval(1.0),
-                ];
-                let dist = p1
-                    .iter()
-                    .zip(p2.iter())
-                    .map(|(a, b)| a.distance(*b))
-                    .min_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Less))
-                    .unwrap();
-                if let Some((best, _, _)) = best_pair {
-                    if dist > best {
-                        continue;
+    #[pyo3(signature = (points, parallel=false))]
+    fn contains_points(
+        &self,
+        py: Python,
+        points: PointArray,
+        parallel: bool,
+    ) -> PyResult<PyObject> {
+        let path = self.path().clone();
+        let result = py.allow_threads(|| {
+            let bbox = path.bounding_box();
+            let test = |pts: &[KPoint]| -> Vec<bool> {
+                pts.iter()
+                    .map(|&p| {
+                        let in_bbox = (bbox.x0..=bbox.x1).contains(&p.x)
+                            && (bbox.y0..=bbox.y1).contains(&p.y);
+                        in_bbox && path.winding(p) != 0
+                    })
+                    .collect()
+            };
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            if !parallel || threads < 2 || points.points.len() < 2 * threads {
+                return test(&points.points);
+            }
+            let chunk = (points.points.len() + threads - 1) / threads;
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = points
+                    .points
+                    .chunks(chunk)
+                    .map(|pts| scope.spawn(move || test(pts)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().unwrap())
+                    .collect()
+            })
+        });
+        points.in_kind(py, result)
+    }
+
+    /// Find the position on the path that is nearest to the given point.
+    ///
+    /// Returns a tuple of the index of the nearest segment (as yielded by
+    /// ``segments``) and a :py:class:`Nearest` describing the position on
+    /// that segment, or ``None`` if no segment was considered.
+    ///
+    /// If ``filter`` is given, it is called with each :py:class:`PathSeg`
+    /// and only segments for which it returns a true value are considered;
+    /// for example, ``filter=lambda s: s.as_line() is not None`` snaps only
+    /// to straight segments.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (pt, accuracy=crate::default_accuracy(), filter=None))]
+    fn nearest(
+        &self,
+        pt: PointLike,
+        accuracy: f64,
+        filter: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<(usize, Nearest)>> {
+        self.nearest_filtered(pt, accuracy, None, filter)
+    }
+
+    /// Find the positions on the path nearest to each of many points.
+    ///
+    /// ``points`` may be a list of points or a NumPy array of shape
+    /// ``(n, 2)``. Returns a list with an entry for each point, as returned
+    /// by ``nearest``. Segments are visited in order of the distance to
+    /// their bounding boxes, and those which cannot be nearer than the best
+    /// found so far are skipped. If ``parallel`` is true, the points are
+    /// divided among several threads. The lock on the interpreter is
+    /// released while searching.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (points, accuracy=crate::default_accuracy(), parallel=false))]
+    fn nearest_many(
+        &self,
+        py: Python,
+        points: PointArray,
+        accuracy: f64,
+        parallel: bool,
+    ) -> Vec<Option<(usize, Nearest)>> {
+        let segs: Vec<(KPathSeg, KRect)> = subpath_segments(&self.path())
+            .into_iter()
+            .map(|(_, seg)| (seg, seg.bounding_box()))
+            .collect();
+        let found = py.allow_threads(|| {
+            let search = |pts: &[KPoint]| -> Vec<Option<(usize, kurbo::Nearest, KPoint)>> {
+                pts.iter()
+                    .map(|&p| {
+                        let point_box = KRect::from_points(p, p);
+                        let mut order: Vec<(f64, usize)> = segs
+                            .iter()
+                            .enumerate()
+                            .map(|(ix, (_, bbox))| (rect_distance(point_box, *bbox), ix))
+                            .collect();
+                        order.sort_by(|a, b| a.0.total_cmp(&b.0));
+                        let mut best: Option<(usize, kurbo::Nearest)> = None;
+                        for (bound, ix) in order {
+                            if best.map_or(false, |(_, b)| bound * bound >= b.distance_sq) {
+                                break;
+                            }
+                            let n = segs[ix].0.nearest(p, accuracy);
+                            if best.map_or(true, |(_, b)| n.distance_sq < b.distance_sq) {
+                                best = Some((ix, n));
+                            }
+                        }
+                        best.map(|(ix, n)| (ix, n, segs[ix].0.eval(n.t)))
+                    })
+                    .collect()
+            };
+            let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+            if !parallel || threads < 2 || points.points.len() < 2 * threads {
+                return search(&points.points);
+            }
+            let chunk = (points.points.len() + threads - 1) / threads;
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = points
+                    .points
+                    .chunks(chunk)
+                    .map(|pts| scope.spawn(move || search(pts)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .flat_map(|h| h.join().unwrap())
+                    .collect()
+            })
+        });
+        found
+            .into_iter()
+            .map(|f| f.map(|(ix, n, p)| (ix, Nearest::new(n, p))))
+            .collect()
+    }
+
+    /// Find the position on a single subpath that is nearest to the given point.
+    ///
+    /// Subpaths are numbered from zero in the order they appear in the path.
+    /// The return value is as for ``nearest``; the segment index counts
+    /// segments across the whole path, not within the subpath.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (pt, subpath_index, accuracy=crate::default_accuracy(), filter=None))]
+    fn nearest_on_subpath(
+        &self,
+        pt: PointLike,
+        subpath_index: usize,
+        accuracy: f64,
+        filter: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Option<(usize, Nearest)>> {
+        self.nearest_filtered(pt, accuracy, Some(subpath_index), filter)
+    }
+
+    /// Computes the intersections with a line as a list of ``Point`` objects.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(text_signature = "($self, line)")]
+    fn intersections(&self, line: &Line) -> Vec<Point> {
+        // XXX Not in original kurbo
+        let mut intersections: Vec<Point> = vec![];
+        for seg in self.path().segments() {
+            for intersect in seg.intersect_line(line.0) {
+                intersections.push(line.0.eval(intersect.line_t).into())
+            }
+        }
+        intersections
+    }
+
+    /// Find the points where a line crosses the path.
+    ///
+    /// Returns a list of :py:class:`PathLineIntersection` objects, giving
+    /// the index of the segment crossed, the parameter values on the segment
+    /// and on the line, and the point. If ``sort`` is true they are ordered
+    /// along the line, as for slicing a shape with a scanline; otherwise
+    /// they are in the order of the path's segments.
+    ///
+    /// A line through a point where two segments join hits both of them;
+    /// if ``dedup`` is true, only the first of these hits is kept.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (line, sort=true, dedup=true))]
+    fn intersect_line(&self, line: &Line, sort: bool, dedup: bool) -> Vec<PathLineIntersection> {
+        const EPS: f64 = 1e-9;
+        let at_end = |t: f64| t <= EPS || t >= 1.0 - EPS;
+        let mut hits: Vec<PathLineIntersection> = self
+            .path()
+            .segments()
+            .enumerate()
+            .flat_map(|(ix, seg)| {
+                seg.intersect_line(line.0)
+                    .into_iter()
+                    .map(move |hit| PathLineIntersection {
+                        segment_index: ix,
+                        segment_t: hit.segment_t,
+                        line_t: hit.line_t,
+                        point: line.0.eval(hit.line_t),
+                    })
+            })
+            .collect();
+        if sort {
+            hits.sort_by(|a, b| a.line_t.total_cmp(&b.line_t));
+        }
+        if dedup {
+            let mut kept: Vec<PathLineIntersection> = vec![];
+            for hit in hits {
+                let duplicate = at_end(hit.segment_t)
+                    && kept.iter().any(|k| {
+                        at_end(k.segment_t) && (k.line_t - hit.line_t).abs() <= EPS
+                    });
+                if !duplicate {
+                    kept.push(hit);
+                }
+            }
+            hits = kept;
+        }
+        hits
+    }
+
+    /// Cast a :py:class:`Ray` at the path, returning the first place it hits
+    /// as a :py:class:`RayHit`, or ``None`` if it misses.
+    ///
+    /// Only segments whose bounding boxes the ray passes through are
+    /// examined, nearest first, and the search stops once no nearer hit is
+    /// possible.
+    ///
+    /// Note that this method is not in original kurbo
+    fn raycast(&self, ray: &Ray) -> Option<RayHit> {
+        ray.hits(&self.path(), true).into_iter().next()
+    }
+
+    /// Cast a :py:class:`Ray` at the path, returning every place it hits as
+    /// a list of :py:class:`RayHit` objects, ordered by distance. A ray
+    /// through the join between two segments hits only the first of them.
+    ///
+    /// Note that this method is not in original kurbo
+    fn raycast_all(&self, ray: &Ray) -> Vec<RayHit> {
+        ray.hits(&self.path(), false)
+    }
+
+    /// Computes the intersections with each of a list of lines.
+    ///
+    /// Returns a list with one entry per input line, each a list of
+    /// ``Point`` objects ordered along that line. The path's segments are
+    /// prepared once and shared by all the lines, so this is much faster
+    /// than calling ``intersections`` repeatedly, e.g. for hatching.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(text_signature = "($self, lines)")]
+    fn intersections_with_lines(&self, lines: Vec<Line>) -> Vec<Vec<Point>> {
+        const EPS: f64 = 1e-9;
+        let segs: Vec<(KPathSeg, KRect)> = self
+            .path()
+            .segments()
+            .map(|seg| (seg, seg.bounding_box()))
+            .collect();
+        lines
+            .iter()
+            .map(|line| {
+                let bbox = KRect::from_points(line.0.p0, line.0.p1);
+                // (line_t, segment_t, index of the segment)
+                let mut hits: Vec<(f64, f64, usize)> = segs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, sbox))| {
+                        sbox.x0 <= bbox.x1
+                            && bbox.x0 <= sbox.x1
+                            && sbox.y0 <= bbox.y1
+                            && bbox.y0 <= sbox.y1
+                    })
+                    .flat_map(|(ix, (seg, _))| {
+                        seg.intersect_line(line.0)
+                            .into_iter()
+                            .map(move |hit| (hit.line_t, hit.segment_t, ix))
+                    })
+                    .collect();
+                hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+                // A line through a vertex hits the end of one segment and the
+                // start of the next; report that as a single point.
+                let at_vertex = |a: &(f64, f64, usize), b: &(f64, f64, usize)| {
+                    let joined = |end: &(f64, f64, usize), start: &(f64, f64, usize)| {
+                        end.1 > 1.0 - EPS
+                            && start.1 < EPS
+                            && segs[end.2].0.end().distance(segs[start.2].0.start())
+                                < EPS
+                    };
+                    (a.0 - b.0).abs() < EPS && (joined(a, b) || joined(b, a))
+                };
+                let mut kept: Vec<(f64, f64, usize)> = vec![];
+                for hit in hits {
+                    if kept.last().map_or(true, |last| !at_vertex(last, &hit)) {
+                        kept.push(hit);
                     }
                 }
-                best_pair = Some((dist, s1, s2));
-            }
-        }
-        if let Some((_, s1, s2)) = best_pair {
-            let curve1 = match s1 {
-                KPathSeg::Line(_) => KPathSeg::Cubic(KCubicBez::new(
-                    s1.eval(0.0),
-                    s1.eval(1.0 / 3.0),
-                    s1.eval(2.0 / 3.0),
-                    s1.eval(1.0),
-                )),
-                _ => s1,
+                kept.into_iter().map(|(t, _, _)| line.0.eval(t).into()).collect()
+            })
+            .collect()
+    }
+
+    /// Flatten the path and clip it to a rectangle.
+    ///
+    /// The result is made of straight lines, with a new subpath starting
+    /// wherever the path leaves and re-enters the rectangle. Closed subpaths
+    /// are clipped as outlines, not as areas, so the result is suitable for
+    /// plotting strokes rather than filling. Raises ``ValueError`` unless
+    /// ``tolerance`` is positive.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (rect, tolerance=crate::DEFAULT_TOLERANCE))]
+    fn clip_lines(slf: &Bound<'_, Self>, rect: &Rect, tolerance: f64) -> PyResult<Py<Self>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let this = slf.borrow();
+        let mut result = KBezPath::new();
+        for line in flatten_subpaths(&this.path(), tolerance) {
+            let mut points = line.points;
+            if line.closed && points.len() > 1 {
+                points.push(points[0]);
+            }
+            let mut last: Option<KPoint> = None;
+            for pair in points.windows(2) {
+                let Some((p0, p1)) = clip_segment(pair[0], pair[1], rect.0) else {
+                    last = None;
+                    continue;
+                };
+                if last != Some(p0) {
+                    result.move_to(p0);
+                }
+                result.line_to(p1);
+                last = Some(p1);
+            }
+        }
+        new_like(slf, result.into())
+    }
+
+    /// Clip the path to a rectangle, keeping its curves.
+    ///
+    /// With ``fill=True``, the path is treated as a filled shape, and the
+    /// result encloses the part of the shape inside the rectangle, with
+    /// straight lines along the edges of the rectangle where the shape was
+    /// cut. Open subpaths are treated as closed, and the fill rule of the
+    /// shape is respected whichever it is. With ``fill=False``, the path is
+    /// treated as an outline and trimmed to the parts inside the rectangle,
+    /// with a new subpath starting wherever it re-enters.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (rect, fill=true))]
+    fn clip_to_rect(slf: &Bound<'_, Self>, rect: &Rect, fill: bool) -> PyResult<Py<Self>> {
+        let rect = rect.0.abs();
+        let this = slf.borrow();
+        let clipped = if fill {
+            crate::clip::clip_fill(&this.path(), rect)
+        } else {
+            crate::clip::clip_stroke(&this.path(), rect)
+        };
+        new_like(slf, clipped.into())
+    }
+
+    /// Merge the overlapping subpaths of the path into their union.
+    ///
+    /// The path is filled with the nonzero rule, and open subpaths are
+    /// treated as closed. Segments are split where they cross and at their
+    /// extrema, and the pieces on the outline of the union are joined back
+    /// into closed subpaths. Curves are kept as curves, and segments which
+    /// are not crossed come through unchanged. Subpaths which lie inside
+    /// others with the same direction are removed.
+    ///
+    /// The result keeps the overall direction of the path: if its area is
+    /// positive, outer contours run anticlockwise in a y-up coordinate
+    /// system and holes clockwise; otherwise the other way round.
+    ///
+    /// Note that this method is not in original kurbo
+    fn remove_overlaps(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, crate::overlaps::remove_overlaps(&slf.borrow().path()).into())
+    }
+
+    /// The direction and nesting depth of each subpath.
+    ///
+    /// Returns a ``(direction, depth)`` tuple for each subpath. The direction
+    /// is ``"anticlockwise"`` or ``"clockwise"`` in a y-up coordinate system,
+    /// as used by font formats (on a y-down screen they appear the other way
+    /// round), or ``None`` if the subpath encloses no area. Open subpaths are
+    /// treated as closed. The depth is the number of other subpaths which
+    /// enclose the subpath, so outer contours have even depths and holes odd
+    /// depths.
+    ///
+    /// Note that this method is not in original kurbo
+    fn subpath_directions(&self) -> Vec<(Option<&'static str>, usize)> {
+        subpath_nesting(&self.path())
+            .into_iter()
+            .map(|(_, area, depth)| {
+                let direction = if area > 0.0 {
+                    Some("anticlockwise")
+                } else if area < 0.0 {
+                    Some("clockwise")
+                } else {
+                    None
+                };
+                (direction, depth)
+            })
+            .collect()
+    }
+
+    /// Reverse subpaths as needed so that their directions follow a font
+    /// format's convention.
+    ///
+    /// With ``convention="postscript"`` (as used by CFF and Type 1 fonts),
+    /// outer contours run anticlockwise and holes clockwise, in a y-up
+    /// coordinate system. With ``convention="truetype"``, it is the other
+    /// way round. Whether a subpath is an outer contour or a hole is found
+    /// from its depth, as given by ``subpath_directions``. Subpaths which
+    /// enclose no area are left alone.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (convention="postscript"))]
+    fn correct_directions(slf: &Bound<'_, Self>, convention: &str) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let outer_anticlockwise = match convention {
+            "postscript" => true,
+            "truetype" => false,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "convention must be 'postscript' or 'truetype'",
+                ))
+            }
+        };
+        let mut result = KBezPath::new();
+        for (piece, area, depth) in subpath_nesting(&this.path()) {
+            let anticlockwise = (depth % 2 == 0) == outer_anticlockwise;
+            if area != 0.0 && (area > 0.0) != anticlockwise {
+                result.extend(piece.reverse_subpaths());
+            } else {
+                result.extend(piece);
+            }
+        }
+        new_like(slf, result.into())
+    }
+
+    /// Put the path into a canonical form, so that paths which draw the
+    /// same segments compare equal however their elements are ordered.
+    ///
+    /// Each closed subpath is made to run anticlockwise in a y-up coordinate
+    /// system (unless it encloses no area), and to start from whichever of
+    /// its segments gives the least sequence of coordinates; each open
+    /// subpath runs whichever way gives the lesser sequence; and the
+    /// subpaths are sorted. A closing line is always left to the
+    /// ``ClosePath``. Note that directions are not kept, which changes
+    /// how overlapping subpaths are filled; use ``correct_directions``
+    /// afterwards to restore a font format's convention.
+    ///
+    /// Paths are only made equal if they have the same segments, so run
+    /// ``cleanup`` or ``round_coordinates`` first to remove small
+    /// differences.
+    ///
+    /// Note that this method is not in original kurbo
+    fn canonicalize(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, crate::canonical::canonicalize(&slf.borrow().path()).into())
+    }
+
+    /// Remove degenerate geometry from the path.
+    ///
+    /// Segments shorter than ``tolerance`` are removed, curves whose control
+    /// points lie within ``tolerance`` of the line between their ends are
+    /// replaced by lines, consecutive lines which carry on in the same
+    /// direction are merged into one, and subpaths which are left with
+    /// nothing to draw are dropped.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::cleanup::DEFAULT_CLEANUP_TOLERANCE))]
+    fn cleanup(slf: &Bound<'_, Self>, tolerance: f64) -> PyResult<Py<Self>> {
+        if !(tolerance.is_finite() && tolerance >= 0.0) {
+            return Err(PyValueError::new_err("tolerance must not be negative"));
+        }
+        new_like(slf, crate::cleanup::cleanup(&slf.borrow().path(), tolerance).into())
+    }
+
+    /// Triangulate the region enclosed by the path, for rendering on a GPU.
+    ///
+    /// The path is flattened to within ``tolerance`` and filled with the
+    /// nonzero rule, with open subpaths treated as closed. Overlapping
+    /// subpaths are merged first, so no point is covered by two triangles.
+    ///
+    /// Returns a tuple of three ``array.array`` objects, which support the
+    /// buffer protocol and can be uploaded directly: the vertices as
+    /// ``float32`` values ``x0, y0, x1, y1, ...``; the triangles as
+    /// ``uint32`` vertex indices, three per triangle, each wound
+    /// anticlockwise in a y-up coordinate system; and for each vertex, the
+    /// ``uint32`` ID of the contour of the merged outline it lies on.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn tessellate(
+        &self,
+        py: Python<'_>,
+        tolerance: f64,
+    ) -> PyResult<(PyObject, PyObject, PyObject)> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let path = self.path().clone();
+        let mesh = py.allow_threads(|| crate::tessellate::tessellate(&path, tolerance));
+        let array = PyModule::import_bound(py, "array")?.getattr("array")?;
+        let vertices: Vec<f32> = mesh
+            .vertices
+            .iter()
+            .flat_map(|p| [p.x as f32, p.y as f32])
+            .collect();
+        let indices: Vec<u32> = mesh.triangles.into_iter().flatten().collect();
+        Ok((
+            array.call1(("f", vertices))?.unbind(),
+            array.call1(("I", indices))?.unbind(),
+            array.call1(("I", mesh.contours))?.unbind(),
+        ))
+    }
+
+    /// Computes the minimum distance between this ``BezPath`` and another.
+    ///
+    /// Every pair of segments whose bounding boxes are close enough to hold
+    /// a nearer pair of points than any found so far is measured with
+    /// ``PathSeg.min_dist``, so the result is within ``accuracy`` of the
+    /// true distance. Returns the largest float if either path is empty.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
+    fn min_distance(&self, other: &BezPath, accuracy: f64) -> f64 {
+        let segs1: Vec<(KPathSeg, KRect)> = self
+            .path()
+            .segments()
+            .map(|s| (as_curve(s), s.bounding_box()))
+            .collect();
+        let segs2: Vec<(KPathSeg, KRect)> = other
+            .path()
+            .segments()
+            .map(|s| (as_curve(s), s.bounding_box()))
+            .collect();
+        // Visit the pairs in order of the distance between their bounding
+        // boxes, which no pair of points on the segments can be nearer than.
+        let mut pairs: Vec<(f64, usize, usize)> = vec![];
+        for (i, (_, r1)) in segs1.iter().enumerate() {
+            for (j, (_, r2)) in segs2.iter().enumerate() {
+                pairs.push((rect_distance(*r1, *r2), i, j));
+            }
+        }
+        pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut best = f64::MAX;
+        for (bound, i, j) in pairs {
+            if bound >= best {
+                break;
+            }
+            let dist = segs1[i].0.min_dist(segs2[j].0, accuracy).distance;
+            best = best.min(dist);
+        }
+        best
+    }
+
+    /// Computes the Hausdorff distance between this ``BezPath`` and another:
+    /// the greatest distance from a point on either path to the nearest
+    /// point on the other.
+    ///
+    /// This is the usual measure of how far an approximation strays from
+    /// the original, such as a conversion of cubics to quadratics. Each path
+    /// is bisected only where the distance could exceed the greatest found so
+    /// far, and the result is within ``accuracy`` of the true distance.
+    /// Returns ``0.0`` if both paths are empty, and the largest float if only
+    /// one of them is. Raises ``ValueError`` if either path has non-finite
+    /// coordinates.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
+    fn hausdorff_distance(&self, other: &BezPath, accuracy: f64) -> PyResult<f64> {
+        if !(accuracy.is_finite() && accuracy > 0.0) {
+            return Err(PyValueError::new_err("accuracy must be finite and positive"));
+        }
+        // Take one lock at a time, as other may be this path.
+        let a = self.path().clone();
+        let b = other.path().clone();
+        if !(a.is_finite() && b.is_finite()) {
+            return Err(PyValueError::new_err("paths must be finite"));
+        }
+        Ok(crate::hausdorff::hausdorff_distance(&a, &b, accuracy))
+    }
+
+    /// Find the axes of mirror symmetry of the path.
+    ///
+    /// Returns a list of ``(axis, error)`` tuples, most symmetric first.
+    /// Each axis is a :py:class:`Line` across the path's bounding box, and
+    /// ``error`` is the Hausdorff distance between the path and its
+    /// reflection in that axis, which is at most ``tolerance``. Contours
+    /// which the reflection reverses still count as symmetric.
+    ///
+    /// ::
+    ///
+    ///    axes = glyph.find_mirror_axes(1.0)
+    ///    if axes:
+    ///        axis, error = axes[0]
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn find_mirror_axes(&self, tolerance: f64) -> PyResult<Vec<(Line, f64)>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let path = self.path().clone();
+        Ok(crate::symmetry::mirror_axes(&path, tolerance)
+            .into_iter()
+            .map(|(line, error)| (line.into(), error))
+            .collect())
+    }
+
+    /// Find the rotational symmetry of the path.
+    ///
+    /// Returns an ``(order, center)`` tuple, where ``order`` is the largest
+    /// ``n`` such that rotating the path by a ``1/n`` turn about ``center``
+    /// leaves it unchanged to within ``tolerance`` (as measured by the
+    /// Hausdorff distance), and ``center`` is the length-weighted centroid
+    /// of the outline. The order is 1 if the path has no rotational
+    /// symmetry. Raises ``ValueError`` if the path has no length.
+    ///
+    /// ::
+    ///
+    ///    order, center = ornament.rotational_symmetry(0.5)
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn rotational_symmetry(&self, tolerance: f64) -> PyResult<(usize, Point)> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        let path = self.path().clone();
+        let (order, center) = crate::symmetry::rotational_symmetry(&path, tolerance)
+            .ok_or_else(|| PyValueError::new_err("path has no length"))?;
+        Ok((order, center.into()))
+    }
+
+    /// Find the points where this path crosses or touches another.
+    ///
+    /// Returns a list of ``(index_a, t_a, index_b, t_b, point)`` tuples, one
+    /// for each meeting point, where ``index_a`` is the index of a segment of
+    /// this path (as yielded by ``segments``) and ``t_a`` the parameter value
+    /// on it, and likewise ``index_b`` and ``t_b`` for the other path. The
+    /// tuples are sorted by ``index_a`` and ``t_a``.
+    ///
+    /// Crossings with lines are found exactly, and crossings between curves
+    /// by subdivision to within ``accuracy``. A crossing at a point where
+    /// segments join is only reported once.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
+    fn intersects(
+        &self,
+        other: &BezPath,
+        accuracy: f64,
+    ) -> PyResult<Vec<Crossing<Point>>> {
+        if !(accuracy.is_finite() && accuracy > 0.0) {
+            return Err(PyValueError::new_err("accuracy must be positive"));
+        }
+        // Take one lock at a time, as other may be this path.
+        let a = self.path().clone();
+        let b = other.path().clone();
+        let result = crossings(&a, &b, accuracy);
+        Ok(result
+            .into_iter()
+            .map(|(i, t1, j, t2, p)| (i, t1, j, t2, p.into()))
+            .collect())
+    }
+
+    /// Find the points where this path crosses or touches another.
+    ///
+    /// Returns a list of :py:class:`PathIntersection` objects, sorted by
+    /// their position along this path. The meeting points are found as for
+    /// ``intersects``, to within ``accuracy``, which returns the same
+    /// information as plain tuples.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
+    fn intersect_path(&self, other: &BezPath, accuracy: f64) -> PyResult<Vec<PathIntersection>> {
+        if !(accuracy.is_finite() && accuracy > 0.0) {
+            return Err(PyValueError::new_err("accuracy must be positive"));
+        }
+        // Take one lock at a time, as other may be this path.
+        let a = self.path().clone();
+        let b = other.path().clone();
+        Ok(crossings(&a, &b, accuracy)
+            .into_iter()
+            .map(|(i, t1, j, t2, point)| PathIntersection {
+                segment_index: i,
+                segment_t: t1,
+                other_index: j,
+                other_t: t2,
+                point,
+            })
+            .collect())
+    }
+
+    /// Round all coordinates of the path, either to ``ndigits`` decimal
+    /// places (as with Python's ``round``) or to the nearest multiple of
+    /// ``grid``. With neither, coordinates are rounded to integers, as font
+    /// formats require.
+    ///
+    /// Every point is rounded independently and the elements of the path are
+    /// kept as they are. To keep the directions of curve handles while
+    /// snapping, use ``grid_fit`` instead.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (ndigits=None, grid=None))]
+    fn round_coordinates(slf: &Bound<'_, Self>, ndigits: Option<i32>, grid: Option<f64>) -> PyResult<Py<Self>> {
+        let rounding = Rounding::new(ndigits, grid)?;
+        new_like(slf, slf.borrow().path().quantize(rounding).into())
+    }
+
+    /// Compare with another path, allowing each coordinate to differ by up
+    /// to ``epsilon``. The paths must have the same elements in the same
+    /// order; use ``canonicalize`` first to compare paths which draw the
+    /// same shape in a different order.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, epsilon=1e-9))]
+    fn approx_eq(&self, other: &BezPath, epsilon: f64) -> bool {
+        let a = self.path().clone();
+        let b = other.path();
+        a.approx_eq(&b, epsilon)
+    }
+
+    /// Round the on-curve points of the path to a grid, adjusting the
+    /// off-curve points so that the character of the curves is kept.
+    ///
+    /// Each on-curve point is rounded to the nearest multiple of ``upm_grid``.
+    /// Cubic handles move together with the on-curve point they belong to, so
+    /// their directions are unchanged: smooth connections stay smooth, and
+    /// extrema (on-curve points with horizontal or vertical handles) stay on
+    /// the on-curve points. The control point of a quadratic segment is
+    /// placed where the original tangents meet at the new end points.
+    ///
+    /// If ``preserve_smooth`` is false, the off-curve points are then rounded
+    /// to the grid as well, at the cost of small changes in tangent direction.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (upm_grid, preserve_smooth=true))]
+    fn grid_fit(slf: &Bound<'_, Self>, upm_grid: f64, preserve_smooth: bool) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        if !(upm_grid.is_finite() && upm_grid > 0.0) {
+            return Err(PyValueError::new_err("grid size must be positive"));
+        }
+        let snap = |p: KPoint| {
+            KPoint::new(
+                (p.x / upm_grid).round() * upm_grid,
+                (p.y / upm_grid).round() * upm_grid,
+            )
+        };
+        let off_curve = |p: KPoint| if preserve_smooth { p } else { snap(p) };
+        let mut result = KBezPath::new();
+        // The current point, before and after snapping.
+        let mut last = (KPoint::ZERO, KPoint::ZERO);
+        let mut start = last;
+        for el in this.path().elements() {
+            match *el {
+                KPathEl::MoveTo(p) => {
+                    last = (p, snap(p));
+                    start = last;
+                    result.move_to(last.1);
+                }
+                KPathEl::LineTo(p) => {
+                    last = (p, snap(p));
+                    result.line_to(last.1);
+                }
+                KPathEl::QuadTo(c, p) => {
+                    let q = snap(p);
+                    let c = refit_quad_control(last.0, c, p, last.1, q);
+                    result.quad_to(off_curve(c), q);
+                    last = (p, q);
+                }
+                KPathEl::CurveTo(c1, c2, p) => {
+                    let q = snap(p);
+                    result.curve_to(
+                        off_curve(c1 + (last.1 - last.0)),
+                        off_curve(c2 + (q - p)),
+                        q,
+                    );
+                    last = (p, q);
+                }
+                KPathEl::ClosePath => {
+                    result.close_path();
+                    last = start;
+                }
+            }
+        }
+        new_like(slf, result.into())
+    }
+
+    /// Sample the curvature of the path for drawing a curvature comb.
+    ///
+    /// Each segment is sampled at ``samples_per_segment`` evenly spaced
+    /// parameter values, including both of its ends. Returns a tuple of
+    /// three lists: the points on the path, the unit normals at those
+    /// points, and the curvature at each point multiplied by ``scale``.
+    /// The normals point away from the center of curvature, so the teeth of
+    /// the comb run from each point to ``point + normal * magnitude``.
+    ///
+    /// If ``as_numpy`` is true, NumPy arrays of shape ``(n, 2)``,
+    /// ``(n, 2)`` and ``(n,)`` are returned instead of lists; this requires
+    /// NumPy to be installed.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (samples_per_segment=16, scale=1.0, as_numpy=false))]
+    fn curvature_comb(
+        &self,
+        py: Python,
+        samples_per_segment: usize,
+        scale: f64,
+        as_numpy: bool,
+    ) -> PyResult<PyObject> {
+        if samples_per_segment == 0 {
+            return Err(PyValueError::new_err("samples_per_segment must be positive"));
+        }
+        let mut points = vec![];
+        let mut normals = vec![];
+        let mut magnitudes = vec![];
+        for seg in self.path().segments() {
+            // to_cubic puts the control points of a line on its ends, where
+            // the derivative would then vanish, so spread them evenly.
+            let cubic = match seg {
+                KPathSeg::Line(l) => KCubicBez::new(l.p0, l.eval(1.0 / 3.0), l.eval(2.0 / 3.0), l.p1),
+                _ => seg.to_cubic(),
             };
-            let curve2 = match s2 {
-                KPathSeg::Line(_) => KPathSeg::Cubic(KCubicBez::new(
-                    s2.eval(0.0),
-                    s2.eval(1.0 / 3.0),
-                    s2.eval(2.0 / 3.0),
-                    s2.eval(1.0),
-                )),
-                _ => s2,
+            let deriv = cubic.deriv();
+            for i in 0..samples_per_segment {
+                let t = if samples_per_segment > 1 {
+                    i as f64 / (samples_per_segment - 1) as f64
+                } else {
+                    0.5
+                };
+                let k = cubic.curvature(t);
+                let tangent = deriv.eval(t).to_vec2().normalize();
+                let left = Vec2::new(-tangent.y, tangent.x);
+                points.push(cubic.eval(t));
+                normals.push(if k < 0.0 { -left } else { left });
+                magnitudes.push(k.abs() * scale);
+            }
+        }
+        if as_numpy {
+            let numpy = PyModule::import_bound(py, "numpy")?;
+            let array = |v: PyObject| numpy.call_method1("asarray", (v,)).map(|a| a.unbind());
+            let points: Vec<(f64, f64)> = points.iter().map(|p| (p.x, p.y)).collect();
+            let normals: Vec<(f64, f64)> = normals.iter().map(|v| (v.x, v.y)).collect();
+            return Ok((
+                array(points.into_py(py))?,
+                array(normals.into_py(py))?,
+                array(magnitudes.into_py(py))?,
+            )
+                .into_py(py));
+        }
+        let points: Vec<Point> = points.into_iter().map(Point).collect();
+        let normals: Vec<PyVec2> = normals.into_iter().map(PyVec2).collect();
+        Ok((points, normals, magnitudes).into_py(py))
+    }
+
+    /// Find the extreme points of the path.
+    ///
+    /// Returns a list of ``(index, t, point)`` tuples, where ``index`` is the
+    /// index of a segment (as yielded by ``segments``) and ``t`` a parameter
+    /// value strictly inside it at which the segment is horizontal or
+    /// vertical; these are the places where font editors expect on-curve
+    /// points.
+    ///
+    /// If ``global_only`` is true, only the points where the path reaches
+    /// its overall minimum or maximum x or y coordinate are returned; these
+    /// may include the ends of segments (``t`` of 0 or 1).
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (global_only=false))]
+    fn extrema(&self, global_only: bool) -> Vec<(usize, f64, Point)> {
+        let segs: Vec<KPathSeg> = self.path().segments().collect();
+        let mut result = vec![];
+        for (ix, seg) in segs.iter().enumerate() {
+            if global_only {
+                result.push((ix, 0.0, seg.start()));
+            }
+            let extrema = kurbo::ParamCurveExtrema::extrema(seg);
+            result.extend(extrema.into_iter().map(|t| (ix, t, seg.eval(t))));
+            if global_only {
+                result.push((ix, 1.0, seg.end()));
+            }
+        }
+        if global_only {
+            let bbox = segs
+                .iter()
+                .map(|seg| seg.bounding_box())
+                .reduce(|a, b| a.union(b));
+            let Some(bbox) = bbox else {
+                return vec![];
             };
-            curve1.min_dist(curve2, 0.05).distance
-        } else {
-            f64::MAX
+            let eps = 1e-9 * bbox.width().max(bbox.height()).max(1.0);
+            let close = |a: f64, b: f64| (a - b).abs() <= eps;
+            result.retain(|(_, _, p)| {
+                close(p.x, bbox.x0)
+                    || close(p.x, bbox.x1)
+                    || close(p.y, bbox.y0)
+                    || close(p.y, bbox.y1)
+            });
+            // A point shared by two segments is only reported once.
+            result.dedup_by(|b, a| a.2 == b.2);
         }
+        result
+            .into_iter()
+            .map(|(ix, t, p)| (ix, t, p.into()))
+            .collect()
     }
 
-    /// Returns true if the two BezPaths intersect
+    /// Split every curve in the path at its extrema.
+    ///
+    /// Returns a new path with the same subpaths, in which every quadratic
+    /// and cubic segment is monotonic in both x and y. The split points
+    /// become on-curve points; lines are already monotonic and are left
+    /// alone.
     ///
     /// Note that this method is not in original kurbo
-    #[pyo3(text_signature = "($self, other)")]
-    fn intersects(&self, other: &BezPath) -> Vec<Point> {
-        let b1 = &self.path();
-        let b2 = &other.path();
-        if b1.bounding_box().intersect(b2.bounding_box()).area() < f64::EPSILON {
-            return vec![];
-        }
-        let mut rv = vec![];
-        let mut pts1 = vec![];
-        let mut pts2 = vec![];
-        b1.flatten(0.1, |el| match el {
-            KPathEl::MoveTo(a) => pts1.push(a),
-            KPathEl::LineTo(a) => pts1.push(a),
-            _ => {}
-        });
-        b2.flatten(0.1, |el| match el {
-            KPathEl::MoveTo(a) => pts2.push(a),
-            KPathEl::LineTo(a) => pts2.push(a),
-            _ => {}
-        });
-        for (&la1, &la2) in pts1.iter().circular_tuple_windows() {
-            for (&lb1, &lb2) in pts2.iter().circular_tuple_windows() {
-                let seg1 = KPathSeg::Line(kurbo::Line::new(la1, la2));
-                let seg2 = kurbo::Line::new(lb1, lb2);
-                rv.extend(
-                    seg1.intersect_line(seg2)
-                        .iter()
-                        .map(|x| Point(seg1.eval(x.line_t))),
-                );
+    fn to_monotonic(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let mut result = KBezPath::new();
+        let mut start = KPoint::ORIGIN;
+        let mut last = KPoint::ORIGIN;
+        for el in this.path().elements() {
+            match *el {
+                KPathEl::QuadTo(p1, p2) => {
+                    let quad = KQuadBez::new(last, p1, p2);
+                    for r in kurbo::ParamCurveExtrema::extrema_ranges(&quad) {
+                        let piece = quad.subsegment(r);
+                        result.quad_to(piece.p1, piece.p2);
+                    }
+                }
+                KPathEl::CurveTo(p1, p2, p3) => {
+                    let cubic = KCubicBez::new(last, p1, p2, p3);
+                    for r in kurbo::ParamCurveExtrema::extrema_ranges(&cubic) {
+                        let piece = cubic.subsegment(r);
+                        result.curve_to(piece.p1, piece.p2, piece.p3);
+                    }
+                }
+                el => result.push(el),
             }
+            last = match *el {
+                KPathEl::MoveTo(p) => {
+                    start = p;
+                    p
+                }
+                KPathEl::ClosePath => start,
+                _ => el.end_point().unwrap_or(last),
+            };
         }
-        rv
+        new_like(slf, result.into())
+    }
+
+    /// Approximate the path by circular arcs and lines.
+    ///
+    /// Returns a list with an entry for each subpath, which is a list of
+    /// ``Arc`` and ``Line`` objects joined end to end, including the line
+    /// which closes a closed subpath. Lines are kept as they are, and curves
+    /// are replaced by arcs as described in ``CubicBez.to_biarcs``, staying
+    /// within ``tolerance`` of the original path.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn to_arcs(&self, tolerance: f64) -> PyResult<Vec<Vec<crate::biarc::Piece>>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        Ok(crate::biarc::path_to_arcs(&self.path(), tolerance))
+    }
+
+    /// Find the corners of the path.
+    ///
+    /// A corner is a join between two segments where the direction of the
+    /// path turns by more than ``angle_threshold`` radians, or a cusp
+    /// inside a cubic segment, where the curve stops and reverses. Joins
+    /// between the last and first segments of closed subpaths are included.
+    ///
+    /// Returns a list of ``(index, t, point, angle)`` tuples, where
+    /// ``index`` is the index of the segment (as yielded by ``segments``)
+    /// and ``t`` the parameter value on it: 0 for a join at the start of
+    /// the segment, or the position of a cusp. ``angle`` is the signed
+    /// turning angle in radians, which is π for a cusp.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(text_signature = "($self, angle_threshold)")]
+    fn corners(&self, angle_threshold: f64) -> Vec<(usize, f64, Point, f64)> {
+        let path = self.path();
+        let segs: Vec<KPathSeg> = path.segments().collect();
+        crate::corners::find_corners(&path, angle_threshold)
+            .into_iter()
+            .filter_map(|(ix, t, angle)| Some((ix, t, segs.get(ix)?.eval(t).into(), angle)))
+            .collect()
+    }
+
+    /// Returns true if this path can be interpolated with ``other``.
+    ///
+    /// Paths are compatible when they have the same number of elements and
+    /// each pair of corresponding elements is of the same kind.
+    ///
+    /// Note that this method is not in original kurbo
+    fn is_compatible(&self, other: &BezPath) -> bool {
+        // Take one lock at a time, as other may be this path.
+        let a = self.path().clone();
+        let b = other.path().clone();
+        incompatibility(&a, &b).is_none()
+    }
+
+    /// Interpolate between this path and ``other``, which must be
+    /// compatible (see ``is_compatible``).
+    ///
+    /// Each point of the result is ``t`` of the way from the corresponding
+    /// point of this path to that of ``other``; ``t`` outside 0 to 1
+    /// extrapolates. Raises ``ValueError`` describing the first difference
+    /// if the paths are not compatible.
+    ///
+    /// Note that this method is not in original kurbo
+    fn lerp(slf: &Bound<'_, Self>, other: &BezPath, t: f64) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let a = this.path().clone();
+        let b = other.path().clone();
+        if let Some(reason) = incompatibility(&a, &b) {
+            return Err(PyValueError::new_err(format!(
+                "paths are not compatible: {}",
+                reason
+            )));
+        }
+        let els = a
+            .elements()
+            .iter()
+            .zip(b.elements())
+            .map(|(x, y)| match (*x, *y) {
+                (KPathEl::MoveTo(p), KPathEl::MoveTo(q)) => KPathEl::MoveTo(p.lerp(q, t)),
+                (KPathEl::LineTo(p), KPathEl::LineTo(q)) => KPathEl::LineTo(p.lerp(q, t)),
+                (KPathEl::QuadTo(p1, p2), KPathEl::QuadTo(q1, q2)) => {
+                    KPathEl::QuadTo(p1.lerp(q1, t), p2.lerp(q2, t))
+                }
+                (KPathEl::CurveTo(p1, p2, p3), KPathEl::CurveTo(q1, q2, q3)) => {
+                    KPathEl::CurveTo(p1.lerp(q1, t), p2.lerp(q2, t), p3.lerp(q3, t))
+                }
+                _ => KPathEl::ClosePath,
+            })
+            .collect();
+        new_like(slf, KBezPath::from_vec(els).into())
+    }
+
+    /// Approximate the medial axis (skeleton) of a closed shape.
+    ///
+    /// The medial axis is the set of centers of the circles which touch
+    /// the outline in two or more places; for a stroked shape, it runs
+    /// down the middle of the strokes. It is returned as a list of
+    /// polylines, each a list of points, which run between the ends of the
+    /// axis and the points where it branches.
+    ///
+    /// The outline is sampled at points at most ``tolerance`` apart (but
+    /// no more than 4000 points in all), and the result is accurate to
+    /// roughly the same degree. Small wiggles in the outline produce small
+    /// spurious branches.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn medial_axis(&self, tolerance: f64) -> PyResult<Vec<Vec<Point>>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        Ok(crate::medial::medial_axis(&self.path(), tolerance)
+            .into_iter()
+            .map(|line| line.into_iter().map(Point).collect())
+            .collect())
+    }
+
+    /// Grow or shrink the region enclosed by the path.
+    ///
+    /// With a positive ``distance``, the result covers every point within
+    /// ``distance`` of the shape; with a negative one, it covers the points
+    /// of the shape which are further than ``-distance`` from its outline.
+    /// Unlike offsetting the segments, overlaps and self-intersections are
+    /// resolved, so the result is always a valid set of closed outlines,
+    /// and parts of the shape which are too thin disappear when shrinking.
+    ///
+    /// ``join`` is ``"round"``, ``"miter"`` or ``"bevel"``, and determines
+    /// the shape of the buffered corners. Miters longer than four times the
+    /// distance are beveled. The shape is filled with the nonzero rule and
+    /// open subpaths are treated as closed.
+    ///
+    /// The path is flattened to within ``tolerance``, so the result is made
+    /// of straight lines, with outer contours running anticlockwise in a
+    /// y-up coordinate system and holes running clockwise.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (distance, join="round", tolerance=crate::DEFAULT_TOLERANCE))]
+    fn buffer(slf: &Bound<'_, Self>, distance: f64, join: &str, tolerance: f64) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let join = crate::buffer::Join::parse(join)?;
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        if !distance.is_finite() {
+            return Err(PyValueError::new_err("distance must be finite"));
+        }
+        let mut result = KBezPath::new();
+        for poly in crate::buffer::buffer(&this.path(), distance, join, tolerance) {
+            push_polygon(&mut result, &poly);
+        }
+        new_like(slf, result.into())
+    }
+
+    /// Adjust the control points of the path so that its smooth joins are
+    /// truly smooth.
+    ///
+    /// With ``level="g1"``, the handles on either side of each join are
+    /// rotated to share a tangent direction, keeping their lengths. Where
+    /// one side is a straight line, the handle of the curve on the other
+    /// side is aligned with the line. With ``level="g2"``, joins between two
+    /// cubic segments are then made curvature-continuous as well, by
+    /// sliding the on-curve point along its handles.
+    ///
+    /// Joins which turn by more than ``angle_threshold`` radians (10° by
+    /// default) are considered corners and are left alone, as are joins
+    /// next to quadratic segments.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (level="g1", angle_threshold=crate::corners::SMOOTH_JOIN_ANGLE))]
+    fn harmonize(slf: &Bound<'_, Self>, level: &str, angle_threshold: f64) -> PyResult<Py<Self>> {
+        let g2 = match level {
+            "g1" => false,
+            "g2" => true,
+            _ => return Err(PyValueError::new_err("level must be \"g1\" or \"g2\"")),
+        };
+        new_like(slf, crate::corners::harmonize(&slf.borrow().path(), g2, angle_threshold).into())
+    }
+
+    /// Stroke the path with a width which varies along its length,
+    /// returning the outline of the stroke.
+    ///
+    /// ``width`` may be a function, which is called with the position along
+    /// each subpath as a fraction of its length (0 at the start, 1 at the
+    /// end) and returns the full width of the stroke there; a list of
+    /// widths, which are spaced evenly along each subpath and interpolated
+    /// linearly; or a single number for a constant width.
+    ///
+    /// Open subpaths produce a single closed outline with square-cut ends;
+    /// closed subpaths produce an outer and an inner outline. The result is
+    /// made of straight line segments, flattened and simplified to within
+    /// ``tolerance``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (width, tolerance=crate::DEFAULT_TOLERANCE))]
+    fn stroke_variable(slf: &Bound<'_, Self>, width: &Bound<'_, PyAny>, tolerance: f64) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        // Release the lock before calling back into Python.
+        let path = this.path().clone();
+        let result = if width.is_callable() {
+            crate::varstroke::stroke_variable(
+                &path,
+                |t| width.call1((t,))?.extract::<f64>(),
+                tolerance,
+            )?
+        } else if let Ok(w) = width.extract::<f64>() {
+            crate::varstroke::stroke_variable(&path, |_| Ok(w), tolerance)?
+        } else {
+            let samples: Vec<f64> = width.extract()?;
+            if samples.is_empty() {
+                return Err(PyValueError::new_err("at least one width is needed"));
+            }
+            let last = (samples.len() - 1) as f64;
+            crate::varstroke::stroke_variable(
+                &path,
+                |t| {
+                    let pos = (t * last).clamp(0.0, last);
+                    let ix = (pos.floor() as usize).min(samples.len() - 1);
+                    let next = samples.get(ix + 1).copied().unwrap_or(samples[ix]);
+                    Ok(samples[ix] + (next - samples[ix]) * (pos - ix as f64))
+                },
+                tolerance,
+            )?
+        };
+        new_like(slf, result.into())
+    }
+
+    /// Round the corners between straight lines with curvature-continuous
+    /// ("squircle") corners.
+    ///
+    /// Each corner is a circular arc of the given ``radius``, joined to the
+    /// lines on either side by transition curves whose curvature rises
+    /// smoothly from zero, as in the continuous corners used by iOS and
+    /// Figma. ``smoothness`` runs from 0, which gives plain circular fillets,
+    /// to 1, where the corner has no circular part at all; larger values
+    /// extend the corner further along the lines. The radius is reduced
+    /// where a corner would not fit in half the length of its lines.
+    /// Corners next to curved segments are left as they are.
+    ///
+    /// To make a smoothly rounded rectangle, use
+    /// ``rect.to_path().round_corners_smooth(radius, smoothness)``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (radius, smoothness=0.6))]
+    fn round_corners_smooth(slf: &Bound<'_, Self>, radius: f64, smoothness: f64) -> PyResult<Py<Self>> {
+        if !(radius.is_finite() && radius >= 0.0) {
+            return Err(PyValueError::new_err("radius must be non-negative"));
+        }
+        if !(0.0..=1.0).contains(&smoothness) {
+            return Err(PyValueError::new_err("smoothness must be between 0 and 1"));
+        }
+        new_like(slf, crate::corners::round_corners_smooth(&slf.borrow().path(), radius, smoothness).into())
     }
 
     #[pyo3(text_signature = "($self, scale_factor)")]
-    fn scale_path(&self, scale_factor: f64) -> BezPath {
-        let c = self.path().bounding_box().center();
+    fn scale_path(slf: &Bound<'_, Self>, scale_factor: f64) -> PyResult<Py<Self>> {
+        let this = slf.borrow();
+        let c = this.path().bounding_box().center();
         let c_vec = Vec2::new(c.x, c.y);
-        BezPath {
-            _path: Arc::new(Mutex::new(
-                KAffine::translate(c_vec)
-                    * KAffine::scale(scale_factor)
-                    * KAffine::translate(c_vec * -1.0)
-                    * &*self.path(),
-            )),
-        }
+        let scaled = KAffine::translate(c_vec)
+            * KAffine::scale(scale_factor)
+            * KAffine::translate(c_vec * -1.0)
+            * &*this.path();
+        new_like(slf, scaled.into())
     }
 
     fn segments(&self) -> SegmentIterator {
@@ -452,3 +2477,675 @@ impl ElementIterator {
         self.items.lock().unwrap().elements().len()
     }
 }
+
+/// An immutable Bézier path.
+///
+/// A ``BezPath`` can be modified in place, so it cannot be hashed, and
+/// sharing it between threads means locking it. A ``FrozenBezPath`` holds
+/// a snapshot of a path which never changes: it is hashable, compares equal
+/// to another frozen path with the same elements, and so can be used as a
+/// dictionary or cache key.
+///
+/// ::
+///
+///    from kurbopy import BezPath, FrozenBezPath
+///    path = BezPath.from_svg("M0,0 L10,0 L10,10 Z")
+///    frozen = path.freeze()  # or FrozenBezPath(path)
+///    cache = {frozen: frozen.area()}
+///    editable = frozen.thaw()
+///
+/// Note that this class is not in original kurbo
+#[pyclass(frozen, subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct FrozenBezPath(pub KBezPath);
+
+impl From<KBezPath> for FrozenBezPath {
+    fn from(p: KBezPath) -> Self {
+        Self(p)
+    }
+}
+
+#[pymethods]
+impl FrozenBezPath {
+    #[new]
+    #[pyo3(signature = (path=None))]
+    fn __new__(path: Option<&BezPath>) -> Self {
+        path.map_or_else(KBezPath::new, |p| p.path().clone()).into()
+    }
+
+    /// Returns a new, mutable ``BezPath`` with the same elements.
+    fn thaw(&self) -> BezPath {
+        self.0.clone().into()
+    }
+
+    /// Returns the segment at the given element index, as with
+    /// ``BezPath.get_seg``.
+    fn get_seg(&self, ix: usize) -> Option<PathSeg> {
+        self.0.get_seg(ix).map(|p| p.into())
+    }
+
+    /// Returns `true` if the path contains no segments.
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.elements().len()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.elements().is_empty()
+    }
+
+    /// Is this path finite?
+    fn is_finite(&self) -> bool {
+        self.0.is_finite()
+    }
+
+    /// Is this path NaN?
+    fn is_nan(&self) -> bool {
+        self.0.is_nan()
+    }
+
+    /// Returns a rectangle that conservatively encloses the path.
+    fn control_box(&self) -> Rect {
+        self.0.control_box().into()
+    }
+
+    /// The smallest rectangle that encloses the path.
+    fn bounding_box(&self) -> Rect {
+        Shape::bounding_box(&self.0).into()
+    }
+
+    /// Convert the path to an SVG path string representation, with the same
+    /// options as ``BezPath.to_svg``.
+    #[pyo3(signature = (precision=None, relative=false, shorthand=false))]
+    fn to_svg(&self, precision: Option<usize>, relative: bool, shorthand: bool) -> String {
+        if precision.is_none() && !relative && !shorthand {
+            return self.0.to_svg();
+        }
+        let options = SvgOptions {
+            precision,
+            relative,
+            shorthand,
+        };
+        crate::svg::to_svg(&self.0, options)
+    }
+
+    fn segments(&self) -> SegmentIterator {
+        SegmentIterator {
+            items: Arc::new(Mutex::new(self.0.clone())),
+            index: 0,
+        }
+    }
+
+    fn elements(&self) -> ElementIterator {
+        ElementIterator {
+            items: Arc::new(Mutex::new(self.0.clone())),
+            index: 0,
+        }
+    }
+
+    fn __eq__(&self, other: &FrozenBezPath) -> bool {
+        self.0.elements() == other.0.elements()
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        for el in self.0.elements() {
+            let origin = KPoint::ZERO;
+            let (tag, points, n) = match *el {
+                KPathEl::MoveTo(p) => (0u8, [p, origin, origin], 1),
+                KPathEl::LineTo(p) => (1, [p, origin, origin], 1),
+                KPathEl::QuadTo(p1, p2) => (2, [p1, p2, origin], 2),
+                KPathEl::CurveTo(p1, p2, p3) => (3, [p1, p2, p3], 3),
+                KPathEl::ClosePath => (4, [origin; 3], 0),
+            };
+            tag.hash(&mut hasher);
+            for p in &points[..n] {
+                // Adding zero turns -0.0 into 0.0, as the two compare equal.
+                (p.x + 0.0).to_bits().hash(&mut hasher);
+                (p.y + 0.0).to_bits().hash(&mut hasher);
+            }
+        }
+        hasher.finish()
+    }
+
+    fn __repr__(&self) -> String {
+        if self.0.elements().is_empty() {
+            "FrozenBezPath()".to_string()
+        } else {
+            format!("BezPath.from_svg({:?}).freeze()", self.0.to_svg())
+        }
+    }
+}
+
+impl_shape_no_bounding_box!(FrozenBezPath);
+
+/// A point where a line crosses a path, as found by
+/// :py:meth:`BezPath.intersect_line`.
+///
+/// Note that this class is not in original kurbo
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct PathLineIntersection {
+    segment_index: usize,
+    segment_t: f64,
+    line_t: f64,
+    point: KPoint,
+}
+
+#[pymethods]
+impl PathLineIntersection {
+    #[new]
+    fn __new__(segment_index: usize, segment_t: f64, line_t: f64, point: PointLike) -> Self {
+        PathLineIntersection {
+            segment_index,
+            segment_t,
+            line_t,
+            point: point.0,
+        }
+    }
+    /// The index of the segment crossed, as yielded by ``BezPath.segments``.
+    #[getter]
+    fn segment_index(&self) -> usize {
+        self.segment_index
+    }
+    /// The parameter value of the crossing on the segment.
+    #[getter]
+    fn segment_t(&self) -> f64 {
+        self.segment_t
+    }
+    /// The parameter value of the crossing on the line.
+    #[getter]
+    fn line_t(&self) -> f64 {
+        self.line_t
+    }
+    /// The crossing point itself.
+    #[getter]
+    fn point(&self) -> Point {
+        self.point.into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PathLineIntersection({}, {}, {})",
+            self.segment_index,
+            crate::repr::floats(&[self.segment_t, self.line_t]),
+            crate::repr::point(self.point)
+        )
+    }
+}
+
+/// A point where two paths meet, as found by :py:meth:`BezPath.intersect_path`.
+///
+/// Note that this class is not in original kurbo
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct PathIntersection {
+    segment_index: usize,
+    segment_t: f64,
+    other_index: usize,
+    other_t: f64,
+    point: KPoint,
+}
+
+#[pymethods]
+impl PathIntersection {
+    #[new]
+    fn __new__(
+        segment_index: usize,
+        segment_t: f64,
+        other_index: usize,
+        other_t: f64,
+        point: PointLike,
+    ) -> Self {
+        PathIntersection {
+            segment_index,
+            segment_t,
+            other_index,
+            other_t,
+            point: point.0,
+        }
+    }
+    /// The index of the segment of the first path, as yielded by
+    /// ``BezPath.segments``.
+    #[getter]
+    fn segment_index(&self) -> usize {
+        self.segment_index
+    }
+    /// The parameter value of the meeting point on that segment.
+    #[getter]
+    fn segment_t(&self) -> f64 {
+        self.segment_t
+    }
+    /// The index of the segment of the other path.
+    #[getter]
+    fn other_index(&self) -> usize {
+        self.other_index
+    }
+    /// The parameter value of the meeting point on the other path's segment.
+    #[getter]
+    fn other_t(&self) -> f64 {
+        self.other_t
+    }
+    /// The meeting point itself.
+    #[getter]
+    fn point(&self) -> Point {
+        self.point.into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PathIntersection({}, {}, {}, {}, {})",
+            self.segment_index,
+            crate::repr::float(self.segment_t),
+            self.other_index,
+            crate::repr::float(self.other_t),
+            crate::repr::point(self.point)
+        )
+    }
+}
+
+/// The direction of a segment at `t`, falling back to the direction of its
+/// chord where the derivative vanishes.
+fn tangent(seg: &KPathSeg, t: f64) -> Vec2 {
+    let d = seg.to_cubic().deriv().eval(t).to_vec2();
+    if d.hypot2() == 0.0 {
+        seg.end() - seg.start()
+    } else {
+        d
+    }
+}
+
+/// Place copies of a shape at regular intervals along a path.
+///
+/// Positions are ``spacing`` apart by arc length, starting at the start of
+/// each subpath. At each position, the transform moves the origin to the
+/// point on the path and, if ``align_to_tangent`` is true, rotates the
+/// positive x axis to follow the path's direction.
+///
+/// If ``stamp`` is given, a list of transformed copies of it is returned;
+/// otherwise the list of :py:class:`Affine` transforms is returned.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (
+    path, stamp, spacing, align_to_tangent=true, accuracy=crate::default_accuracy()
+))]
+pub fn distribute_along_path(
+    py: Python,
+    path: &BezPath,
+    stamp: Option<&BezPath>,
+    spacing: f64,
+    align_to_tangent: bool,
+    accuracy: f64,
+) -> PyResult<Vec<PyObject>> {
+    if !(spacing.is_finite() && spacing > 0.0) {
+        return Err(PyValueError::new_err("spacing must be finite and positive"));
+    }
+    let mut subpaths: Vec<Vec<(KPathSeg, f64)>> = vec![];
+    for (subpath, seg) in subpath_segments(&path.path()) {
+        if subpaths.len() <= subpath {
+            subpaths.resize(subpath + 1, vec![]);
+        }
+        subpaths[subpath].push((seg, seg.arclen(accuracy)));
+    }
+    let mut transforms = vec![];
+    for segs in subpaths.iter().filter(|segs| !segs.is_empty()) {
+        let length: f64 = segs.iter().map(|(_, len)| len).sum();
+        // Allow for rounding error when the length is a multiple of the spacing.
+        let n = (length / spacing + 1e-9).floor() as usize + 1;
+        let locations =
+            BezPath::locate_distances(segs, (0..n).map(|i| i as f64 * spacing), accuracy);
+        for (ix, t) in locations {
+            let seg = segs[ix].0;
+            let mut affine = KAffine::translate(seg.eval(t).to_vec2());
+            if align_to_tangent {
+                affine *= KAffine::rotate(tangent(&seg, t).atan2());
+            }
+            transforms.push(affine);
+        }
+    }
+    Ok(match stamp {
+        Some(stamp) => {
+            let stamp = stamp.path().clone();
+            transforms
+                .into_iter()
+                .map(|affine| BezPath::from(affine * &stamp).into_py(py))
+                .collect()
+        }
+        None => transforms
+            .into_iter()
+            .map(|affine| Affine(affine).into_py(py))
+            .collect(),
+    })
+}
+
+/// Split a subpath into `n` cubic pieces of equal arc length. A piece which
+/// lies within a single segment is exact; one which spans several segments
+/// is approximated by a cubic with the same end points and tangents.
+fn split_evenly(
+    segs: &[(KPathSeg, f64)],
+    start: KPoint,
+    n: usize,
+    accuracy: f64,
+) -> Vec<KCubicBez> {
+    let length: f64 = segs.iter().map(|(_, len)| len).sum();
+    if length == 0.0 {
+        return vec![KCubicBez::new(start, start, start, start); n];
+    }
+    let step = length / n as f64;
+    let distances = (0..=n).map(|i| i as f64 * step);
+    BezPath::locate_distances(segs, distances, accuracy)
+        .windows(2)
+        .map(|w| {
+            let ((mut ix0, mut t0), (ix1, t1)) = (w[0], w[1]);
+            // A piece starting at the end of a segment starts the next one.
+            if t0 >= 1.0 && ix0 < ix1 {
+                (ix0, t0) = (ix0 + 1, 0.0);
+            }
+            let (seg0, seg1) = (segs[ix0].0, segs[ix1].0);
+            if ix0 == ix1 {
+                return seg0.subsegment(t0..t1).to_cubic();
+            }
+            let (p0, p3) = (seg0.eval(t0), seg1.eval(t1));
+            let handle = step / 3.0;
+            KCubicBez::new(
+                p0,
+                p0 + handle * tangent(&seg0, t0).normalize(),
+                p3 - handle * tangent(&seg1, t1).normalize(),
+                p3,
+            )
+        })
+        .collect()
+}
+
+/// Resample two paths to a common structure so that they can be
+/// interpolated with ``BezPath.lerp``.
+///
+/// Corresponding subpaths of the two paths are each split into ``samples``
+/// cubic segments of equal arc length, so that the n-th segment of one
+/// path corresponds to the same fraction of the length of the other.
+/// Pieces which span a corner or a join between segments are approximated.
+/// Where one path has more subpaths than the other, the extra subpaths are
+/// matched with a subpath shrunk to a point at the center of their
+/// bounding box. A subpath of the result is closed if both of the subpaths
+/// it comes from are closed, in which case the subpath from ``b`` is
+/// rotated to start at whichever piece best lines up with the start of the
+/// subpath from ``a``.
+///
+/// Returns a tuple of the two resampled paths.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (a, b, samples=64, accuracy=crate::default_accuracy()))]
+pub fn match_paths(
+    a: &BezPath,
+    b: &BezPath,
+    samples: usize,
+    accuracy: f64,
+) -> PyResult<(BezPath, BezPath)> {
+    if samples == 0 {
+        return Err(PyValueError::new_err("samples must be positive"));
+    }
+    let subpaths = |path: &BezPath| {
+        crate::corners::subpaths(&path.path())
+            .into_iter()
+            .map(|sp| {
+                let segs: Vec<(KPathSeg, f64)> = sp
+                    .segs
+                    .iter()
+                    .map(|seg| (*seg, seg.arclen(accuracy)))
+                    .collect();
+                (sp.start, segs, sp.closed)
+            })
+            .collect::<Vec<_>>()
+    };
+    let (subpaths_a, subpaths_b) = (subpaths(a), subpaths(b));
+    // A subpath shrunk to the center of its bounding box.
+    let shrunk = |(start, segs, _): &(KPoint, Vec<(KPathSeg, f64)>, bool)| {
+        let center = segs
+            .iter()
+            .map(|(seg, _)| seg.bounding_box())
+            .reduce(|r, s| r.union(s))
+            .map_or(*start, |r| r.center());
+        (center, vec![], false)
+    };
+    let mut result_a = KBezPath::new();
+    let mut result_b = KBezPath::new();
+    for ix in 0..subpaths_a.len().max(subpaths_b.len()) {
+        let sp_a = subpaths_a.get(ix).cloned().unwrap_or_else(|| shrunk(&subpaths_b[ix]));
+        let sp_b = subpaths_b.get(ix).cloned().unwrap_or_else(|| shrunk(&subpaths_a[ix]));
+        let closed = sp_a.2 && sp_b.2;
+        let cubics_a = split_evenly(&sp_a.1, sp_a.0, samples, accuracy);
+        let mut cubics_b = split_evenly(&sp_b.1, sp_b.0, samples, accuracy);
+        if closed {
+            // Start the subpath of b at the piece whose points are closest
+            // to those of a.
+            let distance = |offset: usize| -> f64 {
+                cubics_a
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| (c.p0 - cubics_b[(i + offset) % samples].p0).hypot2())
+                    .sum()
+            };
+            let best = (0..samples)
+                .min_by(|&i, &j| distance(i).total_cmp(&distance(j)))
+                .unwrap_or(0);
+            cubics_b.rotate_left(best);
+        }
+        for (cubics, result) in [(cubics_a, &mut result_a), (cubics_b, &mut result_b)] {
+            result.move_to(cubics[0].p0);
+            for c in cubics {
+                result.curve_to(c.p1, c.p2, c.p3);
+            }
+            if closed {
+                result.close_path();
+            }
+        }
+    }
+    Ok((result_a.into(), result_b.into()))
+}
+
+/// The Minkowski sum of the regions enclosed by two paths: the shape
+/// covered by ``b`` as its origin is moved over every point of ``a``.
+///
+/// Growing a shape by a circle of radius ``r`` centered on the origin is
+/// the same as buffering it by ``r``, and the sum of a shape with another
+/// shape reflected through the origin gives the region in which the two
+/// overlap, which is useful for collision margins.
+///
+/// Both paths are flattened to within ``tolerance`` and filled with the
+/// nonzero rule, with open subpaths treated as closed. The sum of two
+/// convex shapes is quick to compute; otherwise the time taken grows with
+/// the product of the number of flattened edges in each.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (a, b, tolerance=crate::DEFAULT_TOLERANCE))]
+pub fn minkowski_sum(a: &BezPath, b: &BezPath, tolerance: f64) -> PyResult<BezPath> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(PyValueError::new_err("tolerance must be positive"));
+    }
+    // Take one lock at a time, as a and b may be the same path.
+    let a = a.path().clone();
+    let b = b.path().clone();
+    let mut result = KBezPath::new();
+    for poly in crate::minkowski::minkowski_sum(&a, &b, tolerance) {
+        push_polygon(&mut result, &poly);
+    }
+    Ok(result.into())
+}
+
+/// Split the segments of a path at the given points, listed for each
+/// segment (as yielded by `segments`) in order of parameter value.
+fn split_segments(path: &KBezPath, splits: &[Vec<(f64, KPoint)>]) -> KBezPath {
+    let mut result = KBezPath::new();
+    let mut segs = path.segments().zip(splits);
+    let mut start = KPoint::ZERO;
+    let mut last = KPoint::ZERO;
+    for el in path.elements() {
+        match *el {
+            KPathEl::MoveTo(p) => {
+                result.move_to(p);
+                start = p;
+                last = p;
+            }
+            KPathEl::ClosePath => {
+                // The closing line, if it has any length, is a segment too;
+                // all but its last piece become explicit lines.
+                if last != start {
+                    let (_, splits) = segs.next().unwrap();
+                    for &(_, p) in splits {
+                        result.line_to(p);
+                    }
+                }
+                result.close_path();
+                last = start;
+            }
+            _ => {
+                let (seg, splits) = segs.next().unwrap();
+                for (piece, _, _) in crate::overlaps::split_at(seg, splits) {
+                    match piece {
+                        KPathSeg::Line(l) => result.line_to(l.p1),
+                        KPathSeg::Quad(q) => result.quad_to(q.p1, q.p2),
+                        KPathSeg::Cubic(c) => result.curve_to(c.p1, c.p2, c.p3),
+                    }
+                }
+                last = seg.end();
+            }
+        }
+    }
+    result
+}
+
+/// Split two paths where they cross each other.
+///
+/// Returns a tuple of copies of ``a`` and ``b`` in which every point where
+/// the two paths meet (found as for ``BezPath.intersect_path``, to within
+/// ``accuracy``) is the end of a segment, with the same coordinates in both
+/// paths. The shapes of the paths are otherwise unchanged, so the pieces
+/// between crossings can be picked out with ``segments`` to build boolean
+/// operations or to trim one path against another.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (a, b, accuracy=crate::default_accuracy()))]
+pub fn split_at_intersections(
+    a: &BezPath,
+    b: &BezPath,
+    accuracy: f64,
+) -> PyResult<(BezPath, BezPath)> {
+    if !(accuracy.is_finite() && accuracy > 0.0) {
+        return Err(PyValueError::new_err("accuracy must be positive"));
+    }
+    const EPS: f64 = 1e-9;
+    // Take one lock at a time, as a and b may be the same path.
+    let path_a = a.path().clone();
+    let path_b = b.path().clone();
+    let mut splits_a = vec![vec![]; path_a.segments().count()];
+    let mut splits_b = vec![vec![]; path_b.segments().count()];
+    for (i, t1, j, t2, p) in crossings(&path_a, &path_b, accuracy) {
+        // Crossings at the ends of segments need no split.
+        if t1 > EPS && t1 < 1.0 - EPS {
+            splits_a[i].push((t1, p));
+        }
+        if t2 > EPS && t2 < 1.0 - EPS {
+            splits_b[j].push((t2, p));
+        }
+    }
+    for splits in splits_a.iter_mut().chain(splits_b.iter_mut()) {
+        splits.sort_by(|x: &(f64, KPoint), y| x.0.total_cmp(&y.0));
+        splits.dedup_by(|x, y| x.0 - y.0 <= EPS);
+    }
+    Ok((
+        split_segments(&path_a, &splits_a).into(),
+        split_segments(&path_b, &splits_b).into(),
+    ))
+}
+
+/// The area of the region enclosed by both ``a`` and ``b``.
+///
+/// This measures how much two shapes overlap without building the path of
+/// their intersection. Both paths are flattened to within ``tolerance`` and
+/// filled with the nonzero rule, with open subpaths treated as closed, so
+/// the result is exact for polygons and otherwise within about ``tolerance``
+/// times the length of the overlapping outline.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (a, b, tolerance=crate::DEFAULT_TOLERANCE))]
+pub fn intersection_area(a: &BezPath, b: &BezPath, tolerance: f64) -> PyResult<f64> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(PyValueError::new_err("tolerance must be positive"));
+    }
+    let a = polygons(&a.path(), tolerance);
+    let b = polygons(&b.path(), tolerance);
+    Ok(boolean(&a, &b, Op::Intersection)
+        .iter()
+        .map(|poly| polygon_area(poly))
+        .sum())
+}
+
+/// An argument to ``iou``, which may be a ``BezPath`` or a ``Rect``.
+pub(crate) enum Region {
+    Path(KBezPath),
+    Rect(KRect),
+}
+
+impl<'py> FromPyObject<'py> for Region {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(rect) = ob.downcast::<Rect>() {
+            return Ok(Region::Rect(rect.borrow().0.abs()));
+        }
+        ob.downcast::<BezPath>()
+            .map(|path| Region::Path(path.borrow().path().clone()))
+            .map_err(|_| PyTypeError::new_err("expected BezPath or Rect"))
+    }
+}
+
+impl Region {
+    fn polygons(&self, tolerance: f64) -> Vec<Vec<KPoint>> {
+        match self {
+            Region::Path(path) => polygons(path, tolerance),
+            Region::Rect(rect) => vec![vec![
+                KPoint::new(rect.x0, rect.y0),
+                KPoint::new(rect.x1, rect.y0),
+                KPoint::new(rect.x1, rect.y1),
+                KPoint::new(rect.x0, rect.y1),
+            ]],
+        }
+    }
+}
+
+/// The intersection over union of two shapes: the area enclosed by both,
+/// divided by the area enclosed by either. This is ``1.0`` for identical
+/// shapes and ``0.0`` for shapes which do not overlap (or which are both
+/// empty).
+///
+/// Each shape may be a ``BezPath`` or a ``Rect``. Paths are flattened to
+/// within ``tolerance`` and filled with the nonzero rule, as with
+/// ``intersection_area``; the result for two ``Rect`` objects is exact.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (a, b, tolerance=crate::DEFAULT_TOLERANCE))]
+pub fn iou(a: Region, b: Region, tolerance: f64) -> PyResult<f64> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(PyValueError::new_err("tolerance must be positive"));
+    }
+    let (intersection, union) = if let (Region::Rect(a), Region::Rect(b)) = (&a, &b) {
+        let overlap = a.intersect(*b).area();
+        (overlap, a.area() + b.area() - overlap)
+    } else {
+        let area = |polys: Vec<Vec<KPoint>>| -> f64 {
+            polys.iter().map(|p| polygon_area(p)).sum()
+        };
+        let (a, b) = (a.polygons(tolerance), b.polygons(tolerance));
+        let overlap = area(boolean(&a, &b, Op::Intersection));
+        let union = area(boolean(&a, &b, Op::Union));
+        (overlap, union)
+    };
+    Ok(if union > 0.0 { intersection / union } else { 0.0 })
+}
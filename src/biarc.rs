@@ -0,0 +1,207 @@
+// Approximation of curves by circular arcs. Each cubic is split at its
+// inflections and fitted with a biarc: two arcs meeting with a common
+// tangent, which between them match the ends and end tangents of the
+// cubic. Where the fit is not close enough the cubic is halved and each
+// half fitted in turn.
+use crate::cleanup::flatness;
+use crate::corners::subpaths;
+use kurbo::{
+    Arc as KArc, BezPath as KBezPath, CubicBez as KCubicBez, Line as KLine, ParamCurve,
+    PathSeg as KPathSeg, Point as KPoint, Vec2,
+};
+use pyo3::prelude::*;
+use std::f64::consts::TAU;
+
+/// How many times a cubic may be halved before its fit is accepted anyway.
+const MAX_DEPTH: usize = 16;
+
+/// The number of points along a cubic at which the fit is checked.
+const SAMPLES: usize = 16;
+
+/// A piece of the approximation.
+pub(crate) enum Piece {
+    Arc(KArc),
+    Line(KLine),
+}
+
+impl IntoPy<PyObject> for Piece {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Piece::Arc(arc) => crate::arc::Arc(arc).into_py(py),
+            Piece::Line(line) => crate::line::Line(line).into_py(py),
+        }
+    }
+}
+
+/// The circular arc from `p` to `q` which leaves `p` in the direction
+/// `tangent`, or a line if the arc would be straight.
+fn arc_from_tangent(p: KPoint, tangent: Vec2, q: KPoint) -> Piece {
+    let chord = q - p;
+    let normal = Vec2::new(-tangent.y, tangent.x);
+    let denom = 2.0 * normal.dot(chord);
+    // A straight arc has its center at infinity.
+    if denom.abs() <= 1e-12 * chord.hypot() {
+        return Piece::Line(KLine::new(p, q));
+    }
+    let s = chord.hypot2() / denom;
+    let center = p + normal * s;
+    let start_angle = (p - center).atan2();
+    let mut sweep = (q - center).atan2() - start_angle;
+    if s > 0.0 {
+        sweep = sweep.rem_euclid(TAU);
+    } else {
+        sweep = -(-sweep).rem_euclid(TAU);
+    }
+    Piece::Arc(KArc {
+        center,
+        radii: Vec2::new(s.abs(), s.abs()),
+        start_angle,
+        sweep_angle: sweep,
+        x_rotation: 0.0,
+    })
+}
+
+/// The distance from `p` to a piece.
+fn distance(piece: &Piece, p: KPoint) -> f64 {
+    match piece {
+        Piece::Line(line) => {
+            let t = ((p - line.p0).dot(line.p1 - line.p0) / (line.p1 - line.p0).hypot2())
+                .clamp(0.0, 1.0);
+            (p - line.eval(t)).hypot()
+        }
+        Piece::Arc(arc) => {
+            // The offset of the angle of `p` from the start of the arc, in
+            // the direction of the sweep.
+            let angle = ((p - arc.center).atan2() - arc.start_angle) * arc.sweep_angle.signum();
+            if angle.rem_euclid(TAU) <= arc.sweep_angle.abs() {
+                ((p - arc.center).hypot() - arc.radii.x).abs()
+            } else {
+                let end = |a: f64| arc.center + Vec2::from_angle(a) * arc.radii.x;
+                let d0 = (p - end(arc.start_angle)).hypot();
+                let d1 = (p - end(arc.start_angle + arc.sweep_angle)).hypot();
+                d0.min(d1)
+            }
+        }
+    }
+}
+
+/// The direction of a cubic at its start, skipping coincident control
+/// points.
+fn start_tangent(c: &KCubicBez) -> Option<Vec2> {
+    [c.p1 - c.p0, c.p2 - c.p0, c.p3 - c.p0]
+        .into_iter()
+        .find(|v| v.hypot2() > 0.0)
+        .map(|v| v.normalize())
+}
+
+/// The direction of a cubic at its end, skipping coincident control points.
+fn end_tangent(c: &KCubicBez) -> Option<Vec2> {
+    [c.p3 - c.p2, c.p3 - c.p1, c.p3 - c.p0]
+        .into_iter()
+        .find(|v| v.hypot2() > 0.0)
+        .map(|v| v.normalize())
+}
+
+/// Fit a biarc to a cubic without inflections, matching its ends and end
+/// tangents.
+fn biarc(c: &KCubicBez) -> Option<[Piece; 2]> {
+    let t0 = start_tangent(c)?;
+    let t3 = end_tangent(c)?;
+    let v = c.p3 - c.p0;
+    let t = t0 + t3;
+    // The two arcs have control polygons of equal length `d`, so the joint
+    // is at the midpoint of p0 + d t0 and p3 - d t3, which are 2d apart.
+    let a = 2.0 * (t0.dot(t3) - 1.0);
+    let b = v.dot(t);
+    let d = if a.abs() < 1e-12 {
+        if b <= 0.0 {
+            return None;
+        }
+        v.hypot2() / (2.0 * b)
+    } else {
+        (b - (b * b - a * v.hypot2()).sqrt()) / a
+    };
+    if !d.is_finite() || d <= 0.0 {
+        return None;
+    }
+    let joint = ((c.p0 + t0 * d).to_vec2() + (c.p3 - t3 * d).to_vec2()) * 0.5;
+    let joint = joint.to_point();
+    let first = arc_from_tangent(c.p0, t0, joint);
+    // The second arc is built backwards from the end, and then reversed.
+    let second = match arc_from_tangent(c.p3, -t3, joint) {
+        Piece::Arc(arc) => Piece::Arc(KArc {
+            start_angle: arc.start_angle + arc.sweep_angle,
+            sweep_angle: -arc.sweep_angle,
+            ..arc
+        }),
+        Piece::Line(line) => Piece::Line(KLine::new(line.p1, line.p0)),
+    };
+    Some([first, second])
+}
+
+/// Add a line to the output, unless it has no length.
+fn push_line(out: &mut Vec<Piece>, p0: KPoint, p1: KPoint) {
+    if p0 != p1 {
+        out.push(Piece::Line(KLine::new(p0, p1)));
+    }
+}
+
+fn fit(c: KCubicBez, tolerance: f64, depth: usize, out: &mut Vec<Piece>) {
+    if flatness(&[c.p0, c.p1, c.p2, c.p3]) <= tolerance {
+        push_line(out, c.p0, c.p3);
+        return;
+    }
+    if let Some(pieces) = biarc(&c) {
+        let error = (1..SAMPLES)
+            .map(|i| {
+                let p = c.eval(i as f64 / SAMPLES as f64);
+                distance(&pieces[0], p).min(distance(&pieces[1], p))
+            })
+            .fold(0.0, f64::max);
+        if error <= tolerance || depth >= MAX_DEPTH {
+            for piece in pieces {
+                match piece {
+                    Piece::Line(line) => push_line(out, line.p0, line.p1),
+                    arc => out.push(arc),
+                }
+            }
+            return;
+        }
+    } else if depth >= MAX_DEPTH {
+        push_line(out, c.p0, c.p3);
+        return;
+    }
+    fit(c.subsegment(0.0..0.5), tolerance, depth + 1, out);
+    fit(c.subsegment(0.5..1.0), tolerance, depth + 1, out);
+}
+
+/// Approximate a cubic by arcs and lines to within `tolerance`.
+pub(crate) fn cubic_to_arcs(c: KCubicBez, tolerance: f64) -> Vec<Piece> {
+    let mut out = vec![];
+    let mut ts = vec![0.0];
+    ts.extend(c.inflections().into_iter().filter(|&t| t > 0.0 && t < 1.0));
+    ts.push(1.0);
+    for w in ts.windows(2) {
+        fit(c.subsegment(w[0]..w[1]), tolerance, 0, &mut out);
+    }
+    out
+}
+
+/// Approximate each subpath of a path by arcs and lines to within
+/// `tolerance`.
+pub(crate) fn path_to_arcs(path: &KBezPath, tolerance: f64) -> Vec<Vec<Piece>> {
+    subpaths(path)
+        .into_iter()
+        .map(|sp| {
+            let mut out = vec![];
+            for seg in sp.segs {
+                match seg {
+                    KPathSeg::Line(line) => push_line(&mut out, line.p0, line.p1),
+                    KPathSeg::Quad(q) => out.extend(cubic_to_arcs(q.raise(), tolerance)),
+                    KPathSeg::Cubic(c) => out.extend(cubic_to_arcs(c, tolerance)),
+                }
+            }
+            out
+        })
+        .collect()
+}
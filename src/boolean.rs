@@ -0,0 +1,307 @@
+// Boolean operations on polygons. Each operand is a set of polygons filled
+// with the nonzero winding rule. All edges are split where they cross, and
+// each piece is kept if the region of the result lies on exactly one side
+// of it; the kept pieces are then linked into closed polygons.
+use crate::polyline::cross;
+use kurbo::{Point as KPoint, Vec2 as KVec2};
+use std::collections::HashMap;
+
+/// A closed polygon, without a repeated first point.
+pub(crate) type Polygon = Vec<KPoint>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Op {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Op::Union => a || b,
+            Op::Intersection => a && b,
+            Op::Difference => a && !b,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Edge {
+    p0: KPoint,
+    p1: KPoint,
+    operand: usize,
+}
+
+/// A hashable identity for a point. Points are only ever compared exactly,
+/// which works because every split point is shared by the edges it splits.
+fn key(p: KPoint) -> (u64, u64) {
+    // Adding zero turns -0.0 into 0.0.
+    ((p.x + 0.0).to_bits(), (p.y + 0.0).to_bits())
+}
+
+fn edges(polygons: &[Polygon], operand: usize, out: &mut Vec<Edge>) {
+    for poly in polygons {
+        let n = poly.len();
+        for i in 0..n {
+            let (p0, p1) = (poly[i], poly[(i + 1) % n]);
+            if p0 != p1 && p0.is_finite() && p1.is_finite() {
+                out.push(Edge { p0, p1, operand });
+            }
+        }
+    }
+}
+
+/// Find the points where each edge is crossed or touched by the others,
+/// as parameter values along the edge.
+fn split_points(edges: &[Edge], eps: f64) -> Vec<Vec<(f64, KPoint)>> {
+    let mut splits: Vec<Vec<(f64, KPoint)>> = vec![vec![]; edges.len()];
+    let min_x = |e: &Edge| e.p0.x.min(e.p1.x);
+    let max_x = |e: &Edge| e.p0.x.max(e.p1.x);
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&a, &b| min_x(&edges[a]).total_cmp(&min_x(&edges[b])));
+    // The parameter of `p` along edge `e`, if it lies strictly inside it.
+    let param = |e: &Edge, p: KPoint| {
+        let d = e.p1 - e.p0;
+        let t = (p - e.p0).dot(d) / d.hypot2();
+        (t > 0.0 && t < 1.0 && (e.p0 + d * t - p).hypot() <= eps).then_some(t)
+    };
+    for (ix, &i) in order.iter().enumerate() {
+        let e1 = edges[i];
+        for &j in &order[ix + 1..] {
+            let e2 = edges[j];
+            if min_x(&e2) > max_x(&e1) + eps {
+                break;
+            }
+            if e1.p0.y.max(e1.p1.y) + eps < e2.p0.y.min(e2.p1.y)
+                || e2.p0.y.max(e2.p1.y) + eps < e1.p0.y.min(e1.p1.y)
+            {
+                continue;
+            }
+            // Endpoints of one edge lying on the other, which also covers
+            // overlapping collinear edges.
+            for (a, b, sa) in [(e1, e2, i), (e2, e1, j)] {
+                for p in [b.p0, b.p1] {
+                    if let Some(t) = param(&a, p) {
+                        splits[sa].push((t, p));
+                    }
+                }
+            }
+            // A proper crossing.
+            let (d1, d2) = (e1.p1 - e1.p0, e2.p1 - e2.p0);
+            let denom = cross(d1, d2);
+            if denom.abs() <= 1e-12 * d1.hypot() * d2.hypot() {
+                continue;
+            }
+            let w = e2.p0 - e1.p0;
+            let t = cross(w, d2) / denom;
+            let u = cross(w, d1) / denom;
+            if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 {
+                let p = e1.p0 + d1 * t;
+                // Touching at an endpoint was handled above.
+                let near_end = |e: &Edge| (p - e.p0).hypot() <= eps || (p - e.p1).hypot() <= eps;
+                if !near_end(&e1) && !near_end(&e2) {
+                    splits[i].push((t, p));
+                    splits[j].push((u, p));
+                }
+            }
+        }
+    }
+    splits
+}
+
+/// Winding numbers of a set of edges, with the edges binned by height so
+/// that only those which might cross a horizontal ray are examined.
+struct WindingIndex {
+    y0: f64,
+    bin_height: f64,
+    bins: Vec<Vec<Edge>>,
+}
+
+impl WindingIndex {
+    fn new(edges: &[Edge]) -> Self {
+        let y0 = edges.iter().map(|e| e.p0.y.min(e.p1.y)).fold(f64::INFINITY, f64::min);
+        let y1 = edges.iter().map(|e| e.p0.y.max(e.p1.y)).fold(f64::NEG_INFINITY, f64::max);
+        let count = ((edges.len() as f64).sqrt().ceil() as usize).max(1);
+        let bin_height = if y1 > y0 { (y1 - y0) / count as f64 } else { 1.0 };
+        let mut bins = vec![vec![]; count];
+        for e in edges {
+            let lo = ((e.p0.y.min(e.p1.y) - y0) / bin_height).floor().max(0.0) as usize;
+            let hi = ((e.p0.y.max(e.p1.y) - y0) / bin_height).floor().max(0.0) as usize;
+            for bin in &mut bins[lo.min(count - 1)..=hi.min(count - 1)] {
+                bin.push(*e);
+            }
+        }
+        WindingIndex {
+            y0,
+            bin_height,
+            bins,
+        }
+    }
+
+    /// The winding number of each operand at `p`.
+    fn winding(&self, p: KPoint) -> [i32; 2] {
+        let mut result = [0, 0];
+        let bin = ((p.y - self.y0) / self.bin_height).floor();
+        if !(bin >= 0.0 && (bin as usize) < self.bins.len()) {
+            return result;
+        }
+        for e in &self.bins[bin as usize] {
+            let side = cross(e.p1 - e.p0, p - e.p0);
+            if e.p0.y <= p.y && e.p1.y > p.y && side > 0.0 {
+                result[e.operand] += 1;
+            } else if e.p1.y <= p.y && e.p0.y > p.y && side < 0.0 {
+                result[e.operand] -= 1;
+            }
+        }
+        result
+    }
+}
+
+/// A fast inside test for a set of polygons, using the nonzero rule.
+pub(crate) struct Region(WindingIndex);
+
+impl Region {
+    pub(crate) fn new(polygons: &[Polygon]) -> Self {
+        let mut all = vec![];
+        edges(polygons, 0, &mut all);
+        Region(WindingIndex::new(&all))
+    }
+
+    pub(crate) fn contains(&self, p: KPoint) -> bool {
+        self.0.winding(p)[0] != 0
+    }
+}
+
+/// Remove vertices where a polygon carries straight on.
+fn remove_collinear(poly: Polygon) -> Polygon {
+    let mut result: Polygon = vec![];
+    let n = poly.len();
+    for i in 0..n {
+        let (prev, p, next) = (poly[(i + n - 1) % n], poly[i], poly[(i + 1) % n]);
+        let (u, v) = (p - prev, next - p);
+        if cross(u, v).abs() > 1e-12 * u.hypot() * v.hypot() || u.dot(v) < 0.0 {
+            result.push(p);
+        }
+    }
+    result
+}
+
+/// Combine two sets of polygons. The result is oriented so that the region
+/// lies to the left of each edge in a y-up coordinate system, giving it a
+/// positive area.
+pub(crate) fn boolean(a: &[Polygon], b: &[Polygon], op: Op) -> Vec<Polygon> {
+    let mut all = vec![];
+    edges(a, 0, &mut all);
+    edges(b, 1, &mut all);
+    if all.is_empty() {
+        return vec![];
+    }
+    let scale = all
+        .iter()
+        .flat_map(|e| [e.p0.x.abs(), e.p0.y.abs(), e.p1.x.abs(), e.p1.y.abs()])
+        .fold(1.0, f64::max);
+    let eps = 1e-10 * scale;
+    let splits = split_points(&all, eps);
+    let index = WindingIndex::new(&all);
+    let inside = |p: KPoint| {
+        let [wa, wb] = index.winding(p);
+        op.apply(wa != 0, wb != 0)
+    };
+
+    // Classify the pieces of each edge.
+    let mut kept: Vec<(KPoint, KPoint)> = vec![];
+    let mut seen = std::collections::HashSet::new();
+    for (e, mut points) in all.iter().zip(splits) {
+        points.push((0.0, e.p0));
+        points.push((1.0, e.p1));
+        points.sort_by(|x, y| x.0.total_cmp(&y.0));
+        points.dedup_by(|x, y| x.1 == y.1);
+        for w in points.windows(2) {
+            let (p, q) = (w[0].1, w[1].1);
+            let d = q - p;
+            if d.hypot2() == 0.0 {
+                continue;
+            }
+            let mid = p.midpoint(q);
+            let normal = KVec2::new(-d.y, d.x).normalize() * (100.0 * eps);
+            let (left, right) = (inside(mid + normal), inside(mid - normal));
+            if left == right {
+                continue;
+            }
+            let piece = if left { (p, q) } else { (q, p) };
+            if seen.insert((key(piece.0), key(piece.1))) {
+                kept.push(piece);
+            }
+        }
+    }
+
+    // Link the pieces into polygons, turning as far right as possible
+    // where several pieces leave the same point.
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (ix, piece) in kept.iter().enumerate() {
+        outgoing.entry(key(piece.0)).or_default().push(ix);
+    }
+    let mut used = vec![false; kept.len()];
+    let mut result = vec![];
+    for start in 0..kept.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let mut poly = vec![kept[start].0];
+        let mut current = start;
+        loop {
+            let (p, q) = kept[current];
+            if key(q) == key(kept[start].0) {
+                break;
+            }
+            poly.push(q);
+            let dir = q - p;
+            let next = outgoing.get(&key(q)).and_then(|cands| {
+                cands
+                    .iter()
+                    .copied()
+                    .filter(|&c| !used[c])
+                    .min_by(|&x, &y| {
+                        let turn = |c: usize| {
+                            let v = kept[c].1 - kept[c].0;
+                            cross(dir, v).atan2(dir.dot(v))
+                        };
+                        turn(x).total_cmp(&turn(y))
+                    })
+            });
+            let Some(next) = next else {
+                break;
+            };
+            used[next] = true;
+            current = next;
+        }
+        let poly = remove_collinear(poly);
+        if poly.len() >= 3 {
+            result.push(poly);
+        }
+    }
+    result
+}
+
+/// The union of many regions, each a set of polygons. Regions are merged
+/// in pairs so that each boolean operation works on similar amounts of
+/// geometry.
+pub(crate) fn union_all(mut regions: Vec<Vec<Polygon>>) -> Vec<Polygon> {
+    while regions.len() > 1 {
+        let mut merged = Vec::with_capacity(regions.len() / 2 + 1);
+        let mut iter = regions.into_iter();
+        while let Some(a) = iter.next() {
+            match iter.next() {
+                Some(b) => merged.push(boolean(&a, &b, Op::Union)),
+                None => merged.push(a),
+            }
+        }
+        regions = merged;
+    }
+    match regions.pop() {
+        Some(region) => boolean(&region, &[], Op::Union),
+        None => vec![],
+    }
+}
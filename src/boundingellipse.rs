@@ -0,0 +1,136 @@
+// The minimum-area ellipse enclosing a set of points (the Löwner–John
+// ellipse), found with Khachiyan's algorithm. Only the points of the convex
+// hull can touch the ellipse, so the others are discarded first.
+use crate::polyline::{centroid, convex_hull, symmetric_eigen};
+use kurbo::{Ellipse as KEllipse, Point as KPoint, Vec2 as KVec2};
+
+const MAX_ITERATIONS: usize = 10_000;
+const TOLERANCE: f64 = 1e-9;
+
+fn inverse3(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let cofactor = |r: usize, c: usize| {
+        let (r0, r1) = ((r + 1) % 3, (r + 2) % 3);
+        let (c0, c1) = ((c + 1) % 3, (c + 2) % 3);
+        m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+    };
+    let det = (0..3).map(|c| m[0][c] * cofactor(0, c)).sum::<f64>();
+    if det == 0.0 || !det.is_finite() {
+        return None;
+    }
+    let mut inv = [[0.0; 3]; 3];
+    for (r, row) in inv.iter_mut().enumerate() {
+        for (c, x) in row.iter_mut().enumerate() {
+            *x = cofactor(c, r) / det;
+        }
+    }
+    Some(inv)
+}
+
+/// The ellipse for a hull of at least three points, not all collinear.
+fn khachiyan(hull: &[KPoint]) -> KEllipse {
+    // Work relative to the centroid, for better conditioning.
+    let n = hull.len();
+    let origin = centroid(hull).to_vec2();
+    let q: Vec<KVec2> = hull.iter().map(|p| p.to_vec2() - origin).collect();
+    let lift = |v: KVec2| [v.x, v.y, 1.0];
+
+    // Find the weights of the points in the ellipse's covariance.
+    let mut u = vec![1.0 / n as f64; n];
+    for _ in 0..MAX_ITERATIONS {
+        let mut x = [[0.0; 3]; 3];
+        for (v, w) in q.iter().zip(&u) {
+            let v = lift(*v);
+            for r in 0..3 {
+                for c in 0..3 {
+                    x[r][c] += w * v[r] * v[c];
+                }
+            }
+        }
+        let Some(xi) = inverse3(x) else {
+            break;
+        };
+        let m: Vec<f64> = q
+            .iter()
+            .map(|v| {
+                let v = lift(*v);
+                (0..3)
+                    .map(|r| (0..3).map(|c| v[r] * xi[r][c] * v[c]).sum::<f64>())
+                    .sum::<f64>()
+            })
+            .collect();
+        // The weights are optimal once every point is within the ellipse
+        // (m ≤ 3) and every point with weight is on its boundary (m ≥ 3).
+        // Either move weight towards the point furthest outside, or, as in
+        // Todd and Yildirim's variant, away from the point furthest inside,
+        // whichever is further from optimal.
+        let by_m = |a: &usize, b: &usize| m[*a].total_cmp(&m[*b]);
+        let far = (0..n).max_by(by_m).unwrap();
+        let near = (0..n).filter(|&i| u[i] > 0.0).min_by(by_m).unwrap();
+        if m[far] - 3.0 <= 3.0 * TOLERANCE && 3.0 - m[near] <= 3.0 * TOLERANCE {
+            break;
+        }
+        let (j, step) = if m[far] - 3.0 >= 3.0 - m[near] {
+            (far, (m[far] - 3.0) / (3.0 * (m[far] - 1.0)))
+        } else {
+            let step = (m[near] - 3.0) / (3.0 * (m[near] - 1.0));
+            (near, step.max(-u[near] / (1.0 - u[near])))
+        };
+        for w in u.iter_mut() {
+            *w *= 1.0 - step;
+        }
+        u[j] = (u[j] + step).max(0.0);
+    }
+
+    // The ellipse is {x : (x - c)ᵀ S⁻¹ (x - c) ≤ 1}, where S is twice the
+    // weighted covariance of the points about c.
+    let c = q
+        .iter()
+        .zip(&u)
+        .fold(KVec2::ZERO, |acc, (v, w)| acc + *v * *w);
+    let (mut a, mut b, mut d) = (0.0, 0.0, 0.0);
+    for (v, w) in q.iter().zip(&u) {
+        let e = *v - c;
+        a += 2.0 * w * e.x * e.x;
+        b += 2.0 * w * e.x * e.y;
+        d += 2.0 * w * e.y * e.y;
+    }
+    let (major, minor, angle) = symmetric_eigen(a, b, d);
+    let mut radii = KVec2::new(major.max(0.0).sqrt(), minor.max(0.0).sqrt());
+
+    // The iteration stops short of the exact ellipse, so grow it to take in
+    // any points left outside.
+    let (sin, cos) = angle.sin_cos();
+    let reach = q
+        .iter()
+        .map(|v| {
+            let e = *v - c;
+            let (x, y) = (e.x * cos + e.y * sin, e.y * cos - e.x * sin);
+            let term = |p: f64, r: f64| if r > 0.0 { (p / r).powi(2) } else { 0.0 };
+            term(x, radii.x) + term(y, radii.y)
+        })
+        .fold(0.0, f64::max);
+    if reach > 1.0 {
+        radii *= reach.sqrt();
+    }
+    KEllipse::new((origin + c).to_point(), radii, angle)
+}
+
+/// The smallest ellipse enclosing all of the finite points, or `None` if
+/// there are none. The ellipse is degenerate (a line or a point) if all the
+/// points are collinear.
+pub(crate) fn min_bounding_ellipse(points: &[KPoint]) -> Option<KEllipse> {
+    let hull = convex_hull(points);
+    match hull[..] {
+        [] => None,
+        [p] => Some(KEllipse::new(p, KVec2::ZERO, 0.0)),
+        [p0, p1] => {
+            let d = p1 - p0;
+            Some(KEllipse::new(
+                p0.midpoint(p1),
+                KVec2::new(d.hypot() / 2.0, 0.0),
+                d.atan2(),
+            ))
+        }
+        _ => Some(khachiyan(&hull)),
+    }
+}
@@ -0,0 +1,131 @@
+// Buffering of closed shapes. The region within the buffer distance of the
+// outline is built up from a rectangle along each edge of the flattened
+// outline and a join piece at each vertex, and is then added to the shape
+// to grow it or taken away from it to shrink it.
+use crate::boolean::{boolean, Op, Polygon, Region};
+use crate::polyline::{cross, flatten_subpaths};
+use kurbo::{BezPath as KBezPath, Point as KPoint, Vec2 as KVec2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::f64::consts::PI;
+
+/// How far a miter may extend, in multiples of the buffer distance, before
+/// it is beveled instead.
+const MITER_LIMIT: f64 = 4.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Join {
+    Round,
+    Miter,
+    Bevel,
+}
+
+impl Join {
+    pub(crate) fn parse(join: &str) -> PyResult<Self> {
+        match join {
+            "round" => Ok(Join::Round),
+            "miter" => Ok(Join::Miter),
+            "bevel" => Ok(Join::Bevel),
+            _ => Err(PyValueError::new_err(
+                "join must be 'round', 'miter' or 'bevel'",
+            )),
+        }
+    }
+}
+
+/// Flatten a path into polygons, closing any open subpaths.
+pub(crate) fn polygons(path: &KBezPath, tolerance: f64) -> Vec<Polygon> {
+    flatten_subpaths(path, tolerance)
+        .into_iter()
+        .filter_map(|line| {
+            let mut points = line.points;
+            points.dedup();
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+            (points.len() >= 3).then_some(points)
+        })
+        .collect()
+}
+
+/// The signed area of a polygon, positive when it is anticlockwise in a
+/// y-up coordinate system.
+pub(crate) fn polygon_area(poly: &[KPoint]) -> f64 {
+    let n = poly.len();
+    (0..n)
+        .map(|i| cross(poly[i].to_vec2(), poly[(i + 1) % n].to_vec2()))
+        .sum::<f64>()
+        * 0.5
+}
+
+fn positive(mut poly: Polygon) -> Polygon {
+    if polygon_area(&poly) < 0.0 {
+        poly.reverse();
+    }
+    poly
+}
+
+/// A polygon approximating a circle to within `tolerance`.
+fn circle(center: KPoint, radius: f64, tolerance: f64) -> Polygon {
+    let step = 2.0 * (1.0 - (tolerance / radius).min(1.0)).acos();
+    let n = ((2.0 * PI / step).ceil() as usize).clamp(8, 1024);
+    (0..n)
+        .map(|i| center + KVec2::from_angle(2.0 * PI * i as f64 / n as f64) * radius)
+        .collect()
+}
+
+/// Grow (for a positive `distance`) or shrink (for a negative one) the
+/// region enclosed by `path`.
+pub(crate) fn buffer(path: &KBezPath, distance: f64, join: Join, tolerance: f64) -> Vec<Polygon> {
+    let shape = polygons(path, tolerance);
+    let radius = distance.abs();
+    if radius == 0.0 || shape.is_empty() {
+        return boolean(&shape, &[], Op::Union);
+    }
+    let region = Region::new(&shape);
+    let mut pieces: Vec<Polygon> = vec![];
+    for poly in &shape {
+        let n = poly.len();
+        // The unit normal of each edge pointing to the side being grown
+        // into: outwards when growing, inwards when shrinking.
+        let normals: Vec<KVec2> = (0..n)
+            .map(|i| {
+                let (a, b) = (poly[i], poly[(i + 1) % n]);
+                let d = (b - a).normalize();
+                let left = KVec2::new(-d.y, d.x);
+                let probe = a.midpoint(b) + left * (1e-6 * radius).min(1e-3 * (b - a).hypot());
+                if region.contains(probe) == (distance > 0.0) {
+                    -left
+                } else {
+                    left
+                }
+            })
+            .collect();
+        for i in 0..n {
+            let (a, b) = (poly[i], poly[(i + 1) % n]);
+            let offset = normals[i] * radius;
+            pieces.push(positive(vec![a + offset, b + offset, b - offset, a - offset]));
+            // The join at the end of this edge.
+            let (n1, n2) = (normals[i], normals[(i + 1) % n]);
+            let next_dir = poly[(i + 2) % n] - b;
+            match join {
+                Join::Round => pieces.push(circle(b, radius, tolerance)),
+                Join::Miter if next_dir.dot(n1) < 0.0 => {
+                    let miter = b + (n1 + n2) * (radius / (1.0 + n1.dot(n2)));
+                    if (miter - b).hypot() <= MITER_LIMIT * radius {
+                        pieces.push(positive(vec![b, b + n1 * radius, miter, b + n2 * radius]));
+                    } else {
+                        pieces.push(positive(vec![b, b + n1 * radius, b + n2 * radius]));
+                    }
+                }
+                _ => pieces.push(positive(vec![b, b + n1 * radius, b + n2 * radius])),
+            }
+        }
+    }
+    let op = if distance > 0.0 {
+        Op::Union
+    } else {
+        Op::Difference
+    };
+    boolean(&shape, &pieces, op)
+}
@@ -0,0 +1,169 @@
+// A canonical form for paths, so that paths which draw the same outline
+// with the same segments compare equal, whatever subpath each starts from
+// and whichever way it goes round.
+use crate::bezpath::subpath_segments;
+use crate::cleanup::control_points;
+use kurbo::{
+    BezPath as KBezPath, ParamCurve, ParamCurveArea, PathEl as KPathEl, PathSeg as KPathSeg,
+    Point as KPoint,
+};
+use std::cmp::Ordering;
+
+struct Subpath {
+    start: KPoint,
+    segs: Vec<KPathSeg>,
+    closed: bool,
+}
+
+/// Split a path into its subpaths, with the closing line of each closed
+/// subpath as an explicit segment.
+fn subpaths(path: &KBezPath) -> Vec<Subpath> {
+    let mut result: Vec<Subpath> = vec![];
+    let mut segs = subpath_segments(path).into_iter().peekable();
+    for el in path.elements() {
+        let closed = result.last().map_or(false, |s| s.closed);
+        match *el {
+            KPathEl::MoveTo(p) => result.push(Subpath {
+                start: p,
+                segs: vec![],
+                closed: false,
+            }),
+            KPathEl::ClosePath => {
+                if let Some(last) = result.last_mut() {
+                    last.closed = true;
+                }
+            }
+            // Drawing on after a ClosePath starts a new subpath from the
+            // same point.
+            _ if closed => {
+                let start = result.last().map_or(KPoint::ZERO, |s| s.start);
+                result.push(Subpath {
+                    start,
+                    segs: vec![],
+                    closed: false,
+                });
+            }
+            _ => {}
+        }
+    }
+    // `subpath_segments` numbers the subpaths as they were pushed above.
+    for (ix, sub) in result.iter_mut().enumerate() {
+        while let Some((_, seg)) = segs.next_if(|(i, _)| *i == ix) {
+            sub.segs.push(seg);
+        }
+    }
+    result
+}
+
+/// The numbers describing a run of segments, for comparing runs: the start
+/// point, then the kind and remaining control points of each segment.
+fn key(segs: &[KPathSeg]) -> Vec<f64> {
+    let mut key = vec![];
+    if let Some(first) = segs.first() {
+        key.extend([first.start().x, first.start().y]);
+    }
+    for seg in segs {
+        let points = control_points(seg);
+        key.push(points.len() as f64);
+        for p in &points[1..] {
+            key.extend([p.x, p.y]);
+        }
+    }
+    // Treat -0.0 and 0.0 alike.
+    key.into_iter().map(|x| x + 0.0).collect()
+}
+
+fn compare(a: &[f64], b: &[f64]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|(x, y)| x.total_cmp(y))
+        .find(|o| o.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
+
+fn reversed(segs: &[KPathSeg]) -> Vec<KPathSeg> {
+    segs.iter().rev().map(|s| s.reverse()).collect()
+}
+
+/// The canonical form of a subpath, with its sort key.
+fn canonical_subpath(sub: Subpath) -> (Vec<f64>, Subpath) {
+    let Subpath {
+        start,
+        segs,
+        closed,
+    } = sub;
+    let candidates: Vec<Vec<KPathSeg>> = if closed && !segs.is_empty() {
+        let area: f64 = segs.iter().map(|s| s.signed_area()).sum();
+        let directions = if area > 0.0 {
+            vec![segs]
+        } else if area < 0.0 {
+            vec![reversed(&segs)]
+        } else {
+            let back = reversed(&segs);
+            vec![segs, back]
+        };
+        directions
+            .into_iter()
+            .flat_map(|d| {
+                (0..d.len()).map(move |i| {
+                    let mut r = d.clone();
+                    r.rotate_left(i);
+                    r
+                })
+            })
+            .collect()
+    } else if segs.is_empty() {
+        vec![vec![]]
+    } else {
+        let back = reversed(&segs);
+        vec![segs, back]
+    };
+    let (best_key, best) = candidates
+        .into_iter()
+        .map(|c| (key(&c), c))
+        .min_by(|a, b| compare(&a.0, &b.0))
+        .unwrap();
+    let start = best.first().map_or(start, |s| s.start());
+    let mut sort_key = vec![if closed { 1.0 } else { 0.0 }];
+    if best.is_empty() {
+        sort_key.extend([start.x + 0.0, start.y + 0.0]);
+    }
+    sort_key.extend(best_key);
+    (
+        sort_key,
+        Subpath {
+            start,
+            segs: best,
+            closed,
+        },
+    )
+}
+
+/// The canonical form of a path. Each closed subpath runs anticlockwise in
+/// a y-up coordinate system, unless it encloses no area, and starts at
+/// whichever of its segments gives the least sequence of coordinates; open
+/// subpaths run whichever way gives the lesser sequence; and the subpaths
+/// are sorted by those sequences.
+pub(crate) fn canonicalize(path: &KBezPath) -> KBezPath {
+    let mut subs: Vec<(Vec<f64>, Subpath)> =
+        subpaths(path).into_iter().map(canonical_subpath).collect();
+    subs.sort_by(|a, b| compare(&a.0, &b.0));
+    let mut result = KBezPath::new();
+    for (_, sub) in subs {
+        result.move_to(sub.start);
+        let mut segs = sub.segs.as_slice();
+        // A closing line is left to the ClosePath.
+        if sub.closed {
+            if let Some((KPathSeg::Line(_), rest)) = segs.split_last() {
+                segs = rest;
+            }
+        }
+        for seg in segs {
+            result.push(seg.as_path_el());
+        }
+        if sub.closed {
+            result.close_path();
+        }
+    }
+    result
+}
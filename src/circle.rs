@@ -1,9 +1,12 @@
-use crate::point::Point;
+use crate::impl_serialize;
+use crate::line::Line;
+use crate::point::{Point, PointLike};
 use crate::rect::Rect;
-use crate::vec2::Vec2;
+use crate::subclass::new_like;
+use crate::vec2::Vec2Like;
 use crate::{impl_isfinitenan, impl_shape};
 
-use kurbo::{Circle as KCircle, CircleSegment as KCircleSegment, Shape};
+use kurbo::{Affine as KAffine, Line as KLine, Circle as KCircle, CircleSegment as KCircleSegment, Shape};
 use pyo3::prelude::*;
 
 #[derive(Clone, Debug)]
@@ -17,11 +20,18 @@ impl From<KCircle> for Circle {
     }
 }
 
+impl Circle {
+    /// The transform which maps the unit circle onto this circle.
+    fn unit_transform(&self) -> KAffine {
+        KAffine::translate(self.0.center.to_vec2()) * KAffine::scale(self.0.radius)
+    }
+}
+
 #[pymethods]
 impl Circle {
     /// Create a new `Circle`.
     #[new]
-    pub fn __new__(center: Point, radius: f64) -> Self {
+    pub fn __new__(center: PointLike, radius: f64) -> Self {
         Self(KCircle::new(center.0, radius))
     }
     #[getter]
@@ -29,7 +39,7 @@ impl Circle {
         self.0.center.into()
     }
     #[setter]
-    pub fn set_center(&mut self, center: Point) {
+    pub fn set_center(&mut self, center: PointLike) {
         self.0.center = center.0
     }
     #[getter]
@@ -46,14 +56,83 @@ impl Circle {
         CircleSegment(self.0.segment(inner_radius, start_angle, sweep_angle))
     }
 
-    fn __add__(&self, v: Vec2) -> Circle {
-        Circle(self.0 + v.0)
+    /// The lines from ``pt`` which touch the circle.
+    ///
+    /// Returns a list of ``(line, point)`` tuples, where ``line`` runs from
+    /// ``pt`` to ``point``, the point at which it touches the circle. There are
+    /// two tangents from a point outside the circle, none from a point inside it,
+    /// and a single zero-length line from a point on it.
+    ///
+    /// Note that this method is not in original kurbo
+    fn tangent_lines(&self, pt: PointLike) -> Vec<(Line, Point)> {
+        crate::conic::ellipse_tangents(self.unit_transform(), pt.0)
+            .into_iter()
+            .map(|p| (Line(KLine::new(pt.0, p)), Point(p)))
+            .collect()
+    }
+
+    /// Find where a line crosses the circle.
+    ///
+    /// Returns a list of up to two ``(point, t, angle)`` tuples, in order
+    /// along the line, where ``t`` is the parameter of the point on the line
+    /// and ``angle`` is its angle around the circle in radians, measured from
+    /// the positive x axis as in :py:meth:`segment`. Only the points within
+    /// the line segment are returned; to intersect an infinite line, extend
+    /// the line first. A line which just touches the circle gives a single
+    /// point.
+    ///
+    /// Note that this method is not in original kurbo
+    fn intersect_line(&self, line: &Line) -> Vec<(Point, f64, f64)> {
+        crate::conic::ellipse_line(self.unit_transform(), line.0)
+            .into_iter()
+            .map(|(p, t, angle)| (Point(p), t, angle))
+            .collect()
+    }
+
+    /// Find where this circle meets another.
+    ///
+    /// Returns a tuple of a string describing how the circles meet and a
+    /// list of the points where they do. The string is ``"disjoint"`` if
+    /// each circle is outside the other, ``"contained"`` if one is inside
+    /// the other, ``"tangent"`` if they touch at one point, ``"intersecting"``
+    /// if they cross at two points, and ``"coincident"`` if they are the same
+    /// circle. Only tangent and intersecting circles have points. Distances
+    /// within ``accuracy`` of each other are treated as equal, so that
+    /// nearly tangent circles are reported as tangent.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
+    fn intersect_circle(&self, other: &Circle, accuracy: f64) -> (&'static str, Vec<Point>) {
+        let (a, b) = (self.0, other.0);
+        let (relation, points) =
+            crate::conic::circle_circle(a.center, a.radius, b.center, b.radius, accuracy);
+        (relation.name(), points.into_iter().map(Point).collect())
+    }
+
+    fn __add__(slf: &Bound<'_, Self>, v: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Circle(slf.borrow().0 + v.0))
+    }
+    fn __sub__(slf: &Bound<'_, Self>, v: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Circle(slf.borrow().0 - v.0))
     }
-    fn __sub__(&self, v: Vec2) -> Circle {
-        Circle(self.0 - v.0)
+
+    /// Support ``point in shape``, which is the same as ``contains``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __contains__(&self, pt: PointLike) -> bool {
+        self.contains(pt)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Circle({}, {})",
+            crate::repr::point(self.0.center),
+            crate::repr::float(self.0.radius)
+        )
     }
 }
 impl_isfinitenan!(Circle);
+impl_serialize!(Circle);
 impl_shape!(Circle);
 
 #[derive(Clone, Debug)]
@@ -74,7 +153,7 @@ impl CircleSegment {
     /// Create a `CircleSegment` out of its constituent parts.
     #[new]
     pub fn __new__(
-        center: Point,
+        center: PointLike,
         outer_radius: f64,
         inner_radius: f64,
         start_angle: f64,
@@ -95,7 +174,7 @@ impl CircleSegment {
         self.0.center.into()
     }
     #[setter]
-    pub fn set_center(&mut self, center: Point) {
+    pub fn set_center(&mut self, center: PointLike) {
         self.0.center = center.0
     }
     #[getter]
@@ -131,12 +210,29 @@ impl CircleSegment {
         self.0.sweep_angle = sweep_angle
     }
 
-    fn __add__(&self, v: Vec2) -> CircleSegment {
+    fn __add__(&self, v: Vec2Like) -> CircleSegment {
         CircleSegment(self.0 + v.0)
     }
-    fn __sub__(&self, v: Vec2) -> CircleSegment {
+    fn __sub__(&self, v: Vec2Like) -> CircleSegment {
         CircleSegment(self.0 - v.0)
     }
+
+    /// Support ``point in shape``, which is the same as ``contains``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __contains__(&self, pt: PointLike) -> bool {
+        self.contains(pt)
+    }
+
+    fn __repr__(&self) -> String {
+        let s = &self.0;
+        format!(
+            "CircleSegment({}, {})",
+            crate::repr::point(s.center),
+            crate::repr::floats(&[s.outer_radius, s.inner_radius, s.start_angle, s.sweep_angle])
+        )
+    }
 }
 impl_isfinitenan!(CircleSegment);
+impl_serialize!(CircleSegment);
 impl_shape!(CircleSegment);
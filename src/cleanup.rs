@@ -0,0 +1,130 @@
+// Removal of degenerate geometry: segments which go nowhere, curves which
+// are really straight lines, lines which carry straight on, and subpaths
+// which draw nothing.
+use crate::corners::subpaths;
+use crate::overlaps::with_ends;
+use crate::polyline::cross;
+use kurbo::{
+    BezPath as KBezPath, Line as KLine, ParamCurve, ParamCurveNearest, PathSeg as KPathSeg,
+    Point as KPoint,
+};
+
+pub(crate) fn control_points(seg: &KPathSeg) -> Vec<KPoint> {
+    match *seg {
+        KPathSeg::Line(l) => vec![l.p0, l.p1],
+        KPathSeg::Quad(q) => vec![q.p0, q.p1, q.p2],
+        KPathSeg::Cubic(c) => vec![c.p0, c.p1, c.p2, c.p3],
+    }
+}
+
+/// Whether `p` lies within `tolerance` of the line from `a` to `b`, and
+/// between its ends.
+fn on_chord(p: KPoint, a: KPoint, b: KPoint, tolerance: f64) -> bool {
+    let chord = b - a;
+    let len = chord.hypot();
+    if len <= tolerance {
+        return (p - a).hypot() <= tolerance;
+    }
+    let along = (p - a).dot(chord) / len;
+    cross(chord, p - a).abs() / len <= tolerance && along >= -tolerance && along <= len + tolerance
+}
+
+/// The greatest distance of the inner control points from the line segment
+/// joining the first and last. By the convex hull property, the curve lies
+/// within this distance of its chord.
+pub(crate) fn flatness(points: &[KPoint]) -> f64 {
+    let (Some(&a), Some(&b)) = (points.first(), points.last()) else {
+        return 0.0;
+    };
+    let chord = KLine::new(a, b);
+    points[1..points.len() - 1]
+        .iter()
+        .map(|&p| {
+            let t = chord.nearest(p, 0.0).t;
+            (p - chord.eval(t)).hypot()
+        })
+        .fold(0.0, f64::max)
+}
+
+/// The tolerance used by `BezPath.cleanup` when none is given. It is small,
+/// so that by default only geometry which is degenerate up to rounding
+/// error is removed.
+pub(crate) const DEFAULT_CLEANUP_TOLERANCE: f64 = 1e-6;
+
+/// Clean up a path, treating points within `tolerance` of each other as
+/// the same.
+pub(crate) fn cleanup(path: &KBezPath, tolerance: f64) -> KBezPath {
+    let mut result = KBezPath::new();
+    for sp in subpaths(path) {
+        let mut segs: Vec<KPathSeg> = vec![];
+        let mut last = sp.start;
+        for seg in sp.segs {
+            let points = control_points(&seg);
+            // Segments which go nowhere are dropped, and the next one is
+            // joined to the end of the previous.
+            if points.iter().all(|p| (*p - last).hypot() <= tolerance) {
+                continue;
+            }
+            let (start, end) = (last, seg.end());
+            let straight = points[1..points.len() - 1]
+                .iter()
+                .all(|p| on_chord(*p, start, end, tolerance));
+            let seg = if straight {
+                KPathSeg::Line(KLine::new(start, end))
+            } else {
+                with_ends(seg, start, end)
+            };
+            // Lines which carry straight on from the previous one are merged.
+            if let (KPathSeg::Line(line), Some(KPathSeg::Line(prev))) = (seg, segs.last()) {
+                if (line.p1 - line.p0).dot(prev.p1 - prev.p0) > 0.0
+                    && on_chord(prev.p1, prev.p0, line.p1, tolerance)
+                {
+                    let merged = KLine::new(prev.p0, line.p1);
+                    *segs.last_mut().unwrap() = KPathSeg::Line(merged);
+                    last = end;
+                    continue;
+                }
+            }
+            segs.push(seg);
+            last = end;
+        }
+        let mut start = sp.start;
+        if sp.closed {
+            // The closing line is drawn by closing the path.
+            let ends_with_closing_line = |segs: &[KPathSeg], start: KPoint| match segs.last() {
+                Some(KPathSeg::Line(line)) => (line.p1 - start).hypot() <= tolerance,
+                _ => false,
+            };
+            if ends_with_closing_line(&segs, start) {
+                segs.pop();
+            }
+            // So that the closing line can be merged with the first line,
+            // the start moves back to the start of the closing line, and
+            // the last line becomes the new closing line.
+            let closing = segs.last().map(|seg| seg.end());
+            if let (Some(end), Some(KPathSeg::Line(first))) = (closing, segs.first()) {
+                if segs.len() > 2
+                    && (first.p1 - first.p0).dot(start - end) > 0.0
+                    && on_chord(start, end, first.p1, tolerance)
+                {
+                    segs[0] = KPathSeg::Line(KLine::new(end, first.p1));
+                    start = end;
+                    if ends_with_closing_line(&segs, start) {
+                        segs.pop();
+                    }
+                }
+            }
+        }
+        if segs.is_empty() {
+            continue;
+        }
+        result.move_to(start);
+        for seg in &segs {
+            result.push(seg.as_path_el());
+        }
+        if sp.closed {
+            result.close_path();
+        }
+    }
+    result
+}
@@ -0,0 +1,212 @@
+// Curve-aware clipping of paths to rectangles. Segments are split exactly
+// where they cross the edges of the rectangle, so curves stay curves.
+//
+// Filled shapes are clipped against each edge of the rectangle in turn, in
+// the manner of Sutherland–Hodgman: the parts of each closed subpath
+// outside the edge are replaced by straight lines along it. This leaves the
+// winding number of every point inside the edge unchanged, so it works for
+// either fill rule.
+use crate::corners::subpaths;
+use kurbo::common::{solve_cubic, solve_quadratic};
+use kurbo::{
+    BezPath as KBezPath, Line as KLine, ParamCurve, PathSeg as KPathSeg, Point as KPoint,
+    Rect as KRect,
+};
+
+#[derive(Clone, Copy)]
+enum Axis {
+    X,
+    Y,
+}
+
+fn coord(p: KPoint, axis: Axis) -> f64 {
+    match axis {
+        Axis::X => p.x,
+        Axis::Y => p.y,
+    }
+}
+
+/// Parameter values strictly inside the segment where it crosses the line
+/// on which the given coordinate is `value`.
+fn crossings(seg: &KPathSeg, axis: Axis, value: f64) -> Vec<f64> {
+    let c = |p: KPoint| coord(p, axis) - value;
+    let roots = match *seg {
+        KPathSeg::Line(l) => {
+            let (a, b) = (c(l.p0), c(l.p1));
+            if a == b {
+                vec![]
+            } else {
+                vec![a / (a - b)]
+            }
+        }
+        KPathSeg::Quad(q) => {
+            let (a, b, d) = (c(q.p0), c(q.p1), c(q.p2));
+            solve_quadratic(a, 2.0 * (b - a), a - 2.0 * b + d).to_vec()
+        }
+        KPathSeg::Cubic(k) => {
+            let (a, b, d, e) = (c(k.p0), c(k.p1), c(k.p2), c(k.p3));
+            solve_cubic(
+                a,
+                3.0 * (b - a),
+                3.0 * (a - 2.0 * b + d),
+                e - a + 3.0 * (b - d),
+            )
+            .to_vec()
+        }
+    };
+    let mut roots: Vec<f64> = roots
+        .into_iter()
+        .filter(|t| *t > 1e-9 && *t < 1.0 - 1e-9)
+        .collect();
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+/// Split a segment at the given sorted parameter values.
+fn split(seg: &KPathSeg, ts: &[f64]) -> Vec<KPathSeg> {
+    let mut bounds = vec![0.0];
+    bounds.extend(ts.iter().copied());
+    bounds.push(1.0);
+    bounds.dedup();
+    bounds
+        .windows(2)
+        .map(|w| seg.subsegment(w[0]..w[1]))
+        .collect()
+}
+
+/// Clip closed loops of segments to one side of a line.
+fn clip_loops(
+    loops: Vec<Vec<KPathSeg>>,
+    axis: Axis,
+    value: f64,
+    keep_above: bool,
+) -> Vec<Vec<KPathSeg>> {
+    let inside = |seg: &KPathSeg| {
+        let v = coord(seg.eval(0.5), axis) - value;
+        if keep_above {
+            v >= 0.0
+        } else {
+            v <= 0.0
+        }
+    };
+    let mut result = vec![];
+    for segs in loops {
+        let pieces: Vec<KPathSeg> = segs
+            .iter()
+            .flat_map(|seg| split(seg, &crossings(seg, axis, value)))
+            .collect();
+        // A loop entirely outside collapses onto the line, enclosing nothing.
+        let Some(first) = pieces.iter().position(inside) else {
+            continue;
+        };
+        let mut out: Vec<KPathSeg> = vec![];
+        for k in 0..pieces.len() {
+            let piece = pieces[(first + k) % pieces.len()];
+            if !inside(&piece) {
+                continue;
+            }
+            if let Some(last) = out.last() {
+                if last.end() != piece.start() {
+                    out.push(KPathSeg::Line(KLine::new(last.end(), piece.start())));
+                }
+            }
+            out.push(piece);
+        }
+        let (end, start) = (out[out.len() - 1].end(), out[0].start());
+        if end != start {
+            out.push(KPathSeg::Line(KLine::new(end, start)));
+        }
+        result.push(out);
+    }
+    result
+}
+
+/// Clip the region enclosed by a path to a rectangle. Open subpaths are
+/// treated as closed.
+pub(crate) fn clip_fill(path: &KBezPath, rect: KRect) -> KBezPath {
+    let mut loops: Vec<Vec<KPathSeg>> = subpaths(path)
+        .into_iter()
+        .filter(|sp| !sp.segs.is_empty())
+        .map(|sp| {
+            let mut segs = sp.segs;
+            let end = segs[segs.len() - 1].end();
+            if end != sp.start {
+                segs.push(KPathSeg::Line(KLine::new(end, sp.start)));
+            }
+            segs
+        })
+        .collect();
+    loops = clip_loops(loops, Axis::X, rect.x0, true);
+    loops = clip_loops(loops, Axis::X, rect.x1, false);
+    loops = clip_loops(loops, Axis::Y, rect.y0, true);
+    loops = clip_loops(loops, Axis::Y, rect.y1, false);
+    let mut result = KBezPath::new();
+    for segs in loops {
+        result.move_to(segs[0].start());
+        for seg in segs {
+            result.push(seg.as_path_el());
+        }
+        result.close_path();
+    }
+    result
+}
+
+/// Trim a path to the parts which lie inside a rectangle, as an outline.
+pub(crate) fn clip_stroke(path: &KBezPath, rect: KRect) -> KBezPath {
+    let scale = rect
+        .x0
+        .abs()
+        .max(rect.x1.abs())
+        .max(rect.y0.abs())
+        .max(rect.y1.abs())
+        .max(1.0);
+    let eps = 1e-9 * scale;
+    let inside = |seg: &KPathSeg| {
+        let p = seg.eval(0.5);
+        p.x >= rect.x0 - eps && p.x <= rect.x1 + eps && p.y >= rect.y0 - eps && p.y <= rect.y1 + eps
+    };
+    let mut result = KBezPath::new();
+    for sp in subpaths(path) {
+        let mut pieces: Vec<KPathSeg> = sp
+            .segs
+            .iter()
+            .flat_map(|seg| {
+                let mut ts = crossings(seg, Axis::X, rect.x0);
+                ts.extend(crossings(seg, Axis::X, rect.x1));
+                ts.extend(crossings(seg, Axis::Y, rect.y0));
+                ts.extend(crossings(seg, Axis::Y, rect.y1));
+                ts.sort_by(f64::total_cmp);
+                split(seg, &ts)
+            })
+            .collect();
+        if pieces.iter().all(inside) {
+            result.move_to(sp.start);
+            for seg in &pieces {
+                result.push(seg.as_path_el());
+            }
+            if sp.closed {
+                result.close_path();
+            }
+            continue;
+        }
+        if sp.closed {
+            // Start after a piece which is dropped, so that the pieces on
+            // either side of the start of the subpath are joined up.
+            let outside = pieces.iter().position(|seg| !inside(seg)).unwrap_or(0);
+            pieces.rotate_left(outside);
+        }
+        let mut last: Option<KPoint> = None;
+        for seg in pieces {
+            if !inside(&seg) {
+                last = None;
+                continue;
+            }
+            if last != Some(seg.start()) {
+                result.move_to(seg.start());
+            }
+            result.push(seg.as_path_el());
+            last = Some(seg.end());
+        }
+    }
+    result
+}
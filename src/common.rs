@@ -1,5 +1,8 @@
+use crate::point::{Point, PointLike};
 use kurbo::common;
+use kurbo::Point as KPoint;
 
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 #[pyfunction]
@@ -134,6 +137,261 @@ pub fn solve_quartic(c0: f64, c1: f64, c2: f64, c3: f64, c4: f64) -> Vec<f64> {
     common::solve_quartic(c0, c1, c2, c3, c4).to_vec()
 }
 
+/// Evaluate the polynomial with the given coefficients, lowest degree first.
+fn eval_poly(coeffs: &[f64], x: f64) -> f64 {
+    coeffs.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
+
+/// Real roots of a polynomial of any degree, lowest degree coefficient first.
+///
+/// Up to degree four, kurbo's solvers are used directly. Above that, the
+/// roots of the derivative divide the real line into intervals on which
+/// the polynomial is monotonic; each interval contains at most one root,
+/// which is found by bisection.
+fn solve_poly_inner(coeffs: &[f64]) -> Vec<f64> {
+    let degree = match coeffs.iter().rposition(|c| *c != 0.0) {
+        Some(degree) => degree,
+        None if coeffs.is_empty() => return vec![],
+        None => return vec![0.0],
+    };
+    let c = &coeffs[..=degree];
+    match degree {
+        0 => return vec![],
+        1 | 2 => {
+            return common::solve_quadratic(c[0], c[1], c.get(2).copied().unwrap_or(0.0))
+                .to_vec()
+        }
+        3 => return common::solve_cubic(c[0], c[1], c[2], c[3]).to_vec(),
+        4 => return common::solve_quartic(c[0], c[1], c[2], c[3], c[4]).to_vec(),
+        _ => {}
+    }
+    let derivative: Vec<f64> = c
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, ci)| i as f64 * ci)
+        .collect();
+    // Cauchy's bound: all roots lie strictly within (-bound, bound).
+    let bound = 1.0
+        + c[..degree]
+            .iter()
+            .map(|ci| (ci / c[degree]).abs())
+            .fold(0.0, f64::max);
+    let mut critical: Vec<f64> = solve_poly_inner(&derivative)
+        .into_iter()
+        .filter(|x| x.is_finite() && x.abs() < bound)
+        .collect();
+    critical.sort_by(|a, b| a.total_cmp(b));
+
+    let mut roots = vec![];
+    // A critical point where the polynomial vanishes is a repeated root.
+    for &x in &critical {
+        let scale: f64 = c
+            .iter()
+            .enumerate()
+            .map(|(i, ci)| (ci * x.powi(i as i32)).abs())
+            .sum();
+        if eval_poly(c, x).abs() <= 1e-12 * scale {
+            roots.push(x);
+        }
+    }
+    let mut points = vec![-bound];
+    points.extend(critical);
+    points.push(bound);
+    for w in points.windows(2) {
+        let (mut lo, mut hi) = (w[0], w[1]);
+        let y_lo = eval_poly(c, lo);
+        if y_lo * eval_poly(c, hi) >= 0.0 {
+            continue;
+        }
+        loop {
+            let mid = 0.5 * (lo + hi);
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            let y_mid = eval_poly(c, mid);
+            if y_mid == 0.0 {
+                lo = mid;
+                hi = mid;
+                break;
+            }
+            if (y_mid < 0.0) == (y_lo < 0.0) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        roots.push(0.5 * (lo + hi));
+    }
+    roots.sort_by(|a, b| a.total_cmp(b));
+    roots.dedup_by(|a, b| (*a - *b).abs() <= 1e-12 * b.abs().max(1.0));
+    roots
+}
+
+/// Find real roots of a polynomial of arbitrary degree.
+///
+/// Return values of x for which c0 + c1 x + c2 x² + ... + cn xⁿ = 0, where
+/// `coeffs` is `[c0, c1, ..., cn]`. Roots are returned in ascending order.
+///
+/// Polynomials of degree four or less are handed to the corresponding
+/// solver in this module. Higher degree polynomials are solved by finding
+/// the turning points (recursively, from the derivative) and bisecting
+/// between them, which finds every real root including repeated ones.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+pub fn solve_poly(coeffs: Vec<f64>) -> Vec<f64> {
+    solve_poly_inner(&coeffs)
+}
+
+/// The Gauss-Legendre coefficient tables provided by kurbo, by order.
+///
+/// Each table is a list of `(weight, abscissa)` pairs over the interval
+/// [-1, 1].
+pub const GAUSS_LEGENDRE_TABLES: &[(usize, &[(f64, f64)])] = &[
+    (3, common::GAUSS_LEGENDRE_COEFFS_3),
+    (4, common::GAUSS_LEGENDRE_COEFFS_4),
+    (5, common::GAUSS_LEGENDRE_COEFFS_5),
+    (6, common::GAUSS_LEGENDRE_COEFFS_6),
+    (7, common::GAUSS_LEGENDRE_COEFFS_7),
+    (8, common::GAUSS_LEGENDRE_COEFFS_8),
+    (9, common::GAUSS_LEGENDRE_COEFFS_9),
+    (16, common::GAUSS_LEGENDRE_COEFFS_16),
+    (24, common::GAUSS_LEGENDRE_COEFFS_24),
+    (32, common::GAUSS_LEGENDRE_COEFFS_32),
+];
+
+fn gauss_legendre_table(n: usize) -> PyResult<&'static [(f64, f64)]> {
+    GAUSS_LEGENDRE_TABLES
+        .iter()
+        .find(|(order, _)| *order == n)
+        .map(|(_, table)| *table)
+        .ok_or_else(|| {
+            let orders: Vec<String> = GAUSS_LEGENDRE_TABLES
+                .iter()
+                .map(|(order, _)| order.to_string())
+                .collect();
+            PyValueError::new_err(format!(
+                "no Gauss-Legendre coefficients of order {} (available: {})",
+                n,
+                orders.join(", ")
+            ))
+        })
+}
+
+/// The Gauss-Legendre coefficients of order `n`.
+///
+/// Returns a list of `(weight, abscissa)` pairs over the interval [-1, 1].
+/// The available orders are 3 to 9, 16, 24 and 32; each table is also
+/// available as a module constant such as `GAUSS_LEGENDRE_COEFFS_8`.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+pub fn gauss_legendre_coeffs(n: usize) -> PyResult<Vec<(f64, f64)>> {
+    gauss_legendre_table(n).map(|table| table.to_vec())
+}
+
+/// Integrate a function over the interval [a, b] using Gauss-Legendre
+/// quadrature of order `n`.
+///
+/// `fun` is called with a single float and must return a float. Any
+/// exception it raises is propagated.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+#[pyo3(signature = (fun, a, b, n=8))]
+pub fn gauss_quadrature(py: Python, fun: Py<PyAny>, a: f64, b: f64, n: usize) -> PyResult<f64> {
+    let table = gauss_legendre_table(n)?;
+    let half_width = 0.5 * (b - a);
+    let mid = 0.5 * (a + b);
+    let mut sum = 0.0;
+    for &(wi, xi) in table {
+        let y: f64 = fun.call1(py, (half_width * xi + mid,))?.extract(py)?;
+        sum += wi * y;
+    }
+    Ok(sum * half_width)
+}
+
+/// The Bernstein basis polynomials of the given degree, evaluated at `t`.
+///
+/// Returns a list of `degree + 1` values, where the `i`th is
+/// C(degree, i) tⁱ (1 - t)^(degree - i). A Bézier curve's point at `t` is
+/// the sum of its control points weighted by these values.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+pub fn bernstein_basis(degree: usize, t: f64) -> Vec<f64> {
+    // Build up the basis of each degree from the one below, which avoids
+    // computing binomial coefficients.
+    let mut basis = vec![1.0];
+    for _ in 0..degree {
+        let mut next = vec![0.0; basis.len() + 1];
+        for (i, b) in basis.iter().enumerate() {
+            next[i] += (1.0 - t) * b;
+            next[i + 1] += t * b;
+        }
+        basis = next;
+    }
+    basis
+}
+
+fn control_points(points: Vec<PointLike>) -> PyResult<Vec<KPoint>> {
+    if points.is_empty() {
+        return Err(PyValueError::new_err("need at least one control point"));
+    }
+    Ok(points.into_iter().map(|p| p.0).collect())
+}
+
+/// Raise the degree of a Bézier curve with any number of control points.
+///
+/// Returns the control points of a curve of degree one higher (applied
+/// `times` times) which traces exactly the same path; for example, raising
+/// the three points of a quadratic gives the four points of the equivalent
+/// cubic.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+#[pyo3(signature = (points, times=1))]
+pub fn elevate_degree(points: Vec<PointLike>, times: usize) -> PyResult<Vec<Point>> {
+    let mut pts = control_points(points)?;
+    for _ in 0..times {
+        let n = pts.len() as f64;
+        let mut raised = vec![pts[0]];
+        for i in 1..pts.len() {
+            let a = i as f64 / n;
+            raised.push(pts[i - 1].lerp(pts[i], 1.0 - a));
+        }
+        raised.push(pts[pts.len() - 1]);
+        pts = raised;
+    }
+    Ok(pts.into_iter().map(Point).collect())
+}
+
+/// Split a Bézier curve with any number of control points at `t`, using
+/// de Casteljau's algorithm.
+///
+/// Returns a tuple of the control points of the two halves, covering the
+/// ranges 0..t and t..1 of the original; the last point of the first half
+/// is the first point of the second, and is the point on the curve at `t`.
+///
+/// Note that this function is not in original kurbo.
+#[pyfunction]
+pub fn split_bezier(points: Vec<PointLike>, t: f64) -> PyResult<(Vec<Point>, Vec<Point>)> {
+    let mut pts = control_points(points)?;
+    let mut left = vec![pts[0]];
+    let mut right = vec![pts[pts.len() - 1]];
+    while pts.len() > 1 {
+        pts = pts.windows(2).map(|w| w[0].lerp(w[1], t)).collect();
+        left.push(pts[0]);
+        right.push(pts[pts.len() - 1]);
+    }
+    right.reverse();
+    Ok((
+        left.into_iter().map(Point).collect(),
+        right.into_iter().map(Point).collect(),
+    ))
+}
+
 #[macro_export]
 macro_rules! impl_isfinitenan {
     ($name:ident) => {
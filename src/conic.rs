@@ -0,0 +1,134 @@
+// Intersections involving circles and ellipses.
+use kurbo::common::solve_quadratic;
+use kurbo::{Affine as KAffine, Line as KLine, ParamCurve, Point as KPoint};
+
+/// The parameters along the line from `p0` to `p1`, in increasing order,
+/// at which it crosses the unit circle.
+fn unit_circle_line(p0: KPoint, p1: KPoint) -> Vec<f64> {
+    let d = p1 - p0;
+    let f = p0.to_vec2();
+    let mut ts: Vec<f64> = solve_quadratic(f.hypot2() - 1.0, 2.0 * f.dot(d), d.hypot2())
+        .into_iter()
+        .filter(|t| (0.0..=1.0).contains(t))
+        .collect();
+    ts.sort_by(f64::total_cmp);
+    ts.dedup();
+    ts
+}
+
+/// Intersect a line with the ellipse which is the image of the unit circle
+/// under `transform`.
+///
+/// Returns the intersection points with their parameters along the line,
+/// and their angles on the unit circle.
+pub(crate) fn ellipse_line(transform: KAffine, line: KLine) -> Vec<(KPoint, f64, f64)> {
+    if transform.determinant() == 0.0 {
+        return vec![];
+    }
+    let inverse = transform.inverse();
+    let (q0, q1) = (inverse * line.p0, inverse * line.p1);
+    unit_circle_line(q0, q1)
+        .into_iter()
+        .map(|t| {
+            let unit = q0.lerp(q1, t);
+            (line.eval(t), t, unit.y.atan2(unit.x))
+        })
+        .collect()
+}
+
+/// The points at which lines through `p` touch the ellipse which is the
+/// image of the unit circle under `transform`.
+pub(crate) fn ellipse_tangents(transform: KAffine, p: KPoint) -> Vec<KPoint> {
+    if transform.determinant() == 0.0 {
+        return vec![];
+    }
+    // Tangency is preserved by affine maps, so find the tangents to the unit
+    // circle and map them back.
+    let q = transform.inverse() * p;
+    let d = q.to_vec2().hypot();
+    if d < 1.0 {
+        return vec![];
+    }
+    let base = q.y.atan2(q.x);
+    let spread = (1.0 / d).acos();
+    if spread == 0.0 {
+        return vec![p];
+    }
+    [base + spread, base - spread]
+        .iter()
+        .map(|angle| transform * KPoint::new(angle.cos(), angle.sin()))
+        .collect()
+}
+
+/// How two circles meet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum CircleRelation {
+    /// Each circle is outside the other.
+    Disjoint,
+    /// One circle is inside the other.
+    Contained,
+    /// The circles touch at a single point.
+    Tangent,
+    /// The circles cross at two points.
+    Intersecting,
+    /// The circles are the same.
+    Coincident,
+}
+
+impl CircleRelation {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            CircleRelation::Disjoint => "disjoint",
+            CircleRelation::Contained => "contained",
+            CircleRelation::Tangent => "tangent",
+            CircleRelation::Intersecting => "intersecting",
+            CircleRelation::Coincident => "coincident",
+        }
+    }
+}
+
+/// Intersect two circles, treating distances within `accuracy` as equal.
+pub(crate) fn circle_circle(
+    c1: KPoint,
+    r1: f64,
+    c2: KPoint,
+    r2: f64,
+    accuracy: f64,
+) -> (CircleRelation, Vec<KPoint>) {
+    let (r1, r2) = (r1.abs(), r2.abs());
+    let delta = c2 - c1;
+    let d = delta.hypot();
+    if d <= accuracy && (r1 - r2).abs() <= accuracy {
+        return (CircleRelation::Coincident, vec![]);
+    }
+    if d <= accuracy {
+        return (CircleRelation::Contained, vec![]);
+    }
+    if (d - (r1 + r2)).abs() <= accuracy {
+        return (CircleRelation::Tangent, vec![c1 + delta * (r1 / d)]);
+    }
+    if (d - (r1 - r2).abs()).abs() <= accuracy {
+        // Internal tangency: the point is on the far side of the larger
+        // circle from its center, in the direction of the smaller one.
+        let point = if r1 >= r2 {
+            c1 + delta * (r1 / d)
+        } else {
+            c2 - delta * (r2 / d)
+        };
+        return (CircleRelation::Tangent, vec![point]);
+    }
+    if d > r1 + r2 {
+        return (CircleRelation::Disjoint, vec![]);
+    }
+    if d < (r1 - r2).abs() {
+        return (CircleRelation::Contained, vec![]);
+    }
+    // The intersections lie on the radical line, at distance `a` from `c1`
+    // along the line of centers.
+    let u = delta / d;
+    let a = (d * d + r1 * r1 - r2 * r2) / (2.0 * d);
+    let h = (r1 * r1 - a * a).max(0.0).sqrt();
+    let base = c1 + u * a;
+    let perp = kurbo::Vec2::new(-u.y, u.x) * h;
+    (CircleRelation::Intersecting, vec![base + perp, base - perp])
+}
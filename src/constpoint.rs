@@ -1,6 +1,7 @@
 use crate::{impl_isfinitenan, impl_paramcurve, impl_paramcurvearclen, impl_paramcurvederiv};
 use kurbo::{ParamCurveDeriv, ParamCurveArclen, ParamCurve};
-use kurbo::ConstPoint as KConstPoint;
+use kurbo::{ConstPoint as KConstPoint, Line as KLine, Point as KPoint};
+use crate::point::PointLike;
 use pyo3::prelude::*;
 
 #[derive(Clone, Debug)]
@@ -14,6 +15,23 @@ impl From<KConstPoint> for ConstPoint {
     }
 }
 
+#[pymethods]
+impl ConstPoint {
+    /// Create a `ConstPoint` which is always at the given point.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    fn __new__(p: PointLike) -> Self {
+        // kurbo only makes these as derivatives; a line from the origin has
+        // a constant derivative equal to its end point.
+        ConstPoint(KLine::new(KPoint::ZERO, p.0).deriv())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ConstPoint({})", crate::repr::point(self.0.eval(0.0)))
+    }
+}
+
 impl_isfinitenan!(ConstPoint);
 impl_paramcurve!(ConstPoint);
 impl_paramcurvearclen!(ConstPoint);
@@ -0,0 +1,503 @@
+// Corner detection and smoothing. Smoothed corners are made of a circular
+// arc with a cubic transition curve on either side; the transitions start
+// with zero curvature on the straight edge and meet the arc with matching
+// curvature, so the whole corner is curvature-continuous.
+use crate::polyline::cross;
+use kurbo::{
+    common::solve_quadratic, BezPath as KBezPath, ParamCurve, PathEl as KPathEl,
+    PathSeg as KPathSeg, Point as KPoint, Vec2,
+};
+use std::f64::consts::FRAC_PI_2;
+
+/// A subpath as a list of segments.
+pub(crate) struct Subpath {
+    pub start: KPoint,
+    pub segs: Vec<KPathSeg>,
+    pub closed: bool,
+}
+
+/// The replacement for a corner: the distance trimmed from each of the lines
+/// meeting there, and the curves connecting the trimmed ends.
+struct Corner {
+    trim: f64,
+    els: Vec<KPathEl>,
+}
+
+pub(crate) fn subpaths(path: &KBezPath) -> Vec<Subpath> {
+    let mut result: Vec<Subpath> = vec![];
+    let mut start = KPoint::ZERO;
+    let mut last = KPoint::ZERO;
+    let mut current: Option<Subpath> = None;
+    for el in path.elements() {
+        let seg = match *el {
+            KPathEl::MoveTo(p) => {
+                result.extend(current.take());
+                current = Some(Subpath {
+                    start: p,
+                    segs: vec![],
+                    closed: false,
+                });
+                start = p;
+                last = p;
+                continue;
+            }
+            KPathEl::LineTo(p) => KPathSeg::Line(kurbo::Line::new(last, p)),
+            KPathEl::QuadTo(p1, p2) => KPathSeg::Quad(kurbo::QuadBez::new(last, p1, p2)),
+            KPathEl::CurveTo(p1, p2, p3) => {
+                KPathSeg::Cubic(kurbo::CubicBez::new(last, p1, p2, p3))
+            }
+            KPathEl::ClosePath => {
+                let mut sp = current.take().unwrap_or(Subpath {
+                    start,
+                    segs: vec![],
+                    closed: false,
+                });
+                if last != start {
+                    sp.segs.push(KPathSeg::Line(kurbo::Line::new(last, start)));
+                }
+                sp.closed = true;
+                result.push(sp);
+                last = start;
+                continue;
+            }
+        };
+        current
+            .get_or_insert_with(|| Subpath {
+                start,
+                segs: vec![],
+                closed: false,
+            })
+            .segs
+            .push(seg);
+        last = seg.end();
+    }
+    result.extend(current);
+    result
+}
+
+/// The direction in which a segment leaves its start point, or `None` if
+/// the segment has zero length.
+pub(crate) fn start_tangent(seg: &KPathSeg) -> Option<Vec2> {
+    let (p0, rest) = match *seg {
+        KPathSeg::Line(l) => (l.p0, vec![l.p1]),
+        KPathSeg::Quad(q) => (q.p0, vec![q.p1, q.p2]),
+        KPathSeg::Cubic(c) => (c.p0, vec![c.p1, c.p2, c.p3]),
+    };
+    rest.into_iter().map(|p| p - p0).find(|v| v.hypot2() > 0.0)
+}
+
+/// The direction in which a segment arrives at its end point, or `None` if
+/// the segment has zero length.
+pub(crate) fn end_tangent(seg: &KPathSeg) -> Option<Vec2> {
+    start_tangent(&seg.reverse()).map(|v| -v)
+}
+
+/// The parameter values strictly inside a cubic where its derivative
+/// vanishes.
+fn cubic_cusps(c: &kurbo::CubicBez) -> Vec<f64> {
+    let (a, b, d) = (c.p1 - c.p0, c.p2 - c.p1, c.p3 - c.p2);
+    let deriv = |t: f64| a * ((1.0 - t) * (1.0 - t)) + b * (2.0 * (1.0 - t) * t) + d * (t * t);
+    let scale = a.hypot().max(b.hypot()).max(d.hypot());
+    let mut result: Vec<f64> = vec![];
+    for (ca, cb, cd) in [(a.x, b.x, d.x), (a.y, b.y, d.y)] {
+        for t in solve_quadratic(ca, 2.0 * (cb - ca), ca - 2.0 * cb + cd) {
+            if t > 0.0
+                && t < 1.0
+                && deriv(t).hypot() <= 1e-9 * scale
+                && !result.iter().any(|u| (u - t).abs() < 1e-9)
+            {
+                result.push(t);
+            }
+        }
+    }
+    result.sort_by(|a, b| a.total_cmp(b));
+    result
+}
+
+/// Find the corners of a path: the joins between segments where the
+/// direction turns by more than `threshold` radians, and the cusps inside
+/// cubic segments. Each corner is given as the index of the segment (as
+/// numbered by `BezPath::segments`) it lies at the start of or within, the
+/// parameter value on that segment, and the signed turning angle.
+pub(crate) fn find_corners(path: &KBezPath, threshold: f64) -> Vec<(usize, f64, f64)> {
+    let mut result = vec![];
+    let mut base = 0;
+    for sp in subpaths(path) {
+        let n = sp.segs.len();
+        for (j, seg) in sp.segs.iter().enumerate() {
+            let prev = if j > 0 {
+                Some(&sp.segs[j - 1])
+            } else if sp.closed && n > 1 {
+                Some(&sp.segs[n - 1])
+            } else {
+                None
+            };
+            if let (Some(u), Some(v)) = (prev.and_then(end_tangent), start_tangent(seg)) {
+                let angle = cross(u, v).atan2(u.dot(v));
+                if angle.abs() > threshold {
+                    result.push((base + j, 0.0, angle));
+                }
+            }
+            if let KPathSeg::Cubic(c) = seg {
+                result.extend(
+                    cubic_cusps(c)
+                        .into_iter()
+                        .map(|t| (base + j, t, std::f64::consts::PI)),
+                );
+            }
+        }
+        base += n;
+    }
+    result
+}
+
+/// Approximate a circular arc with cubic Béziers, appending them to `els`.
+fn push_arc(els: &mut Vec<KPathEl>, center: KPoint, radius: f64, start: f64, sweep: f64) {
+    let n = (sweep.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let delta = sweep / n as f64;
+    let k = 4.0 / 3.0 * (delta / 4.0).tan() * radius;
+    for i in 0..n {
+        let th0 = start + delta * i as f64;
+        let th1 = th0 + delta;
+        let (s0, c0) = th0.sin_cos();
+        let (s1, c1) = th1.sin_cos();
+        let p0 = center + radius * Vec2::new(c0, s0);
+        let p1 = center + radius * Vec2::new(c1, s1);
+        els.push(KPathEl::CurveTo(
+            p0 + k * Vec2::new(-s0, c0),
+            p1 - k * Vec2::new(-s1, c1),
+            p1,
+        ));
+    }
+}
+
+/// Build a smoothed corner at `vertex`, between lines arriving in direction
+/// `u_in` and leaving in direction `u_out` (both unit vectors). `limit` is
+/// the greatest distance the corner may extend along either line.
+fn corner(
+    vertex: KPoint,
+    u_in: Vec2,
+    u_out: Vec2,
+    radius: f64,
+    smoothness: f64,
+    limit: f64,
+) -> Option<Corner> {
+    let turn = cross(u_in, u_out).atan2(u_in.dot(u_out));
+    if turn.abs() < 1e-9 || turn.abs() > std::f64::consts::PI - 1e-9 {
+        return None;
+    }
+    let side = turn.signum();
+    let half_tan = (turn.abs() / 2.0).tan();
+    // Shrink the radius if the corner would not fit on the lines.
+    let extent = (1.0 + smoothness) * radius * half_tan;
+    let r = if extent > limit {
+        radius * limit / extent
+    } else {
+        radius
+    };
+    if r <= 0.0 {
+        return None;
+    }
+    let t = r * half_tan;
+    // Each transition covers `beta` of the turn, and the arc the rest.
+    let beta = turn.abs() * smoothness / 2.0;
+    let n_in = side * Vec2::new(-u_in.y, u_in.x);
+    let n_out = side * Vec2::new(-u_out.y, u_out.x);
+    let center = vertex - t * u_in + r * n_in;
+
+    // The incoming transition, in a frame with its origin where a plain
+    // fillet would meet the incoming line, x along the line and y towards
+    // the center. The outgoing transition is its mirror image.
+    let local: Vec<(f64, f64)> = if beta > 1e-9 {
+        let (sb, cb) = beta.sin_cos();
+        let end = (r * sb, r * (1.0 - cb));
+        let handle = r * (beta / 2.0).tan();
+        let c2 = (end.0 - handle * cb, end.1 - handle * sb);
+        // Matching the arc's curvature at the end of the transition
+        let b = 3.0 * handle * handle / (2.0 * r * sb);
+        let c1 = (c2.0 - b, c2.1);
+        let a = (c1.0 + smoothness * t).max(0.0);
+        vec![(c1.0 - a, 0.0), c1, c2, end]
+    } else {
+        vec![(0.0, 0.0)]
+    };
+    let to_in = |(x, y): (f64, f64)| vertex - t * u_in + x * u_in + y * n_in;
+    let to_out = |(x, y): (f64, f64)| vertex + t * u_out - x * u_out + y * n_out;
+
+    let mut els = vec![];
+    if let [_, c1, c2, end] = local[..] {
+        els.push(KPathEl::CurveTo(to_in(c1), to_in(c2), to_in(end)));
+    }
+    let arc_end = local[local.len() - 1];
+    push_arc(
+        &mut els,
+        center,
+        r,
+        (to_in(arc_end) - center).atan2(),
+        side * (turn.abs() - 2.0 * beta),
+    );
+    // Snap the end of the arc so that it meets the outgoing side exactly.
+    if let Some(KPathEl::CurveTo(_, _, p)) = els.last_mut() {
+        *p = to_out(arc_end);
+    }
+    if let [p0, c1, c2, _] = local[..] {
+        els.push(KPathEl::CurveTo(to_out(c2), to_out(c1), to_out(p0)));
+    }
+    Some(Corner {
+        trim: t - local[0].0,
+        els,
+    })
+}
+
+/// Round the corners between straight lines in a path with curvature-continuous
+/// corners. `smoothness` ranges from 0 (circular fillets) to 1.
+pub(crate) fn round_corners_smooth(path: &KBezPath, radius: f64, smoothness: f64) -> KBezPath {
+    let mut result = KBezPath::new();
+    for sp in subpaths(path) {
+        let n = sp.segs.len();
+        let line = |ix: usize| match sp.segs[ix] {
+            KPathSeg::Line(l) if l.p0 != l.p1 => Some(l),
+            _ => None,
+        };
+        // corners[j] replaces the vertex at the start of segment j.
+        let corners: Vec<Option<Corner>> = (0..n)
+            .map(|j| {
+                if j == 0 && !sp.closed {
+                    return None;
+                }
+                let incoming = line((j + n - 1) % n)?;
+                let outgoing = line(j)?;
+                let len_in = (incoming.p1 - incoming.p0).hypot();
+                let len_out = (outgoing.p1 - outgoing.p0).hypot();
+                corner(
+                    outgoing.p0,
+                    (incoming.p1 - incoming.p0) / len_in,
+                    (outgoing.p1 - outgoing.p0) / len_out,
+                    radius,
+                    smoothness,
+                    len_in.min(len_out) / 2.0,
+                )
+            })
+            .collect();
+        let trimmed_start = |j: usize| match (&corners[j], line(j)) {
+            (Some(c), Some(l)) => l.p0 + c.trim * (l.p1 - l.p0).normalize(),
+            _ => sp.segs[j].start(),
+        };
+        if n == 0 {
+            result.move_to(sp.start);
+            if sp.closed {
+                result.close_path();
+            }
+            continue;
+        }
+        result.move_to(trimmed_start(0));
+        for j in 0..n {
+            let next = if j + 1 < n {
+                corners[j + 1].as_ref()
+            } else if sp.closed {
+                corners[0].as_ref()
+            } else {
+                None
+            };
+            match sp.segs[j] {
+                KPathSeg::Line(l) => {
+                    let trim = next.map_or(0.0, |c| c.trim);
+                    result.line_to(l.p1 - trim * (l.p1 - l.p0).normalize());
+                }
+                KPathSeg::Quad(q) => result.quad_to(q.p1, q.p2),
+                KPathSeg::Cubic(c) => result.curve_to(c.p1, c.p2, c.p3),
+            }
+            if let Some(c) = next {
+                result.extend(c.els.iter().copied());
+            }
+        }
+        if sp.closed {
+            result.close_path();
+        }
+    }
+    result
+}
+
+/// Joins which turn by less than this many radians are treated as smooth
+/// by `harmonize` unless another threshold is given.
+pub(crate) const SMOOTH_JOIN_ANGLE: f64 = std::f64::consts::PI / 18.0;
+
+/// One side of a join: a path element, or the implicit closing line of a
+/// subpath.
+#[derive(Clone, Copy)]
+enum Side {
+    Element(usize),
+    Closing,
+}
+
+/// A join between two segments of a subpath.
+struct Join {
+    incoming: Side,
+    outgoing: Side,
+    /// The index of the `MoveTo` element, if the join is at the start of a
+    /// closed subpath and so moves with it.
+    move_to: Option<usize>,
+}
+
+/// The joins in a path, as indices into its elements.
+fn joins(els: &[KPathEl]) -> Vec<Join> {
+    let mut result = vec![];
+    let mut i = 0;
+    while i < els.len() {
+        let KPathEl::MoveTo(start) = els[i] else {
+            i += 1;
+            continue;
+        };
+        let move_ix = i;
+        let first = i + 1;
+        i += 1;
+        while i < els.len() && !matches!(els[i], KPathEl::MoveTo(_) | KPathEl::ClosePath) {
+            i += 1;
+        }
+        let last = i;
+        for j in first + 1..last {
+            result.push(Join {
+                incoming: Side::Element(j - 1),
+                outgoing: Side::Element(j),
+                move_to: None,
+            });
+        }
+        let closed = matches!(els.get(i), Some(KPathEl::ClosePath));
+        if closed && last > first {
+            if els[last - 1].end_point() == Some(start) {
+                result.push(Join {
+                    incoming: Side::Element(last - 1),
+                    outgoing: Side::Element(first),
+                    move_to: Some(move_ix),
+                });
+            } else {
+                result.push(Join {
+                    incoming: Side::Element(last - 1),
+                    outgoing: Side::Closing,
+                    move_to: None,
+                });
+                result.push(Join {
+                    incoming: Side::Closing,
+                    outgoing: Side::Element(first),
+                    move_to: None,
+                });
+            }
+        }
+    }
+    result
+}
+
+/// Harmonize one join, returning how far its points moved.
+fn harmonize_join(els: &mut [KPathEl], join: &Join, g2: bool, threshold: f64) -> Option<f64> {
+    // The subpath's start and the end of its last element, for the
+    // implicit closing line.
+    let closing = |els: &[KPathEl], ix: usize| {
+        let start = els[..=ix].iter().rev().find_map(|el| match el {
+            KPathEl::MoveTo(p) => Some(*p),
+            _ => None,
+        })?;
+        let end = els[ix..]
+            .iter()
+            .take_while(|el| !matches!(el, KPathEl::ClosePath))
+            .last()?
+            .end_point()?;
+        Some((end, start))
+    };
+    let (p, h_in, a1) = match join.incoming {
+        Side::Element(ix) => match els[ix] {
+            KPathEl::CurveTo(p1, p2, p3) => (p3, p2, Some(p1)),
+            KPathEl::LineTo(p) => (p, els[ix - 1].end_point()?, None),
+            _ => return None,
+        },
+        Side::Closing => {
+            let Side::Element(ix) = join.outgoing else {
+                return None;
+            };
+            let (end, start) = closing(els, ix)?;
+            (start, end, None)
+        }
+    };
+    let (h_out, b2) = match join.outgoing {
+        Side::Element(ix) => match els[ix] {
+            KPathEl::CurveTo(p1, p2, _) => (p1, Some(p2)),
+            KPathEl::LineTo(p) => (p, None),
+            _ => return None,
+        },
+        Side::Closing => {
+            let Side::Element(ix) = join.incoming else {
+                return None;
+            };
+            (closing(els, ix)?.1, None)
+        }
+    };
+    if a1.is_none() && b2.is_none() {
+        return None;
+    }
+    let (u_in, u_out) = (p - h_in, h_out - p);
+    let (len_in, len_out) = (u_in.hypot(), u_out.hypot());
+    if len_in == 0.0 || len_out == 0.0 {
+        return None;
+    }
+    if cross(u_in, u_out).atan2(u_in.dot(u_out)).abs() > threshold {
+        return None;
+    }
+    // A straight side keeps its direction; otherwise split the difference.
+    let dir = match (a1, b2) {
+        (None, _) => u_in / len_in,
+        (_, None) => u_out / len_out,
+        _ => (u_in / len_in + u_out / len_out).normalize(),
+    };
+    let a2 = p - len_in * dir;
+    let b1 = p + len_out * dir;
+    let mut new_p = p;
+    if let (true, Some(a1), Some(b2)) = (g2, a1, b2) {
+        // The curvatures at the ends of the two cubics are proportional to
+        // the distances of the outer handles from the tangent line, and
+        // inversely to the squares of the inner handle lengths. Sliding
+        // the on-curve point along the handles balances them.
+        let h_a = cross(a1 - a2, dir);
+        let h_b = cross(b2 - b1, dir);
+        if h_a * h_b > 0.0 {
+            let r = (h_a / h_b).sqrt();
+            new_p = a2.lerp(b1, r / (1.0 + r));
+        }
+    }
+    if let Side::Element(ix) = join.incoming {
+        if let KPathEl::CurveTo(_, p2, p3) = &mut els[ix] {
+            *p2 = a2;
+            *p3 = new_p;
+        }
+    }
+    if let Side::Element(ix) = join.outgoing {
+        if let KPathEl::CurveTo(p1, _, _) = &mut els[ix] {
+            *p1 = b1;
+        }
+    }
+    if let Some(ix) = join.move_to {
+        els[ix] = KPathEl::MoveTo(new_p);
+    }
+    Some((a2 - h_in).hypot().max((b1 - h_out).hypot()).max((new_p - p).hypot()))
+}
+
+/// Make the smooth joins of a path tangent-continuous (G1), or with `g2`
+/// also curvature-continuous where the segments on both sides are cubics.
+/// Joins which turn by more than `threshold` radians are corners and are
+/// left alone.
+pub(crate) fn harmonize(path: &KBezPath, g2: bool, threshold: f64) -> KBezPath {
+    let mut els = path.elements().to_vec();
+    let joins = joins(&els);
+    // Adjusting one join disturbs the curvature at its neighbours, so
+    // repeat until the points settle.
+    for _ in 0..16 {
+        let mut moved: f64 = 0.0;
+        for join in &joins {
+            if let Some(d) = harmonize_join(&mut els, join, g2, threshold) {
+                moved = moved.max(d);
+            }
+        }
+        if moved < 1e-9 {
+            break;
+        }
+    }
+    KBezPath::from_vec(els)
+}
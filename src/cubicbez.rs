@@ -1,16 +1,18 @@
+use crate::impl_serialize;
 use crate::nearest::Nearest;
-use crate::point::Point;
+use crate::point::{Point, PointLike};
 use crate::quadbez::QuadBez;
 use crate::{
-    impl_isfinitenan, impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea,
+    impl_approx_eq, impl_isfinitenan, impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea,
     impl_paramcurvecurvature, impl_paramcurvederiv, impl_paramcurveextrema, impl_paramcurvenearest,
-    impl_shape_no_bounding_box,
+    impl_paramcurveproject, impl_round_coordinates, impl_shape_no_bounding_box,
 };
 
 use kurbo::{
     CubicBez as KCubicBez, ParamCurve, ParamCurveArclen, ParamCurveArea, ParamCurveCurvature,
-    ParamCurveDeriv, ParamCurveExtrema, ParamCurveNearest,
+    ParamCurveDeriv, ParamCurveExtrema, ParamCurveNearest, PathSeg as KPathSeg, QuadBez as KQuadBez,
 };
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 #[derive(Clone, Debug)]
@@ -26,7 +28,7 @@ impl From<KCubicBez> for CubicBez {
 #[pymethods]
 impl CubicBez {
     #[new]
-    fn __new__(p0: Point, p1: Point, p2: Point, p3: Point) -> Self {
+    fn __new__(p0: PointLike, p1: PointLike, p2: PointLike, p3: PointLike) -> Self {
         CubicBez(KCubicBez::new(p0.0, p1.0, p2.0, p3.0))
     }
 
@@ -39,13 +41,68 @@ impl CubicBez {
     /// they are optimized for minimizing distance error.
     ///
     /// This iterator will always produce at least one :py:class:`QuadBez`.
-    #[inline]
-    fn to_quads(&self, accuracy: f64) -> Vec<(f64, f64, QuadBez)> {
-        self.0
-            .to_quads(accuracy)
-            .map(|(a, b, c)| (a, b, c.into()))
-            .collect()
+    ///
+    /// If ``return_error`` is true, returns a ``(quads, error)`` tuple, where
+    /// ``error`` estimates the greatest distance between the quadratics and
+    /// the cubic.
+    #[pyo3(signature = (accuracy=crate::default_accuracy(), return_error=false))]
+    fn to_quads(&self, py: Python<'_>, accuracy: f64, return_error: bool) -> PyObject {
+        let quads: Vec<(f64, f64, KQuadBez)> = self.0.to_quads(accuracy).collect();
+        let error = || {
+            crate::accuracy::max_deviation(
+                quads.iter().map(|&(_, _, q)| KPathSeg::Quad(q)),
+                &[KPathSeg::Cubic(self.0)],
+                accuracy * crate::accuracy::REFINEMENT,
+            )
+        };
+        let result: Vec<(f64, f64, QuadBez)> =
+            quads.iter().map(|&(a, b, c)| (a, b, c.into())).collect();
+        crate::accuracy::with_error(py, result, error, return_error)
+    }
+    /// Approximate the cubic with a single quadratic Bézier, if that is
+    /// possible to within ``tolerance``.
+    ///
+    /// The control point of the quadratic is the average of the points where
+    /// the cubic's tangents at either end would put it. Returns ``None`` if
+    /// the distance between the two curves might exceed ``tolerance``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn try_approx_quad(&self, tolerance: f64) -> Option<QuadBez> {
+        let KCubicBez { p0, p1, p2, p3 } = self.0;
+        // Upper bound on the distance between the cubic and the quadratic
+        let error = (p3.to_vec2() - 3.0 * p2.to_vec2() + 3.0 * p1.to_vec2() - p0.to_vec2())
+            .hypot()
+            * 3f64.sqrt()
+            / 36.0;
+        if error > tolerance || !error.is_finite() {
+            return None;
+        }
+        let c = ((p1.to_vec2() + p2.to_vec2()) * 3.0 - p0.to_vec2() - p3.to_vec2()) / 4.0;
+        Some(KQuadBez::new(p0, c.to_point(), p3).into())
+    }
+
+    /// Approximate the cubic by circular arcs.
+    ///
+    /// Returns a list of ``Arc`` objects, joined end to end with matching
+    /// tangents, which stay within ``tolerance`` of the cubic. The cubic is
+    /// split at its inflections and fitted with pairs of arcs ("biarcs"),
+    /// which are subdivided further until they are close enough. Parts of
+    /// the cubic which are straight to within ``tolerance`` become ``Line``
+    /// objects instead, as an arc cannot have an infinite radius.
+    ///
+    /// This is useful for output formats which only support lines and arcs,
+    /// such as G-code for CNC machines.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn to_biarcs(&self, tolerance: f64) -> PyResult<Vec<crate::biarc::Piece>> {
+        if !(tolerance.is_finite() && tolerance > 0.0) {
+            return Err(PyValueError::new_err("tolerance must be positive"));
+        }
+        Ok(crate::biarc::cubic_to_arcs(self.0, tolerance))
     }
+
     /// Determine the inflection points.
     ///
     /// Return value is t parameter for the inflection points of the curve segment.
@@ -57,6 +114,31 @@ impl CubicBez {
         self.0.inflections().to_vec()
     }
 
+    /// The greatest distance of the control points from the chord (the line
+    /// segment from ``p0`` to ``p3``).
+    ///
+    /// The curve lies entirely within this distance of the chord, so this
+    /// is an upper bound on how far it strays from a straight line.
+    ///
+    /// Note that this method is not in original kurbo
+    fn flatness(&self) -> f64 {
+        let p = self.0;
+        crate::cleanup::flatness(&[p.p0, p.p1, p.p2, p.p3])
+    }
+
+    /// Whether the cubic is a straight line to within ``tolerance``, that is,
+    /// whether it could be replaced by a ``Line`` from ``p0`` to ``p3``
+    /// without moving any part of it further than ``tolerance``.
+    ///
+    /// A curve whose control points lie on the line through its ends but
+    /// beyond them doubles back on itself, and is not considered linear.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn is_linear(&self, tolerance: f64) -> bool {
+        self.flatness() <= tolerance
+    }
+
     #[getter]
     fn get_p0(&self) -> Point {
         self.0.p0.into()
@@ -74,23 +156,28 @@ impl CubicBez {
         self.0.p3.into()
     }
     #[setter]
-    fn set_p0(&mut self, p0: Point) {
+    fn set_p0(&mut self, p0: PointLike) {
         self.0.p0 = p0.0;
     }
     #[setter]
-    fn set_p1(&mut self, p1: Point) {
+    fn set_p1(&mut self, p1: PointLike) {
         self.0.p1 = p1.0;
     }
     #[setter]
-    fn set_p2(&mut self, p2: Point) {
+    fn set_p2(&mut self, p2: PointLike) {
         self.0.p2 = p2.0;
     }
     #[setter]
-    fn set_p3(&mut self, p3: Point) {
+    fn set_p3(&mut self, p3: PointLike) {
         self.0.p3 = p3.0;
     }
+
+    fn __repr__(&self) -> String {
+        crate::repr::cubic(self.0)
+    }
 }
 impl_isfinitenan!(CubicBez);
+impl_serialize!(CubicBez);
 impl_paramcurve!(CubicBez);
 impl_paramcurvearclen!(CubicBez);
 impl_paramcurvearea!(CubicBez);
@@ -98,4 +185,7 @@ impl_paramcurvecurvature!(CubicBez);
 impl_paramcurvederiv!(CubicBez, QuadBez);
 impl_paramcurveextrema!(CubicBez);
 impl_paramcurvenearest!(CubicBez);
+impl_paramcurveproject!(CubicBez);
 impl_shape_no_bounding_box!(CubicBez);
+impl_round_coordinates!(CubicBez);
+impl_approx_eq!(CubicBez);
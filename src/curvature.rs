@@ -0,0 +1,91 @@
+// Local maxima of the absolute curvature of a segment. A quadratic has at
+// most one, where its speed is least, which can be found directly. For a
+// cubic the absolute curvature is sampled, and each sample which is larger
+// than its neighbours is refined by golden section search.
+use crate::polyline::cross;
+use kurbo::{ParamCurve, ParamCurveDeriv, PathSeg as KPathSeg, Vec2};
+
+/// The number of intervals at which a cubic's curvature is sampled.
+const SAMPLES: usize = 64;
+
+/// The absolute curvature of a curve with first and second derivatives
+/// `d1` and `d2`, which is infinite where the curve stops.
+fn abs_curvature(d1: Vec2, d2: Vec2) -> f64 {
+    let speed3 = d1.hypot().powi(3);
+    if speed3 == 0.0 {
+        return f64::INFINITY;
+    }
+    cross(d1, d2).abs() / speed3
+}
+
+/// Find the parameter values inside the segment at which the absolute
+/// curvature is locally greatest, in increasing order.
+pub(crate) fn curvature_extrema(seg: KPathSeg) -> Vec<f64> {
+    match seg {
+        KPathSeg::Line(_) => vec![],
+        KPathSeg::Quad(q) => {
+            // The derivative is d0 + t * dd, and the cross product of the
+            // derivatives is constant, so the curvature is greatest where
+            // the speed is least.
+            let d0 = 2.0 * (q.p1 - q.p0);
+            let dd = 2.0 * (q.p2.to_vec2() - 2.0 * q.p1.to_vec2() + q.p0.to_vec2());
+            if cross(d0, dd) == 0.0 {
+                return vec![];
+            }
+            let t = -d0.dot(dd) / dd.hypot2();
+            if t > 0.0 && t < 1.0 {
+                vec![t]
+            } else {
+                vec![]
+            }
+        }
+        KPathSeg::Cubic(c) => {
+            let d1 = c.deriv();
+            let d2 = d1.deriv();
+            let k = |t: f64| abs_curvature(d1.eval(t).to_vec2(), d2.eval(t).to_vec2());
+            let samples: Vec<f64> = (0..=SAMPLES)
+                .map(|i| k(i as f64 / SAMPLES as f64))
+                .collect();
+            let mut result: Vec<f64> = vec![];
+            for i in 1..SAMPLES {
+                if samples[i] == 0.0 || samples[i] < samples[i - 1] || samples[i] <= samples[i + 1]
+                {
+                    continue;
+                }
+                let t = golden_section_max(
+                    k,
+                    (i - 1) as f64 / SAMPLES as f64,
+                    (i + 1) as f64 / SAMPLES as f64,
+                );
+                if t > 0.0 && t < 1.0 && result.last().map_or(true, |&last| t - last > 1e-9) {
+                    result.push(t);
+                }
+            }
+            result
+        }
+    }
+}
+
+/// Find the maximum of `f` between `a` and `b`, assuming it has only one.
+fn golden_section_max(f: impl Fn(f64) -> f64, mut a: f64, mut b: f64) -> f64 {
+    const INV_PHI: f64 = 0.618_033_988_749_895;
+    let mut c = b - INV_PHI * (b - a);
+    let mut d = a + INV_PHI * (b - a);
+    let (mut fc, mut fd) = (f(c), f(d));
+    while b - a > 1e-12 {
+        if fc >= fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - INV_PHI * (b - a);
+            fc = f(c);
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + INV_PHI * (b - a);
+            fd = f(d);
+        }
+    }
+    0.5 * (a + b)
+}
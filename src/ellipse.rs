@@ -1,10 +1,13 @@
+use crate::impl_serialize;
 use crate::affine::Affine;
+use crate::line::Line;
 use crate::{impl_isfinitenan, impl_shape};
-use crate::point::Point;
+use crate::point::{Point, PointLike};
 use crate::rect::Rect;
-use crate::vec2::Vec2;
+use crate::subclass::{new_like, new_of_type};
+use crate::vec2::{Vec2, Vec2Like};
 
-use kurbo::{Ellipse as KEllipse, Shape};
+use kurbo::{Affine as KAffine, Line as KLine, Ellipse as KEllipse, Shape};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -19,11 +22,21 @@ impl From<KEllipse> for Ellipse {
     }
 }
 
+impl Ellipse {
+    /// The transform which maps the unit circle onto this ellipse.
+    fn unit_transform(&self) -> KAffine {
+        let (radii, rotation) = self.0.radii_and_rotation();
+        KAffine::translate(self.0.center().to_vec2())
+            * KAffine::rotate(rotation)
+            * KAffine::scale_non_uniform(radii.x, radii.y)
+    }
+}
+
 #[pymethods]
 impl Ellipse {
     /// Create a new `Ellipse`.
     #[new]
-    pub fn __new__(center: Point, radii: Vec2, x_rotation: f64) -> Self {
+    pub fn __new__(center: PointLike, radii: Vec2Like, x_rotation: f64) -> Self {
         Self(KEllipse::new(center.0, radii.0, x_rotation))
     }
     #[getter]
@@ -54,24 +67,24 @@ impl Ellipse {
     /// [`with_rotation`] with the result.
     ///
     /// [`with_rotation`]: Ellipse::with_rotation    #[pyo3(text_signature = "(cls, p0, p1)")]
-    fn from_rect(_cls: &Bound<'_, PyType>, rect: Rect) -> Ellipse {
-        Ellipse(KEllipse::from_rect(rect.0))
+    fn from_rect(cls: &Bound<'_, PyType>, rect: Rect) -> PyResult<Py<Self>> {
+        new_of_type(cls, Ellipse(KEllipse::from_rect(rect.0)))
     }
 
     #[classmethod]
     /// Create an ellipse from an affine transformation of the unit circle.
-    pub fn from_affine(_cls: &Bound<'_, PyType>, affine: Affine) -> Self {
-        Ellipse(KEllipse::from_affine(affine.0))
+    pub fn from_affine(cls: &Bound<'_, PyType>, affine: Affine) -> PyResult<Py<Self>> {
+        new_of_type(cls, Ellipse(KEllipse::from_affine(affine.0)))
     }
 
     /// Create a new `Ellipse` centered on the provided point.
-    pub fn with_center(&self, new_center: Point) -> Ellipse {
-        Ellipse(self.0.with_center(new_center.0))
+    pub fn with_center(slf: &Bound<'_, Self>, new_center: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, Ellipse(slf.borrow().0.with_center(new_center.0)))
     }
 
     /// Create a new `Ellipse` with the provided radii.
-    pub fn with_radii(&self, new_radii: Vec2) -> Ellipse {
-        Ellipse(self.0.with_radii(new_radii.0))
+    pub fn with_radii(slf: &Bound<'_, Self>, new_radii: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Ellipse(slf.borrow().0.with_radii(new_radii.0)))
     }
 
     /// Create a new `Ellipse`, with the rotation replaced by `rotation`
@@ -79,19 +92,70 @@ impl Ellipse {
     ///
     /// The rotation is clockwise, for a y-down coordinate system. For more
     /// on rotation, See [`Affine::rotate`].
-    fn with_rotation(&self, rotation: f64) -> Ellipse {
-        Ellipse(self.0.with_rotation(rotation))
+    fn with_rotation(slf: &Bound<'_, Self>, rotation: f64) -> PyResult<Py<Self>> {
+        new_like(slf, Ellipse(slf.borrow().0.with_rotation(rotation)))
+    }
+
+    /// The lines from ``pt`` which touch the ellipse.
+    ///
+    /// Returns a list of ``(line, point)`` tuples, where ``line`` runs from
+    /// ``pt`` to ``point``, the point at which it touches the ellipse. There are
+    /// two tangents from a point outside the ellipse, none from a point inside it,
+    /// and a single zero-length line from a point on it.
+    ///
+    /// Note that this method is not in original kurbo
+    fn tangent_lines(&self, pt: PointLike) -> Vec<(Line, Point)> {
+        crate::conic::ellipse_tangents(self.unit_transform(), pt.0)
+            .into_iter()
+            .map(|p| (Line(KLine::new(pt.0, p)), Point(p)))
+            .collect()
+    }
+
+    /// Find where a line crosses the ellipse.
+    ///
+    /// Returns a list of up to two ``(point, t, angle)`` tuples, in order
+    /// along the line, where ``t`` is the parameter of the point on the line
+    /// and ``angle`` is its parameter on the ellipse: the point is at
+    /// ``(rx * cos(angle), ry * sin(angle))``, rotated by the ellipse's
+    /// rotation and moved to its center, as for :py:class:`Arc` angles.
+    /// Only the points within the line segment are returned. The line is
+    /// transformed so that the ellipse becomes the unit circle, intersected
+    /// with it, and the results transformed back.
+    ///
+    /// Note that this method is not in original kurbo
+    fn intersect_line(&self, line: &Line) -> Vec<(Point, f64, f64)> {
+        crate::conic::ellipse_line(self.unit_transform(), line.0)
+            .into_iter()
+            .map(|(p, t, angle)| (Point(p), t, angle))
+            .collect()
     }
 
     #[allow(non_snake_case)]
-    fn __add__(&self, rhs: Vec2) -> Ellipse {
-        Ellipse(self.0 + rhs.0)
+    fn __add__(slf: &Bound<'_, Self>, rhs: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Ellipse(slf.borrow().0 + rhs.0))
     }
     #[allow(non_snake_case)]
-    fn __sub__(&self, rhs: Vec2) -> Ellipse {
-        Ellipse(self.0 + rhs.0)
+    fn __sub__(slf: &Bound<'_, Self>, rhs: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, Ellipse(slf.borrow().0 + rhs.0))
+    }
+
+    /// Support ``point in shape``, which is the same as ``contains``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __contains__(&self, pt: PointLike) -> bool {
+        self.contains(pt)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Ellipse({}, {}, {})",
+            crate::repr::point(self.0.center()),
+            crate::repr::vec2(self.0.radii()),
+            crate::repr::float(self.0.rotation())
+        )
     }
 }
 
 impl_isfinitenan!(Ellipse);
+impl_serialize!(Ellipse);
 impl_shape!(Ellipse);
@@ -0,0 +1,192 @@
+// Interpolation with Euler spirals (clothoids), curves whose curvature
+// changes linearly with arc length. A tangent direction is chosen at each
+// sample from the circle through it and its neighbours, and consecutive
+// samples are joined by the Euler spiral segment which matches the tangents
+// at both ends. kurbo's curve fitting then converts each segment to cubics.
+use crate::bezpath::BezPath;
+use crate::point::PointLike;
+use kurbo::common::GAUSS_LEGENDRE_COEFFS_32;
+use kurbo::{
+    fit_to_bezpath, BezPath as KBezPath, CurveFitSample, ParamCurveFit, PathEl as KPathEl,
+    Point as KPoint, Vec2,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::f64::consts::{PI, TAU};
+use std::ops::Range;
+
+/// An Euler spiral segment from `p0` to `p1`.
+///
+/// In its own frame the segment has unit length and starts in the direction
+/// of the x axis, with its tangent at arc length `u` at angle
+/// `a * u + b * u * u / 2`. `frame` is the complex number which takes the
+/// chord of that curve to the chord from `p0` to `p1`.
+struct EulerSeg {
+    p0: KPoint,
+    a: f64,
+    b: f64,
+    frame: Vec2,
+}
+
+/// Multiply two vectors as complex numbers.
+fn complex_mul(v: Vec2, w: Vec2) -> Vec2 {
+    Vec2::new(v.x * w.x - v.y * w.y, v.x * w.y + v.y * w.x)
+}
+
+/// Wrap an angle into the range -π..π.
+fn wrap(angle: f64) -> f64 {
+    (angle + PI).rem_euclid(TAU) - PI
+}
+
+/// The position at arc length `u` along the spiral with parameters `a` and
+/// `b`, in its own frame.
+fn integrate(a: f64, b: f64, u: f64) -> Vec2 {
+    let mut sum = Vec2::ZERO;
+    for &(w, x) in GAUSS_LEGENDRE_COEFFS_32 {
+        let s = 0.5 * u * (x + 1.0);
+        sum += w * Vec2::from_angle(a * s + 0.5 * b * s * s);
+    }
+    sum * (0.5 * u)
+}
+
+impl EulerSeg {
+    /// Find the segment from `p0` to `p1` whose tangents make angles `th0`
+    /// and `th1` with the chord.
+    fn new(p0: KPoint, p1: KPoint, th0: f64, th1: f64) -> EulerSeg {
+        // The turning along the segment is a + b / 2 = th1 - th0, which
+        // leaves `a` to be chosen so that the chord makes an angle of -th0
+        // with the start tangent.
+        let turn = th1 - th0;
+        let error = |a: f64| wrap(integrate(a, 2.0 * (turn - a), 1.0).atan2() + th0);
+        // Approximating the angle of the chord by the mean tangent angle
+        // gives the starting estimate.
+        let mut a0 = -4.0 * th0 - 2.0 * th1;
+        let mut a1 = a0 + 1e-3;
+        let (mut e0, mut e1) = (error(a0), error(a1));
+        for _ in 0..32 {
+            if e1.abs() < 1e-12 || e1 == e0 {
+                break;
+            }
+            let a2 = a1 - e1 * (a1 - a0) / (e1 - e0);
+            (a0, e0) = (a1, e1);
+            a1 = a2;
+            e1 = error(a1);
+        }
+        let (a, b) = (a1, 2.0 * (turn - a1));
+        let chord = integrate(a, b, 1.0);
+        let target = p1 - p0;
+        let frame = complex_mul(target, Vec2::new(chord.x, -chord.y)) / chord.hypot2();
+        EulerSeg { p0, a, b, frame }
+    }
+}
+
+impl ParamCurveFit for EulerSeg {
+    fn sample_pt_tangent(&self, t: f64, _sign: f64) -> CurveFitSample {
+        let (p, tangent) = self.sample_pt_deriv(t);
+        CurveFitSample { p, tangent }
+    }
+
+    fn sample_pt_deriv(&self, t: f64) -> (KPoint, Vec2) {
+        let p = self.p0 + complex_mul(self.frame, integrate(self.a, self.b, t));
+        let deriv = complex_mul(
+            self.frame,
+            Vec2::from_angle(self.a * t + 0.5 * self.b * t * t),
+        );
+        (p, deriv)
+    }
+
+    fn break_cusp(&self, _: Range<f64>) -> Option<f64> {
+        None
+    }
+}
+
+/// The direction of the circle through `p`, `q` and `r` at `p`, heading
+/// towards `q`, or of the line through them if they are collinear.
+fn circle_tangent(p: KPoint, q: KPoint, r: KPoint) -> Vec2 {
+    let (u, w) = (q - p, r - p);
+    u * w.hypot2() - w * u.hypot2()
+}
+
+/// Interpolate `points` with Euler spiral segments, each converted to
+/// cubics to within `accuracy`.
+pub(crate) fn fit_euler_path(points: &[KPoint], accuracy: f64, closed: bool) -> KBezPath {
+    let mut pts: Vec<KPoint> = points.to_vec();
+    pts.dedup();
+    if closed && pts.len() > 1 && pts.first() == pts.last() {
+        pts.pop();
+    }
+    let mut path = KBezPath::new();
+    let n = pts.len();
+    let Some(&first) = pts.first() else {
+        return path;
+    };
+    path.move_to(first);
+    if n == 1 {
+        return path;
+    }
+    let tangents: Vec<Vec2> = (0..n)
+        .map(|i| {
+            if closed || (i > 0 && i + 1 < n) {
+                let prev = pts[(i + n - 1) % n];
+                let next = pts[(i + 1) % n];
+                circle_tangent(pts[i], next, prev)
+            } else if n == 2 {
+                pts[1] - pts[0]
+            } else if i == 0 {
+                circle_tangent(pts[0], pts[1], pts[2])
+            } else {
+                -circle_tangent(pts[i], pts[i - 1], pts[i - 2])
+            }
+        })
+        .collect();
+    let count = if closed { n } else { n - 1 };
+    for i in 0..count {
+        let j = (i + 1) % n;
+        let (p0, p1) = (pts[i], pts[j]);
+        let chord = (p1 - p0).atan2();
+        let th0 = wrap(tangents[i].atan2() - chord);
+        let th1 = wrap(tangents[j].atan2() - chord);
+        if th0.abs() < 1e-12 && th1.abs() < 1e-12 {
+            path.line_to(p1);
+            continue;
+        }
+        let seg = EulerSeg::new(p0, p1, th0, th1);
+        for el in fit_to_bezpath(&seg, accuracy).elements() {
+            if !matches!(el, KPathEl::MoveTo(_)) {
+                path.push(*el);
+            }
+        }
+    }
+    if closed {
+        path.close_path();
+    }
+    path
+}
+
+/// Interpolate a list of points with a smooth curve made of Euler spirals.
+///
+/// An Euler spiral (or clothoid) is a curve whose curvature changes
+/// linearly along its length, which makes it the smoothest way of joining
+/// two directions; they are used to lay out roads and railway tracks. The
+/// curve passes through each point in ``samples`` in order, with a tangent
+/// taken from the circle through the point and its neighbours, and each
+/// pair of consecutive points is joined by the Euler spiral segment which
+/// matches those tangents. The segments are then converted to cubic Béziers
+/// staying within ``tolerance`` of the spirals.
+///
+/// If ``closed`` is true, the last point is joined back to the first and the
+/// path is closed.
+///
+/// Returns a ``BezPath``. Raises ``ValueError`` if ``tolerance`` is not
+/// positive.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (samples, tolerance=crate::DEFAULT_TOLERANCE, closed=false))]
+pub fn fit_euler(samples: Vec<PointLike>, tolerance: f64, closed: bool) -> PyResult<BezPath> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(PyValueError::new_err("tolerance must be positive"));
+    }
+    let points: Vec<KPoint> = samples.into_iter().map(|p| p.0).collect();
+    Ok(fit_euler_path(&points, tolerance, closed).into())
+}
@@ -0,0 +1,98 @@
+use kurbo::{
+    BezPath as KBezPath, ParamCurve, ParamCurveArclen, ParamCurveNearest, PathSeg as KPathSeg,
+    Point as KPoint, Rect as KRect, Shape,
+};
+
+/// The distance from a point to the nearest point of a rectangle, which is
+/// zero if the point is inside it.
+fn point_rect_distance(p: KPoint, r: KRect) -> f64 {
+    let dx = (r.x0 - p.x).max(p.x - r.x1).max(0.0);
+    let dy = (r.y0 - p.y).max(p.y - r.y1).max(0.0);
+    dx.hypot(dy)
+}
+
+/// A path prepared for repeated distance queries.
+pub(crate) struct Target {
+    segs: Vec<(KPathSeg, KRect)>,
+    accuracy: f64,
+}
+
+impl Target {
+    pub(crate) fn new(path: &KBezPath, accuracy: f64) -> Self {
+        Target {
+            segs: path.segments().map(|s| (s, s.bounding_box())).collect(),
+            accuracy,
+        }
+    }
+
+    /// The distance from `p` to the nearest point of the target, except that
+    /// once it is known to be at most `cutoff`, the search stops and an upper
+    /// bound no greater than `cutoff` is returned.
+    pub(crate) fn distance(&self, p: KPoint, cutoff: f64) -> f64 {
+        let mut bounds: Vec<(f64, usize)> = self
+            .segs
+            .iter()
+            .enumerate()
+            .map(|(ix, (_, r))| (point_rect_distance(p, *r), ix))
+            .collect();
+        bounds.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut best = f64::INFINITY;
+        for (bound, ix) in bounds {
+            if bound >= best {
+                break;
+            }
+            let d = self.segs[ix].0.nearest(p, self.accuracy).distance_sq.sqrt();
+            best = best.min(d);
+            if best <= cutoff {
+                break;
+            }
+        }
+        best
+    }
+}
+
+/// The greatest distance from a point of `a` to the nearest point of `b`.
+///
+/// The distance to `b` changes no faster than the arc length along `a`, so
+/// the greatest distance over a span of `a` whose ends are `d0` and `d1` from
+/// `b` and whose length is `len` is at most `(d0 + d1 + len) / 2`. Spans are
+/// bisected until that bound is within `accuracy` of the best distance found
+/// so far, and spans which cannot improve on it are discarded.
+fn directed(a: &KBezPath, b: &Target, accuracy: f64) -> f64 {
+    let segs: Vec<KPathSeg> = a.segments().collect();
+    let mut best: f64 = 0.0;
+    let mut ends = Vec::with_capacity(segs.len());
+    for seg in &segs {
+        let d0 = b.distance(seg.start(), best);
+        let d1 = b.distance(seg.end(), best);
+        best = best.max(d0).max(d1);
+        ends.push((d0, d1));
+    }
+    let mut stack: Vec<(KPathSeg, f64, f64, f64, f64)> = segs
+        .iter()
+        .zip(ends)
+        .map(|(seg, (d0, d1))| (*seg, 0.0, 1.0, d0, d1))
+        .collect();
+    while let Some((seg, t0, t1, d0, d1)) = stack.pop() {
+        let len = seg.subsegment(t0..t1).arclen(accuracy);
+        if (d0 + d1 + len) / 2.0 <= best + accuracy || len <= accuracy {
+            continue;
+        }
+        let t = (t0 + t1) / 2.0;
+        let d = b.distance(seg.eval(t), best);
+        best = best.max(d);
+        stack.push((seg, t0, t, d0, d));
+        stack.push((seg, t, t1, d, d1));
+    }
+    best
+}
+
+/// The Hausdorff distance between two paths, to within `accuracy`.
+pub(crate) fn hausdorff_distance(a: &KBezPath, b: &KBezPath, accuracy: f64) -> f64 {
+    let (ta, tb) = (Target::new(a, accuracy), Target::new(b, accuracy));
+    match (ta.segs.is_empty(), tb.segs.is_empty()) {
+        (true, true) => 0.0,
+        (false, false) => directed(a, &tb, accuracy).max(directed(b, &ta, accuracy)),
+        _ => f64::MAX,
+    }
+}
@@ -1,7 +1,8 @@
-use crate::impl_isfinitenan;
+use crate::{impl_isfinitenan, impl_serialize};
 use crate::rect::Rect;
 use crate::size::Size;
-use kurbo::Insets as KInsets;
+use crate::subclass::{new_like, new_of_type};
+use kurbo::{Insets as KInsets, Size as KSize};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -82,6 +83,16 @@ use pyo3::types::PyType;
 /// assert insets2.x_value() == insets.x_value()
 /// assert insets2.y_value() == insets.y_value()
 /// ```
+///
+/// `Insets` can also be added to and subtracted from each other, scaled by
+/// a number, and added to a [`Size`] to give the size of a rectangle of that
+/// size once the insets are applied (these are not in original kurbo):
+///
+/// ```
+/// padding = Insets.uniform(2.) + Insets(1., 0., 1., 0.) * 2
+/// assert padding.x0 == 4.0
+/// assert (padding + Size(10., 10.)).width == 18.0
+/// ```
 pub struct Insets(pub KInsets);
 
 impl From<KInsets> for Insets {
@@ -134,19 +145,19 @@ impl Insets {
     #[classmethod]
     #[allow(non_snake_case)]
     /// Zeroed insets
-    fn ZERO(_cls: &Bound<'_, PyType>) -> Self {
-        Self(KInsets::ZERO)
+    fn ZERO(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KInsets::ZERO))
     }
 
     /// New uniform insets.
     #[classmethod]
-    fn uniform(_cls: &Bound<'_, PyType>, value: f64) -> Self {
-        Self(KInsets::uniform(value))
+    fn uniform(cls: &Bound<'_, PyType>, value: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KInsets::uniform(value)))
     }
     /// New insets with uniform values along each axis.
     #[classmethod]
-    pub fn uniform_xy(_cls: &Bound<'_, PyType>, x_value: f64, y_value: f64) -> Self {
-        Self(KInsets::uniform_xy(x_value, y_value))
+    pub fn uniform_xy(cls: &Bound<'_, PyType>, x_value: f64, y_value: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KInsets::uniform_xy(x_value, y_value)))
     }
 
     /// The total delta on the x-axis represented by these insets.
@@ -198,28 +209,93 @@ impl Insets {
     ///
     /// This is provided as a convenience for applications where negative insets
     /// are not meaningful.
-    pub fn nonnegative(&self) -> Insets {
-        self.0.nonnegative().into()
+    pub fn nonnegative(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.nonnegative().into())
+    }
+
+    fn __neg__(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, (-slf.borrow().0).into())
+    }
+
+    fn __add__(slf: PyRef<'_, Self>, rhs: &Bound<PyAny>) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let magic = PyModule::import_bound(py, "kurbopy.magic")?;
+            magic.getattr("magic_add")?.call1((slf, rhs))?.extract()
+        })
+    }
+
+    fn __sub__(slf: PyRef<'_, Self>, rhs: &Bound<PyAny>) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let magic = PyModule::import_bound(py, "kurbopy.magic")?;
+            magic.getattr("magic_sub")?.call1((slf, rhs))?.extract()
+        })
+    }
+
+    fn __mul__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        let KInsets { x0, y0, x1, y1 } = slf.borrow().0;
+        new_like(
+            slf,
+            Self(KInsets {
+                x0: x0 * rhs,
+                y0: y0 * rhs,
+                x1: x1 * rhs,
+                y1: y1 * rhs,
+            }),
+        )
+    }
+
+    fn __rmul__(slf: &Bound<'_, Self>, lhs: f64) -> PyResult<Py<Self>> {
+        Self::__mul__(slf, lhs)
     }
 
-    fn __neg__(&self) -> Self {
-        (-self.0).into()
+    fn __truediv__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        Self::__mul__(slf, 1.0 / rhs)
     }
 
-    fn __add__(&self, rhs: &Rect) -> Rect {
+    #[allow(non_snake_case)]
+    fn _add_Rect(&self, rhs: &Rect) -> Rect {
         (rhs.0 + self.0).into()
     }
 
-    fn __sub__(&self, rhs: &Rect) -> Rect {
+    #[allow(non_snake_case)]
+    fn _sub_Rect(&self, rhs: &Rect) -> Rect {
         (rhs.0 - self.0).into()
     }
 
-    fn __repr__(&self) -> String {
-        // format!("Insets{{ x0:{}, y0: {}, x1: {}, y1: {} }}", self.0.x0, self.0.y0, self.0.x1, self.0.y1)
-        format!("{:?}", self.0)
+    #[allow(non_snake_case)]
+    fn _add_Insets(slf: &Bound<'_, Self>, rhs: &Insets) -> PyResult<Py<Self>> {
+        let insets = slf.borrow().0;
+        new_like(
+            slf,
+            Self(KInsets {
+                x0: insets.x0 + rhs.0.x0,
+                y0: insets.y0 + rhs.0.y0,
+                x1: insets.x1 + rhs.0.x1,
+                y1: insets.y1 + rhs.0.y1,
+            }),
+        )
+    }
 
+    #[allow(non_snake_case)]
+    fn _sub_Insets(slf: &Bound<'_, Self>, rhs: &Insets) -> PyResult<Py<Self>> {
+        Self::_add_Insets(slf, &Insets(-rhs.0))
+    }
+
+    #[allow(non_snake_case)]
+    fn _add_Size(&self, rhs: &Size) -> Size {
+        KSize::new(
+            rhs.0.width + self.0.x_value(),
+            rhs.0.height + self.0.y_value(),
+        )
+        .into()
+    }
+
+    fn __repr__(&self) -> String {
+        let i = &self.0;
+        format!("Insets({})", crate::repr::floats(&[i.x0, i.y0, i.x1, i.y1]))
     }
 
 }
 
-impl_isfinitenan!(Insets);
\ No newline at end of file
+impl_isfinitenan!(Insets);
+impl_serialize!(Insets);
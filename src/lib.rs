@@ -1,44 +1,358 @@
+mod accuracy;
 mod affine;
+mod approx;
 mod arc;
+mod arclengths;
 mod bezpath;
+mod biarc;
+mod boolean;
+mod boundingellipse;
+mod buffer;
+mod canonical;
 mod circle;
+mod cleanup;
+mod clip;
 mod common;
+mod conic;
 mod constpoint;
+mod corners;
 mod cubicbez;
+mod curvature;
 mod ellipse;
+mod euler;
+mod hausdorff;
 mod insets;
 mod line;
+mod line2;
 mod magic;
+mod medial;
+mod minkowski;
 mod mindistance;
 mod nearest;
+mod overlaps;
 mod paramcurve;
 mod pathel;
 mod pathseg;
 mod point;
+mod polyline;
 mod quadbez;
+mod quantize;
 mod quadspline;
+mod ray;
 mod rect;
+mod repr;
+mod roundedrect;
+mod serialize;
 mod shape;
 mod size;
+mod subclass;
+mod svg;
+mod sweep;
+mod symmetry;
+mod tessellate;
 mod translatescale;
+mod varstroke;
 mod vec2;
 
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use std::cell::Cell;
+use std::sync::Mutex;
 
+/// The accuracy used by measurements (arc length, nearest point and so on)
+/// when none is given, unless changed with `set_default_accuracy`.
+pub(crate) const DEFAULT_ACCURACY: f64 = 1e-6;
+
+static ACCURACY: Mutex<f64> = Mutex::new(DEFAULT_ACCURACY);
+
+thread_local! {
+    /// The accuracy set for this thread by the `accuracy` context manager,
+    /// which takes precedence over `ACCURACY`.
+    static THREAD_ACCURACY: Cell<Option<f64>> = const { Cell::new(None) };
+}
+
+/// The accuracy currently used by measurements when none is given.
+pub(crate) fn default_accuracy() -> f64 {
+    THREAD_ACCURACY
+        .with(Cell::get)
+        .unwrap_or_else(|| *ACCURACY.lock().unwrap())
+}
+
+/// The tolerance used when approximating curves (flattening, conversion to
+/// paths and so on) when none is given.
+pub(crate) const DEFAULT_TOLERANCE: f64 = 0.1;
+
+/// Convert multiple cubic Bézier curves to quadratic splines.
+///
+/// Ensures that the resulting splines have the same number of control points,
+/// so that they remain interpolation compatible.
+///
+/// Rather than failing outright when one curve cannot be converted, this
+/// returns a :py:class:`QuadSplineConversion` describing which curves
+/// succeeded and which failed (and why); the remaining curves are still
+/// converted to compatible splines. If ``fallback`` is true and the
+/// remaining curves cannot share a spline order, each is instead converted
+/// independently at whatever order it needs, at the cost of compatibility.
 #[pyfunction]
-fn cubics_to_quadratic_splines(curves: Vec<cubicbez::CubicBez>, accuracy: f64) -> Option<Vec<crate::quadspline::QuadSpline>> {
+#[pyo3(signature = (curves, accuracy, fallback=false))]
+fn cubics_to_quadratic_splines(
+    curves: Vec<cubicbez::CubicBez>,
+    accuracy: f64,
+    fallback: bool,
+) -> quadspline::QuadSplineConversion {
     let kcurves: Vec<kurbo::CubicBez> = curves.iter().map(|x| x.0).collect();
-    kurbo::cubics_to_quadratic_splines(&kcurves, accuracy).map(|vecquads| {
-        vecquads.into_iter().map(|x| x.into()).collect()
-    })
+    // kurbo does not terminate on non-finite curves, so they must never
+    // reach it.
+    let all_finite = kcurves.iter().all(|c| c.is_finite());
+    if all_finite {
+        if let Some(splines) = kurbo::cubics_to_quadratic_splines(&kcurves, accuracy) {
+            return quadspline::QuadSplineConversion {
+                splines: splines.into_iter().map(|x| Some(x.into())).collect(),
+                failures: vec![],
+                compatible: true,
+            };
+        }
+    }
+    let mut individual: Vec<Option<kurbo::QuadSpline>> = vec![None; kcurves.len()];
+    let mut failures = vec![];
+    let mut good = vec![];
+    for (ix, curve) in kcurves.iter().enumerate() {
+        if !curve.is_finite() {
+            failures.push((ix, "curve has non-finite coordinates".to_string()));
+            continue;
+        }
+        individual[ix] = curve.approx_spline(accuracy);
+        if individual[ix].is_none() {
+            failures.push((ix, "curve cannot be approximated within accuracy".to_string()));
+        } else {
+            good.push(ix);
+        }
+    }
+    let mut splines: Vec<Option<quadspline::QuadSpline>> = vec![None; kcurves.len()];
+    let subset: Vec<kurbo::CubicBez> = good.iter().map(|&ix| kcurves[ix]).collect();
+    if let Some(shared) = kurbo::cubics_to_quadratic_splines(&subset, accuracy) {
+        for (&ix, spline) in good.iter().zip(shared) {
+            splines[ix] = Some(spline.into());
+        }
+        return quadspline::QuadSplineConversion {
+            splines,
+            failures,
+            compatible: true,
+        };
+    }
+    if fallback {
+        for &ix in &good {
+            splines[ix] = individual[ix].clone().map(|x| x.into());
+        }
+    } else {
+        for &ix in &good {
+            failures.push((ix, "no common spline order satisfies accuracy".to_string()));
+        }
+        failures.sort_by_key(|f| f.0);
+    }
+    quadspline::QuadSplineConversion {
+        splines,
+        failures,
+        compatible: false,
+    }
+}
+
+/// Simplify a polyline with the Douglas–Peucker algorithm.
+///
+/// Returns the subset of ``points`` needed for the simplified polyline to
+/// stay within ``epsilon`` of the original. The first and last points are
+/// always kept. Raises ``ValueError`` if ``epsilon`` is negative.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn simplify_polyline(points: Vec<point::PointLike>, epsilon: f64) -> PyResult<Vec<point::Point>> {
+    if !(epsilon.is_finite() && epsilon >= 0.0) {
+        return Err(PyValueError::new_err("epsilon must not be negative"));
+    }
+    let points: Vec<kurbo::Point> = points.into_iter().map(|p| p.0).collect();
+    Ok(polyline::simplify(&points, epsilon)
+        .into_iter()
+        .map(point::Point)
+        .collect())
+}
+
+/// Set the accuracy used by measurements (arc length, nearest point, inverse
+/// arc length and so on) when no ``accuracy`` argument is given. The setting
+/// is shared by all threads, except within a ``kurbopy.accuracy`` block.
+///
+/// To change the accuracy for a block of code in the current thread, use the
+/// ``kurbopy.accuracy`` context manager::
+///
+///    with kurbopy.accuracy(1e-3):
+///        length = path.perimeter()
+///
+/// Raises ``ValueError`` unless ``value`` is positive and finite.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn set_default_accuracy(value: f64) -> PyResult<()> {
+    if !(value.is_finite() && value > 0.0) {
+        return Err(PyValueError::new_err("accuracy must be positive"));
+    }
+    *ACCURACY.lock().unwrap() = value;
+    Ok(())
+}
+
+/// The accuracy used by measurements when no ``accuracy`` argument is
+/// given. This is ``DEFAULT_ACCURACY`` unless changed with
+/// ``set_default_accuracy`` or ``kurbopy.accuracy``.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn get_default_accuracy() -> f64 {
+    default_accuracy()
+}
+
+/// Set the accuracy used by measurements in the current thread, or stop
+/// overriding the shared default with ``None``, and return the previous
+/// setting for the thread. This backs the ``kurbopy.accuracy`` context
+/// manager.
+#[pyfunction]
+#[pyo3(name = "_set_thread_accuracy")]
+fn set_thread_accuracy(value: Option<f64>) -> PyResult<Option<f64>> {
+    if value.map_or(false, |v| !(v.is_finite() && v > 0.0)) {
+        return Err(PyValueError::new_err("accuracy must be positive"));
+    }
+    Ok(THREAD_ACCURACY.with(|a| a.replace(value)))
+}
+
+/// Find the bounding box of a set of points.
+///
+/// ``points`` may be a list of points or a NumPy array of shape ``(n, 2)``.
+/// Raises ``ValueError`` if there are no points.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn points_bbox(points: point::PointArray) -> PyResult<rect::Rect> {
+    let mut iter = points.points.iter();
+    let first = iter
+        .next()
+        .ok_or_else(|| PyValueError::new_err("no points given"))?;
+    let bbox = iter.fold(kurbo::Rect::from_points(*first, *first), |r, p| {
+        r.union_pt(*p)
+    });
+    Ok(rect::Rect(bbox))
+}
+
+/// Find the centroid (mean) of a set of points.
+///
+/// ``points`` may be a list of points or a NumPy array of shape ``(n, 2)``.
+/// Raises ``ValueError`` if there are no points.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn points_centroid(points: point::PointArray) -> PyResult<point::Point> {
+    if points.points.is_empty() {
+        return Err(PyValueError::new_err("no points given"));
+    }
+    Ok(point::Point(polyline::centroid(&points.points)))
+}
+
+/// Find the principal axes of a set of points: the directions in which
+/// they are most and least spread out about their centroid.
+///
+/// Returns ``[(major, variance), (minor, variance)]``, where ``major`` and
+/// ``minor`` are perpendicular unit ``Vec2`` objects and each ``variance``
+/// is the variance of the points along that axis. ``points`` may be a list
+/// of points or a NumPy array of shape ``(n, 2)``. Raises ``ValueError`` if
+/// there are no points.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn points_principal_axes(points: point::PointArray) -> PyResult<Vec<(vec2::Vec2, f64)>> {
+    if points.points.is_empty() {
+        return Err(PyValueError::new_err("no points given"));
+    }
+    Ok(polyline::principal_axes(&points.points)
+        .into_iter()
+        .map(|(axis, variance)| (vec2::Vec2(axis), variance))
+        .collect())
+}
+
+/// Find the smallest ellipse enclosing a set of points (the Löwner–John
+/// ellipse).
+///
+/// The result is found to within a tiny fraction of the smallest area, and
+/// always encloses every point. If all the points lie on a line, the
+/// ellipse is degenerate, with a second radius of zero. Non-finite points
+/// are ignored; raises ``ValueError`` if there are no finite points.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn min_bounding_ellipse(points: point::PointArray) -> PyResult<ellipse::Ellipse> {
+    boundingellipse::min_bounding_ellipse(&points.points)
+        .map(ellipse::Ellipse)
+        .ok_or_else(|| PyValueError::new_err("no finite points"))
+}
+
+/// The bounding box of a single shape, curve or point.
+fn bounding_box_of(ob: &Bound<'_, PyAny>) -> PyResult<kurbo::Rect> {
+    use kurbo::{ParamCurveExtrema, Shape};
+    macro_rules! try_types {
+        ($bbox:path: $($t:ty),*) => {
+            $(if let Ok(x) = ob.downcast::<$t>() {
+                return Ok($bbox(&x.borrow().0));
+            })*
+        };
+    }
+    try_types!(Shape::bounding_box: rect::Rect, arc::Arc, circle::Circle,
+        circle::CircleSegment, ellipse::Ellipse, roundedrect::RoundedRect);
+    try_types!(ParamCurveExtrema::bounding_box: line::Line, quadbez::QuadBez,
+        cubicbez::CubicBez, pathseg::PathSeg);
+    if let Ok(path) = ob.downcast::<bezpath::BezPath>() {
+        return Ok(path.borrow().path().bounding_box());
+    }
+    if let Ok(path) = ob.downcast::<bezpath::FrozenBezPath>() {
+        return Ok(path.get().0.bounding_box());
+    }
+    if let Ok(p) = ob.downcast::<point::Point>() {
+        let p = p.borrow().0;
+        return Ok(kurbo::Rect::from_points(p, p));
+    }
+    Err(PyTypeError::new_err(format!(
+        "cannot find the bounding box of {}",
+        ob.get_type().name()?
+    )))
+}
+
+/// Find the smallest rectangle enclosing all of the given shapes.
+///
+/// ``shapes`` may mix any of ``BezPath``, ``FrozenBezPath``, ``Rect``,
+/// ``RoundedRect``, ``Circle``, ``CircleSegment``, ``Ellipse``, ``Arc``,
+/// ``Line``, ``QuadBez``, ``CubicBez``, ``PathSeg`` and ``Point``. Raises
+/// ``ValueError`` if there are no shapes, and ``TypeError`` for anything
+/// else.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+fn union_bounding_box(shapes: &Bound<'_, PyAny>) -> PyResult<rect::Rect> {
+    let mut result: Option<kurbo::Rect> = None;
+    for shape in shapes.iter()? {
+        let bbox = bounding_box_of(&shape?)?;
+        result = Some(result.map_or(bbox, |r| r.union(bbox)));
+    }
+    result
+        .map(rect::Rect)
+        .ok_or_else(|| PyValueError::new_err("no shapes given"))
 }
 
 #[pymodule]
 fn kurbopy(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     register_child_module(m)?;
+    m.add("DEFAULT_ACCURACY", DEFAULT_ACCURACY)?;
+    m.add("DEFAULT_TOLERANCE", DEFAULT_TOLERANCE)?;
     m.add_class::<affine::Affine>()?;
     m.add_class::<arc::Arc>()?;
+    m.add_class::<arclengths::ArcLengths>()?;
     m.add_class::<bezpath::BezPath>()?;
+    m.add_class::<bezpath::FrozenBezPath>()?;
+    m.add_class::<bezpath::PathIntersection>()?;
+    m.add_class::<bezpath::PathLineIntersection>()?;
     m.add_class::<cubicbez::CubicBez>()?;
     m.add_class::<circle::Circle>()?;
     m.add_class::<circle::CircleSegment>()?;
@@ -46,6 +360,7 @@ fn kurbopy(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ellipse::Ellipse>()?;
     m.add_class::<insets::Insets>()?;
     m.add_class::<line::Line>()?;
+    m.add_class::<line2::Line2>()?;
     m.add_class::<mindistance::MinDistance>()?;
     m.add_class::<nearest::Nearest>()?;
     m.add_class::<pathseg::PathSeg>()?;
@@ -54,11 +369,33 @@ fn kurbopy(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<point::Point>()?;
     m.add_class::<quadbez::QuadBez>()?;
     m.add_class::<quadspline::QuadSpline>()?;
+    m.add_class::<quadspline::QuadSplineConversion>()?;
+    m.add_class::<ray::Ray>()?;
+    m.add_class::<ray::RayHit>()?;
     m.add_class::<rect::Rect>()?;
+    m.add_class::<roundedrect::RoundedRect>()?;
+    m.add_class::<roundedrect::RoundedRectRadii>()?;
     m.add_class::<size::Size>()?;
     m.add_class::<translatescale::TranslateScale>()?;
     m.add_class::<vec2::Vec2>()?;
     m.add_function(wrap_pyfunction!(cubics_to_quadratic_splines, m)?)?;
+    m.add_function(wrap_pyfunction!(sweep::sweep, m)?)?;
+    m.add_function(wrap_pyfunction!(simplify_polyline, m)?)?;
+    m.add_function(wrap_pyfunction!(set_default_accuracy, m)?)?;
+    m.add_function(wrap_pyfunction!(get_default_accuracy, m)?)?;
+    m.add_function(wrap_pyfunction!(set_thread_accuracy, m)?)?;
+    m.add_function(wrap_pyfunction!(points_bbox, m)?)?;
+    m.add_function(wrap_pyfunction!(points_centroid, m)?)?;
+    m.add_function(wrap_pyfunction!(points_principal_axes, m)?)?;
+    m.add_function(wrap_pyfunction!(min_bounding_ellipse, m)?)?;
+    m.add_function(wrap_pyfunction!(union_bounding_box, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::distribute_along_path, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::match_paths, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::minkowski_sum, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::intersection_area, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::iou, m)?)?;
+    m.add_function(wrap_pyfunction!(bezpath::split_at_intersections, m)?)?;
+    m.add_function(wrap_pyfunction!(euler::fit_euler, m)?)?;
     Ok(())
 }
 
@@ -72,6 +409,21 @@ fn register_child_module(parent_module: &Bound<'_, PyModule>) -> PyResult<()> {
     child_module.add_function(wrap_pyfunction!(common::solve_itp, &child_module)?)?;
     child_module.add_function(wrap_pyfunction!(common::solve_quadratic, &child_module)?)?;
     child_module.add_function(wrap_pyfunction!(common::solve_quartic, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction!(common::solve_poly, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction!(
+        common::gauss_legendre_coeffs,
+        &child_module
+    )?)?;
+    child_module.add_function(wrap_pyfunction!(common::gauss_quadrature, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction!(common::bernstein_basis, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction!(common::elevate_degree, &child_module)?)?;
+    child_module.add_function(wrap_pyfunction!(common::split_bezier, &child_module)?)?;
+    for (order, table) in common::GAUSS_LEGENDRE_TABLES {
+        child_module.add(
+            format!("GAUSS_LEGENDRE_COEFFS_{}", order).as_str(),
+            table.to_vec(),
+        )?;
+    }
     parent_module.add_submodule(&child_module)?;
     Ok(())
 }
@@ -1,15 +1,21 @@
+use crate::impl_serialize;
 use crate::constpoint::ConstPoint;
 use crate::nearest::Nearest;
-use crate::point::Point;
-use crate::vec2::Vec2;
+use crate::point::{Point, PointLike};
+use crate::polyline::clip_segment;
+use crate::rect::Rect;
+use crate::subclass::new_like;
+use crate::vec2::Vec2Like;
 use crate::{
-    impl_isfinitenan, impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurvecurvature, impl_paramcurvederiv, impl_paramcurveextrema, impl_paramcurvenearest
+    impl_approx_eq, impl_isfinitenan, impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurvecurvature, impl_paramcurvederiv, impl_paramcurveextrema, impl_paramcurvenearest,
+    impl_round_coordinates,
 };
 
 use kurbo::{
     Line as KLine, ParamCurve, ParamCurveArclen, ParamCurveArea, ParamCurveCurvature,
-    ParamCurveDeriv, ParamCurveExtrema, ParamCurveNearest,
+    ParamCurveDeriv, ParamCurveExtrema, ParamCurveNearest, Vec2 as KVec2,
 };
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 #[pyclass(subclass, module = "kurbopy")]
@@ -22,10 +28,20 @@ impl From<KLine> for Line {
         Self(p)
     }
 }
+
+impl Line {
+    fn unit_direction(&self) -> PyResult<KVec2> {
+        let length = self.0.length();
+        if length == 0.0 || !length.is_finite() {
+            return Err(PyValueError::new_err("line has no direction"));
+        }
+        Ok((self.0.p1 - self.0.p0) / length)
+    }
+}
 #[pymethods]
 impl Line {
     #[new]
-    fn __new__(p0: Point, p1: Point) -> Self {
+    fn __new__(p0: PointLike, p1: PointLike) -> Self {
         Line(KLine::new(p0.0, p1.0))
     }
 
@@ -47,25 +63,99 @@ impl Line {
         self.0.p1.into()
     }
     #[setter]
-    fn set_p0(&mut self, p0: Point) {
+    fn set_p0(&mut self, p0: PointLike) {
         self.0.p0 = p0.0;
     }
     #[setter]
-    fn set_p1(&mut self, p1: Point) {
+    fn set_p1(&mut self, p1: PointLike) {
         self.0.p1 = p1.0;
     }
 
     #[allow(non_snake_case)]
-    fn __add__(&self, rhs: Vec2) -> PyResult<Line> {
+    fn __add__(&self, rhs: Vec2Like) -> PyResult<Line> {
         let p: Line = (self.0 + rhs.0).into();
         Ok(p)
     }
 
     #[allow(non_snake_case)]
-    fn __sub__(&self, rhs: Vec2) -> PyResult<Line> {
+    fn __sub__(&self, rhs: Vec2Like) -> PyResult<Line> {
         let p: Line = (self.0 - rhs.0).into();
         Ok(p)
     }
+
+    /// A line parallel to this one, at a signed distance ``d``. Positive
+    /// distances are to the left of the line's direction in a y-up
+    /// coordinate system (to the right in y-down).
+    ///
+    /// Note that this method is not in original kurbo
+    fn offset(slf: &Bound<'_, Self>, d: f64) -> PyResult<Py<Self>> {
+        let line = slf.borrow();
+        let dir = line.unit_direction()?;
+        let normal = KVec2::new(-dir.y, dir.x);
+        new_like(slf, (line.0 + normal * d).into())
+    }
+
+    /// A line perpendicular to this one, starting at the point at parameter
+    /// ``t`` and heading in the direction of a positive ``offset``. Its length
+    /// defaults to the length of this line.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (t, length=None))]
+    fn perpendicular_at(slf: &Bound<'_, Self>, t: f64, length: Option<f64>) -> PyResult<Py<Self>> {
+        let line = slf.borrow();
+        let dir = line.unit_direction()?;
+        let start = line.0.eval(t);
+        let length = length.unwrap_or_else(|| line.0.length());
+        new_like(slf, KLine::new(start, start + KVec2::new(-dir.y, dir.x) * length).into())
+    }
+
+    /// A line lengthened by ``amount`` at each end, keeping its direction.
+    /// Negative amounts shorten the line.
+    ///
+    /// Note that this method is not in original kurbo
+    fn extend(slf: &Bound<'_, Self>, amount: f64) -> PyResult<Py<Self>> {
+        let line = slf.borrow();
+        let delta = line.unit_direction()? * amount;
+        new_like(slf, KLine::new(line.0.p0 - delta, line.0.p1 + delta).into())
+    }
+
+    /// Clip the line to a rectangle, returning the part of the line inside
+    /// the rectangle (including its edges), or ``None`` if the line lies
+    /// entirely outside it.
+    ///
+    /// Note that this method is not in original kurbo
+    fn clip(&self, rect: &Rect) -> Option<Line> {
+        clip_segment(self.0.p0, self.0.p1, rect.0).map(|(p0, p1)| KLine::new(p0, p1).into())
+    }
+
+    /// Project a point onto the line.
+    ///
+    /// Returns a ``(t, point, distance)`` tuple of the parameter value of
+    /// the nearest position on the line, the point there, and its distance
+    /// from the given point. If ``clamp`` is false, the line is treated as
+    /// extending for ever in both directions, so ``t`` may fall outside
+    /// ``[0, 1]``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (point, clamp=true))]
+    fn project(&self, point: PointLike, clamp: bool) -> (f64, Point, f64) {
+        let d = self.0.p1 - self.0.p0;
+        let len2 = d.hypot2();
+        let mut t = if len2 > 0.0 {
+            (point.0 - self.0.p0).dot(d) / len2
+        } else {
+            0.0
+        };
+        if clamp {
+            t = t.clamp(0.0, 1.0);
+        }
+        let foot = self.0.p0 + d * t;
+        (t, foot.into(), (point.0 - foot).hypot())
+    }
+
+    fn __repr__(&self) -> String {
+        crate::repr::line(self.0)
+    }
 }
 
 impl_paramcurve!(Line);
@@ -75,4 +165,7 @@ impl_paramcurvecurvature!(Line);
 impl_paramcurveextrema!(Line);
 impl_paramcurvenearest!(Line);
 impl_isfinitenan!(Line);
+impl_serialize!(Line);
+impl_round_coordinates!(Line);
+impl_approx_eq!(Line);
 impl_paramcurvederiv!(Line, ConstPoint);
\ No newline at end of file
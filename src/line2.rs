@@ -0,0 +1,217 @@
+use crate::bezpath::{BezPath, FrozenBezPath};
+use crate::cleanup::control_points;
+use crate::cubicbez::CubicBez;
+use crate::line::Line;
+use crate::pathseg::PathSeg;
+use crate::point::{Point, PointLike};
+use crate::polyline::cross;
+use crate::quadbez::QuadBez;
+use crate::subclass::new_of_type;
+use crate::vec2::{Vec2, Vec2Like};
+use kurbo::{Line as KLine, PathSeg as KPathSeg, Point as KPoint, Vec2 as KVec2};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+/// An infinite straight line, through ``point`` in the direction of
+/// ``direction``.
+///
+/// Unlike a :py:class:`Line`, which is a segment between two points, a
+/// ``Line2`` goes on for ever both ways. Positions along it are given by a
+/// parameter ``t``, so that ``eval(t)`` is ``point + direction * t``.
+///
+/// ::
+///
+///    from kurbopy import Line2, Rect
+///    axis = Line2.from_points((0, 0), (1, 1))
+///    axis.side_of((0, 5))  # 1: to the left
+///    axis.intersect(Rect(2, 0, 4, 10).to_path())  # [Point(2, 2), Point(4, 4)]
+///
+/// Note that this class is not in original kurbo
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct Line2 {
+    point: KPoint,
+    direction: KVec2,
+}
+
+impl Line2 {
+    fn new(point: KPoint, direction: KVec2) -> PyResult<Self> {
+        if !(point.is_finite() && direction.is_finite() && direction.hypot2() > 0.0) {
+            return Err(PyValueError::new_err(
+                "line needs a finite point and a finite, non-zero direction",
+            ));
+        }
+        Ok(Line2 { point, direction })
+    }
+
+    fn param(&self, p: KPoint) -> f64 {
+        (p - self.point).dot(self.direction) / self.direction.hypot2()
+    }
+
+    fn cross(&self, p: KPoint) -> f64 {
+        cross(self.direction, p - self.point)
+    }
+
+    /// The parameter values at which the line meets a segment.
+    fn segment_hits(&self, seg: KPathSeg) -> Vec<f64> {
+        let points = control_points(&seg);
+        // By the convex hull property, a segment whose control points all lie
+        // strictly on one side of the line cannot meet it.
+        if points.iter().all(|&p| self.cross(p) > 0.0)
+            || points.iter().all(|&p| self.cross(p) < 0.0)
+        {
+            return vec![];
+        }
+        let (lo, hi) = points
+            .iter()
+            .map(|&p| self.param(p))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), t| {
+                (lo.min(t), hi.max(t))
+            });
+        let margin = (hi - lo) + 1.0;
+        let (t0, t1) = (lo - margin, hi + margin);
+        let line = KLine::new(self.eval_point(t0), self.eval_point(t1));
+        seg.intersect_line(line)
+            .into_iter()
+            .map(|hit| t0 + (t1 - t0) * hit.line_t)
+            .collect()
+    }
+
+    fn eval_point(&self, t: f64) -> KPoint {
+        self.point + self.direction * t
+    }
+}
+
+/// The segments of something a line can be intersected with.
+fn segments_of(ob: &Bound<'_, PyAny>) -> PyResult<Vec<KPathSeg>> {
+    if let Ok(line) = ob.downcast::<Line>() {
+        return Ok(vec![KPathSeg::Line(line.borrow().0)]);
+    }
+    if let Ok(quad) = ob.downcast::<QuadBez>() {
+        return Ok(vec![KPathSeg::Quad(quad.borrow().0)]);
+    }
+    if let Ok(cubic) = ob.downcast::<CubicBez>() {
+        return Ok(vec![KPathSeg::Cubic(cubic.borrow().0)]);
+    }
+    if let Ok(seg) = ob.downcast::<PathSeg>() {
+        return Ok(vec![seg.borrow().0]);
+    }
+    if let Ok(path) = ob.downcast::<BezPath>() {
+        return Ok(path.borrow().path().segments().collect());
+    }
+    if let Ok(path) = ob.downcast::<FrozenBezPath>() {
+        return Ok(path.get().0.segments().collect());
+    }
+    Err(PyTypeError::new_err(format!(
+        "cannot intersect a Line2 with {}",
+        ob.get_type().name()?
+    )))
+}
+
+#[pymethods]
+impl Line2 {
+    /// Create a line through ``point`` in the direction of ``direction``.
+    /// Raises ``ValueError`` if the direction is zero.
+    #[new]
+    fn __new__(point: PointLike, direction: Vec2Like) -> PyResult<Self> {
+        Line2::new(point.0, direction.0)
+    }
+
+    /// Create the line through two points, with ``p0`` at ``t == 0`` and
+    /// ``p1`` at ``t == 1``. Raises ``ValueError`` if they are the same.
+    #[classmethod]
+    fn from_points(cls: &Bound<'_, PyType>, p0: PointLike, p1: PointLike) -> PyResult<Py<Self>> {
+        new_of_type(cls, Line2::new(p0.0, p1.0 - p0.0)?)
+    }
+
+    /// Create the line which extends a :py:class:`Line` segment for ever in
+    /// both directions. Raises ``ValueError`` if the segment has no length.
+    #[classmethod]
+    fn from_line(cls: &Bound<'_, PyType>, line: &Line) -> PyResult<Py<Self>> {
+        new_of_type(cls, Line2::new(line.0.p0, line.0.p1 - line.0.p0)?)
+    }
+
+    /// The point on the line at ``t == 0``.
+    #[getter]
+    fn point(&self) -> Point {
+        self.point.into()
+    }
+
+    /// The direction of the line, as given when it was created.
+    #[getter]
+    fn direction(&self) -> Vec2 {
+        Vec2(self.direction)
+    }
+
+    /// The point on the line at parameter value ``t``.
+    fn eval(&self, t: f64) -> Point {
+        self.eval_point(t).into()
+    }
+
+    /// The parameter value of the point on the line nearest to ``pt``.
+    fn param_of(&self, pt: PointLike) -> f64 {
+        self.param(pt.0)
+    }
+
+    /// The point on the line nearest to ``pt``.
+    fn project(&self, pt: PointLike) -> Point {
+        self.eval_point(self.param(pt.0)).into()
+    }
+
+    /// The distance from the line to ``pt``, positive if the point is to the
+    /// left of the line (looking along its direction in a y-up coordinate
+    /// system) and negative if it is to the right.
+    fn signed_distance(&self, pt: PointLike) -> f64 {
+        self.cross(pt.0) / self.direction.hypot()
+    }
+
+    /// Which side of the line ``pt`` is on: ``1`` for the left (looking
+    /// along its direction in a y-up coordinate system), ``-1`` for the
+    /// right, and ``0`` if it is on the line.
+    fn side_of(&self, pt: PointLike) -> i32 {
+        let c = self.cross(pt.0);
+        if c > 0.0 {
+            1
+        } else if c < 0.0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// Find the points where the line meets ``other``, which may be another
+    /// ``Line2``, a ``Line``, ``QuadBez``, ``CubicBez`` or ``PathSeg``, or a
+    /// ``BezPath`` or ``FrozenBezPath``.
+    ///
+    /// Returns a list of points ordered along the line. Where the line
+    /// passes through the join between two segments of a path, the point is
+    /// only reported once. Parallel lines, and segments lying along the
+    /// line, do not cross it at a single point and are not reported.
+    fn intersect(&self, other: &Bound<'_, PyAny>) -> PyResult<Vec<Point>> {
+        if let Ok(other) = other.downcast::<Line2>() {
+            let other = other.borrow();
+            let denom = cross(self.direction, other.direction);
+            if denom == 0.0 {
+                return Ok(vec![]);
+            }
+            let t = cross(other.point - self.point, other.direction) / denom;
+            return Ok(vec![self.eval_point(t).into()]);
+        }
+        let mut ts: Vec<f64> = segments_of(other)?
+            .into_iter()
+            .flat_map(|seg| self.segment_hits(seg))
+            .collect();
+        ts.sort_by(|a, b| a.total_cmp(b));
+        ts.dedup_by(|a, b| (*a - *b).abs() <= 1e-9 * a.abs().max(1.0));
+        Ok(ts.into_iter().map(|t| self.eval_point(t).into()).collect())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Line2({}, {})",
+            crate::repr::point(self.point),
+            crate::repr::vec2(self.direction)
+        )
+    }
+}
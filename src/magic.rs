@@ -13,8 +13,9 @@ macro_rules! polymorphic {
 
         $( 
             #[allow(non_snake_case)]
-            fn $method(&self, rhs: $rhs) -> $returns {
-            (self.0 + rhs.0).into()
+            fn $method(slf: &Bound<'_, Self>, rhs: $rhs) -> PyResult<PyObject> {
+            let result: $returns = (slf.borrow().0 + rhs.0).into();
+            $crate::subclass::into_py_like(slf, result)
         }
         )+
 
@@ -33,8 +34,9 @@ macro_rules! polymorphic {
 
         $( 
             #[allow(non_snake_case)]
-            fn $method(&self, rhs: $rhs) -> $returns {
-            (self.0 - rhs.0).into()
+            fn $method(slf: &Bound<'_, Self>, rhs: $rhs) -> PyResult<PyObject> {
+            let result: $returns = (slf.borrow().0 - rhs.0).into();
+            $crate::subclass::into_py_like(slf, result)
         }
         )+
 
@@ -52,8 +54,9 @@ macro_rules! polymorphic {
 
         $( 
             #[allow(non_snake_case)]
-            fn $method(&self, rhs: $rhs) -> $returns {
-            (self.0 * rhs.0).into()
+            fn $method(slf: &Bound<'_, Self>, rhs: $rhs) -> PyResult<PyObject> {
+            let result: $returns = (slf.borrow().0 * rhs.0).into();
+            $crate::subclass::into_py_like(slf, result)
         }
         )+
         }
@@ -0,0 +1,211 @@
+// Approximate medial axis extraction. The outline is sampled densely, and
+// the Voronoi diagram of the samples is found as the dual of their Delaunay
+// triangulation. The Voronoi edges which lie inside the shape, and which
+// separate samples from different parts of the outline, approximate the
+// medial axis.
+use crate::polyline::flatten_subpaths;
+use kurbo::{BezPath as KBezPath, Point as KPoint, Rect as KRect, Shape};
+use std::collections::{HashMap, HashSet};
+
+/// The most samples taken from an outline; the triangulation takes time
+/// quadratic in the number of samples.
+const MAX_SAMPLES: usize = 4000;
+
+struct Triangle {
+    vertices: [usize; 3],
+    center: KPoint,
+    radius2: f64,
+}
+
+impl Triangle {
+    fn new(points: &[KPoint], vertices: [usize; 3]) -> Self {
+        let [a, b, c] = vertices.map(|i| points[i]);
+        let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+        let center = if d == 0.0 {
+            // Collinear points: a circle which nothing can fall inside.
+            KPoint::new(f64::INFINITY, f64::INFINITY)
+        } else {
+            let (a2, b2, c2) = (a.to_vec2().hypot2(), b.to_vec2().hypot2(), c.to_vec2().hypot2());
+            KPoint::new(
+                (a2 * (b.y - c.y) + b2 * (c.y - a.y) + c2 * (a.y - b.y)) / d,
+                (a2 * (c.x - b.x) + b2 * (a.x - c.x) + c2 * (b.x - a.x)) / d,
+            )
+        };
+        Triangle {
+            vertices,
+            center,
+            radius2: (center - a).hypot2(),
+        }
+    }
+}
+
+/// Delaunay triangulation by the Bowyer–Watson algorithm. Triangles with
+/// a vertex index past the end of `samples` use the corners of a bounding
+/// triangle.
+fn triangulate(samples: &[KPoint], bounds: KRect) -> Vec<Triangle> {
+    let mut points = samples.to_vec();
+    let size = bounds.width().max(bounds.height()).max(1.0) * 20.0;
+    let c = bounds.center();
+    let n = points.len();
+    points.push(KPoint::new(c.x - size, c.y - size));
+    points.push(KPoint::new(c.x + size, c.y - size));
+    points.push(KPoint::new(c.x, c.y + size));
+    let mut triangles = vec![Triangle::new(&points, [n, n + 1, n + 2])];
+    for i in 0..n {
+        let p = points[i];
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+        triangles.retain(|t| {
+            if (p - t.center).hypot2() >= t.radius2 {
+                return true;
+            }
+            for k in 0..3 {
+                let (a, b) = (t.vertices[k], t.vertices[(k + 1) % 3]);
+                *edges.entry((a.min(b), a.max(b))).or_insert(0) += 1;
+            }
+            false
+        });
+        for ((a, b), count) in edges {
+            if count == 1 {
+                triangles.push(Triangle::new(&points, [a, b, i]));
+            }
+        }
+    }
+    triangles
+}
+
+fn find(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+/// Approximate the medial axis of the closed shape `path`, as polylines.
+/// The outline is sampled at most `spacing` apart.
+pub(crate) fn medial_axis(path: &KBezPath, spacing: f64) -> Vec<Vec<KPoint>> {
+    let lines = flatten_subpaths(path, spacing);
+    let perimeter: f64 = lines
+        .iter()
+        .map(|l| {
+            let n = l.points.len();
+            (0..n).map(|i| (l.points[(i + 1) % n] - l.points[i]).hypot()).sum::<f64>()
+        })
+        .sum();
+    let spacing = spacing.max(perimeter / MAX_SAMPLES as f64);
+    // Each sample remembers its subpath and position, so that neighbouring
+    // samples can be recognized.
+    let mut samples: Vec<KPoint> = vec![];
+    let mut origin: Vec<(usize, usize, usize)> = vec![];
+    for (sp, line) in lines.iter().enumerate() {
+        let mut pts = vec![];
+        let n = line.points.len();
+        for i in 0..n {
+            let (a, b) = (line.points[i], line.points[(i + 1) % n]);
+            let count = ((b - a).hypot() / spacing).ceil() as usize;
+            pts.extend((0..count).map(|k| a.lerp(b, k as f64 / count as f64)));
+        }
+        pts.dedup();
+        let len = pts.len();
+        for (i, p) in pts.into_iter().enumerate() {
+            samples.push(p);
+            origin.push((sp, i, len));
+        }
+    }
+    if samples.len() < 3 {
+        return vec![];
+    }
+    let triangles = triangulate(&samples, path.bounding_box());
+    let n = samples.len();
+    let neighbours = |a: usize, b: usize| {
+        let ((sa, ia, len), (sb, ib, _)) = (origin[a], origin[b]);
+        let d = ia.abs_diff(ib);
+        sa == sb && d.min(len - d) <= 2
+    };
+
+    // Pair up the triangles on either side of each Delaunay edge.
+    let mut sides: HashMap<(usize, usize), Vec<usize>> = HashMap::new();
+    for (ix, t) in triangles.iter().enumerate() {
+        if t.vertices.iter().any(|&v| v >= n) {
+            continue;
+        }
+        for k in 0..3 {
+            let (a, b) = (t.vertices[k], t.vertices[(k + 1) % 3]);
+            sides.entry((a.min(b), a.max(b))).or_default().push(ix);
+        }
+    }
+    let inside: Vec<bool> = triangles
+        .iter()
+        .map(|t| t.center.is_finite() && path.winding(t.center) != 0)
+        .collect();
+    let mut parent: Vec<usize> = (0..triangles.len()).collect();
+    let mut edges = vec![];
+    let eps = 1e-9 * path.bounding_box().size().max_side().max(1.0);
+    for ((a, b), ts) in sides {
+        if let [t1, t2] = ts[..] {
+            if !inside[t1] || !inside[t2] || neighbours(a, b) {
+                continue;
+            }
+            if (triangles[t1].center - triangles[t2].center).hypot() <= eps {
+                // Cocircular samples: the two triangles share a Voronoi vertex.
+                let (r1, r2) = (find(&mut parent, t1), find(&mut parent, t2));
+                parent[r1] = r2;
+            } else {
+                edges.push((t1, t2));
+            }
+        }
+    }
+
+    // Join the edges into polylines which run between the ends and branch
+    // points of the axis.
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for &(t1, t2) in &edges {
+        let (r1, r2) = (find(&mut parent, t1), find(&mut parent, t2));
+        if r1 != r2 {
+            adjacency.entry(r1).or_default().push(r2);
+            adjacency.entry(r2).or_default().push(r1);
+        }
+    }
+    for nbrs in adjacency.values_mut() {
+        nbrs.sort_unstable();
+        nbrs.dedup();
+    }
+    let center = |node: usize| triangles[node].center;
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut result = vec![];
+    let walk = |start: usize, next: usize, visited: &mut HashSet<(usize, usize)>| {
+        let mut line = vec![center(start)];
+        let (mut prev, mut node) = (start, next);
+        loop {
+            visited.insert((prev.min(node), prev.max(node)));
+            line.push(center(node));
+            let nbrs = &adjacency[&node];
+            if nbrs.len() != 2 {
+                break;
+            }
+            let after = if nbrs[0] == prev { nbrs[1] } else { nbrs[0] };
+            if visited.contains(&(node.min(after), node.max(after))) {
+                break;
+            }
+            (prev, node) = (node, after);
+        }
+        line
+    };
+    let mut nodes: Vec<usize> = adjacency.keys().copied().collect();
+    nodes.sort_unstable();
+    // Branches first, then any remaining loops.
+    for pass in 0..2 {
+        for &node in &nodes {
+            let nbrs = adjacency[&node].clone();
+            if pass == 0 && nbrs.len() == 2 {
+                continue;
+            }
+            for next in nbrs {
+                if !visited.contains(&(node.min(next), node.max(next))) {
+                    result.push(walk(node, next, &mut visited));
+                }
+            }
+        }
+    }
+    result
+}
@@ -1,16 +1,27 @@
-use kurbo::MinDistance as KMinDistance;
+use crate::point::{Point, PointLike};
+use kurbo::{MinDistance as KMinDistance, Point as KPoint};
 use pyo3::prelude::*;
 #[pyclass(subclass, module = "kurbopy")]
-pub struct MinDistance(KMinDistance);
+pub struct MinDistance(KMinDistance, KPoint, KPoint);
 
-impl From<KMinDistance> for MinDistance {
-    fn from(p: KMinDistance) -> Self {
-        Self(p)
+impl MinDistance {
+    /// Wrap a kurbo `MinDistance`, along with the closest points on the two
+    /// curves, which kurbo does not record.
+    pub(crate) fn new(d: KMinDistance, p1: KPoint, p2: KPoint) -> Self {
+        Self(d, p1, p2)
     }
 }
 
 #[pymethods]
 impl MinDistance {
+    /// Create a `MinDistance` from its fields.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    fn __new__(distance: f64, t1: f64, t2: f64, p1: PointLike, p2: PointLike) -> Self {
+        MinDistance(KMinDistance { distance, t1, t2 }, p1.0, p2.0)
+    }
+
     // getters and setters
     #[getter]
     fn get_distance(&self) -> f64 {
@@ -36,4 +47,37 @@ impl MinDistance {
     fn set_t2(&mut self, value: f64) {
         self.0.t2 = value;
     }
+
+    /// The closest point on the first curve, at parameter ``t1``.
+    ///
+    /// Note that this property is not in original kurbo
+    #[getter]
+    fn get_p1(&self) -> Point {
+        self.1.into()
+    }
+    #[setter]
+    fn set_p1(&mut self, value: PointLike) {
+        self.1 = value.0;
+    }
+    /// The closest point on the second curve, at parameter ``t2``.
+    ///
+    /// Note that this property is not in original kurbo
+    #[getter]
+    fn get_p2(&self) -> Point {
+        self.2.into()
+    }
+    #[setter]
+    fn set_p2(&mut self, value: PointLike) {
+        self.2 = value.0;
+    }
+
+    fn __repr__(&self) -> String {
+        let d = &self.0;
+        format!(
+            "MinDistance({}, {}, {})",
+            crate::repr::floats(&[d.distance, d.t1, d.t2]),
+            crate::repr::point(self.1),
+            crate::repr::point(self.2)
+        )
+    }
 }
@@ -0,0 +1,87 @@
+// Minkowski sums of filled shapes. Where one shape is a single convex
+// polygon, the sum is the other shape translated by one of its points,
+// together with the convex hull of the polygon swept along each edge of
+// the other shape. In general, each shape is translated by every vertex of
+// the other, and the sum of every pair of edges fills in the rest.
+use crate::boolean::{boolean, union_all, Op, Polygon};
+use crate::buffer::polygons;
+use crate::polyline::{convex_hull, cross};
+use kurbo::{BezPath as KBezPath, Point as KPoint, Vec2 as KVec2};
+
+fn is_convex(poly: &[KPoint]) -> bool {
+    let n = poly.len();
+    (0..n).all(|i| {
+        let (a, b, c) = (poly[i], poly[(i + 1) % n], poly[(i + 2) % n]);
+        cross(b - a, c - b) >= 0.0
+    })
+}
+
+fn translated(region: &[Polygon], by: KVec2) -> Vec<Polygon> {
+    region
+        .iter()
+        .map(|poly| poly.iter().map(|&p| p + by).collect())
+        .collect()
+}
+
+fn edges(region: &[Polygon]) -> impl Iterator<Item = (KPoint, KPoint)> + '_ {
+    region
+        .iter()
+        .flat_map(|poly| (0..poly.len()).map(move |i| (poly[i], poly[(i + 1) % poly.len()])))
+}
+
+fn vertices(region: &[Polygon]) -> impl Iterator<Item = KVec2> + '_ {
+    region.iter().flatten().map(|p| p.to_vec2())
+}
+
+/// The Minkowski sum of the regions enclosed by two paths, flattened to
+/// within `tolerance`.
+pub(crate) fn minkowski_sum(a: &KBezPath, b: &KBezPath, tolerance: f64) -> Vec<Polygon> {
+    // Normalizing the shapes removes overlaps and gives them a consistent
+    // orientation, so that holes are recognised.
+    let mut a = boolean(&polygons(a, tolerance), &[], Op::Union);
+    let mut b = boolean(&polygons(b, tolerance), &[], Op::Union);
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let convex = |region: &[Polygon]| region.len() == 1 && is_convex(&region[0]);
+    if convex(&a) && !convex(&b) {
+        std::mem::swap(&mut a, &mut b);
+    }
+    if convex(&a) && convex(&b) {
+        let sums: Vec<KPoint> = a[0]
+            .iter()
+            .flat_map(|&p| b[0].iter().map(move |&q| p + q.to_vec2()))
+            .collect();
+        return vec![convex_hull(&sums)];
+    }
+    let mut pieces: Vec<Vec<Polygon>> = vec![];
+    if convex(&b) {
+        let convex = &b[0];
+        pieces.push(translated(&a, convex[0].to_vec2()));
+        for (p0, p1) in edges(&a) {
+            let swept: Vec<KPoint> = convex
+                .iter()
+                .flat_map(|&q| [q + p0.to_vec2(), q + p1.to_vec2()])
+                .collect();
+            pieces.push(vec![convex_hull(&swept)]);
+        }
+    } else {
+        pieces.extend(vertices(&b).map(|v| translated(&a, v)));
+        pieces.extend(vertices(&a).map(|v| translated(&b, v)));
+        for (p0, p1) in edges(&a) {
+            for (q0, q1) in edges(&b) {
+                let corners = [
+                    p0 + q0.to_vec2(),
+                    p0 + q1.to_vec2(),
+                    p1 + q0.to_vec2(),
+                    p1 + q1.to_vec2(),
+                ];
+                let hull = convex_hull(&corners);
+                if hull.len() >= 3 {
+                    pieces.push(vec![hull]);
+                }
+            }
+        }
+    }
+    union_all(pieces)
+}
@@ -1,35 +1,122 @@
-use kurbo::Nearest as KNearest;
+use crate::point::{Point, PointLike};
+use kurbo::{Nearest as KNearest, Point as KPoint};
+use pyo3::exceptions::PyDeprecationWarning;
 use pyo3::prelude::*;
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
 pub struct Nearest {
     pub distance_sq: f64,
     pub t: f64,
+    pub point: Option<KPoint>,
 }
 
-impl From<KNearest> for Nearest {
-    fn from(p: KNearest) -> Self {
+impl Nearest {
+    /// Wrap a kurbo `Nearest`, along with the nearest point itself, which
+    /// kurbo does not record.
+    pub(crate) fn new(n: KNearest, point: KPoint) -> Self {
         Self {
-            distance_sq: p.distance_sq,
-            t: p.t,
+            distance_sq: n.distance_sq,
+            t: n.t,
+            point: Some(point),
         }
     }
 }
 
+fn deprecated(py: Python<'_>, old: &str, new: &str) -> PyResult<()> {
+    let message = format!("Nearest.{} is deprecated; use Nearest.{} instead", old, new);
+    PyErr::warn_bound(py, &py.get_type_bound::<PyDeprecationWarning>(), &message, 1)
+}
+
 #[pymethods]
 impl Nearest {
+    /// Create a `Nearest` from its fields. ``point`` may be omitted, in
+    /// which case the ``point`` property is ``None``.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    #[pyo3(signature = (distance_sq, t, point=None))]
+    fn __new__(distance_sq: f64, t: f64, point: Option<PointLike>) -> Self {
+        Nearest {
+            distance_sq,
+            t,
+            point: point.map(|p| p.0),
+        }
+    }
+
     // getters and setters
+    #[getter]
     fn get_distance_sq(&self) -> f64 {
         self.distance_sq
     }
+    #[setter]
     fn set_distance_sq(&mut self, value: f64) {
         self.distance_sq = value;
     }
+    #[getter]
     fn get_t(&self) -> f64 {
         self.t
     }
+    #[setter]
     fn set_t(&mut self, value: f64) {
         self.t = value;
     }
 
+    // The accessor methods which came before the properties, kept so that
+    // existing code keeps working.
+    /// Deprecated: use the ``distance_sq`` property.
+    #[pyo3(name = "get_distance_sq")]
+    fn old_get_distance_sq(&self, py: Python<'_>) -> PyResult<f64> {
+        deprecated(py, "get_distance_sq()", "distance_sq")?;
+        Ok(self.distance_sq)
+    }
+    /// Deprecated: assign to the ``distance_sq`` property.
+    #[pyo3(name = "set_distance_sq")]
+    fn old_set_distance_sq(&mut self, py: Python<'_>, value: f64) -> PyResult<()> {
+        deprecated(py, "set_distance_sq()", "distance_sq")?;
+        self.distance_sq = value;
+        Ok(())
+    }
+    /// Deprecated: use the ``t`` property.
+    #[pyo3(name = "get_t")]
+    fn old_get_t(&self, py: Python<'_>) -> PyResult<f64> {
+        deprecated(py, "get_t()", "t")?;
+        Ok(self.t)
+    }
+    /// Deprecated: assign to the ``t`` property.
+    #[pyo3(name = "set_t")]
+    fn old_set_t(&mut self, py: Python<'_>, value: f64) -> PyResult<()> {
+        deprecated(py, "set_t()", "t")?;
+        self.t = value;
+        Ok(())
+    }
+
+    /// The distance to the nearest point: the square root of
+    /// ``distance_sq``.
+    ///
+    /// Note that this property is not in original kurbo
+    #[getter]
+    fn get_distance(&self) -> f64 {
+        self.distance_sq.sqrt()
+    }
+
+    /// The nearest point on the curve, at parameter ``t``, or ``None`` if
+    /// this `Nearest` was constructed without one.
+    ///
+    /// Note that this property is not in original kurbo
+    #[getter]
+    fn get_point(&self) -> Option<Point> {
+        self.point.map(Point)
+    }
+    #[setter]
+    fn set_point(&mut self, value: Option<PointLike>) {
+        self.point = value.map(|p| p.0);
+    }
+
+    fn __repr__(&self) -> String {
+        let fields = crate::repr::floats(&[self.distance_sq, self.t]);
+        match self.point {
+            Some(point) => format!("Nearest({}, {})", fields, crate::repr::point(point)),
+            None => format!("Nearest({})", fields),
+        }
+    }
 }
@@ -0,0 +1,375 @@
+// Curve-aware overlap removal. Segments are split at their extrema, so that
+// each piece is monotonic and cannot cross itself, and then wherever they
+// cross each other. Every piece with the filled region on exactly one side
+// is kept, and the kept pieces are linked back into closed subpaths, with
+// pieces of the same original segment merged again where possible.
+use crate::corners::{end_tangent, start_tangent, subpaths};
+use crate::polyline::cross;
+use kurbo::{
+    BezPath as KBezPath, Line as KLine, ParamCurve, ParamCurveDeriv, PathSeg as KPathSeg,
+    Point as KPoint, Rect as KRect, Shape, Vec2 as KVec2,
+};
+use std::collections::{HashMap, HashSet};
+
+/// The most subdivisions tried when intersecting two curves; curves which
+/// overlap along a stretch would otherwise subdivide without end.
+const MAX_SUBDIVISIONS: usize = 10_000;
+
+/// A piece of an original segment, between two parameter values on it.
+#[derive(Clone, Copy, Debug)]
+struct Piece {
+    seg: KPathSeg,
+    source: usize,
+    t0: f64,
+    t1: f64,
+}
+
+impl Piece {
+    fn reverse(self) -> Self {
+        Piece {
+            seg: self.seg.reverse(),
+            t0: self.t1,
+            t1: self.t0,
+            ..self
+        }
+    }
+}
+
+fn key(p: KPoint) -> (u64, u64) {
+    ((p.x + 0.0).to_bits(), (p.y + 0.0).to_bits())
+}
+
+/// Replace the end points of a segment, moving the neighbouring control
+/// points along with them so that the shape is kept.
+pub(crate) fn with_ends(seg: KPathSeg, p0: KPoint, p1: KPoint) -> KPathSeg {
+    match seg {
+        KPathSeg::Line(_) => KPathSeg::Line(KLine::new(p0, p1)),
+        KPathSeg::Quad(mut q) => {
+            q.p0 = p0;
+            q.p2 = p1;
+            KPathSeg::Quad(q)
+        }
+        KPathSeg::Cubic(mut c) => {
+            c.p1 += p0 - c.p0;
+            c.p2 += p1 - c.p3;
+            c.p0 = p0;
+            c.p3 = p1;
+            KPathSeg::Cubic(c)
+        }
+    }
+}
+
+/// Split a segment at the given sorted parameter values, with the split
+/// points given so that neighbouring pieces share them exactly.
+pub(crate) fn split_at(seg: KPathSeg, splits: &[(f64, KPoint)]) -> Vec<(KPathSeg, f64, f64)> {
+    let mut bounds = vec![(0.0, seg.start())];
+    bounds.extend(splits.iter().copied());
+    bounds.push((1.0, seg.end()));
+    bounds
+        .windows(2)
+        .map(|w| {
+            let ((t0, p0), (t1, p1)) = (w[0], w[1]);
+            (with_ends(seg.subsegment(t0..t1), p0, p1), t0, t1)
+        })
+        .collect()
+}
+
+fn boxes_overlap(a: KRect, b: KRect, eps: f64) -> bool {
+    a.x0 <= b.x1 + eps && b.x0 <= a.x1 + eps && a.y0 <= b.y1 + eps && b.y0 <= a.y1 + eps
+}
+
+fn subdivide(
+    a: &KPathSeg,
+    ra: (f64, f64),
+    b: &KPathSeg,
+    rb: (f64, f64),
+    eps: f64,
+    budget: &mut usize,
+    out: &mut Vec<(f64, f64)>,
+) {
+    if *budget == 0 {
+        return;
+    }
+    *budget -= 1;
+    let box_a = a.subsegment(ra.0..ra.1).bounding_box();
+    let box_b = b.subsegment(rb.0..rb.1).bounding_box();
+    if !boxes_overlap(box_a, box_b, eps) {
+        return;
+    }
+    let size = |r: KRect| r.width().max(r.height());
+    let (size_a, size_b) = (size(box_a), size(box_b));
+    if size_a <= eps && size_b <= eps {
+        out.push((0.5 * (ra.0 + ra.1), 0.5 * (rb.0 + rb.1)));
+        return;
+    }
+    if size_a >= size_b {
+        let mid = 0.5 * (ra.0 + ra.1);
+        subdivide(a, (ra.0, mid), b, rb, eps, budget, out);
+        subdivide(a, (mid, ra.1), b, rb, eps, budget, out);
+    } else {
+        let mid = 0.5 * (rb.0 + rb.1);
+        subdivide(a, ra, b, (rb.0, mid), eps, budget, out);
+        subdivide(a, ra, b, (mid, rb.1), eps, budget, out);
+    }
+}
+
+/// The parameter values at which two segments meet, found exactly if
+/// either is a line and by subdivision to within `eps` otherwise.
+pub(crate) fn segment_intersections(a: &KPathSeg, b: &KPathSeg, eps: f64) -> Vec<(f64, f64)> {
+    let mut hits: Vec<(f64, f64)> = match (a, b) {
+        (_, KPathSeg::Line(line)) => a
+            .intersect_line(*line)
+            .into_iter()
+            .map(|hit| (hit.segment_t, hit.line_t))
+            .collect(),
+        (KPathSeg::Line(line), _) => b
+            .intersect_line(*line)
+            .into_iter()
+            .map(|hit| (hit.line_t, hit.segment_t))
+            .collect(),
+        _ => {
+            let mut out = vec![];
+            let mut budget = MAX_SUBDIVISIONS;
+            subdivide(a, (0.0, 1.0), b, (0.0, 1.0), eps, &mut budget, &mut out);
+            if budget == 0 {
+                // The curves overlap; they are split where their ends meet
+                // the other curve instead.
+                out.clear();
+            }
+            out
+        }
+    };
+    hits.sort_by(|x, y| x.0.total_cmp(&y.0));
+    // Neighbouring subdivisions may find the same crossing.
+    hits.dedup_by(|x, y| a.eval(x.0).distance(a.eval(y.0)) <= 4.0 * eps);
+    hits
+}
+
+/// The nearest parameter value on `seg` to a point, if it is within `eps`.
+fn point_on(seg: &KPathSeg, p: KPoint, eps: f64) -> Option<f64> {
+    let nearest = kurbo::ParamCurveNearest::nearest(seg, p, eps * 1e-3);
+    (nearest.distance_sq <= eps * eps).then_some(nearest.t)
+}
+
+/// Remove the overlaps between the subpaths of a path, filled with the
+/// nonzero rule. Open subpaths are treated as closed.
+pub(crate) fn remove_overlaps(path: &KBezPath) -> KBezPath {
+    // The closed subpaths, and each segment split at its extrema.
+    let mut closed = KBezPath::new();
+    let mut originals: Vec<KPathSeg> = vec![];
+    // Each monotonic piece, with its original segment and parameter range.
+    let mut segs: Vec<KPathSeg> = vec![];
+    let mut origins: Vec<(usize, f64, f64)> = vec![];
+    for sp in subpaths(path) {
+        let Some(last) = sp.segs.last() else {
+            continue;
+        };
+        let end = last.end();
+        let mut loop_segs = sp.segs.clone();
+        if end != sp.start {
+            loop_segs.push(KPathSeg::Line(KLine::new(end, sp.start)));
+        }
+        closed.move_to(sp.start);
+        for seg in loop_segs {
+            closed.push(seg.as_path_el());
+            let splits: Vec<(f64, KPoint)> = kurbo::ParamCurveExtrema::extrema(&seg)
+                .into_iter()
+                .map(|t| (t, seg.eval(t)))
+                .collect();
+            for (piece, t0, t1) in split_at(seg, &splits) {
+                segs.push(piece);
+                origins.push((originals.len(), t0, t1));
+            }
+            originals.push(seg);
+        }
+        closed.close_path();
+    }
+    let bbox = closed.bounding_box();
+    let scale = bbox
+        .x0
+        .abs()
+        .max(bbox.x1.abs())
+        .max(bbox.y0.abs())
+        .max(bbox.y1.abs())
+        .max(1.0);
+    let eps = 1e-9 * scale;
+
+    // Find where the pieces meet, sharing each meeting point exactly.
+    let mut splits: Vec<Vec<(f64, KPoint)>> = vec![vec![]; segs.len()];
+    let boxes: Vec<KRect> = segs.iter().map(|s| s.bounding_box()).collect();
+    for i in 0..segs.len() {
+        for j in i + 1..segs.len() {
+            if !boxes_overlap(boxes[i], boxes[j], eps) {
+                continue;
+            }
+            let (a, b) = (&segs[i], &segs[j]);
+            let mut hits = segment_intersections(a, b, eps);
+            // Ends of one segment lying on the other, which catches
+            // touching and overlapping segments.
+            for (p, on_a) in [
+                (b.start(), true),
+                (b.end(), true),
+                (a.start(), false),
+                (a.end(), false),
+            ] {
+                let (target, other) = if on_a { (a, b) } else { (b, a) };
+                if let Some(t) = point_on(target, p, 4.0 * eps) {
+                    let u = if other.start() == p { 0.0 } else { 1.0 };
+                    hits.push(if on_a { (t, u) } else { (u, t) });
+                }
+            }
+            for (ta, tb) in hits {
+                // Snap to the ends of either segment, so that joins which
+                // already exist are not split again.
+                let p = a.eval(ta);
+                let ends = [a.start(), a.end(), b.start(), b.end()];
+                let p = ends
+                    .into_iter()
+                    .find(|e| e.distance(p) <= 4.0 * eps)
+                    .unwrap_or(p);
+                for (ix, seg, t) in [(i, a, ta), (j, b, tb)] {
+                    if seg.start() != p && seg.end() != p {
+                        splits[ix].push((t, p));
+                    }
+                }
+            }
+        }
+    }
+
+    // Classify the pieces by the winding numbers on either side.
+    let inside = |p: KPoint| closed.winding(p) != 0;
+    let mut kept: Vec<Piece> = vec![];
+    let mut seen = HashSet::new();
+    for ((seg, mut points), &(source, lo, hi)) in segs.iter().zip(splits).zip(&origins) {
+        points.sort_by(|x, y| x.0.total_cmp(&y.0));
+        points.dedup_by(|x, y| x.1 == y.1);
+        for (piece, t0, t1) in split_at(*seg, &points) {
+            if piece.start() == piece.end() && matches!(piece, KPathSeg::Line(_)) {
+                continue;
+            }
+            let mid = piece.eval(0.5);
+            let deriv = match piece {
+                KPathSeg::Line(l) => l.p1 - l.p0,
+                KPathSeg::Quad(q) => q.deriv().eval(0.5).to_vec2(),
+                KPathSeg::Cubic(c) => c.deriv().eval(0.5).to_vec2(),
+            };
+            if deriv.hypot2() == 0.0 {
+                continue;
+            }
+            let normal = KVec2::new(-deriv.y, deriv.x).normalize() * (1000.0 * eps);
+            let (left, right) = (inside(mid + normal), inside(mid - normal));
+            if left == right {
+                continue;
+            }
+            let piece = Piece {
+                seg: piece,
+                source,
+                t0: lo + t0 * (hi - lo),
+                t1: lo + t1 * (hi - lo),
+            };
+            let piece = if left { piece } else { piece.reverse() };
+            // Coincident pieces of different subpaths are kept once.
+            let grid = |v: f64| (v / (1000.0 * eps)).round() as i64;
+            let mid = piece.seg.eval(0.5);
+            let id = (
+                key(piece.seg.start()),
+                key(piece.seg.end()),
+                grid(mid.x),
+                grid(mid.y),
+            );
+            if seen.insert(id) {
+                kept.push(piece);
+            }
+        }
+    }
+
+    // Link the pieces into loops, turning as far right as possible where
+    // several leave the same point.
+    let mut outgoing: HashMap<(u64, u64), Vec<usize>> = HashMap::new();
+    for (ix, piece) in kept.iter().enumerate() {
+        outgoing.entry(key(piece.seg.start())).or_default().push(ix);
+    }
+    let mut used = vec![false; kept.len()];
+    let mut loops: Vec<Vec<Piece>> = vec![];
+    for start in 0..kept.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+        let mut pieces = vec![kept[start]];
+        loop {
+            let current = pieces[pieces.len() - 1];
+            if key(current.seg.end()) == key(kept[start].seg.start()) {
+                break;
+            }
+            let dir = end_tangent(&current.seg).unwrap_or_default();
+            let turn = |c: usize| {
+                let v = start_tangent(&kept[c].seg).unwrap_or_default();
+                cross(dir, v).atan2(dir.dot(v))
+            };
+            let next = outgoing.get(&key(current.seg.end())).and_then(|cands| {
+                cands
+                    .iter()
+                    .copied()
+                    .filter(|&c| !used[c])
+                    .min_by(|&x, &y| turn(x).total_cmp(&turn(y)))
+            });
+            let Some(next) = next else {
+                break;
+            };
+            used[next] = true;
+            pieces.push(kept[next]);
+        }
+        loops.push(pieces);
+    }
+
+    // Merge neighbouring pieces of the same original segment, and keep the
+    // overall direction of the original path.
+    let mergeable = |a: &Piece, b: &Piece| a.source == b.source && (a.t1 - b.t0).abs() < 1e-9;
+    let reverse = closed.area() < 0.0;
+    let mut result = KBezPath::new();
+    for mut pieces in loops {
+        if let Some(first) = (0..pieces.len()).find(|&i| {
+            let prev = &pieces[(i + pieces.len() - 1) % pieces.len()];
+            !mergeable(prev, &pieces[i])
+        }) {
+            pieces.rotate_left(first);
+        }
+        let mut merged: Vec<Piece> = vec![];
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last) if mergeable(last, &piece) => {
+                    let (lo, hi) = (last.t0.min(piece.t1), last.t0.max(piece.t1));
+                    let original = originals[piece.source];
+                    let seg = if lo < 1e-9 && hi > 1.0 - 1e-9 {
+                        original
+                    } else {
+                        original.subsegment(lo..hi)
+                    };
+                    let seg = if last.t0 < piece.t1 {
+                        seg
+                    } else {
+                        seg.reverse()
+                    };
+                    last.seg = with_ends(seg, last.seg.start(), piece.seg.end());
+                    last.t1 = piece.t1;
+                }
+                _ => merged.push(piece),
+            }
+        }
+        if reverse {
+            merged = merged.into_iter().rev().map(Piece::reverse).collect();
+        }
+        let start = merged[0].seg.start();
+        result.move_to(start);
+        for (ix, piece) in merged.iter().enumerate() {
+            // A final line back to the start is drawn by closing the path.
+            let closing = ix == merged.len() - 1 && piece.seg.end() == start;
+            if !(closing && matches!(piece.seg, KPathSeg::Line(_))) {
+                result.push(piece.seg.as_path_el());
+            }
+        }
+        result.close_path();
+    }
+    result
+}
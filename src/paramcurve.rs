@@ -14,8 +14,9 @@ macro_rules! impl_paramcurve {
 
             /// Get a subsegment of the curve for the given parameter range.
             #[pyo3(text_signature = "($self, (t0,t1))")]
-            fn subsegment(&self, range: (f64, f64)) -> Self {
-                self.0.subsegment(range.0..range.1).into()
+            fn subsegment(slf: &pyo3::Bound<'_, Self>, range: (f64, f64)) -> pyo3::PyResult<pyo3::Py<Self>> {
+                let segment = slf.borrow().0.subsegment(range.0..range.1);
+                $crate::subclass::new_like(slf, segment.into())
             }
 
             /// The start point.
@@ -28,9 +29,12 @@ macro_rules! impl_paramcurve {
             }
 
             /// Subdivide into (roughly) halves.
-            fn subdivide(&self) -> (Self, Self) {
-                let (a, b) = self.0.subdivide();
-                (a.into(), b.into())
+            fn subdivide(slf: &pyo3::Bound<'_, Self>) -> pyo3::PyResult<(pyo3::Py<Self>, pyo3::Py<Self>)> {
+                let (a, b) = slf.borrow().0.subdivide();
+                Ok((
+                    $crate::subclass::new_like(slf, a.into())?,
+                    $crate::subclass::new_like(slf, b.into())?,
+                ))
             }
         }
     }
@@ -47,9 +51,23 @@ macro_rules! impl_paramcurvearclen {
             /// The result is accurate to the given accuracy (subject to
             /// roundoff errors for ridiculously low values). Compute time
             /// may vary with accuracy, if the curve needs to be subdivided.
-            #[pyo3(text_signature = "($self, accuracy)")]
-            fn arclen(&self, accuracy: f64) -> f64 {
-                self.0.arclen(accuracy)
+            ///
+            /// If ``return_error`` is true, returns a ``(length, error)`` tuple,
+            /// where ``error`` estimates the error actually achieved.
+            #[pyo3(signature = (accuracy=$crate::default_accuracy(), return_error=false))]
+            fn arclen(
+                &self,
+                py: pyo3::Python<'_>,
+                accuracy: f64,
+                return_error: bool,
+            ) -> pyo3::PyObject {
+                let length = self.0.arclen(accuracy);
+                $crate::accuracy::with_error(
+                    py,
+                    length,
+                    || (length - self.0.arclen(accuracy * $crate::accuracy::REFINEMENT)).abs(),
+                    return_error,
+                )
             }
 
             /// Solve for the parameter that has the given arc length from the start.
@@ -60,7 +78,7 @@ macro_rules! impl_paramcurvearclen {
             /// care to compute arc lengths of increasingly smaller segments
             /// of the curve, as that is likely faster than repeatedly
             /// computing the arc length of the segment starting at t=0.
-            #[pyo3(text_signature = "($self, arclen, accuracy)")]
+            #[pyo3(signature = (arclen, accuracy=$crate::default_accuracy()))]
             fn inv_arclen(&self, arclen: f64, accuracy: f64) -> f64 {
                 self.0.inv_arclen(arclen, accuracy)
             }
@@ -104,6 +122,18 @@ macro_rules! impl_paramcurvecurvature {
             fn curvature(&self, t: f64) -> f64 {
                 self.0.curvature(t)
             }
+
+            /// Find the parameter values strictly inside the curve at which
+            /// the absolute curvature is locally greatest, in increasing
+            /// order.
+            ///
+            /// These are the tightest points of bends, and the positions of
+            /// cusps, where the curvature is infinite.
+            ///
+            /// Note that this method is not in original kurbo
+            fn curvature_extrema(&self) -> Vec<f64> {
+                $crate::curvature::curvature_extrema(self.0.into())
+            }
         }
     }
 }
@@ -129,6 +159,18 @@ macro_rules! impl_paramcurveextrema {
             fn bounding_box(&self) -> $crate::rect::Rect {
                 kurbo::ParamCurveExtrema::bounding_box(&self.0).into()
             }
+            /// Split the curve at its extrema.
+            ///
+            /// Returns a list of pieces, one for each of the ranges given by
+            /// ``extrema_ranges``, each of which is monotonic in both x and y.
+            ///
+            /// Note that this method is not in original kurbo
+            fn split_at_extrema(&self) -> Vec<$name> {
+                kurbo::ParamCurveExtrema::extrema_ranges(&self.0)
+                    .into_iter()
+                    .map(|r| kurbo::ParamCurve::subsegment(&self.0, r).into())
+                    .collect()
+            }
         }
     }
 }
@@ -142,10 +184,50 @@ macro_rules! impl_paramcurvenearest {
         /// Find the position on the curve that is nearest to the given point.
         ///
         /// This returns a [`Nearest`] struct that contains information about the position.
-        #[pyo3(text_signature = "($self, point, accuracy)")]
-        fn nearest(&self, p: Point, accuracy: f64) -> Nearest {
-            let n = self.0.nearest(p.0, accuracy);
-            n.into()
+        ///
+        /// If ``return_error`` is true, returns a ``(nearest, error)`` tuple,
+        /// where ``error`` estimates the error in the distance to the curve.
+        #[pyo3(signature = (point, accuracy=$crate::default_accuracy(), return_error=false))]
+        fn nearest(
+            &self,
+            py: pyo3::Python<'_>,
+            point: $crate::point::PointLike,
+            accuracy: f64,
+            return_error: bool,
+        ) -> pyo3::PyObject {
+            let n = self.0.nearest(point.0, accuracy);
+            let error = || {
+                let fine = self.0.nearest(point.0, accuracy * $crate::accuracy::REFINEMENT);
+                (n.distance_sq.sqrt() - fine.distance_sq.sqrt()).abs()
+            };
+            let nearest = Nearest::new(n, self.0.eval(n.t));
+            $crate::accuracy::with_error(py, nearest, error, return_error)
+        }
+    }
+}
+}
+
+#[macro_export]
+macro_rules! impl_paramcurveproject {
+    ($name:ident) => {
+        #[pyo3::prelude::pymethods]
+        impl $name {
+
+        /// Project a point onto the curve.
+        ///
+        /// Returns a ``(t, point, distance)`` tuple of the parameter value of
+        /// the nearest position on the curve, the point there, and its
+        /// distance from the given point.
+        ///
+        /// Note that this method is not in original kurbo
+        #[pyo3(signature = (point, accuracy=$crate::default_accuracy()))]
+        fn project(
+            &self,
+            point: $crate::point::PointLike,
+            accuracy: f64,
+        ) -> (f64, $crate::point::Point, f64) {
+            let n = self.0.nearest(point.0, accuracy);
+            (n.t, self.0.eval(n.t).into(), n.distance_sq.sqrt())
         }
     }
 }
@@ -1,7 +1,9 @@
-use kurbo::PathEl as KPathEl;
+use kurbo::{PathEl as KPathEl, Point as KPoint};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
-use crate::{impl_isfinitenan, point::Point};
+use crate::impl_serialize;
+use crate::{impl_isfinitenan, impl_round_coordinates, point::{Point, PointLike}};
 
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
@@ -13,12 +15,92 @@ impl From<KPathEl> for PathEl {
     }
 }
 
+/// The points of an element: its control points followed by its end point.
+pub(crate) fn el_points(el: KPathEl) -> Vec<KPoint> {
+    match el {
+        KPathEl::MoveTo(p) | KPathEl::LineTo(p) => vec![p],
+        KPathEl::QuadTo(p1, p2) => vec![p1, p2],
+        KPathEl::CurveTo(p1, p2, p3) => vec![p1, p2, p3],
+        KPathEl::ClosePath => vec![],
+    }
+}
+
 impl_isfinitenan!(PathEl);
+impl_serialize!(PathEl);
+impl_round_coordinates!(PathEl);
 
 #[pymethods]
 impl PathEl {
+    /// Create a `PathEl` from its kind (as returned by ``kind``) and points,
+    /// for example ``PathEl("QuadTo", (1, 2), (3, 4))``.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    #[pyo3(signature = (kind, *points))]
+    fn __new__(kind: &str, points: Vec<PointLike>) -> PyResult<Self> {
+        let el = match (kind, points.as_slice()) {
+            ("MoveTo", [p]) => KPathEl::MoveTo(p.0),
+            ("LineTo", [p]) => KPathEl::LineTo(p.0),
+            ("QuadTo", [p1, p2]) => KPathEl::QuadTo(p1.0, p2.0),
+            ("CurveTo", [p1, p2, p3]) => KPathEl::CurveTo(p1.0, p2.0, p3.0),
+            ("ClosePath", []) => KPathEl::ClosePath,
+            ("MoveTo" | "LineTo" | "QuadTo" | "CurveTo" | "ClosePath", _) => {
+                return Err(PyValueError::new_err(format!(
+                    "wrong number of points for {}",
+                    kind
+                )))
+            }
+            _ => return Err(PyValueError::new_err(format!("unknown element kind {}", kind))),
+        };
+        Ok(PathEl(el))
+    }
+
     /// Get the end point of the path element, if it exists.
     pub fn end_point(&self) -> Option<Point> {
         self.0.end_point().map(Point)
     }
-}
\ No newline at end of file
+
+    /// The kind of element, as a string: one of ``"MoveTo"``, ``"LineTo"``,
+    /// ``"QuadTo"``, ``"CurveTo"`` or ``"ClosePath"``.
+    ///
+    /// Note that this property is not in original kurbo
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        match self.0 {
+            KPathEl::MoveTo(_) => "MoveTo",
+            KPathEl::LineTo(_) => "LineTo",
+            KPathEl::QuadTo(..) => "QuadTo",
+            KPathEl::CurveTo(..) => "CurveTo",
+            KPathEl::ClosePath => "ClosePath",
+        }
+    }
+
+    /// The points of the element, in order: the control points (if any)
+    /// followed by the end point. A ``ClosePath`` has no points.
+    ///
+    /// Note that this method is not in original kurbo
+    pub fn points(&self) -> Vec<Point> {
+        el_points(self.0).into_iter().map(Point).collect()
+    }
+
+    fn __repr__(&self) -> String {
+        let mut args = vec![format!("{:?}", self.kind())];
+        args.extend(el_points(self.0).into_iter().map(crate::repr::point));
+        format!("PathEl({})", args.join(", "))
+    }
+}
+/// A lazy iterator over the elements of a shape.
+#[pyclass]
+pub struct PathElIterator {
+    pub(crate) items: Box<dyn Iterator<Item = KPathEl> + Send>,
+}
+
+#[pymethods]
+impl PathElIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+    fn __next__(&mut self) -> Option<PathEl> {
+        self.items.next().map(PathEl)
+    }
+}
@@ -1,15 +1,17 @@
+use crate::impl_serialize;
 use crate::pathel::PathEl;
-use crate::{impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurveextrema, impl_paramcurvenearest, impl_shape_no_bounding_box};
-use crate::{cubicbez::CubicBez, impl_isfinitenan};
+use crate::{impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurveextrema, impl_paramcurvenearest, impl_paramcurveproject, impl_shape_no_bounding_box};
+use crate::{cubicbez::CubicBez, impl_approx_eq, impl_isfinitenan, impl_round_coordinates};
 use crate::line::Line;
 use crate::mindistance::MinDistance;
 use crate::nearest::Nearest;
-use crate::point::Point;
 use crate::quadbez::QuadBez;
+use crate::subclass::new_like;
 use kurbo::{
     ParamCurve, ParamCurveArclen, ParamCurveArea, ParamCurveCurvature, ParamCurveExtrema, ParamCurveNearest,
     PathSeg as KPathSeg, LineIntersection as KLineIntersection
 };
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 
 #[pyclass(subclass, module = "kurbopy")]
@@ -24,6 +26,22 @@ impl From<KPathSeg> for PathSeg {
 
 #[pymethods]
 impl PathSeg {
+    /// Create a `PathSeg` from a `Line`, `QuadBez` or `CubicBez`.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    fn __new__(segment: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(l) = segment.extract::<Line>() {
+            Ok(PathSeg(KPathSeg::Line(l.0)))
+        } else if let Ok(q) = segment.extract::<QuadBez>() {
+            Ok(PathSeg(KPathSeg::Quad(q.0)))
+        } else if let Ok(c) = segment.extract::<CubicBez>() {
+            Ok(PathSeg(KPathSeg::Cubic(c.0)))
+        } else {
+            Err(PyTypeError::new_err("expected a Line, QuadBez or CubicBez"))
+        }
+    }
+
     fn as_line(&self) -> Option<Line> {
         if let KPathSeg::Line(l) = self.0 {
             Some(l.into())
@@ -55,8 +73,8 @@ impl PathSeg {
 
     /// Returns a new `PathSeg` describing the same path as `self`, but with
     /// the points reversed.
-    pub fn reverse(&self) -> PathSeg {
-        PathSeg(self.0.reverse())
+    pub fn reverse(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, PathSeg(slf.borrow().0.reverse()))
     }
     /// Convert this segment to a cubic bezier.
     pub fn to_cubic(&self) -> CubicBez {
@@ -88,6 +106,14 @@ impl PathSeg {
         }
     }
 
+    /// Find the parameter values strictly inside the segment at which the
+    /// absolute curvature is locally greatest, in increasing order.
+    ///
+    /// Note that this method is not in original kurbo
+    fn curvature_extrema(&self) -> Vec<f64> {
+        crate::curvature::curvature_extrema(self.0)
+    }
+
     fn deriv(&self, py: Python) -> PyObject {
         match self.0 {
             KPathSeg::Line(line) => Line(line).deriv().into_py(py),
@@ -100,9 +126,22 @@ impl PathSeg {
     ///
     /// Returns a tuple of the distance, the path time `t1` of the closest point
     /// on the first `PathSeg`, and the path time `t2` of the closest point on the
-    /// second `PathSeg`.
+    /// second `PathSeg`. The closest points themselves are available as ``p1``
+    /// and ``p2``.
+    #[pyo3(signature = (other, accuracy=crate::default_accuracy()))]
     fn min_dist(&self, other: &PathSeg, accuracy: f64) -> MinDistance {
-        self.0.min_dist(other.0, accuracy).into()
+        let d = self.0.min_dist(other.0, accuracy);
+        let (p1, p2) = (self.0.eval(d.t1), other.0.eval(d.t2));
+        MinDistance::new(d, p1, p2)
+    }
+
+    fn __repr__(&self) -> String {
+        let inner = match self.0 {
+            KPathSeg::Line(l) => crate::repr::line(l),
+            KPathSeg::Quad(q) => crate::repr::quad(q),
+            KPathSeg::Cubic(c) => crate::repr::cubic(c),
+        };
+        format!("PathSeg({})", inner)
     }
 }
 
@@ -111,8 +150,12 @@ impl_paramcurvearclen!(PathSeg);
 impl_paramcurvearea!(PathSeg);
 impl_paramcurveextrema!(PathSeg);
 impl_paramcurvenearest!(PathSeg);
+impl_paramcurveproject!(PathSeg);
 impl_isfinitenan!(PathSeg);
+impl_serialize!(PathSeg);
 impl_shape_no_bounding_box!(PathSeg);
+impl_round_coordinates!(PathSeg);
+impl_approx_eq!(PathSeg);
 
 
 
@@ -128,6 +171,13 @@ impl From<KLineIntersection> for LineIntersection {
 
 #[pymethods]
 impl LineIntersection {
+    /// Create a `LineIntersection` from its fields.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    fn __new__(line_t: f64, segment_t: f64) -> Self {
+        LineIntersection(KLineIntersection { line_t, segment_t })
+    }
     #[getter]
     fn line_t(&self) -> f64 {
         self.0.line_t
@@ -136,5 +186,10 @@ impl LineIntersection {
     fn segment_t(&self) -> f64 {
         self.0.segment_t
     }
+
+    fn __repr__(&self) -> String {
+        let floats = crate::repr::floats(&[self.0.line_t, self.0.segment_t]);
+        format!("LineIntersection({})", floats)
+    }
 }
 impl_isfinitenan!(LineIntersection);
\ No newline at end of file
@@ -1,10 +1,17 @@
-use crate::{polymorphic, vec2::Vec2};
+use crate::subclass::new_like;
+use crate::{impl_approx_eq, impl_round_coordinates, impl_serialize, polymorphic, vec2::{Vec2, Vec2Like}};
 use kurbo::Point as KPoint;
+use pyo3::exceptions::{PyIndexError, PyTypeError};
 use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyIterator, PyTuple};
 
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
 /// A 2D point.
+///
+/// Anywhere a `Point` is expected as an argument, an ``(x, y)`` tuple
+/// may be given instead. A `Point` is also a sequence of its two
+/// coordinates, so it can be unpacked with ``x, y = pt``.
 pub struct Point(pub KPoint);
 
 impl From<KPoint> for Point {
@@ -18,6 +25,121 @@ impl From<Point> for KPoint {
     }
 }
 
+/// A point argument, which may be given as a `Point` or an `(x, y)` tuple.
+#[derive(Clone, Copy, Debug)]
+pub struct PointLike(pub KPoint);
+
+impl<'py> FromPyObject<'py> for PointLike {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(p) = ob.downcast::<Point>() {
+            return Ok(PointLike(p.borrow().0));
+        }
+        ob.extract::<(f64, f64)>()
+            .map(|(x, y)| PointLike(KPoint::new(x, y)))
+            .map_err(|_| PyTypeError::new_err("expected a Point or an (x, y) tuple"))
+    }
+}
+
+impl From<PointLike> for KPoint {
+    fn from(p: PointLike) -> Self {
+        p.0
+    }
+}
+
+/// An array of points, which may be given as a NumPy array of shape
+/// `(n, 2)` or as a sequence of `Point`s or `(x, y)` tuples. Whether a NumPy
+/// array was given is remembered so that results can be returned in kind.
+#[derive(Clone, Debug)]
+pub struct PointArray {
+    pub points: Vec<KPoint>,
+    pub numpy: bool,
+}
+
+impl<'py> FromPyObject<'py> for PointArray {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if ob.hasattr("__array__")? {
+            // The stable ABI has no access to the buffer protocol before
+            // Python 3.11, so copy the coordinates out as raw bytes rather
+            // than building a Python float for each of them.
+            let numpy = PyModule::import_bound(ob.py(), "numpy")?;
+            let array = numpy.call_method1("ascontiguousarray", (ob, "float64"))?;
+            let shape: Vec<usize> = array.getattr("shape")?.extract()?;
+            if shape.len() != 2 || shape[1] != 2 {
+                return Err(PyTypeError::new_err("expected an array of shape (n, 2)"));
+            }
+            let bytes = array.call_method0("tobytes")?;
+            let coords: Vec<f64> = bytes
+                .downcast::<PyBytes>()?
+                .as_bytes()
+                .chunks_exact(8)
+                .map(|b| f64::from_ne_bytes(b.try_into().unwrap()))
+                .collect();
+            return Ok(PointArray {
+                points: coords.chunks_exact(2).map(|c| KPoint::new(c[0], c[1])).collect(),
+                numpy: true,
+            });
+        }
+        let points: Vec<PointLike> = ob.extract()?;
+        Ok(PointArray {
+            points: points.into_iter().map(|p| p.0).collect(),
+            numpy: false,
+        })
+    }
+}
+
+impl PointArray {
+    /// Return `values` as a NumPy array if the input was one, or as a list
+    /// otherwise.
+    pub(crate) fn in_kind(
+        &self,
+        py: Python<'_>,
+        values: impl IntoPy<PyObject>,
+    ) -> PyResult<PyObject> {
+        let values = values.into_py(py);
+        if self.numpy {
+            let numpy = PyModule::import_bound(py, "numpy")?;
+            Ok(numpy.call_method1("asarray", (values,))?.unbind())
+        } else {
+            Ok(values)
+        }
+    }
+
+    /// Return `points` as a NumPy array of shape `(n, 2)` if the input was a
+    /// NumPy array, or as a list of `Point`s otherwise.
+    pub(crate) fn points_in_kind(
+        &self,
+        py: Python<'_>,
+        points: Vec<KPoint>,
+    ) -> PyResult<PyObject> {
+        if self.numpy {
+            // As when reading, pass the coordinates as raw bytes; a
+            // bytearray keeps the resulting array writable.
+            let bytes: Vec<u8> = points
+                .iter()
+                .flat_map(|p| [p.x.to_ne_bytes(), p.y.to_ne_bytes()])
+                .flatten()
+                .collect();
+            let numpy = PyModule::import_bound(py, "numpy")?;
+            let array = numpy.call_method1(
+                "frombuffer",
+                (PyByteArray::new_bound(py, &bytes), "float64"),
+            )?;
+            Ok(array.call_method1("reshape", ((-1, 2),))?.unbind())
+        } else {
+            Ok(points.into_iter().map(Point).collect::<Vec<_>>().into_py(py))
+        }
+    }
+}
+
+/// Index into an `(x, y)` pair the way a Python sequence would.
+pub(crate) fn coordinate(x: f64, y: f64, ix: isize) -> PyResult<f64> {
+    match ix {
+        0 | -2 => Ok(x),
+        1 | -1 => Ok(y),
+        _ => Err(PyIndexError::new_err("index out of range")),
+    }
+}
+
 #[pymethods]
 impl Point {
     #[new]
@@ -33,19 +155,19 @@ impl Point {
 
     /// Linearly interpolate between two points.
     #[pyo3(text_signature = "($self, other, t)")]
-    fn lerp(&self, other: Self, t: f64) -> Self {
-        self.0.lerp(other.0, t).into()
+    fn lerp(slf: &Bound<'_, Self>, other: PointLike, t: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.lerp(other.0, t).into())
     }
 
     /// Determine the midpoint of two points.
     #[pyo3(text_signature = "($self, other)")]
-    fn midpoint(&self, other: Self) -> Self {
-        self.0.midpoint(other.0).into()
+    fn midpoint(slf: &Bound<'_, Self>, other: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.midpoint(other.0).into())
     }
 
     /// Euclidean distance.
     #[pyo3(text_signature = "($self, other)")]
-    fn distance(&self, other: Self) -> f64 {
+    fn distance(&self, other: PointLike) -> f64 {
         self.0.distance(other.0)
     }
 
@@ -61,8 +183,8 @@ impl Point {
     ///     assert a.y == 4.0
     ///     assert b.x == 3.0
     ///     assert b.y == -3.0
-    fn round(&self) -> Self {
-        self.0.round().into()
+    fn round(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.round().into())
     }
 
     /// Returns a new `Point`,
@@ -78,8 +200,8 @@ impl Point {
     ///     assert a.y == 4.0
     ///     assert b.x == 3.0
     ///     assert b.y == -3.0
-    fn ceil(&self) -> Self {
-        self.0.ceil().into()
+    fn ceil(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.ceil().into())
     }
 
     /// Returns a new `Point`,
@@ -95,8 +217,8 @@ impl Point {
     ///     assert a.y == 3.0
     ///     assert b.x == 3.0
     ///     assert b.y == -4.0
-    fn floor(&self) -> Self {
-        self.0.floor().into()
+    fn floor(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.floor().into())
     }
 
     /// Returns a new `Point`,
@@ -112,8 +234,8 @@ impl Point {
     ///     assert a.y == 4.0
     ///     assert b.x == 3.0
     ///     assert b.y == -4.0
-    fn expand(&self) -> Self {
-        self.0.expand().into()
+    fn expand(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.expand().into())
     }
 
     /// Returns a new `Point`,
@@ -129,8 +251,8 @@ impl Point {
     ///     assert a.y == 3.0
     ///     assert b.x == 3.0
     ///     assert b.y == -3.0
-    fn trunc(&self) -> Self {
-        self.0.trunc().into()
+    fn trunc(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.trunc().into())
     }
 
     /// Is this point finite?
@@ -161,25 +283,44 @@ impl Point {
     }
 
     #[allow(non_snake_case)]
-    fn __iadd__(&mut self, other: Vec2) {
+    fn __iadd__(&mut self, other: Vec2Like) {
         self.0 += other.0;
     }
 
     #[allow(non_snake_case)]
-    fn __isub__(&mut self, other: Vec2) {
+    fn __isub__(&mut self, other: Vec2Like) {
         self.0 -= other.0;
     }
 
-    fn __repr__(&self) -> PyResult<String> {
-        Ok(format!("<Point x={:?} y={:?}>", self.0.x, self.0.y))
+    fn __len__(&self) -> usize {
+        2
+    }
+    fn __getitem__(&self, ix: isize) -> PyResult<f64> {
+        coordinate(self.0.x, self.0.y, ix)
     }
-    fn _add_tuple(&self, other: (f64, f64)) -> Self {
-        (self.0 + other).into()
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyTuple::new_bound(py, [self.0.x, self.0.y]).as_any().iter()
+    }
+
+    fn __repr__(&self) -> String {
+        crate::repr::point(self.0)
     }
-    fn _sub_tuple(&self, other: (f64, f64)) -> Self {
-        (self.0 - other).into()
+    fn _add_tuple(slf: &Bound<'_, Self>, other: (f64, f64)) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 + other).into())
+    }
+    fn _sub_tuple(slf: &Bound<'_, Self>, other: (f64, f64)) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 - other).into())
+    }
+    #[allow(non_snake_case)]
+    fn _add_Point(&self, _other: &Point) -> PyResult<Self> {
+        Err(PyTypeError::new_err(
+            "cannot add two Points; to translate a Point, add a Vec2 (such as other.to_vec2())",
+        ))
     }
 }
 
+impl_round_coordinates!(Point);
+impl_approx_eq!(Point);
+impl_serialize!(Point);
 polymorphic!(add Point => (_add_Vec2, Vec2, Point));
-polymorphic!(sub Point => (_sub_Vec2, Vec2, Point));
\ No newline at end of file
+polymorphic!(sub Point => (_sub_Vec2, Vec2, Point), (_sub_Point, Point, Vec2));
\ No newline at end of file
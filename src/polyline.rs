@@ -0,0 +1,206 @@
+// Helpers for working with flattened paths. These are not exposed to
+// Python directly, but are shared by the functions which operate on
+// polylines.
+use kurbo::{BezPath as KBezPath, PathEl as KPathEl, Point as KPoint, Rect as KRect, Vec2 as KVec2};
+
+/// A single flattened subpath.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Polyline {
+    pub points: Vec<KPoint>,
+    pub closed: bool,
+}
+
+/// Flatten a path, keeping each subpath as a separate polyline.
+pub(crate) fn flatten_subpaths(path: &KBezPath, tolerance: f64) -> Vec<Polyline> {
+    let mut result: Vec<Polyline> = vec![];
+    kurbo::flatten(path, tolerance, |el| match el {
+        KPathEl::MoveTo(p) => result.push(Polyline {
+            points: vec![p],
+            closed: false,
+        }),
+        KPathEl::LineTo(p) => match result.last_mut() {
+            Some(last) if !last.closed => last.points.push(p),
+            Some(last) => {
+                // Drawing after a close continues from the subpath's start.
+                let start = last.points[0];
+                result.push(Polyline {
+                    points: vec![start, p],
+                    closed: false,
+                })
+            }
+            None => result.push(Polyline {
+                points: vec![p],
+                closed: false,
+            }),
+        },
+        KPathEl::ClosePath => {
+            if let Some(last) = result.last_mut() {
+                last.closed = true;
+            }
+        }
+        _ => {}
+    });
+    result
+}
+
+/// The z component of the cross product, positive when `b` is
+/// anticlockwise of `a` in a y-up coordinate system.
+pub(crate) fn cross(a: KVec2, b: KVec2) -> f64 {
+    a.x * b.y - a.y * b.x
+}
+
+/// Compute the convex hull of a set of points.
+///
+/// The result is in anticlockwise order (in a y-up coordinate system),
+/// without repeating the first point.
+pub(crate) fn convex_hull(points: &[KPoint]) -> Vec<KPoint> {
+    let mut pts: Vec<KPoint> = points.iter().copied().filter(|p| p.is_finite()).collect();
+    pts.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+    let turn = |o: KPoint, a: KPoint, b: KPoint| cross(a - o, b - o);
+    let mut lower: Vec<KPoint> = vec![];
+    for &p in &pts {
+        while lower.len() >= 2 && turn(lower[lower.len() - 2], lower[lower.len() - 1], p) <= 0.0 {
+            lower.pop();
+        }
+        lower.push(p);
+    }
+    let mut upper: Vec<KPoint> = vec![];
+    for &p in pts.iter().rev() {
+        while upper.len() >= 2 && turn(upper[upper.len() - 2], upper[upper.len() - 1], p) <= 0.0 {
+            upper.pop();
+        }
+        upper.push(p);
+    }
+    lower.pop();
+    upper.pop();
+    lower.extend(upper);
+    lower
+}
+
+/// The mean of a non-empty set of points.
+pub(crate) fn centroid(points: &[KPoint]) -> KPoint {
+    let sum = points.iter().fold(KVec2::ZERO, |acc, p| acc + p.to_vec2());
+    (sum / points.len() as f64).to_point()
+}
+
+/// The eigenvalues of the symmetric matrix `[[a, b], [b, d]]`, largest
+/// first, and the angle of the eigenvector for the largest.
+pub(crate) fn symmetric_eigen(a: f64, b: f64, d: f64) -> (f64, f64, f64) {
+    let mean = (a + d) / 2.0;
+    let spread = ((a - d) / 2.0).hypot(b);
+    (mean + spread, mean - spread, 0.5 * (2.0 * b).atan2(a - d))
+}
+
+/// The principal axes of a non-empty set of points, as unit vectors with
+/// the variance of the points along them, the major axis first.
+pub(crate) fn principal_axes(points: &[KPoint]) -> [(KVec2, f64); 2] {
+    let c = centroid(points);
+    let (mut a, mut b, mut d) = (0.0, 0.0, 0.0);
+    for p in points {
+        let e = *p - c;
+        a += e.x * e.x;
+        b += e.x * e.y;
+        d += e.y * e.y;
+    }
+    let n = points.len() as f64;
+    let (major, minor, angle) = symmetric_eigen(a / n, b / n, d / n);
+    let axis = KVec2::from_angle(angle);
+    [(axis, major), (KVec2::new(-axis.y, axis.x), minor)]
+}
+
+/// Append a polygon to a path as a closed subpath of straight lines.
+pub(crate) fn push_polygon(path: &mut KBezPath, points: &[KPoint]) {
+    let mut iter = points.iter();
+    if let Some(&first) = iter.next() {
+        path.move_to(first);
+        let mut last = first;
+        for &p in iter {
+            if p != last {
+                path.line_to(p);
+                last = p;
+            }
+        }
+        path.close_path();
+    }
+}
+
+/// Clip the line segment from `p0` to `p1` to a rectangle, using the
+/// Liang–Barsky algorithm. Returns `None` if no part of the segment is
+/// inside the rectangle.
+pub(crate) fn clip_segment(p0: KPoint, p1: KPoint, rect: KRect) -> Option<(KPoint, KPoint)> {
+    let rect = rect.abs();
+    let d = p1 - p0;
+    let mut t0: f64 = 0.0;
+    let mut t1: f64 = 1.0;
+    for (p, q) in [
+        (-d.x, p0.x - rect.x0),
+        (d.x, rect.x1 - p0.x),
+        (-d.y, p0.y - rect.y0),
+        (d.y, rect.y1 - p0.y),
+    ] {
+        if p == 0.0 {
+            if q < 0.0 {
+                return None;
+            }
+        } else {
+            let r = q / p;
+            if p < 0.0 {
+                t0 = t0.max(r);
+            } else {
+                t1 = t1.min(r);
+            }
+        }
+    }
+    if t0 > t1 {
+        return None;
+    }
+    Some((p0 + d * t0, p0 + d * t1))
+}
+
+/// The distance from `p` to the line segment from `a` to `b`.
+fn segment_distance(p: KPoint, a: KPoint, b: KPoint) -> f64 {
+    let d = b - a;
+    let len2 = d.hypot2();
+    let t = if len2 > 0.0 {
+        ((p - a).dot(d) / len2).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (p - (a + d * t)).hypot()
+}
+
+/// Simplify a polyline with the Douglas–Peucker algorithm, keeping the
+/// points needed for the result to stay within `epsilon` of the original.
+/// The first and last points are always kept.
+pub(crate) fn simplify(points: &[KPoint], epsilon: f64) -> Vec<KPoint> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = stack.pop() {
+        let mut worst = (0.0, start);
+        for i in start + 1..end {
+            let dist = segment_distance(points[i], points[start], points[end]);
+            if dist > worst.0 {
+                worst = (dist, i);
+            }
+        }
+        if worst.1 > start && worst.0 > epsilon {
+            keep[worst.1] = true;
+            stack.push((start, worst.1));
+            stack.push((worst.1, end));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, k)| k.then_some(p))
+        .collect()
+}
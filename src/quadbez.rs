@@ -1,8 +1,9 @@
-use crate::{impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurvecurvature, impl_paramcurvederiv, impl_paramcurveextrema, impl_paramcurvenearest, impl_shape_no_bounding_box};
-use crate::{cubicbez::CubicBez, impl_isfinitenan};
+use crate::impl_serialize;
+use crate::{impl_paramcurve, impl_paramcurvearclen, impl_paramcurvearea, impl_paramcurvecurvature, impl_paramcurvederiv, impl_paramcurveextrema, impl_paramcurvenearest, impl_paramcurveproject, impl_shape_no_bounding_box};
+use crate::{cubicbez::CubicBez, impl_approx_eq, impl_isfinitenan, impl_round_coordinates};
 use crate::line::Line;
 use crate::nearest::Nearest;
-use crate::point::Point;
+use crate::point::{Point, PointLike};
 use kurbo::{
     ParamCurve, ParamCurveArclen, ParamCurveArea, ParamCurveCurvature, ParamCurveDeriv,
     ParamCurveExtrema, ParamCurveNearest, QuadBez as KQuadBez,
@@ -22,7 +23,7 @@ impl From<KQuadBez> for QuadBez {
 #[pymethods]
 impl QuadBez {
     #[new]
-    fn __new__(p0: Point, p1: Point, p2: Point) -> Self {
+    fn __new__(p0: PointLike, p1: PointLike, p2: PointLike) -> Self {
         QuadBez(KQuadBez::new(p0.0, p1.0, p2.0))
     }
 
@@ -33,6 +34,31 @@ impl QuadBez {
         self.0.raise().into()
     }
 
+    /// The greatest distance of the control points from the chord (the line
+    /// segment from ``p0`` to ``p2``).
+    ///
+    /// The curve lies entirely within this distance of the chord, so this
+    /// is an upper bound on how far it strays from a straight line.
+    ///
+    /// Note that this method is not in original kurbo
+    fn flatness(&self) -> f64 {
+        let p = self.0;
+        crate::cleanup::flatness(&[p.p0, p.p1, p.p2])
+    }
+
+    /// Whether the quadratic is a straight line to within ``tolerance``, that is,
+    /// whether it could be replaced by a ``Line`` from ``p0`` to ``p2``
+    /// without moving any part of it further than ``tolerance``.
+    ///
+    /// A curve whose control points lie on the line through its ends but
+    /// beyond them doubles back on itself, and is not considered linear.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(signature = (tolerance=crate::DEFAULT_TOLERANCE))]
+    fn is_linear(&self, tolerance: f64) -> bool {
+        self.flatness() <= tolerance
+    }
+
     #[getter]
     fn get_p0(&self) -> Point {
         self.0.p0.into()
@@ -46,20 +72,25 @@ impl QuadBez {
         self.0.p2.into()
     }
     #[setter]
-    fn set_p0(&mut self, p0: Point) {
+    fn set_p0(&mut self, p0: PointLike) {
         self.0.p0 = p0.0;
     }
     #[setter]
-    fn set_p1(&mut self, p1: Point) {
+    fn set_p1(&mut self, p1: PointLike) {
         self.0.p1 = p1.0;
     }
     #[setter]
-    fn set_p2(&mut self, p2: Point) {
+    fn set_p2(&mut self, p2: PointLike) {
         self.0.p2 = p2.0;
     }
+
+    fn __repr__(&self) -> String {
+        crate::repr::quad(self.0)
+    }
 }
 
 impl_isfinitenan!(QuadBez);
+impl_serialize!(QuadBez);
 impl_paramcurve!(QuadBez);
 impl_paramcurvearclen!(QuadBez);
 impl_paramcurvearea!(QuadBez);
@@ -67,4 +98,7 @@ impl_paramcurvecurvature!(QuadBez);
 impl_paramcurvederiv!(QuadBez, Line);
 impl_paramcurveextrema!(QuadBez);
 impl_paramcurvenearest!(QuadBez);
+impl_paramcurveproject!(QuadBez);
 impl_shape_no_bounding_box!(QuadBez);
+impl_round_coordinates!(QuadBez);
+impl_approx_eq!(QuadBez);
@@ -1,6 +1,7 @@
 use kurbo::QuadSpline as KQuadSpline;
-use crate::{point::Point, quadbez::QuadBez};
+use crate::{point::{Point, PointLike}, quadbez::QuadBez};
 use pyo3::prelude::*;
+use pyo3::types::PyType;
 
 #[derive(Clone, Debug)]
 #[pyclass(subclass, module = "kurbopy")]
@@ -15,7 +16,7 @@ impl From<KQuadSpline> for QuadSpline {
 #[pymethods]
 impl QuadSpline {
     #[new]
-    fn __new__(points: Vec<Point>) -> Self {
+    fn __new__(points: Vec<PointLike>) -> Self {
         QuadSpline(KQuadSpline::new(points.iter().map(|p| p.0).collect()))
     }
     /// Return the spline’s control `Point`s.
@@ -26,4 +27,126 @@ impl QuadSpline {
     fn to_quads(&self) -> Vec<QuadBez> {
         self.0.to_quads().map(QuadBez).collect()
     }
+
+    fn __repr__(&self) -> String {
+        format!("QuadSpline([{}])", crate::repr::points(self.0.points()))
+    }
+}
+
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+/// The result of converting a batch of cubics to quadratic splines.
+///
+/// ``splines`` has one entry per input curve, which is ``None`` for
+/// curves that could not be converted. ``failures`` lists the index of
+/// each such curve together with the reason it failed.
+///
+/// When ``compatible`` is true, all converted splines have the same number
+/// of points and can be interpolated against each other.
+pub struct QuadSplineConversion {
+    pub splines: Vec<Option<QuadSpline>>,
+    pub failures: Vec<(usize, String)>,
+    pub compatible: bool,
+}
+
+#[pymethods]
+impl QuadSplineConversion {
+    /// Create a `QuadSplineConversion` from its fields.
+    ///
+    /// Note that this constructor is not in original kurbo
+    #[new]
+    fn __new__(
+        splines: Vec<Option<QuadSpline>>,
+        failures: Vec<(usize, String)>,
+        compatible: bool,
+    ) -> Self {
+        QuadSplineConversion {
+            splines,
+            failures,
+            compatible,
+        }
+    }
+    #[getter]
+    fn get_splines(&self) -> Vec<Option<QuadSpline>> {
+        self.splines.clone()
+    }
+    #[getter]
+    fn get_failures(&self) -> Vec<(usize, String)> {
+        self.failures.clone()
+    }
+    #[getter]
+    fn get_compatible(&self) -> bool {
+        self.compatible
+    }
+    /// The indices of the curves which were successfully converted.
+    fn succeeded(&self) -> Vec<usize> {
+        self.splines
+            .iter()
+            .enumerate()
+            .filter_map(|(ix, s)| s.as_ref().map(|_| ix))
+            .collect()
+    }
+    /// Returns `true` if every curve was converted.
+    fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let splines: Vec<String> = self
+            .splines
+            .iter()
+            .map(|s| s.as_ref().map_or("None".to_string(), |s| s.__repr__()))
+            .collect();
+        let failures = self.failures.to_object(py).into_bound(py).repr()?;
+        let compatible = if self.compatible { "True" } else { "False" };
+        Ok(format!(
+            "QuadSplineConversion([{}], {}, {})",
+            splines.join(", "),
+            failures,
+            compatible
+        ))
+    }
+}
+
+// kurbo's QuadSpline has no serde support, so it is serialized through its
+// points, giving the ``[Point, ...]`` representation.
+#[pymethods]
+impl QuadSpline {
+    /// Serialize to a JSON string, as described in :doc:`serialization`.
+    ///
+    /// Note that this method is not in original kurbo
+    fn to_json(&self) -> PyResult<String> {
+        serde_json::to_string(self.0.points()).map_err(crate::serialize::json_error)
+    }
+
+    /// Deserialize from a JSON string, as produced by ``to_json``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    fn from_json(cls: &Bound<'_, PyType>, json: &str) -> PyResult<Py<Self>> {
+        let points = serde_json::from_str(json).map_err(crate::serialize::json_error)?;
+        crate::subclass::new_of_type(cls, QuadSpline(KQuadSpline::new(points)))
+    }
+
+    /// Convert to plain Python data (dicts, lists and floats) with the same
+    /// structure as the JSON produced by ``to_json``.
+    ///
+    /// Note that this method is not in original kurbo
+    fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let json = self.to_json()?;
+        crate::serialize::json_to_py(py, &json)
+    }
+
+    /// Build from plain Python data, as produced by ``to_dict``.
+    ///
+    /// Note that this method is not in original kurbo
+    #[classmethod]
+    fn from_dict(
+        cls: &Bound<'_, PyType>,
+        py: Python<'_>,
+        data: &Bound<'_, PyAny>,
+    ) -> PyResult<Py<Self>> {
+        let json = crate::serialize::py_to_json(py, data)?;
+        Self::from_json(cls, &json)
+    }
 }
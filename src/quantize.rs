@@ -0,0 +1,163 @@
+// Rounding of coordinates to a number of decimal places or to a grid.
+use kurbo::{
+    BezPath as KBezPath, CubicBez as KCubicBez, Line as KLine, PathEl as KPathEl,
+    PathSeg as KPathSeg, Point as KPoint, QuadBez as KQuadBez, Rect as KRect, Vec2 as KVec2,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// How to round a coordinate.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Rounding {
+    /// To a number of decimal places, as with Python's ``round``.
+    Digits(i32),
+    /// To the nearest multiple of a grid size.
+    Grid(f64),
+}
+
+impl Rounding {
+    /// Parse the ``ndigits`` and ``grid`` arguments of ``round_coordinates``.
+    pub(crate) fn new(ndigits: Option<i32>, grid: Option<f64>) -> PyResult<Self> {
+        match (ndigits, grid) {
+            (Some(_), Some(_)) => Err(PyValueError::new_err(
+                "give either ndigits or grid, not both",
+            )),
+            (_, Some(grid)) if !(grid.is_finite() && grid > 0.0) => {
+                Err(PyValueError::new_err("grid size must be positive"))
+            }
+            (_, Some(grid)) => Ok(Rounding::Grid(grid)),
+            (ndigits, None) => Ok(Rounding::Digits(ndigits.unwrap_or(0))),
+        }
+    }
+
+    pub(crate) fn apply(self, x: f64) -> f64 {
+        match self {
+            // Scaling up and dividing back avoids the error of multiplying
+            // by an inexact power of ten, so 0.3 rounds to 0.3 and not to
+            // 0.30000000000000004.
+            Rounding::Digits(n) if n >= 0 => {
+                let scale = 10f64.powi(n);
+                (x * scale).round() / scale
+            }
+            Rounding::Digits(n) => {
+                let scale = 10f64.powi(-n);
+                (x / scale).round() * scale
+            }
+            Rounding::Grid(grid) => (x / grid).round() * grid,
+        }
+    }
+}
+
+/// Values whose coordinates can be rounded.
+pub(crate) trait Quantize {
+    fn quantize(&self, rounding: Rounding) -> Self;
+}
+
+impl Quantize for KPoint {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KPoint::new(rounding.apply(self.x), rounding.apply(self.y))
+    }
+}
+
+impl Quantize for KVec2 {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KVec2::new(rounding.apply(self.x), rounding.apply(self.y))
+    }
+}
+
+impl Quantize for KLine {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KLine::new(self.p0.quantize(rounding), self.p1.quantize(rounding))
+    }
+}
+
+impl Quantize for KQuadBez {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KQuadBez::new(
+            self.p0.quantize(rounding),
+            self.p1.quantize(rounding),
+            self.p2.quantize(rounding),
+        )
+    }
+}
+
+impl Quantize for KCubicBez {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KCubicBez::new(
+            self.p0.quantize(rounding),
+            self.p1.quantize(rounding),
+            self.p2.quantize(rounding),
+            self.p3.quantize(rounding),
+        )
+    }
+}
+
+impl Quantize for KRect {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        KRect::new(
+            rounding.apply(self.x0),
+            rounding.apply(self.y0),
+            rounding.apply(self.x1),
+            rounding.apply(self.y1),
+        )
+    }
+}
+
+impl Quantize for KPathSeg {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        match self {
+            KPathSeg::Line(l) => KPathSeg::Line(l.quantize(rounding)),
+            KPathSeg::Quad(q) => KPathSeg::Quad(q.quantize(rounding)),
+            KPathSeg::Cubic(c) => KPathSeg::Cubic(c.quantize(rounding)),
+        }
+    }
+}
+
+impl Quantize for KPathEl {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        match *self {
+            KPathEl::MoveTo(p) => KPathEl::MoveTo(p.quantize(rounding)),
+            KPathEl::LineTo(p) => KPathEl::LineTo(p.quantize(rounding)),
+            KPathEl::QuadTo(p1, p2) => {
+                KPathEl::QuadTo(p1.quantize(rounding), p2.quantize(rounding))
+            }
+            KPathEl::CurveTo(p1, p2, p3) => KPathEl::CurveTo(
+                p1.quantize(rounding),
+                p2.quantize(rounding),
+                p3.quantize(rounding),
+            ),
+            KPathEl::ClosePath => KPathEl::ClosePath,
+        }
+    }
+}
+
+impl Quantize for KBezPath {
+    fn quantize(&self, rounding: Rounding) -> Self {
+        self.iter().map(|el| el.quantize(rounding)).collect()
+    }
+}
+
+#[macro_export]
+macro_rules! impl_round_coordinates {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// Round all coordinates, either to ``ndigits`` decimal places
+            /// (as with Python's ``round``) or to the nearest multiple of
+            /// ``grid``. With neither, coordinates are rounded to integers.
+            ///
+            /// Note that this method is not in original kurbo
+            #[pyo3(signature = (ndigits=None, grid=None))]
+            fn round_coordinates(
+                slf: &Bound<'_, Self>,
+                ndigits: Option<i32>,
+                grid: Option<f64>,
+            ) -> PyResult<Py<Self>> {
+                use $crate::quantize::Quantize;
+                let rounding = $crate::quantize::Rounding::new(ndigits, grid)?;
+                let rounded = slf.borrow().0.quantize(rounding);
+                $crate::subclass::new_like(slf, rounded.into())
+            }
+        }
+    };
+}
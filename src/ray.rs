@@ -0,0 +1,211 @@
+use crate::point::{Point, PointLike};
+use crate::vec2::{Vec2, Vec2Like};
+use kurbo::{
+    BezPath as KBezPath, Line as KLine, ParamCurve, ParamCurveExtrema, PathSeg as KPathSeg,
+    Point as KPoint, Rect as KRect, Vec2 as KVec2,
+};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// A half-line, starting at ``origin`` and going on for ever in the
+/// direction of ``direction``.
+///
+/// ::
+///
+///    from kurbopy import Ray, Rect
+///    ray = Ray((5, 5), (1, 0))
+///    hit = Rect(0, 0, 10, 10).to_path().raycast(ray)
+///    # hit.distance == 5, hit.point == Point(10, 5)
+///
+/// Note that this class is not in original kurbo
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct Ray {
+    origin: KPoint,
+    direction: KVec2,
+}
+
+impl Ray {
+    /// The direction as a unit vector.
+    fn unit(&self) -> KVec2 {
+        self.direction.normalize()
+    }
+
+    /// The distances along the ray at which it enters and leaves a
+    /// rectangle, if it meets it.
+    fn rect_span(&self, rect: KRect) -> Option<(f64, f64)> {
+        let d = self.unit();
+        let (mut t0, mut t1) = (0.0_f64, f64::INFINITY);
+        for (o, d, lo, hi) in [
+            (self.origin.x, d.x, rect.x0, rect.x1),
+            (self.origin.y, d.y, rect.y0, rect.y1),
+        ] {
+            if d == 0.0 {
+                if o < lo || o > hi {
+                    return None;
+                }
+            } else {
+                let (a, b) = ((lo - o) / d, (hi - o) / d);
+                t0 = t0.max(a.min(b));
+                t1 = t1.min(a.max(b));
+            }
+        }
+        (t0 <= t1).then_some((t0, t1))
+    }
+
+    /// The places where the ray meets the path, ordered by distance.
+    /// Segments are examined in order of the distance at which the ray
+    /// reaches their bounding boxes, and those it misses are skipped; if
+    /// `first_only` is true, the search stops as soon as no nearer hit is
+    /// possible. Where the ray passes through the join between two
+    /// segments, only the first is reported.
+    pub(crate) fn hits(&self, path: &KBezPath, first_only: bool) -> Vec<RayHit> {
+        const EPS: f64 = 1e-9;
+        let d = self.unit();
+        let mut candidates: Vec<(f64, f64, usize, KPathSeg)> = path
+            .segments()
+            .enumerate()
+            .filter_map(|(ix, seg)| {
+                let (near, far) = self.rect_span(seg.bounding_box())?;
+                Some((near, far, ix, seg))
+            })
+            .collect();
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut hits: Vec<RayHit> = vec![];
+        for (near, far, ix, seg) in candidates {
+            if first_only && hits.iter().any(|h| h.distance < near) {
+                break;
+            }
+            // The ray is cut off a little beyond the segment's bounding box.
+            let length = far + 1.0;
+            let line = KLine::new(self.origin, self.origin + d * length);
+            for hit in seg.intersect_line(line) {
+                hits.push(RayHit {
+                    distance: hit.line_t * length,
+                    point: line.eval(hit.line_t),
+                    segment_index: ix,
+                    segment_t: hit.segment_t,
+                });
+            }
+        }
+        hits.sort_by(|a, b| {
+            a.distance.total_cmp(&b.distance).then(a.segment_index.cmp(&b.segment_index))
+        });
+        let at_end = |t: f64| t <= EPS || t >= 1.0 - EPS;
+        let mut kept: Vec<RayHit> = vec![];
+        for hit in hits {
+            let duplicate = at_end(hit.segment_t)
+                && kept.iter().any(|k| {
+                    at_end(k.segment_t)
+                        && (k.distance - hit.distance).abs() <= EPS * hit.distance.max(1.0)
+                });
+            if !duplicate {
+                kept.push(hit);
+            }
+        }
+        if first_only {
+            kept.truncate(1);
+        }
+        kept
+    }
+}
+
+#[pymethods]
+impl Ray {
+    /// Create a ray from its origin and direction. Raises ``ValueError`` if
+    /// the direction is zero or not finite.
+    #[new]
+    fn __new__(origin: PointLike, direction: Vec2Like) -> PyResult<Self> {
+        let direction = direction.0;
+        if !(direction.is_finite() && direction.hypot2() > 0.0) {
+            return Err(PyValueError::new_err(
+                "direction must be finite and non-zero",
+            ));
+        }
+        Ok(Ray {
+            origin: origin.0,
+            direction,
+        })
+    }
+
+    /// The point the ray starts from.
+    #[getter]
+    fn origin(&self) -> Point {
+        self.origin.into()
+    }
+
+    /// The direction of the ray, as given when it was created.
+    #[getter]
+    fn direction(&self) -> Vec2 {
+        Vec2(self.direction)
+    }
+
+    /// The point ``distance`` along the ray from its origin.
+    fn point_at(&self, distance: f64) -> Point {
+        (self.origin + self.unit() * distance).into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Ray({}, {})",
+            crate::repr::point(self.origin),
+            crate::repr::vec2(self.direction)
+        )
+    }
+}
+
+/// A place where a :py:class:`Ray` meets a path, as found by
+/// :py:meth:`BezPath.raycast` and :py:meth:`BezPath.raycast_all`.
+///
+/// Note that this class is not in original kurbo
+#[pyclass(subclass, module = "kurbopy")]
+#[derive(Clone, Debug)]
+pub struct RayHit {
+    distance: f64,
+    point: KPoint,
+    segment_index: usize,
+    segment_t: f64,
+}
+
+#[pymethods]
+impl RayHit {
+    #[new]
+    fn __new__(distance: f64, point: PointLike, segment_index: usize, segment_t: f64) -> Self {
+        RayHit {
+            distance,
+            point: point.0,
+            segment_index,
+            segment_t,
+        }
+    }
+    /// The distance from the origin of the ray to the hit.
+    #[getter]
+    fn distance(&self) -> f64 {
+        self.distance
+    }
+    /// The point hit.
+    #[getter]
+    fn point(&self) -> Point {
+        self.point.into()
+    }
+    /// The index of the segment hit, as yielded by ``BezPath.segments``.
+    #[getter]
+    fn segment_index(&self) -> usize {
+        self.segment_index
+    }
+    /// The parameter value of the hit on the segment.
+    #[getter]
+    fn segment_t(&self) -> f64 {
+        self.segment_t
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RayHit({}, {}, {}, {})",
+            crate::repr::float(self.distance),
+            crate::repr::point(self.point),
+            self.segment_index,
+            crate::repr::float(self.segment_t)
+        )
+    }
+}
@@ -1,8 +1,11 @@
+use crate::impl_serialize;
 use crate::insets::Insets;
-use crate::point::Point;
+use crate::point::{Point, PointLike};
+use crate::roundedrect::{RadiiLike, RoundedRect};
 use crate::size::Size;
+use crate::subclass::{new_like, new_of_type};
 use crate::vec2::Vec2;
-use crate::{impl_isfinitenan, impl_shape, polymorphic};
+use crate::{impl_approx_eq, impl_isfinitenan, impl_round_coordinates, impl_shape, polymorphic};
 use pyo3::types::PyType;
 
 use kurbo::{Rect as KRect, Shape};
@@ -56,32 +59,32 @@ impl Rect {
     ///
     /// The result will have non-negative width and height.
     #[pyo3(text_signature = "(cls, p0, p1)")]
-    fn from_points(_cls: &Bound<'_, PyType>, p0: Point, p1: Point) -> Rect {
-        Rect(KRect::from_points(p0.0, p1.0))
+    fn from_points(cls: &Bound<'_, PyType>, p0: PointLike, p1: PointLike) -> PyResult<Py<Self>> {
+        new_of_type(cls, Rect(KRect::from_points(p0.0, p1.0)))
     }
 
     #[classmethod]
-    fn from_origin_size(_cls: &Bound<'_, PyType>, p0: Point, p1: Size) -> Self {
-        Rect(KRect::from_origin_size(p0.0, p1.0))
+    fn from_origin_size(cls: &Bound<'_, PyType>, p0: PointLike, p1: Size) -> PyResult<Py<Self>> {
+        new_of_type(cls, Rect(KRect::from_origin_size(p0.0, p1.0)))
     }
 
     #[classmethod]
-    fn from_center_size(_cls: &Bound<'_, PyType>, p0: Point, p1: Size) -> Self {
-        Rect(KRect::from_center_size(p0.0, p1.0))
+    fn from_center_size(cls: &Bound<'_, PyType>, p0: PointLike, p1: Size) -> PyResult<Py<Self>> {
+        new_of_type(cls, Rect(KRect::from_center_size(p0.0, p1.0)))
     }
 
     /// Create a new `Rect` with the same size as `self` and a new origin.
     #[pyo3(text_signature = "($self, origin)")]
-    fn with_origin(&self, origin: Point) -> Self {
-        self.0.with_origin(origin.0).into()
+    fn with_origin(slf: &Bound<'_, Self>, origin: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.with_origin(origin.0).into())
     }
 
-    fn with_size(&self, size: Size) -> Self {
-        self.0.with_size(size.0).into()
+    fn with_size(slf: &Bound<'_, Self>, size: Size) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.with_size(size.0).into())
     }
 
-    fn inset(&self, inset: Insets) -> Self {
-        self.0.inset(inset.0).into()
+    fn inset(slf: &Bound<'_, Self>, inset: Insets) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.inset(inset.0).into())
     }
 
     /// The width of the rectangle.
@@ -143,15 +146,15 @@ impl Rect {
     ///
     /// The resulting rect has the same extents as the original, but is
     /// guaranteed to have non-negative width and height.
-    fn abs(&self) -> Rect {
-        self.0.abs().into()
+    fn abs(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.abs().into())
     }
 
     /// The smallest rectangle enclosing two rectangles.
     ///
     /// Results are valid only if width and height are non-negative.
-    fn union(&self, other: Rect) -> Rect {
-        self.0.union(other.0).into()
+    fn union(slf: &Bound<'_, Self>, other: Rect) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.union(other.0).into())
     }
 
     /// Compute the union with one point.
@@ -161,16 +164,48 @@ impl Rect {
     /// points yields their enclosing rectangle.
     ///
     /// Results are valid only if width and height are non-negative.
-    fn union_pt(&self, pt: Point) -> Rect {
-        self.0.union_pt(pt.0).into()
+    fn union_pt(slf: &Bound<'_, Self>, pt: PointLike) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.union_pt(pt.0).into())
     }
 
     /// The intersection of two rectangles.
     ///
     /// The result is zero-area if either input has negative width or
     /// height. The result always has non-negative width and height.
-    fn intersect(&self, other: Rect) -> Rect {
-        self.0.intersect(other.0).into()
+    fn intersect(slf: &Bound<'_, Self>, other: Rect) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.intersect(other.0).into())
+    }
+
+    /// Returns true if ``other`` lies entirely within this rectangle,
+    /// including the case where they share edges.
+    ///
+    /// Note that this method is not in original kurbo
+    fn contains_rect(&self, other: &Rect) -> bool {
+        let (a, b) = (self.0.abs(), other.0.abs());
+        a.x0 <= b.x0 && b.x1 <= a.x1 && a.y0 <= b.y0 && b.y1 <= a.y1
+    }
+
+    /// Returns true if the two rectangles have any point in common.
+    ///
+    /// Rectangles which only touch along an edge or at a corner count as
+    /// overlapping, as do zero-width or zero-height rectangles lying within
+    /// the other, even though their intersection has no area.
+    ///
+    /// Note that this method is not in original kurbo
+    fn overlaps(&self, other: &Rect) -> bool {
+        let (a, b) = (self.0.abs(), other.0.abs());
+        a.x0 <= b.x1 && b.x0 <= a.x1 && a.y0 <= b.y1 && b.y0 <= a.y1
+    }
+
+    /// Supports ``point in rect`` and ``other_rect in rect``. Points on the
+    /// edge of the rectangle are considered to be inside it.
+    fn __contains__(&self, item: &Bound<'_, PyAny>) -> PyResult<bool> {
+        if let Ok(other) = item.downcast::<Rect>() {
+            return Ok(self.contains_rect(&other.borrow()));
+        }
+        let pt = item.extract::<PointLike>()?.0;
+        let r = self.0.abs();
+        Ok(r.x0 <= pt.x && pt.x <= r.x1 && r.y0 <= pt.y && pt.y <= r.y1)
     }
 
     /// Expand a rectangle by a constant amount in both directions.
@@ -178,26 +213,26 @@ impl Rect {
     /// The logic simply applies the amount in each direction. If rectangle
     /// area or added dimensions are negative, this could give odd results.
     #[pyo3(text_signature = "($self, width, height)")]
-    fn inflate(&self, width: f64, height: f64) -> Rect {
-        self.0.inflate(width, height).into()
+    fn inflate(slf: &Bound<'_, Self>, width: f64, height: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.inflate(width, height).into())
     }
 
     /// Returns a new `Rect`,
     /// with each coordinate value rounded to the nearest integer.
-    fn round(&self) -> Rect {
-        self.0.round().into()
+    fn round(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.round().into())
     }
     /// Returns a new `Rect`,
     /// with each coordinate value rounded up to the nearest integer,
     /// unless they are already an integer.
-    fn ceil(&self) -> Rect {
-        self.0.ceil().into()
+    fn ceil(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.ceil().into())
     }
     /// Returns a new `Rect`,
     /// with each coordinate value rounded down to the nearest integer,
     /// unless they are already an integer.
-    fn floor(&self) -> Rect {
-        self.0.floor().into()
+    fn floor(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.floor().into())
     }
     /// Returns a new `Rect`,
     /// with each coordinate value rounded away from the center of the `Rect`
@@ -205,16 +240,16 @@ impl Rect {
     /// That is to say this function will return the smallest possible `Rect`
     /// with integer coordinates that is a superset of `self`.
     ///
-    fn expand(&self) -> Rect {
-        self.0.expand().into()
+    fn expand(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.expand().into())
     }
     /// Returns a new `Rect`,
     /// with each coordinate value rounded towards the center of the `Rect`
     /// to the nearest integer, unless they are already an integer.
     /// That is to say this function will return the biggest possible `Rect`
     /// with integer coordinates that is a subset of `self`.
-    fn trunc(&self) -> Rect {
-        self.0.trunc().into()
+    fn trunc(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.trunc().into())
     }
     /// Scales the ``Rect`` by ``factor`` with respect to the origin (the point ``(0, 0)``).
     ///
@@ -225,8 +260,8 @@ impl Rect {
     ///     assert rect.x0 == 4
     ///     assert rect.x1 == 8
     #[pyo3(text_signature = "($self, factor)")]
-    fn scale_from_origin(&self, factor: f64) -> Rect {
-        self.0.scale_from_origin(factor).into()
+    fn scale_from_origin(slf: &Bound<'_, Self>, factor: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.scale_from_origin(factor).into())
     }
 
     /// The aspect ratio of the ``Rect``.
@@ -252,17 +287,45 @@ impl Rect {
     /// For the special case where the aspect ratio is ``1.0``, the resulting
     /// ``Rect`` will be square.
     #[pyo3(text_signature = "($self, aspect_ratio)")]
-    fn contained_rect_with_aspect_ratio(&self, aspect_ratio: f64) -> Rect {
-        self.0.contained_rect_with_aspect_ratio(aspect_ratio).into()
+    fn contained_rect_with_aspect_ratio(slf: &Bound<'_, Self>, aspect_ratio: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.contained_rect_with_aspect_ratio(aspect_ratio).into())
+    }
+
+    /// Create a :py:class:`RoundedRect` from this `Rect` with the provided
+    /// corner radii, given either as a single radius, a tuple of four radii
+    /// or a :py:class:`RoundedRectRadii`.
+    fn to_rounded_rect(&self, radii: RadiiLike) -> RoundedRect {
+        self.0.to_rounded_rect(radii.0).into()
+    }
+
+    /// Scale all coordinates of the rectangle, about the origin.
+    ///
+    /// Note that this method is not in original kurbo
+    fn __mul__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        let KRect { x0, y0, x1, y1 } = slf.borrow().0;
+        new_like(slf, Rect(KRect::new(x0 * rhs, y0 * rhs, x1 * rhs, y1 * rhs)))
+    }
+
+    fn __rmul__(slf: &Bound<'_, Self>, lhs: f64) -> PyResult<Py<Self>> {
+        Self::__mul__(slf, lhs)
+    }
+
+    fn __truediv__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        let KRect { x0, y0, x1, y1 } = slf.borrow().0;
+        new_like(slf, Rect(KRect::new(x0 / rhs, y0 / rhs, x1 / rhs, y1 / rhs)))
     }
 
     fn __repr__(&self) -> String {
-        format!("{:?}", self.0)
+        let r = &self.0;
+        format!("Rect({})", crate::repr::floats(&[r.x0, r.y0, r.x1, r.y1]))
     }
 }
 
 impl_isfinitenan!(Rect);
+impl_serialize!(Rect);
 impl_shape!(Rect);
+impl_round_coordinates!(Rect);
+impl_approx_eq!(Rect);
 polymorphic!(add Rect => (_add_Vec2, Vec2, Rect),
                          (_add_Insets, Insets, Rect)
 );
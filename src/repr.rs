@@ -0,0 +1,45 @@
+// Helpers for `__repr__` strings which can be pasted back into Python.
+use kurbo::{
+    CubicBez as KCubicBez, Line as KLine, Point as KPoint, QuadBez as KQuadBez, Vec2 as KVec2,
+};
+
+/// A float as a Python expression.
+pub(crate) fn float(x: f64) -> String {
+    if x.is_nan() {
+        "float('nan')".to_string()
+    } else if x.is_infinite() {
+        format!("{}float('inf')", if x < 0.0 { "-" } else { "" })
+    } else {
+        format!("{:?}", x)
+    }
+}
+
+/// A comma-separated list of floats.
+pub(crate) fn floats(xs: &[f64]) -> String {
+    xs.iter().map(|x| float(*x)).collect::<Vec<_>>().join(", ")
+}
+
+pub(crate) fn point(p: KPoint) -> String {
+    format!("Point({}, {})", float(p.x), float(p.y))
+}
+
+pub(crate) fn vec2(v: KVec2) -> String {
+    format!("Vec2({}, {})", float(v.x), float(v.y))
+}
+
+/// A comma-separated list of points.
+pub(crate) fn points(ps: &[KPoint]) -> String {
+    ps.iter().map(|p| point(*p)).collect::<Vec<_>>().join(", ")
+}
+
+pub(crate) fn line(l: KLine) -> String {
+    format!("Line({})", points(&[l.p0, l.p1]))
+}
+
+pub(crate) fn quad(q: KQuadBez) -> String {
+    format!("QuadBez({})", points(&[q.p0, q.p1, q.p2]))
+}
+
+pub(crate) fn cubic(c: KCubicBez) -> String {
+    format!("CubicBez({})", points(&[c.p0, c.p1, c.p2, c.p3]))
+}
@@ -0,0 +1,228 @@
+use crate::impl_serialize;
+use crate::point::{Point, PointLike};
+use crate::rect::Rect;
+use crate::size::Size;
+use crate::subclass::{new_like, new_of_type};
+use crate::vec2::Vec2Like;
+use crate::{impl_isfinitenan, impl_shape};
+
+use kurbo::{RoundedRect as KRoundedRect, RoundedRectRadii as KRoundedRectRadii, Shape};
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
+#[derive(Clone, Debug)]
+#[pyclass(subclass, module = "kurbopy")]
+/// Radii for each corner of a rounded rectangle.
+///
+/// The use of `top` as in `top_left` assumes a y-down coordinate space. Piet
+/// (and Druid by extension) uses a y-down coordinate space, but Kurbo also
+/// supports a y-up coordinate space, in which case `top_left` would actually
+/// refer to the bottom-left corner, and vice versa. Top may not always
+/// actually be the top, but `top` corners will always have a smaller y-value
+/// than `bottom` corners.
+pub struct RoundedRectRadii(pub KRoundedRectRadii);
+
+impl From<KRoundedRectRadii> for RoundedRectRadii {
+    fn from(p: KRoundedRectRadii) -> Self {
+        Self(p)
+    }
+}
+
+/// A radii argument, which may be given as a `RoundedRectRadii`, a single
+/// radius for all corners, or a ``(top_left, top_right, bottom_right,
+/// bottom_left)`` tuple.
+#[derive(Clone, Copy, Debug)]
+pub struct RadiiLike(pub KRoundedRectRadii);
+
+impl<'py> FromPyObject<'py> for RadiiLike {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(r) = ob.downcast::<RoundedRectRadii>() {
+            return Ok(RadiiLike(r.borrow().0));
+        }
+        if let Ok(radius) = ob.extract::<f64>() {
+            return Ok(RadiiLike(KRoundedRectRadii::from_single_radius(radius)));
+        }
+        ob.extract::<(f64, f64, f64, f64)>()
+            .map(|(tl, tr, br, bl)| RadiiLike(KRoundedRectRadii::new(tl, tr, br, bl)))
+            .map_err(|_| {
+                PyTypeError::new_err(
+                    "expected RoundedRectRadii, a radius or a tuple of four radii",
+                )
+            })
+    }
+}
+
+#[pymethods]
+impl RoundedRectRadii {
+    /// Create a new `RoundedRectRadii`. This function takes radius values for
+    /// the four corners. The argument order is `top_left`, `top_right`,
+    /// `bottom_right`, `bottom_left`, or clockwise starting from `top_left`.
+    #[new]
+    fn __new__(top_left: f64, top_right: f64, bottom_right: f64, bottom_left: f64) -> Self {
+        Self(KRoundedRectRadii::new(
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+        ))
+    }
+
+    /// Create a new `RoundedRectRadii` from a single radius.
+    #[classmethod]
+    fn from_single_radius(cls: &Bound<'_, PyType>, radius: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KRoundedRectRadii::from_single_radius(radius)))
+    }
+
+    #[getter]
+    fn top_left(&self) -> f64 {
+        self.0.top_left
+    }
+    #[getter]
+    fn top_right(&self) -> f64 {
+        self.0.top_right
+    }
+    #[getter]
+    fn bottom_right(&self) -> f64 {
+        self.0.bottom_right
+    }
+    #[getter]
+    fn bottom_left(&self) -> f64 {
+        self.0.bottom_left
+    }
+
+    /// Takes the absolute value of all corner radii.
+    fn abs(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.abs().into())
+    }
+
+    /// For each corner, takes the min of that value and the given `max`.
+    fn clamp(slf: &Bound<'_, Self>, max: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.clamp(max).into())
+    }
+
+    /// If all radii are equal, returns the value of the radii. Otherwise,
+    /// returns `None`.
+    fn as_single_radius(&self) -> Option<f64> {
+        self.0.as_single_radius()
+    }
+
+    fn __repr__(&self) -> String {
+        let r = &self.0;
+        format!(
+            "RoundedRectRadii({})",
+            crate::repr::floats(&[r.top_left, r.top_right, r.bottom_right, r.bottom_left])
+        )
+    }
+}
+impl_isfinitenan!(RoundedRectRadii);
+impl_serialize!(RoundedRectRadii);
+
+#[derive(Clone, Debug)]
+#[pyclass(subclass, module = "kurbopy")]
+/// A rectangle with equally sized rounded corners.
+///
+/// Wherever radii are expected, a single radius for all four corners or a
+/// ``(top_left, top_right, bottom_right, bottom_left)`` tuple may be given
+/// instead of a :py:class:`RoundedRectRadii`.
+pub struct RoundedRect(pub KRoundedRect);
+
+impl From<KRoundedRect> for RoundedRect {
+    fn from(p: KRoundedRect) -> Self {
+        Self(p)
+    }
+}
+
+#[pymethods]
+impl RoundedRect {
+    /// A new rectangle from minimum and maximum coordinates.
+    ///
+    /// The result will have non-negative width, height and radii.
+    #[new]
+    fn __new__(x0: f64, y0: f64, x1: f64, y1: f64, radii: RadiiLike) -> Self {
+        Self(KRoundedRect::new(x0, y0, x1, y1, radii.0))
+    }
+
+    /// A new rounded rectangle from a rectangle and corner radii.
+    #[classmethod]
+    fn from_rect(cls: &Bound<'_, PyType>, rect: &Rect, radii: RadiiLike) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KRoundedRect::from_rect(rect.0, radii.0)))
+    }
+
+    /// A new rectangle from two `Point`s.
+    ///
+    /// The result will have non-negative width, height and radius.
+    #[classmethod]
+    fn from_points(
+        cls: &Bound<'_, PyType>,
+        p0: PointLike,
+        p1: PointLike,
+        radii: RadiiLike,
+    ) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KRoundedRect::from_points(p0.0, p1.0, radii.0)))
+    }
+
+    /// A new rectangle from origin and size.
+    ///
+    /// The result will have non-negative width, height and radius.
+    #[classmethod]
+    fn from_origin_size(
+        cls: &Bound<'_, PyType>,
+        origin: PointLike,
+        size: &Size,
+        radii: RadiiLike,
+    ) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KRoundedRect::from_origin_size(origin.0, size.0, radii.0)))
+    }
+
+    /// The width of the rectangle.
+    fn width(&self) -> f64 {
+        self.0.width()
+    }
+
+    /// The height of the rectangle.
+    fn height(&self) -> f64 {
+        self.0.height()
+    }
+
+    /// Radii of the rounded corners.
+    fn radii(&self) -> RoundedRectRadii {
+        self.0.radii().into()
+    }
+
+    /// The (non-rounded) rectangle.
+    fn rect(&self) -> Rect {
+        self.0.rect().into()
+    }
+
+    /// The origin of the rectangle.
+    ///
+    /// This is the top left corner in a y-down space.
+    fn origin(&self) -> Point {
+        self.0.origin().into()
+    }
+
+    /// The center point of the rectangle.
+    fn center(&self) -> Point {
+        self.0.center().into()
+    }
+
+    fn __add__(slf: &Bound<'_, Self>, v: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, RoundedRect(slf.borrow().0 + v.0))
+    }
+    fn __sub__(slf: &Bound<'_, Self>, v: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, RoundedRect(slf.borrow().0 - v.0))
+    }
+
+    fn __repr__(&self) -> String {
+        let r = self.0.rect();
+        format!(
+            "RoundedRect({}, {})",
+            crate::repr::floats(&[r.x0, r.y0, r.x1, r.y1]),
+            RoundedRectRadii(self.0.radii()).__repr__()
+        )
+    }
+}
+impl_isfinitenan!(RoundedRect);
+impl_serialize!(RoundedRect);
+impl_shape!(RoundedRect);
@@ -0,0 +1,70 @@
+// Serialization of geometry to JSON and to plain Python data, using the
+// serde representations of the kurbo types.
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+pub(crate) fn json_error(e: serde_json::Error) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// Convert JSON text to plain Python data with the `json` module.
+pub(crate) fn json_to_py(py: Python<'_>, json: &str) -> PyResult<PyObject> {
+    let loads = PyModule::import_bound(py, "json")?.getattr("loads")?;
+    Ok(loads.call1((json,))?.unbind())
+}
+
+/// Convert plain Python data to JSON text with the `json` module.
+pub(crate) fn py_to_json(py: Python<'_>, data: &Bound<'_, PyAny>) -> PyResult<String> {
+    let dumps = PyModule::import_bound(py, "json")?.getattr("dumps")?;
+    dumps.call1((data,))?.extract()
+}
+
+#[macro_export]
+macro_rules! impl_serialize {
+    ($name:ident) => {
+        #[pymethods]
+        impl $name {
+            /// Serialize to a JSON string, as described in :doc:`serialization`.
+            ///
+            /// Note that this method is not in original kurbo
+            fn to_json(&self) -> PyResult<String> {
+                serde_json::to_string(&self.0).map_err($crate::serialize::json_error)
+            }
+
+            /// Deserialize from a JSON string, as produced by ``to_json``.
+            ///
+            /// Note that this method is not in original kurbo
+            #[classmethod]
+            fn from_json(
+                cls: &Bound<'_, pyo3::types::PyType>,
+                json: &str,
+            ) -> PyResult<Py<Self>> {
+                let value = serde_json::from_str(json).map_err($crate::serialize::json_error)?;
+                $crate::subclass::new_of_type(cls, Self(value))
+            }
+
+            /// Convert to plain Python data (dicts, lists and floats) with
+            /// the same structure as the JSON produced by ``to_json``.
+            ///
+            /// Note that this method is not in original kurbo
+            fn to_dict(&self, py: Python<'_>) -> PyResult<PyObject> {
+                let json = serde_json::to_string(&self.0).map_err($crate::serialize::json_error)?;
+                $crate::serialize::json_to_py(py, &json)
+            }
+
+            /// Build from plain Python data, as produced by ``to_dict``.
+            ///
+            /// Note that this method is not in original kurbo
+            #[classmethod]
+            fn from_dict(
+                cls: &Bound<'_, pyo3::types::PyType>,
+                py: Python<'_>,
+                data: &Bound<'_, PyAny>,
+            ) -> PyResult<Py<Self>> {
+                let json = $crate::serialize::py_to_json(py, data)?;
+                let value = serde_json::from_str(&json).map_err($crate::serialize::json_error)?;
+                $crate::subclass::new_of_type(cls, Self(value))
+            }
+        }
+    };
+}
@@ -20,7 +20,7 @@ macro_rules! impl_shape {
         }
 
         /// Total length of perimeter.
-        #[pyo3(text_signature = "($self, accuracy)")]
+        #[pyo3(signature = (accuracy=$crate::default_accuracy()))]
         fn perimeter(&self, accuracy: f64) -> f64 {
             self.0.perimeter(accuracy)
         }
@@ -34,7 +34,7 @@ macro_rules! impl_shape {
         /// and -1 when it is inside a negative area shape. Of course, greater
         /// magnitude values are also possible when the shape is more complex.
         #[pyo3(text_signature = "($self, pt)")]
-        fn winding(&self, pt: Point) -> i32 {
+        fn winding(&self, pt: $crate::point::PointLike) -> i32 {
             self.0.winding(pt.0)
         }
 
@@ -46,13 +46,48 @@ macro_rules! impl_shape {
         /// Returns `true` if the [`Point`] is inside this shape.
         ///
         /// This is only meaningful for closed shapes.
-        fn contains(&self, pt: Point) -> bool {
+        fn contains(&self, pt: $crate::point::PointLike) -> bool {
             self.winding(pt) != 0
         }
 
         /// Convert to a Bézier path.
-        fn to_path(&self, tolerance: f64) -> $crate::bezpath::BezPath {
-            self.0.to_path(tolerance).into()
+        ///
+        /// If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        /// where ``error`` estimates the greatest distance between the path
+        /// and the shape.
+        #[pyo3(signature = (tolerance=$crate::DEFAULT_TOLERANCE, return_error=false))]
+        fn to_path(
+            &self,
+            py: pyo3::Python<'_>,
+            tolerance: f64,
+            return_error: bool,
+        ) -> pyo3::PyObject {
+            let path = self.0.to_path(tolerance);
+            let error = || {
+                let fine: Vec<kurbo::PathSeg> = self.0.to_path(tolerance * $crate::accuracy::REFINEMENT).segments().collect();
+                $crate::accuracy::max_deviation(
+                    path.segments(),
+                    &fine,
+                    tolerance * $crate::accuracy::REFINEMENT,
+                )
+            };
+            $crate::accuracy::with_error(
+                py,
+                $crate::bezpath::BezPath::from(path.clone()),
+                error,
+                return_error,
+            )
+        }
+
+        /// Returns an iterator over this shape's path elements.
+        ///
+        /// The elements are generated lazily, which avoids building a full
+        /// :py:class:`BezPath` when they only need to be visited once.
+        #[pyo3(signature = (tolerance=$crate::DEFAULT_TOLERANCE))]
+        fn path_elements(&self, tolerance: f64) -> $crate::pathel::PathElIterator {
+            $crate::pathel::PathElIterator {
+                items: Box::new(self.0.path_elements(tolerance)),
+            }
         }
     }
 }
@@ -80,7 +115,7 @@ macro_rules! impl_shape_no_bounding_box {
         }
 
         /// Total length of perimeter.
-        #[pyo3(text_signature = "($self, accuracy)")]
+        #[pyo3(signature = (accuracy=$crate::default_accuracy()))]
         fn perimeter(&self, accuracy: f64) -> f64 {
             kurbo::Shape::perimeter(&self.0, accuracy)
         }
@@ -94,7 +129,7 @@ macro_rules! impl_shape_no_bounding_box {
         /// and -1 when it is inside a negative area shape. Of course, greater
         /// magnitude values are also possible when the shape is more complex.
         #[pyo3(text_signature = "($self, pt)")]
-        fn winding(&self, pt: Point) -> i32 {
+        fn winding(&self, pt: $crate::point::PointLike) -> i32 {
             kurbo::Shape::winding(&self.0, pt.0)
         }
 
@@ -106,13 +141,37 @@ macro_rules! impl_shape_no_bounding_box {
         /// Returns `true` if the [`Point`] is inside this shape.
         ///
         /// This is only meaningful for closed shapes.
-        fn contains(&self, pt: Point) -> bool {
+        fn contains(&self, pt: $crate::point::PointLike) -> bool {
             kurbo::Shape::contains(&self.0, pt.0)
         }
 
         /// Convert to a Bézier path.
-        fn to_path(&self, tolerance: f64) -> $crate::bezpath::BezPath {
-            kurbo::Shape::to_path(&self.0, tolerance).into()
+        ///
+        /// If ``return_error`` is true, returns a ``(path, error)`` tuple,
+        /// where ``error`` estimates the greatest distance between the path
+        /// and the shape.
+        #[pyo3(signature = (tolerance=$crate::DEFAULT_TOLERANCE, return_error=false))]
+        fn to_path(
+            &self,
+            py: pyo3::Python<'_>,
+            tolerance: f64,
+            return_error: bool,
+        ) -> pyo3::PyObject {
+            let path = kurbo::Shape::to_path(&self.0, tolerance);
+            let error = || {
+                let fine: Vec<kurbo::PathSeg> = kurbo::Shape::to_path(&self.0, tolerance * $crate::accuracy::REFINEMENT).segments().collect();
+                $crate::accuracy::max_deviation(
+                    path.segments(),
+                    &fine,
+                    tolerance * $crate::accuracy::REFINEMENT,
+                )
+            };
+            $crate::accuracy::with_error(
+                py,
+                $crate::bezpath::BezPath::from(path.clone()),
+                error,
+                return_error,
+            )
         }
     }
 }
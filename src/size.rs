@@ -1,9 +1,12 @@
+use crate::impl_serialize;
 use crate::rect::Rect;
+use crate::subclass::{new_like, new_of_type};
 use crate::vec2::Vec2;
 use pyo3::types::PyType;
 
 use kurbo::Size as KSize;
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
 
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
@@ -44,8 +47,8 @@ impl Size {
     #[classmethod]
     #[allow(non_snake_case)]
     /// A size with zero width or height.
-    fn ZERO(_cls: &Bound<'_, PyType>) -> Self {
-        Self(KSize::ZERO)
+    fn ZERO(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Self(KSize::ZERO))
     }
 
     /// Returns the max of `width` and `height`.
@@ -94,8 +97,30 @@ impl Size {
     /// max = Size(50., 50.)
     /// assert this.clamp(min, max) == Size(10., 50.))
     /// ```
-    fn clamp(&self, min: Size, max: Size) -> Size {
-        self.0.clamp(min.0, max.0).into()
+    fn clamp(slf: &Bound<'_, Self>, min: Size, max: Size) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.clamp(min.0, max.0).into())
+    }
+
+    /// Returns a new size with the smaller of each of the widths and heights.
+    ///
+    /// Note that this method is not in original kurbo
+    fn min(slf: &Bound<'_, Self>, other: &Size) -> PyResult<Py<Self>> {
+        let size = slf.borrow().0;
+        new_like(
+            slf,
+            KSize::new(size.width.min(other.0.width), size.height.min(other.0.height)).into(),
+        )
+    }
+
+    /// Returns a new size with the larger of each of the widths and heights.
+    ///
+    /// Note that this method is not in original kurbo
+    fn max(slf: &Bound<'_, Self>, other: &Size) -> PyResult<Py<Self>> {
+        let size = slf.borrow().0;
+        new_like(
+            slf,
+            KSize::new(size.width.max(other.0.width), size.height.max(other.0.height)).into(),
+        )
     }
 
     /// Convert this size into a [`Vec2`], with `width` mapped to `x` and `height`
@@ -117,36 +142,36 @@ impl Size {
     /// assert size_neg.width == -3.0)
     /// assert size_neg.height == -4.0)
     /// ```
-    fn round(&self) -> Size {
-        self.0.round().into()
+    fn round(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.round().into())
     }
 
     /// Returns a new `Size`,
     /// with `width` and `height` rounded up to the nearest integer,
     /// unless they are already an integer.
-    fn ceil(&self) -> Size {
-        self.0.ceil().into()
+    fn ceil(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.ceil().into())
     }
 
     /// Returns a new `Size`,
     /// with `width` and `height` rounded down to the nearest integer,
     /// unless they are already an integer.
-    fn floor(&self) -> Size {
-        self.0.floor().into()
+    fn floor(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.floor().into())
     }
 
     /// Returns a new `Size`,
     /// with `width` and `height` rounded away from zero to the nearest integer,
     /// unless they are already an integer.
-    fn expand(&self) -> Size {
-        self.0.expand().into()
+    fn expand(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.expand().into())
     }
 
     /// Returns a new `Size`,
     /// with `width` and `height` rounded down towards zero the nearest integer,
     /// unless they are already an integer.
-    fn trunc(&self) -> Size {
-        self.0.trunc().into()
+    fn trunc(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.trunc().into())
     }
 
     /// Returns the aspect ratio of a rectangle with the given size.
@@ -174,28 +199,69 @@ impl Size {
     fn __imul__(&mut self, other: f64) {
         self.0 *= other;
     }
-    fn __mul__(&self, other: f64) -> Size {
-        (self.0 * other).into()
+    /// Multiplies by a scalar, or elementwise by another `Size`.
+    fn __mul__(slf: &Bound<'_, Self>, other: &Bound<'_, PyAny>) -> PyResult<Py<Self>> {
+        let size = slf.borrow().0;
+        if let Ok(other) = other.downcast::<Size>() {
+            let other = other.borrow().0;
+            let (width, height) = (size.width * other.width, size.height * other.height);
+            return new_like(slf, KSize::new(width, height).into());
+        }
+        new_like(slf, (size * other.extract::<f64>()?).into())
     }
-    fn __rmul__(&self, other: f64) -> Size {
-        (other * self.0).into()
+    fn __rmul__(slf: &Bound<'_, Self>, other: f64) -> PyResult<Py<Self>> {
+        new_like(slf, (other * slf.borrow().0).into())
     }
     fn __itruediv__(&mut self, other: f64) {
         self.0 /= other;
     }
-    fn __truediv__(&self, other: f64) -> Size {
-        (self.0 / other).into()
+    fn __truediv__(slf: &Bound<'_, Self>, other: f64) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 / other).into())
     }
-    fn __add__(&self, other: Size) -> Size {
-        (self.0 + other.0).into()
+    fn __add__(slf: &Bound<'_, Self>, other: Size) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 + other.0).into())
     }
     fn __iadd__(&mut self, other: Size) {
         self.0 += other.0;
     }
-    fn __sub__(&self, other: Size) -> Size {
-        (self.0 - other.0).into()
+    fn __sub__(slf: &Bound<'_, Self>, other: Size) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 - other.0).into())
     }
     fn __isub__(&mut self, other: Size) {
         self.0 -= other.0;
     }
+
+    /// Sizes compare elementwise, against another `Size` or against a number
+    /// used for both dimensions: ``a <= b`` is true when both the width and
+    /// the height of ``a`` are no greater than those of ``b``. This is a
+    /// partial order, so ``a <= b`` being false does not mean ``a > b``.
+    fn __richcmp__(&self, other: &Bound<'_, PyAny>, op: CompareOp) -> PyObject {
+        let py = other.py();
+        let (width, height) = if let Ok(other) = other.downcast::<Size>() {
+            let other = other.borrow().0;
+            (other.width, other.height)
+        } else if let Ok(value) = other.extract::<f64>() {
+            (value, value)
+        } else {
+            return py.NotImplemented();
+        };
+        let both = |cmp: fn(&f64, &f64) -> bool| {
+            cmp(&self.0.width, &width) && cmp(&self.0.height, &height)
+        };
+        match op {
+            CompareOp::Lt => both(f64::lt),
+            CompareOp::Le => both(f64::le),
+            CompareOp::Eq => both(f64::eq),
+            CompareOp::Ne => !both(f64::eq),
+            CompareOp::Gt => both(f64::gt),
+            CompareOp::Ge => both(f64::ge),
+        }
+        .into_py(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Size({})", crate::repr::floats(&[self.0.width, self.0.height]))
+    }
 }
+
+impl_serialize!(Size);
@@ -0,0 +1,55 @@
+// Construction of return values as instances of the caller's own class, so
+// that the methods of a Python subclass of a kurbopy class return the
+// subclass rather than the kurbopy class.
+use pyo3::prelude::*;
+use pyo3::pyclass_init::{PyClassInitializer, PyObjectInit};
+use pyo3::types::PyType;
+use pyo3::PyClass;
+use std::any::Any;
+
+/// Create an instance of `cls`, which is `T` or a subclass of it, holding
+/// `value`. As with `Py::new`, the `__init__` of the class is not called.
+pub(crate) fn new_of_type<T>(cls: &Bound<'_, PyType>, value: T) -> PyResult<Py<T>>
+where
+    T: PyClass,
+    PyClassInitializer<T>: From<T>,
+{
+    let py = cls.py();
+    if cls.is(&T::type_object_bound(py)) || !cls.is_subclass_of::<T>()? {
+        return Py::new(py, value);
+    }
+    // Safety: `cls` is the type object of a subclass of `T`, which is what
+    // `into_new_object` requires.
+    unsafe {
+        let obj = PyClassInitializer::from(value).into_new_object(py, cls.as_type_ptr())?;
+        Ok(Py::from_owned_ptr(py, obj))
+    }
+}
+
+/// Create an instance of the class of `slf` holding `value`.
+pub(crate) fn new_like<T>(slf: &Bound<'_, T>, value: T) -> PyResult<Py<T>>
+where
+    T: PyClass,
+    PyClassInitializer<T>: From<T>,
+{
+    new_of_type(&slf.as_any().get_type(), value)
+}
+
+/// Convert the result of a method of `slf` to Python, as an instance of the
+/// class of `slf` if it has the same type as `slf`. This is for code
+/// generated by macros, which do not know whether they return `Self`.
+pub(crate) fn into_py_like<T, R>(slf: &Bound<'_, T>, value: R) -> PyResult<PyObject>
+where
+    T: PyClass + 'static,
+    PyClassInitializer<T>: From<T>,
+    R: IntoPy<PyObject> + 'static,
+{
+    let py = slf.py();
+    let mut value = Some(value);
+    if let Some(same) = (&mut value as &mut dyn Any).downcast_mut::<Option<T>>() {
+        if let Some(same) = same.take() {
+            return Ok(new_like(slf, same)?.into_py(py));
+        }
+    }
+    Ok(value.into_py(py))
+}
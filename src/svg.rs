@@ -0,0 +1,132 @@
+// SVG path data output with control over its size.
+use crate::quantize::{Quantize, Rounding};
+use kurbo::{BezPath as KBezPath, PathEl as KPathEl, Point as KPoint, Vec2 as KVec2};
+
+/// Options for writing SVG path data.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct SvgOptions {
+    /// The number of decimal places to write, or `None` for full precision.
+    pub precision: Option<usize>,
+    /// Whether to use relative (lowercase) commands.
+    pub relative: bool,
+    /// Whether to use the `S` and `T` commands where a control point is the
+    /// reflection of the previous one.
+    pub shorthand: bool,
+}
+
+impl SvgOptions {
+    fn number(&self, x: f64) -> String {
+        let s = match self.precision {
+            Some(precision) => {
+                let s = format!("{:.*}", precision, x);
+                if s.contains('.') {
+                    s.trim_end_matches('0').trim_end_matches('.').to_string()
+                } else {
+                    s
+                }
+            }
+            None => format!("{}", x),
+        };
+        if s == "-0" {
+            "0".to_string()
+        } else {
+            s
+        }
+    }
+
+    fn coords(&self, v: KVec2) -> String {
+        format!("{},{}", self.number(v.x), self.number(v.y))
+    }
+
+    /// Whether two points would be written the same.
+    fn same(&self, a: KPoint, b: KPoint) -> bool {
+        self.coords(a.to_vec2()) == self.coords(b.to_vec2())
+    }
+}
+
+/// Write a path as SVG path data.
+pub(crate) fn to_svg(path: &KBezPath, options: SvgOptions) -> String {
+    // Coordinates are rounded before making them relative, so that rounding
+    // errors do not build up along the path.
+    let path = match options.precision {
+        Some(precision) => path.quantize(Rounding::Digits(precision as i32)),
+        None => path.clone(),
+    };
+    let mut words: Vec<String> = vec![];
+    let mut current = KPoint::ZERO;
+    let mut start = KPoint::ZERO;
+    // The last control point of the previous element, if it was a quadratic
+    // or a cubic respectively.
+    let mut last_quad: Option<KPoint> = None;
+    let mut last_cubic: Option<KPoint> = None;
+    for el in path.elements() {
+        let origin = if options.relative {
+            current
+        } else {
+            KPoint::ZERO
+        };
+        let coords = |points: &[KPoint]| {
+            points
+                .iter()
+                .map(|p| options.coords(*p - origin))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let (letter, text) = match *el {
+            KPathEl::MoveTo(p) => {
+                start = p;
+                current = p;
+                ('M', coords(&[p]))
+            }
+            KPathEl::LineTo(p) => {
+                current = p;
+                ('L', coords(&[p]))
+            }
+            KPathEl::QuadTo(p1, p2) => {
+                let reflected = last_quad.map(|c| current + (current - c));
+                let shorthand =
+                    options.shorthand && reflected.map_or(false, |r| options.same(r, p1));
+                current = p2;
+                words.push(if shorthand {
+                    command('T', options.relative, coords(&[p2]))
+                } else {
+                    command('Q', options.relative, coords(&[p1, p2]))
+                });
+                last_quad = Some(p1);
+                last_cubic = None;
+                continue;
+            }
+            KPathEl::CurveTo(p1, p2, p3) => {
+                let reflected = last_cubic.map(|c| current + (current - c));
+                let shorthand =
+                    options.shorthand && reflected.map_or(false, |r| options.same(r, p1));
+                current = p3;
+                words.push(if shorthand {
+                    command('S', options.relative, coords(&[p2, p3]))
+                } else {
+                    command('C', options.relative, coords(&[p1, p2, p3]))
+                });
+                last_quad = None;
+                last_cubic = Some(p2);
+                continue;
+            }
+            KPathEl::ClosePath => {
+                current = start;
+                ('Z', String::new())
+            }
+        };
+        words.push(command(letter, options.relative, text));
+        last_quad = None;
+        last_cubic = None;
+    }
+    words.join(" ")
+}
+
+fn command(letter: char, relative: bool, coords: String) -> String {
+    let letter = if relative {
+        letter.to_ascii_lowercase()
+    } else {
+        letter
+    };
+    format!("{}{}", letter, coords)
+}
@@ -0,0 +1,145 @@
+use crate::bezpath::BezPath;
+use crate::polyline::{convex_hull, flatten_subpaths, push_polygon};
+use kurbo::{Affine as KAffine, BezPath as KBezPath, Point as KPoint};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::f64::consts::PI;
+
+/// Place the profile's spine frames: a point on the spine and the angle of
+/// the spine's tangent there. Corners are filled with a fan of frames so
+/// that the profile rotates smoothly around them.
+fn spine_frames(pts: &[KPoint], closed: bool, step: f64) -> Vec<(KPoint, f64)> {
+    let n = pts.len();
+    if n < 2 {
+        return pts.iter().map(|&p| (p, 0.0)).collect();
+    }
+    let dir = |i: usize, j: usize| (pts[j] - pts[i]).atan2();
+    let mut frames = vec![];
+    for (i, &pt) in pts.iter().enumerate() {
+        let incoming = if i > 0 {
+            Some(dir(i - 1, i))
+        } else if closed {
+            Some(dir(n - 1, 0))
+        } else {
+            None
+        };
+        let outgoing = if i + 1 < n {
+            Some(dir(i, i + 1))
+        } else if closed {
+            Some(dir(n - 1, 0))
+        } else {
+            None
+        };
+        match (incoming, outgoing) {
+            (Some(a), Some(b)) => {
+                let mut delta = b - a;
+                while delta > PI {
+                    delta -= 2.0 * PI;
+                }
+                while delta <= -PI {
+                    delta += 2.0 * PI;
+                }
+                let steps = (delta.abs() / step).ceil() as usize;
+                if steps == 0 {
+                    frames.push((pt, a));
+                } else {
+                    for k in 0..=steps {
+                        frames.push((pt, a + delta * k as f64 / steps as f64));
+                    }
+                }
+            }
+            (Some(a), None) | (None, Some(a)) => frames.push((pt, a)),
+            (None, None) => {}
+        }
+    }
+    frames
+}
+
+/// Sweep a profile along a spine, returning the outline of the swept area.
+///
+/// The profile is placed with its origin on the spine and rotated so that
+/// its positive x axis follows the spine's tangent, as if it were the nib of
+/// a pen being drawn along the spine. The sides of the outline are traced by
+/// the points of the profile which are furthest from the spine on either side,
+/// and the ends are capped with the leading and trailing halves of the
+/// profile. Open spine subpaths produce a single closed outline; closed
+/// spine subpaths produce an outer and an inner outline.
+///
+/// The convex hull of the profile is used, and the result is made of straight
+/// line segments, with ``tolerance`` used both to flatten the inputs and to
+/// subdivide the rotation of the profile around corners. Profiles which
+/// extend far along the tangent may be clipped where the spine turns sharply.
+/// Raises ``ValueError`` unless ``tolerance`` is positive.
+///
+/// Note that this function is not in original kurbo
+#[pyfunction]
+#[pyo3(signature = (profile, spine, tolerance=crate::DEFAULT_TOLERANCE))]
+pub fn sweep(profile: &BezPath, spine: &BezPath, tolerance: f64) -> PyResult<BezPath> {
+    if !(tolerance.is_finite() && tolerance > 0.0) {
+        return Err(PyValueError::new_err("tolerance must be positive"));
+    }
+    let profile_pts: Vec<KPoint> = flatten_subpaths(&profile.path(), tolerance)
+        .into_iter()
+        .flat_map(|p| p.points)
+        .collect();
+    let hull = convex_hull(&profile_pts);
+    let mut result = KBezPath::new();
+    if hull.is_empty() {
+        return Ok(result.into());
+    }
+    let n = hull.len();
+    let top = (0..n)
+        .max_by(|&a, &b| hull[a].y.total_cmp(&hull[b].y))
+        .unwrap_or(0);
+    let bottom = (0..n)
+        .min_by(|&a, &b| hull[a].y.total_cmp(&hull[b].y))
+        .unwrap_or(0);
+    let radius = hull
+        .iter()
+        .map(|p| p.to_vec2().hypot())
+        .fold(0.0, f64::max);
+    let step = if radius > tolerance {
+        (2.0 * (1.0 - tolerance / radius).acos()).max(1e-3)
+    } else {
+        PI / 2.0
+    };
+    let place = |frame: (KPoint, f64), ix: usize| -> KPoint {
+        frame.0 + (KAffine::rotate(frame.1) * hull[ix]).to_vec2()
+    };
+    // Walk clockwise around the hull, from `from` to `to` inclusive.
+    let hull_arc = |from: usize, to: usize| -> Vec<usize> {
+        let mut ixs = vec![from];
+        let mut ix = from;
+        while ix != to {
+            ix = (ix + n - 1) % n;
+            ixs.push(ix);
+        }
+        ixs
+    };
+
+    for line in flatten_subpaths(&spine.path(), tolerance) {
+        let mut pts = line.points;
+        pts.dedup();
+        if line.closed && pts.len() > 1 && pts.first() == pts.last() {
+            pts.pop();
+        }
+        let closed = line.closed && pts.len() > 2;
+        let frames = spine_frames(&pts, closed, step);
+        let (Some(&first), Some(&last)) = (frames.first(), frames.last()) else {
+            continue;
+        };
+        let left: Vec<KPoint> = frames.iter().map(|&f| place(f, top)).collect();
+        let right: Vec<KPoint> = frames.iter().rev().map(|&f| place(f, bottom)).collect();
+        if closed {
+            push_polygon(&mut result, &left);
+            push_polygon(&mut result, &right);
+        } else {
+            let mut outline = left;
+            outline.extend(hull_arc(top, bottom).into_iter().map(|ix| place(last, ix)));
+            outline.extend(right);
+            outline.extend(hull_arc(bottom, top).into_iter().map(|ix| place(first, ix)));
+            push_polygon(&mut result, &outline);
+        }
+    }
+    Ok(result.into())
+}
@@ -0,0 +1,215 @@
+// Detection of approximate mirror and rotational symmetry. Any axis of
+// reflection symmetry passes through the centroid of the outline, and is
+// either a principal axis of it, or passes through a point of the path which
+// lies on the axis, or is the perpendicular bisector of a pair of points
+// which are each other's mirror images. Likewise any centre of rotational
+// symmetry is the centroid, and the rotation takes the point of the path
+// furthest from it to another point at the same distance. Candidate
+// transforms are gathered from these, checked cheaply against the images of
+// the path's points, and then measured by the Hausdorff distance between the
+// path and its image.
+use crate::hausdorff::{hausdorff_distance, Target};
+use crate::polyline::{clip_segment, cross, flatten_subpaths, symmetric_eigen};
+use kurbo::{
+    Affine as KAffine, BezPath as KBezPath, Line as KLine, ParamCurve, Point as KPoint, Shape,
+    Vec2 as KVec2,
+};
+use std::f64::consts::PI;
+
+/// The centroid of the outline, weighting each part of it by its length,
+/// and the angles of its principal axes.
+fn outline_moments(path: &KBezPath, tolerance: f64) -> Option<(KPoint, [f64; 2])> {
+    let mut total = 0.0;
+    let mut sum = KVec2::ZERO;
+    let mut edges = vec![];
+    for poly in flatten_subpaths(path, tolerance) {
+        let mut points = poly.points.clone();
+        if poly.closed {
+            points.extend(poly.points.first());
+        }
+        for w in points.windows(2) {
+            let len = (w[1] - w[0]).hypot();
+            let mid = w[0].midpoint(w[1]);
+            total += len;
+            sum += mid.to_vec2() * len;
+            edges.push((mid, len));
+        }
+    }
+    if total <= 0.0 {
+        return None;
+    }
+    let c = (sum / total).to_point();
+    let (mut a, mut b, mut d) = (0.0, 0.0, 0.0);
+    for (mid, len) in edges {
+        let e = mid - c;
+        a += e.x * e.x * len;
+        b += e.x * e.y * len;
+        d += e.y * e.y * len;
+    }
+    let (_, _, angle) = symmetric_eigen(a, b, d);
+    Some((c, [angle, angle + PI / 2.0]))
+}
+
+/// The start and middle of each segment of the path, which any symmetry
+/// of a path built of symmetric parts maps onto one another.
+fn sample_points(path: &KBezPath) -> Vec<KPoint> {
+    path.segments()
+        .flat_map(|seg| [seg.start(), seg.eval(0.5)])
+        .collect()
+}
+
+/// The Hausdorff distance between the path and its image under `m`, if it
+/// is within `tolerance`. The images of the sample points are checked
+/// first, as they are much quicker to measure.
+fn image_error(
+    path: &KBezPath,
+    target: &Target,
+    samples: &[KPoint],
+    m: KAffine,
+    tolerance: f64,
+) -> Option<f64> {
+    if samples
+        .iter()
+        .any(|p| target.distance(m * *p, tolerance) > tolerance)
+    {
+        return None;
+    }
+    let error = hausdorff_distance(path, &(m * path.clone()), tolerance / 100.0);
+    (error <= tolerance).then_some(error)
+}
+
+/// The reflection in the line through `c` at angle `angle`.
+fn reflection(c: KPoint, angle: f64) -> KAffine {
+    let (sin, cos) = (2.0 * angle).sin_cos();
+    let m = KAffine::new([cos, sin, sin, -cos, 0.0, 0.0]);
+    KAffine::translate(c.to_vec2()) * m * KAffine::translate(-c.to_vec2())
+}
+
+/// Normalize an axis angle to the range `[0, π)`.
+fn axis_angle(angle: f64) -> f64 {
+    let a = angle.rem_euclid(PI);
+    if a >= PI {
+        0.0
+    } else {
+        a
+    }
+}
+
+/// Remove angles within `eps` of a previous one, including across the wrap
+/// from π back to 0.
+fn dedup_angles(angles: &mut Vec<f64>, eps: f64) {
+    angles.sort_by(|a, b| a.total_cmp(b));
+    angles.dedup_by(|a, b| (*a - *b).abs() <= eps);
+    if angles.len() > 1 && angles[0] + PI - angles[angles.len() - 1] <= eps {
+        angles.pop();
+    }
+}
+
+/// The axes about which the path is symmetric to within `tolerance`, each
+/// clipped to the path's bounding box, with the Hausdorff distance between
+/// the path and its reflection, most symmetric first.
+pub(crate) fn mirror_axes(path: &KBezPath, tolerance: f64) -> Vec<(KLine, f64)> {
+    let Some((c, principal)) = outline_moments(path, tolerance / 10.0) else {
+        return vec![];
+    };
+    let samples = sample_points(path);
+    let radius = samples.iter().map(|p| (*p - c).hypot()).fold(0.0, f64::max);
+    let eps = (tolerance / radius.max(tolerance)).min(0.01);
+
+    let mut angles: Vec<f64> = principal.to_vec();
+    for (i, p) in samples.iter().enumerate() {
+        let dp = *p - c;
+        if dp.hypot() > tolerance {
+            angles.push(dp.atan2());
+        }
+        for q in &samples[i + 1..] {
+            let dq = *q - c;
+            let pq = *q - *p;
+            if (dp.hypot() - dq.hypot()).abs() <= 2.0 * tolerance && pq.hypot() > tolerance {
+                angles.push(pq.atan2() + PI / 2.0);
+            }
+        }
+    }
+    let mut angles: Vec<f64> = angles.into_iter().map(axis_angle).collect();
+    dedup_angles(&mut angles, eps);
+
+    let target = Target::new(path, tolerance / 10.0);
+    let bbox = path.bounding_box();
+    let reach = bbox.width().hypot(bbox.height()) + 1.0;
+    let found: Vec<(f64, f64)> = angles
+        .into_iter()
+        .filter_map(|angle| {
+            image_error(path, &target, &samples, reflection(c, angle), tolerance)
+                .map(|error| (angle, error))
+        })
+        .collect();
+
+    // Neighbouring candidates may pass for the same axis; keep the best.
+    let mut found = found;
+    found.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let mut kept: Vec<(f64, f64)> = vec![];
+    for (angle, error) in found {
+        let close = |k: &(f64, f64)| {
+            let diff = (k.0 - angle).abs();
+            diff.min(PI - diff) <= 10.0 * eps
+        };
+        if !kept.iter().any(close) {
+            kept.push((angle, error));
+        }
+    }
+    kept.into_iter()
+        .map(|(angle, error)| {
+            let u = KVec2::from_angle(angle);
+            let (p0, p1) = clip_segment(c - u * reach, c + u * reach, bbox).unwrap_or((c, c));
+            (KLine::new(p0, p1), error)
+        })
+        .collect()
+}
+
+/// The order of the rotational symmetry of the path to within `tolerance`,
+/// and its centre. The order is 1 if the path has no rotational symmetry,
+/// and `None` is returned if the path has no length.
+pub(crate) fn rotational_symmetry(path: &KBezPath, tolerance: f64) -> Option<(usize, KPoint)> {
+    let (c, _) = outline_moments(path, tolerance / 10.0)?;
+    let samples = sample_points(path);
+    let far = samples
+        .iter()
+        .map(|p| *p - c)
+        .max_by(|a, b| a.hypot2().total_cmp(&b.hypot2()))?;
+    let radius = far.hypot();
+    if radius <= tolerance {
+        return Some((1, c));
+    }
+
+    // The rotation by 2π/n must carry the furthest point to another point
+    // at the same distance, so the angles between them suggest the orders.
+    let eps = tolerance / radius;
+    let mut orders: Vec<usize> = samples
+        .iter()
+        .map(|p| *p - c)
+        .filter(|d| (d.hypot() - radius).abs() <= tolerance)
+        .filter_map(|d| {
+            let angle = cross(far, d).atan2(far.dot(d)).abs();
+            if angle <= eps {
+                return None;
+            }
+            let n = (2.0 * PI / angle).round();
+            ((2.0 * PI / n - angle).abs() <= eps).then_some(n as usize)
+        })
+        .collect();
+    orders.sort_unstable_by(|a, b| b.cmp(a));
+    orders.dedup();
+
+    // Symmetry of order n implies symmetry of every order dividing it, so
+    // the first order to pass, trying the highest first, is the answer.
+    let target = Target::new(path, tolerance / 10.0);
+    for n in orders {
+        let m = KAffine::translate(c.to_vec2())
+            * KAffine::rotate(2.0 * PI / n as f64)
+            * KAffine::translate(-c.to_vec2());
+        if image_error(path, &target, &samples, m, tolerance).is_some() {
+            return Some((n, c));
+        }
+    }
+    Some((1, c))
+}
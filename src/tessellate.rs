@@ -0,0 +1,153 @@
+// Triangulation of filled paths. The flattened path is first normalized
+// with a union, so that overlaps are resolved and outer contours and holes
+// have opposite orientations. Each outer contour is then joined to its
+// holes by bridging edges and triangulated by ear clipping.
+use crate::boolean::{boolean, Op, Polygon, Region};
+use crate::buffer::{polygon_area, polygons};
+use crate::polyline::cross;
+use kurbo::{BezPath as KBezPath, Point as KPoint};
+
+/// A triangle mesh. Each vertex records the contour of the normalized
+/// outline it lies on.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Mesh {
+    pub vertices: Vec<KPoint>,
+    pub contours: Vec<u32>,
+    pub triangles: Vec<[u32; 3]>,
+}
+
+fn in_triangle(p: KPoint, a: KPoint, b: KPoint, c: KPoint) -> bool {
+    cross(b - a, p - a) >= 0.0 && cross(c - b, p - b) >= 0.0 && cross(a - c, p - c) >= 0.0
+}
+
+/// Splice a hole (a clockwise ring of vertex indices) into an outer ring,
+/// joining the hole's rightmost vertex to a vertex of the ring it can see.
+fn bridge(ring: &mut Vec<u32>, hole: &[u32], points: &[KPoint]) {
+    let Some(m_ix) = (0..hole.len()).max_by(|&a, &b| {
+        points[hole[a] as usize]
+            .x
+            .total_cmp(&points[hole[b] as usize].x)
+    }) else {
+        return;
+    };
+    let m = points[hole[m_ix] as usize];
+    // The nearest edge of the ring crossed by a ray to the right of `m`.
+    let n = ring.len();
+    let mut best: Option<(f64, usize)> = None;
+    for i in 0..n {
+        let (a, b) = (points[ring[i] as usize], points[ring[(i + 1) % n] as usize]);
+        if a.y == b.y || m.y < a.y.min(b.y) || m.y > a.y.max(b.y) {
+            continue;
+        }
+        let x = a.x + (m.y - a.y) * (b.x - a.x) / (b.y - a.y);
+        if x >= m.x && best.map_or(true, |(bx, _)| x < bx) {
+            let end = if a.x > b.x { i } else { (i + 1) % n };
+            best = Some((x, end));
+        }
+    }
+    let Some((x, mut p_ix)) = best else {
+        return;
+    };
+    // A vertex of the ring inside the triangle between `m`, the crossing
+    // and the candidate would block the view; take the one nearest in
+    // angle to the ray instead.
+    let hit = KPoint::new(x, m.y);
+    let p = points[ring[p_ix] as usize];
+    let (a, b, c) = if p.y < m.y { (m, p, hit) } else { (m, hit, p) };
+    let mut best_angle = f64::INFINITY;
+    for (i, &v) in ring.iter().enumerate() {
+        let q = points[v as usize];
+        if q == p || q == m || !in_triangle(q, a, b, c) {
+            continue;
+        }
+        let angle = (q.y - m.y).abs().atan2(q.x - m.x);
+        if angle < best_angle {
+            best_angle = angle;
+            p_ix = i;
+        }
+    }
+    let mut spliced = ring[..=p_ix].to_vec();
+    spliced.extend(hole[m_ix..].iter().chain(&hole[..=m_ix]));
+    spliced.extend_from_slice(&ring[p_ix..]);
+    *ring = spliced;
+}
+
+/// Triangulate an anticlockwise ring of vertex indices by ear clipping.
+fn ear_clip(mut ring: Vec<u32>, points: &[KPoint], triangles: &mut Vec<[u32; 3]>) {
+    let pt = |i: u32| points[i as usize];
+    while ring.len() > 3 {
+        let n = ring.len();
+        let is_ear = |i: usize| {
+            let (a, b, c) = (ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]);
+            let (pa, pb, pc) = (pt(a), pt(b), pt(c));
+            cross(pb - pa, pc - pb) > 0.0
+                && ring.iter().all(|&v| {
+                    let q = pt(v);
+                    q == pa || q == pb || q == pc || !in_triangle(q, pa, pb, pc)
+                })
+        };
+        // If there are no ears, the ring is degenerate; clipping anyway
+        // guarantees progress.
+        let i = (0..n).find(|&i| is_ear(i)).unwrap_or(0);
+        triangles.push([ring[(i + n - 1) % n], ring[i], ring[(i + 1) % n]]);
+        ring.remove(i);
+    }
+    if ring.len() == 3 {
+        triangles.push([ring[0], ring[1], ring[2]]);
+    }
+}
+
+/// Triangulate the region enclosed by a path, flattened to `tolerance` and
+/// filled with the nonzero rule.
+pub(crate) fn tessellate(path: &KBezPath, tolerance: f64) -> Mesh {
+    let contours: Vec<Polygon> = boolean(&polygons(path, tolerance), &[], Op::Union);
+    let mut mesh = Mesh::default();
+    let mut rings: Vec<Vec<u32>> = vec![];
+    for (id, poly) in contours.iter().enumerate() {
+        let start = mesh.vertices.len() as u32;
+        mesh.vertices.extend(poly.iter().copied());
+        mesh.contours
+            .extend(std::iter::repeat(id as u32).take(poly.len()));
+        rings.push((start..start + poly.len() as u32).collect());
+    }
+    let areas: Vec<f64> = contours.iter().map(|poly| polygon_area(poly)).collect();
+    let regions: Vec<Option<Region>> = contours
+        .iter()
+        .zip(&areas)
+        .map(|(poly, &area)| (area > 0.0).then(|| Region::new(std::slice::from_ref(poly))))
+        .collect();
+    // Each hole belongs to the smallest outer contour containing it.
+    let mut holes: Vec<Vec<usize>> = vec![vec![]; contours.len()];
+    for (ix, poly) in contours.iter().enumerate() {
+        if areas[ix] > 0.0 {
+            continue;
+        }
+        let probe = poly[0].midpoint(poly[1]);
+        let owner = (0..contours.len())
+            .filter(|&o| regions[o].as_ref().map_or(false, |r| r.contains(probe)))
+            .min_by(|&a, &b| areas[a].total_cmp(&areas[b]));
+        if let Some(owner) = owner {
+            holes[owner].push(ix);
+        }
+    }
+    for (ix, mut owned) in holes.into_iter().enumerate() {
+        if areas[ix] <= 0.0 {
+            continue;
+        }
+        // Bridge the holes from right to left, so that each bridge can only
+        // be blocked by holes which are already part of the ring.
+        let max_x = |h: &usize| {
+            contours[*h]
+                .iter()
+                .map(|p| p.x)
+                .fold(f64::NEG_INFINITY, f64::max)
+        };
+        owned.sort_by(|a, b| max_x(b).total_cmp(&max_x(a)));
+        let mut ring = rings[ix].clone();
+        for hole in owned {
+            bridge(&mut ring, &rings[hole], &mesh.vertices);
+        }
+        ear_clip(ring, &mesh.vertices, &mut mesh.triangles);
+    }
+    mesh
+}
@@ -1,12 +1,15 @@
+use crate::impl_serialize;
+use crate::affine::Affine;
 use crate::bezpath::BezPath;
 use crate::line::Line;
-use crate::point::Point;
+use crate::point::{Point, PointArray, PointLike};
 use crate::rect::Rect;
-use crate::vec2::Vec2;
+use crate::subclass::{new_like, new_of_type};
+use crate::vec2::{Vec2, Vec2Like};
 use crate::cubicbez::CubicBez;
 use crate::polymorphic;
 
-use kurbo::TranslateScale as KTranslateScale;
+use kurbo::{Affine as KAffine, TranslateScale as KTranslateScale};
 use pyo3::prelude::*;
 use pyo3::types::PyType;
 
@@ -47,22 +50,41 @@ impl From<KTranslateScale> for TranslateScale {
 impl TranslateScale {
     #[new]
     /// Create a new transformation from translation and scale.
-    fn __new__(translation: Vec2, scale: f64) -> Self {
+    fn __new__(translation: Vec2Like, scale: f64) -> Self {
         TranslateScale(KTranslateScale::new(translation.into(), scale))
     }
 
     #[classmethod]
     /// Create a new transformation with scale only.
     #[pyo3(text_signature = "(cls, scale)")]
-    fn scale(_cls: &Bound<PyType>, scale: f64) -> Self {
-        TranslateScale(KTranslateScale::scale(scale))
+    fn scale(cls: &Bound<'_, PyType>, scale: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, TranslateScale(KTranslateScale::scale(scale)))
     }
 
     #[classmethod]
     /// Create a new transformation with translation only.
     #[pyo3(text_signature = "(cls, vec2)")]
-    fn translate(_cls: &Bound<PyType>, t: Vec2) -> Self {
-        TranslateScale(KTranslateScale::translate(t))
+    fn translate(cls: &Bound<'_, PyType>, t: Vec2Like) -> PyResult<Py<Self>> {
+        new_of_type(cls, TranslateScale(KTranslateScale::translate(t)))
+    }
+
+    #[classmethod]
+    /// Create a new transformation which scales about the given center
+    /// point, leaving that point where it is.
+    ///
+    /// Note that this method is not in original kurbo
+    #[pyo3(text_signature = "(cls, scale, center)")]
+    fn from_scale_about(cls: &Bound<'_, PyType>, scale: f64, center: PointLike) -> PyResult<Py<Self>> {
+        let center = center.0.to_vec2();
+        new_of_type(cls, TranslateScale(KTranslateScale::new(center - scale * center, scale)))
+    }
+
+    /// Convert this transformation into an equivalent `Affine`.
+    ///
+    /// Note that this method is not in original kurbo
+    fn to_affine(&self) -> Affine {
+        let KTranslateScale { translation, scale } = self.0;
+        Affine(KAffine::new([scale, 0.0, 0.0, scale, translation.x, translation.y]))
     }
 
     /// Decompose transformation into translation and scale.
@@ -78,8 +100,20 @@ impl TranslateScale {
     /// (modulo floating point rounding errors).
     ///
     /// Produces NaN values when scale is zero.
-    fn inverse(&self) -> Self {
-        self.0.inverse().into()
+    fn inverse(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.inverse().into())
+    }
+
+    /// Apply the transform to many points at once.
+    ///
+    /// ``points`` may be a NumPy array of shape ``(n, 2)``, in which case an
+    /// array of the same shape is returned, or a list of points, in which
+    /// case a list of :py:class:`Point` is returned.
+    ///
+    /// Note that this method is not in original kurbo
+    fn transform_points(&self, py: Python, points: PointArray) -> PyResult<PyObject> {
+        let result = points.points.iter().map(|&p| self.0 * p).collect();
+        points.points_in_kind(py, result)
     }
 
     /// Is this translate/scale finite?
@@ -93,8 +127,8 @@ impl TranslateScale {
     }
 
     #[allow(non_snake_case)]
-    fn __add__(&self, rhs: Vec2) -> Self {
-        (self.0 + rhs.0).into()
+    fn __add__(slf: &Bound<'_, Self>, rhs: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 + rhs.0).into())
     }
 
     // We need to define this one manually because we don't have a 
@@ -103,6 +137,14 @@ impl TranslateScale {
     fn _mul_BezPath(&self, bez: BezPath) -> BezPath {
         (self.0 * &*bez.path()).into()
     }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TranslateScale({}, {})",
+            crate::repr::vec2(self.0.translation),
+            crate::repr::float(self.0.scale)
+        )
+    }
 }
 
 
@@ -113,3 +155,5 @@ polymorphic!(mul TranslateScale =>
     (_mul_Rect, Rect, Rect),
     (_mul_CubicBez, CubicBez, CubicBez)
 );
+
+impl_serialize!(TranslateScale);
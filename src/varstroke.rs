@@ -0,0 +1,119 @@
+// Variable-width stroking. The centerline is flattened, and its vertices
+// are offset to either side along the bisector of the adjacent edges by
+// half the width at that point.
+use crate::polyline::{flatten_subpaths, push_polygon, simplify};
+use kurbo::{BezPath as KBezPath, Point as KPoint, Vec2 as KVec2};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// The width is sampled at least this many times along each subpath.
+const MIN_SAMPLES: usize = 100;
+
+/// The greatest factor by which an offset is lengthened at a sharp corner.
+const MITER_LIMIT: f64 = 4.0;
+
+/// Subdivide the edges of a polyline so that none is longer than `step`.
+fn subdivide(pts: &[KPoint], closed: bool, step: f64) -> Vec<KPoint> {
+    let mut result = vec![];
+    let n = pts.len();
+    let edges = if closed { n } else { n - 1 };
+    for i in 0..edges {
+        let (a, b) = (pts[i], pts[(i + 1) % n]);
+        let count = ((b - a).hypot() / step).ceil().max(1.0) as usize;
+        result.extend((0..count).map(|k| a.lerp(b, k as f64 / count as f64)));
+    }
+    if !closed {
+        result.push(pts[n - 1]);
+    }
+    result
+}
+
+fn left_normal(v: KVec2) -> KVec2 {
+    KVec2::new(-v.y, v.x)
+}
+
+/// Stroke `path` with a width which varies along each subpath. `width` is
+/// called with the fraction of the subpath's length at each sample point.
+pub(crate) fn stroke_variable(
+    path: &KBezPath,
+    mut width: impl FnMut(f64) -> PyResult<f64>,
+    accuracy: f64,
+) -> PyResult<KBezPath> {
+    let mut result = KBezPath::new();
+    for line in flatten_subpaths(path, accuracy) {
+        let mut pts = line.points;
+        pts.dedup();
+        if line.closed && pts.len() > 1 && pts.first() == pts.last() {
+            pts.pop();
+        }
+        if pts.len() < 2 {
+            continue;
+        }
+        let closed = line.closed && pts.len() > 2;
+        let n = pts.len();
+        let length: f64 = (0..if closed { n } else { n - 1 })
+            .map(|i| (pts[(i + 1) % n] - pts[i]).hypot())
+            .sum();
+        let pts = subdivide(&pts, closed, length / MIN_SAMPLES as f64);
+        let n = pts.len();
+
+        let mut distance = 0.0;
+        let mut left = vec![];
+        let mut right = vec![];
+        for i in 0..n {
+            if i > 0 {
+                distance += (pts[i] - pts[i - 1]).hypot();
+            }
+            let w = width(distance / length)?;
+            if !(w.is_finite() && w >= 0.0) {
+                return Err(PyValueError::new_err(
+                    "widths must be finite and non-negative",
+                ));
+            }
+            let incoming = if i > 0 {
+                Some(pts[i] - pts[i - 1])
+            } else if closed {
+                Some(pts[0] - pts[n - 1])
+            } else {
+                None
+            };
+            let outgoing = if i + 1 < n {
+                Some(pts[i + 1] - pts[i])
+            } else if closed {
+                Some(pts[0] - pts[n - 1])
+            } else {
+                None
+            };
+            let (d_in, d_out) = match (incoming, outgoing) {
+                (Some(a), Some(b)) => (a.normalize(), b.normalize()),
+                (Some(a), None) | (None, Some(a)) => (a.normalize(), a.normalize()),
+                (None, None) => continue,
+            };
+            let bisector = left_normal(d_in + d_out);
+            let normal = if bisector.hypot2() > 0.0 {
+                bisector.normalize()
+            } else {
+                left_normal(d_in)
+            };
+            // Lengthen the offset at corners so that the sides stay parallel.
+            let cos = normal.dot(left_normal(d_in)).max(1.0 / MITER_LIMIT);
+            let offset = normal * (0.5 * w / cos);
+            left.push(pts[i] + offset);
+            right.push(pts[i] - offset);
+        }
+        right.reverse();
+        let outlines = if closed {
+            vec![left, right]
+        } else {
+            left.extend(right);
+            vec![left]
+        };
+        for mut outline in outlines {
+            outline.push(outline[0]);
+            let mut simplified = simplify(&outline, accuracy);
+            simplified.pop();
+            push_polygon(&mut result, &simplified);
+        }
+    }
+    Ok(result)
+}
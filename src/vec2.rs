@@ -1,7 +1,12 @@
-use crate::point::Point;
+use crate::impl_approx_eq;
+use crate::impl_serialize;
+use crate::impl_round_coordinates;
+use crate::point::{coordinate, Point};
+use crate::subclass::{new_like, new_of_type};
 use kurbo::Vec2 as KVec2;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::PyType;
+use pyo3::types::{PyIterator, PyTuple, PyType};
 
 #[pyclass(subclass, module = "kurbopy")]
 #[derive(Clone, Debug)]
@@ -10,6 +15,10 @@ use pyo3::types::PyType;
 /// This is intended primarily for a vector in the mathematical sense,
 /// but it can be interpreted as a translation, and converted to and
 /// from a point (vector relative to the origin) and size.
+///
+/// Anywhere a `Vec2` is expected as an argument, an ``(x, y)`` tuple
+/// may be given instead. A `Vec2` is also a sequence of its two
+/// coordinates, so it can be unpacked with ``x, y = vec``.
 pub struct Vec2(pub KVec2);
 
 impl From<KVec2> for Vec2 {
@@ -24,6 +33,27 @@ impl From<Vec2> for KVec2 {
     }
 }
 
+/// A vector argument, which may be given as a `Vec2` or an `(x, y)` tuple.
+#[derive(Clone, Copy, Debug)]
+pub struct Vec2Like(pub KVec2);
+
+impl<'py> FromPyObject<'py> for Vec2Like {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(v) = ob.downcast::<Vec2>() {
+            return Ok(Vec2Like(v.borrow().0));
+        }
+        ob.extract::<(f64, f64)>()
+            .map(|(x, y)| Vec2Like(KVec2::new(x, y)))
+            .map_err(|_| PyTypeError::new_err("expected a Vec2 or an (x, y) tuple"))
+    }
+}
+
+impl From<Vec2Like> for KVec2 {
+    fn from(v: Vec2Like) -> Self {
+        v.0
+    }
+}
+
 #[pymethods]
 impl Vec2 {
     #[new]
@@ -34,8 +64,8 @@ impl Vec2 {
     #[classmethod]
     #[allow(non_snake_case)]
     /// The vector (0, 0).
-    fn ZERO(_cls: &Bound<'_, PyType>) -> Self {
-        Vec2(KVec2::ZERO)
+    fn ZERO(cls: &Bound<'_, PyType>) -> PyResult<Py<Self>> {
+        new_of_type(cls, Vec2(KVec2::ZERO))
     }
 
     /// Convert this vector into a :py:class:`Point`.
@@ -45,7 +75,7 @@ impl Vec2 {
 
     /// Dot product of two vectors.
     #[pyo3(text_signature = "($self, other)")]
-    fn dot(&self, other: Vec2) -> f64 {
+    fn dot(&self, other: Vec2Like) -> f64 {
         self.0.dot(other.0)
     }
 
@@ -53,7 +83,7 @@ impl Vec2 {
     ///
     /// This is signed so that (0, 1) × (1, 0) = 1.
     #[pyo3(text_signature = "($self, other)")]
-    fn cross(&self, other: Vec2) -> f64 {
+    fn cross(&self, other: Vec2Like) -> f64 {
         self.0.cross(other.0)
     }
 
@@ -87,56 +117,56 @@ impl Vec2 {
     /// _`Affine.rotate`.
     #[classmethod]
     #[pyo3(text_signature = "(cls, th)")]
-    fn from_angle(_cls: &Bound<'_, PyType>, th: f64) -> Self {
-        KVec2::from_angle(th).into()
+    fn from_angle(cls: &Bound<'_, PyType>, th: f64) -> PyResult<Py<Self>> {
+        new_of_type(cls, KVec2::from_angle(th).into())
     }
 
     /// Linearly interpolate between two vectors.
     #[pyo3(text_signature = "($self, other, t)")]
-    fn lerp(&self, other: Self, t: f64) -> Self {
-        self.0.lerp(other.0, t).into()
+    fn lerp(slf: &Bound<'_, Self>, other: Vec2Like, t: f64) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.lerp(other.0, t).into())
     }
 
     /// Returns a vector of magnitude 1.0 with the same angle as `self`; i.e.
     /// a unit/direction vector.
     ///
     /// This produces `NaN` values when the magnitutde is `0`.
-    fn normalize(&self) -> Self {
-        self.0.normalize().into()
+    fn normalize(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.normalize().into())
     }
 
     /// Returns a new `Vec2`,
     /// with `x` and `y` rounded to the nearest integer.
-    fn round(&self) -> Self {
-        self.0.round().into()
+    fn round(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.round().into())
     }
 
     /// Returns a new `Vec2`,
     /// with `x` and `y` rounded up to the nearest integer,
     /// unless they are already an integer.
-    fn ceil(&self) -> Self {
-        self.0.ceil().into()
+    fn ceil(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.ceil().into())
     }
 
     /// Returns a new `Vec2`,
     /// with `x` and `y` rounded down to the nearest integer,
     /// unless they are already an integer.
-    fn floor(&self) -> Self {
-        self.0.floor().into()
+    fn floor(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.floor().into())
     }
 
     /// Returns a new `Vec2`,
     /// with `x` and `y` rounded away from zero to the nearest integer,
     /// unless they are already an integer.
-    fn expand(&self) -> Self {
-        self.0.expand().into()
+    fn expand(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.expand().into())
     }
 
     /// Returns a new `Vec2`,
     /// with `x` and `y` rounded towards zero to the nearest integer,
     /// unless they are already an integer.
-    fn trunc(&self) -> Self {
-        self.0.trunc().into()
+    fn trunc(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, slf.borrow().0.trunc().into())
     }
 
     /// Is this Vec2 finite?
@@ -166,17 +196,17 @@ impl Vec2 {
         self.0.y = y;
     }
 
-    fn __add__(&self, rhs: Self) -> PyResult<Self> {
-        Ok((self.0 + rhs.0).into())
+    fn __add__(slf: &Bound<'_, Self>, rhs: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 + rhs.0).into())
     }
 
-    fn __iadd__(&mut self, other: Self) -> PyResult<()> {
+    fn __iadd__(&mut self, other: Vec2Like) -> PyResult<()> {
         self.0 += other.0;
         Ok(())
     }
 
-    fn __truediv__(&self, rhs: f64) -> PyResult<Self> {
-        Ok((self.0 / rhs).into())
+    fn __truediv__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 / rhs).into())
     }
 
     fn __itruediv__(&mut self, rhs: f64) -> PyResult<()> {
@@ -184,8 +214,8 @@ impl Vec2 {
         Ok(())
     }
 
-    fn __mul__(&self, rhs: f64) -> PyResult<Self> {
-        Ok((self.0 * rhs).into())
+    fn __mul__(slf: &Bound<'_, Self>, rhs: f64) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 * rhs).into())
     }
 
     fn __imul__(&mut self, rhs: f64) -> PyResult<()> {
@@ -193,16 +223,35 @@ impl Vec2 {
         Ok(())
     }
 
-    fn __neg__(&self) -> PyResult<Self> {
-        Ok((-self.0).into())
+    fn __neg__(slf: &Bound<'_, Self>) -> PyResult<Py<Self>> {
+        new_like(slf, (-slf.borrow().0).into())
     }
 
-    fn __sub__(&self, rhs: Self) -> PyResult<Self> {
-        Ok((self.0 - rhs.0).into())
+    fn __sub__(slf: &Bound<'_, Self>, rhs: Vec2Like) -> PyResult<Py<Self>> {
+        new_like(slf, (slf.borrow().0 - rhs.0).into())
     }
 
-    fn __isub__(&mut self, other: Self) -> PyResult<()> {
+    fn __isub__(&mut self, other: Vec2Like) -> PyResult<()> {
         self.0 -= other.0;
         Ok(())
     }
+
+    fn __len__(&self) -> usize {
+        2
+    }
+    fn __getitem__(&self, ix: isize) -> PyResult<f64> {
+        coordinate(self.0.x, self.0.y, ix)
+    }
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyTuple::new_bound(py, [self.0.x, self.0.y]).as_any().iter()
+    }
+
+    fn __repr__(&self) -> String {
+        crate::repr::vec2(self.0)
+    }
 }
+
+impl_round_coordinates!(Vec2);
+impl_approx_eq!(Vec2);
+
+impl_serialize!(Vec2);
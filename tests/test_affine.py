@@ -1,7 +1,43 @@
-from kurbopy import Affine
+from kurbopy import Affine, Point, TranslateScale
+import math
+import pytest
 
 
 def test_affine_mul():
     i = Affine.IDENTITY()
     a2 = 2.0 * i
     assert a2.as_coeffs()[0] == 2.0
+
+
+def test_affine_invertible():
+    assert Affine.rotate(0.3).is_invertible()
+    assert not Affine.scale_non_uniform(1.0, 0.0).is_invertible()
+    a = Affine.rotate(0.3) * Affine.translate((1.0, 2.0))
+    round_trip = a * a.inverse()
+    assert round_trip.approx_eq(Affine.IDENTITY())
+    assert not Affine.scale(1.1).approx_eq(Affine.IDENTITY())
+
+
+def test_affine_fit():
+    src = [(0, 0), (10, 0), (10, 5), (0, 5)]
+    expected = Affine.translate((3, 4)) * Affine.rotate(0.5) * Affine.scale(2)
+    dst = [expected * Point(*p) for p in src]
+    assert Affine.fit(src, dst).approx_eq(expected)
+    assert Affine.fit(src, dst, kind="similarity").approx_eq(expected)
+    rigid = Affine.fit(src, dst, kind="rigid")
+    assert math.isclose(rigid.determinant(), 1.0)
+    with pytest.raises(ValueError):
+        Affine.fit([(0, 0), (1, 1), (2, 2)], [(0, 0), (1, 1), (2, 2)])
+
+
+def test_transform_points():
+    a = Affine.translate((1, 2)) * Affine.scale(2)
+    points = a.transform_points([(0, 0), Point(1, 1)])
+    assert [(p.x, p.y) for p in points] == [(1, 2), (3, 4)]
+    ts = TranslateScale((1, 2), 2)
+    assert [(p.x, p.y) for p in ts.transform_points([(1, 1)])] == [(3, 4)]
+    np = pytest.importorskip("numpy")
+    array = a.transform_points(np.array([[0.0, 0.0], [1.0, 1.0]]))
+    assert array.shape == (2, 2)
+    assert array.tolist() == [[1, 2], [3, 4]]
+    assert ts.transform_points(np.zeros((0, 2))).shape == (0, 2)
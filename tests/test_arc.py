@@ -7,3 +7,35 @@ def test_arc_iterator():
     lst = []
     arc.to_cubic_beziers(0.5, lambda a, b, c: lst.append((a, b, c)))
     assert len(lst) == 2
+
+
+def test_arc_from_svg_arc():
+    arc = Arc.from_svg_arc(Point(0, 0), 1, 1, 0, False, True, Point(2, 0))
+    assert abs(arc.center.x - 1.0) < 1e-9
+    assert abs(arc.center.y) < 1e-9
+    assert abs(abs(arc.sweep_angle) - math.pi) < 1e-9
+    assert Arc.from_svg_arc(Point(0, 0), 0, 0, 0, False, True, Point(2, 0)) is None
+
+
+def test_arc_from_three_points():
+    arc = Arc.from_three_points((1, 0), (0, 1), (-1, 0))
+    assert abs(arc.center.x) < 1e-9 and abs(arc.center.y) < 1e-9
+    assert math.isclose(arc.radii.x, 1) and math.isclose(arc.radii.y, 1)
+    assert math.isclose(arc.start_angle, 0, abs_tol=1e-9)
+    assert math.isclose(arc.sweep_angle, math.pi)
+    arc = Arc.from_three_points((1, 0), (0, -1), (-1, 0))
+    assert math.isclose(arc.sweep_angle, -math.pi)
+    # The long way round, through a point past the end.
+    arc = Arc.from_three_points((1, 0), (0, -1), (0, 1))
+    assert math.isclose(arc.sweep_angle, -1.5 * math.pi)
+    assert Arc.from_three_points((0, 0), (1, 1), (2, 2)) is None
+
+
+def test_arc_from_center_endpoints():
+    arc = Arc.from_center_endpoints((0, 0), (2, 0), (0, 5))
+    assert math.isclose(arc.radii.x, 2)
+    assert math.isclose(arc.sweep_angle, math.pi / 2)
+    arc = Arc.from_center_endpoints((0, 0), (2, 0), (0, 5), "clockwise")
+    assert math.isclose(arc.sweep_angle, -1.5 * math.pi)
+    full = Arc.from_center_endpoints((0, 0), (2, 0), (3, 0))
+    assert math.isclose(full.sweep_angle, 2 * math.pi)
@@ -1,5 +1,17 @@
-from kurbopy import Point, Vec2, TranslateScale
+from kurbopy import (
+    Affine,
+    Point,
+    Rect,
+    Size,
+    Vec2,
+    TranslateScale,
+    points_bbox,
+    points_centroid,
+    points_principal_axes,
+)
+import math
 import pytest
+import threading
 
 
 def test_point_basic():
@@ -7,7 +19,7 @@ def test_point_basic():
     assert p.x == 1.0
     p.y = 3.0
     assert p.y == 3.0
-    assert str(p) == "<Point x=1.0 y=3.0>"
+    assert str(p) == "Point(1.0, 3.0)"
 
 
 def test_point_magic():
@@ -15,6 +27,16 @@ def test_point_magic():
     assert p.x == 2.0
     p = Point(1.0, 2.0) + (1.0, 1.0)
     assert p.x == 2.0
+    v = Point(4.0, 6.0) - Point(1.0, 2.0)
+    assert isinstance(v, Vec2)
+    assert tuple(v) == (3.0, 4.0)
+    p = Point(4.0, 6.0) - (1.0, 2.0)
+    assert isinstance(p, Point)
+    assert tuple(p) == (3.0, 4.0)
+    with pytest.raises(TypeError, match="cannot add two Points"):
+        Point(1.0, 2.0) + Point(1.0, 1.0)
+    with pytest.raises(TypeError, match="unsupported operand"):
+        Point(1.0, 2.0) - "foo"
 
 
 def test_point_lerp():
@@ -61,3 +83,242 @@ def test_common():
     f = lambda x: x**3 - x - 2.0
     x = solve_itp(f, 1.0, 2.0, 1e-12, 0, 0.2, f(1.0), f(2.0))
     assert abs(f(x)) < 6e-12
+
+
+def test_gauss_quadrature():
+    import math
+    from kurbopy import common
+
+    assert math.isclose(common.gauss_quadrature(lambda x: x * x, 0.0, 1.0), 1.0 / 3.0)
+    assert math.isclose(common.gauss_quadrature(math.sin, 0.0, math.pi, 16), 2.0)
+    table = common.gauss_legendre_coeffs(5)
+    assert len(table) == 5
+    assert math.isclose(sum(w for w, _ in table), 2.0)
+    assert common.GAUSS_LEGENDRE_COEFFS_5 == table
+
+
+def test_bezier_utilities():
+    import math
+    from kurbopy import QuadBez
+    from kurbopy.common import bernstein_basis, elevate_degree, split_bezier
+
+    assert bernstein_basis(3, 0.5) == [0.125, 0.375, 0.375, 0.125]
+    assert bernstein_basis(0, 0.3) == [1.0]
+
+    def close(a, b):
+        return len(a) == len(b) and all(math.isclose(p.x, q.x) and math.isclose(p.y, q.y) for p, q in zip(a, b))
+
+    quad = [Point(0, 0), Point(3, 3), Point(6, 0)]
+    cubic = elevate_degree(quad)
+    raised = getattr(QuadBez(*quad), "raise")()
+    assert close(cubic, [raised.p0, raised.p1, raised.p2, raised.p3])
+    assert len(elevate_degree(quad, times=3)) == 6
+
+    left, right = split_bezier(cubic, 0.5)
+    assert close(left, [Point(0, 0), Point(1, 1), Point(2, 1.5), Point(3, 1.5)])
+    assert close(right, [Point(3, 1.5), Point(4, 1.5), Point(5, 1), Point(6, 0)])
+    with pytest.raises(ValueError):
+        split_bezier([], 0.5)
+
+
+def test_solve_poly():
+    from kurbopy.common import solve_poly
+
+    # (x + 0.5)(x - 1)(x - 2)(x - 3)(x - 4)(x - 5)
+    coeffs = [-60.0, 17.0, 161.5, -182.5, 77.5, -14.5, 1.0]
+    expected = [-0.5, 1.0, 2.0, 3.0, 4.0, 5.0]
+    roots = solve_poly(coeffs)
+    assert len(roots) == len(expected)
+    for r, e in zip(roots, expected):
+        assert abs(r - e) < 1e-9
+    assert solve_poly([1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0]) == []
+    assert solve_poly([-2.0, 0.0, 1.0]) == solve_poly([-2.0, 0.0, 1.0, 0.0])
+
+
+def test_point_vec2_sequence():
+    x, y = Point(1.0, 2.0)
+    assert (x, y) == (1.0, 2.0)
+    v = Vec2(3.0, 4.0)
+    assert len(v) == 2
+    assert v[0] == 3.0 and v[-1] == 4.0
+    assert list(v) == [3.0, 4.0]
+    with pytest.raises(IndexError):
+        v[2]
+
+
+def test_translatescale_conversions():
+    ts = TranslateScale.from_scale_about(2.0, (10.0, 10.0))
+    p = ts * Point(10.0, 10.0)
+    assert (p.x, p.y) == (10.0, 10.0)
+    affine = ts.to_affine()
+    assert affine.as_coeffs() == [2.0, 0.0, 0.0, 2.0, -10.0, -10.0]
+    back = affine.as_translate_scale()
+    assert back.as_tuple()[1] == 2.0
+    assert Affine.rotate(0.5).as_translate_scale() is None
+
+
+def test_size_elementwise():
+    a = Size(10.0, 40.0)
+    b = Size(20.0, 30.0)
+    assert a.min(b) == Size(10.0, 30.0)
+    assert a.max(b) == Size(20.0, 40.0)
+    assert a * b == Size(200.0, 1200.0)
+    assert a * 2 == Size(20.0, 80.0)
+    assert a <= 40
+    assert not a < 40
+    assert not a <= b and not a >= b
+
+
+def test_point_round_coordinates():
+    p = Point(1.26, -3.74)
+    assert tuple(p.round_coordinates()) == (1.0, -4.0)
+    assert tuple(p.round_coordinates(1)) == (1.3, -3.7)
+    assert tuple(p.round_coordinates(grid=0.5)) == (1.5, -3.5)
+    assert tuple(Vec2(0.14, 25.0).round_coordinates(-1)) == (0.0, 30.0)
+    with pytest.raises(ValueError):
+        p.round_coordinates(1, grid=0.5)
+    with pytest.raises(ValueError):
+        p.round_coordinates(grid=0)
+
+
+def test_json_round_trip():
+    p = Point(1.5, -2.0)
+    assert p.to_dict() == {"x": 1.5, "y": -2.0}
+    assert tuple(Point.from_json(p.to_json())) == (1.5, -2.0)
+    assert tuple(Vec2.from_dict({"x": 3.0, "y": 4.0})) == (3.0, 4.0)
+    a = Affine.translate((10, 20)) * Affine.scale(2)
+    assert a.to_dict() == [2.0, 0.0, 0.0, 2.0, 10.0, 20.0]
+    assert Affine.from_dict(a.to_dict()) == a
+    with pytest.raises(ValueError):
+        Point.from_json('{"x": 1.0}')
+
+
+def test_repr_round_trip():
+    import kurbopy
+    from kurbopy import (
+        Arc, BezPath, Circle, CubicBez, Ellipse, Insets, Line, MinDistance, Nearest, PathEl,
+        PathSeg, QuadBez, Rect, RoundedRect,
+    )
+
+    namespace = vars(kurbopy)
+    values = [
+        Point(1.5, -2.0),
+        Vec2(0.1, 1e-20),
+        Size(3.0, 4.0),
+        Rect(0, 0, 10, 20),
+        Insets(1, 2, 3, 4),
+        Affine.translate((10, 20)) * Affine.scale(2),
+        TranslateScale((1, 2), 3),
+        Line((0, 0), (1, 1)),
+        QuadBez((0, 0), (1, 1), (2, 0)),
+        CubicBez((0, 0), (1, 1), (2, 1), (3, 0)),
+        PathSeg(Line((0, 0), (1, 1))),
+        PathEl("CurveTo", (1, 2), (3, 4), (5, 6)),
+        PathEl("ClosePath"),
+        Circle((1, 2), 3),
+        Arc((0, 0), (1, 2), 0.0, 1.0, 0.5),
+        RoundedRect(0, 0, 10, 10, 2),
+        BezPath.from_svg("M0,0 L10,0 Q15,5 10,10 Z"),
+        BezPath(),
+        Nearest(4.0, 0.25, (1, 2)),
+        Nearest(4.0, 0.25),
+        MinDistance(1.0, 0.5, 0.0, (1, 0), (1, 1)),
+    ]
+    for value in values:
+        text = repr(value)
+        assert text.startswith(type(value).__name__)
+        assert repr(eval(text, namespace)) == text
+    # An ellipse's radii and rotation are recovered from its transform, so
+    # may not come back exactly.
+    assert repr(Ellipse((1, 2), (3, 4), 0.0)).startswith("Ellipse(Point(1.0, 2.0), Vec2(")
+    assert repr(Point(float("nan"), float("inf"))) == "Point(float('nan'), float('inf'))"
+
+
+def test_point_cloud_utilities():
+    points = [(0, 0), (10, 10), (20, 20), (9, 11), (11, 9)]
+    bbox = points_bbox(points)
+    assert (bbox.min_x(), bbox.min_y(), bbox.max_x(), bbox.max_y()) == (0, 0, 20, 20)
+    centroid = points_centroid(points)
+    assert (centroid.x, centroid.y) == (10, 10)
+
+    (major, major_var), (minor, minor_var) = points_principal_axes(points)
+    assert math.isclose(abs(major.x), math.sqrt(0.5))
+    assert math.isclose(major.x, major.y)
+    assert math.isclose(major.dot(minor), 0, abs_tol=1e-12)
+    assert major_var > minor_var > 0
+    assert math.isclose(major_var, 80)
+    assert math.isclose(minor_var, 0.8)
+
+    with pytest.raises(ValueError):
+        points_centroid([])
+
+    np = pytest.importorskip("numpy")
+    array = np.array(points, dtype=float)
+    assert points_bbox(array).x1 == 20
+    assert points_centroid(array).y == 10
+
+
+def test_approx_eq():
+    p = Point(0.1 + 0.2, 1)
+    assert p.approx_eq(Point(0.3, 1))
+    assert not p.approx_eq(Point(0.3, 1.001))
+    assert p.approx_eq(Point(0.3, 1.001), epsilon=0.01)
+    assert Vec2(1, 2).approx_eq(Vec2(1, 2 + 1e-12))
+    assert Rect(0, 0, 10, 10).approx_eq(Rect(1e-12, 0, 10, 10))
+    assert not Rect(0, 0, 10, 10).approx_eq(Rect(0, 0, 10, 11))
+
+
+def test_default_accuracy():
+    import kurbopy
+
+    assert kurbopy.get_default_accuracy() == kurbopy.DEFAULT_ACCURACY
+    with kurbopy.accuracy(1e-3):
+        assert kurbopy.get_default_accuracy() == 1e-3
+        with pytest.raises(ValueError):
+            with kurbopy.accuracy(1e-9):
+                assert kurbopy.get_default_accuracy() == 1e-9
+                raise ValueError
+        assert kurbopy.get_default_accuracy() == 1e-3
+    assert kurbopy.get_default_accuracy() == kurbopy.DEFAULT_ACCURACY
+    with pytest.raises(ValueError):
+        kurbopy.set_default_accuracy(0)
+    with pytest.raises(ValueError):
+        with kurbopy.accuracy(float("nan")):
+            pass
+
+    # The context manager only affects the thread it is used in.
+    seen = []
+    with kurbopy.accuracy(1e-3):
+        thread = threading.Thread(target=lambda: seen.append(kurbopy.get_default_accuracy()))
+        thread.start()
+        thread.join()
+    assert seen == [kurbopy.DEFAULT_ACCURACY]
+
+
+def test_subclass_preserved():
+    class MyPoint(Point):
+        def label(self):
+            return "(%g, %g)" % (self.x, self.y)
+
+    class MyVec(Vec2):
+        pass
+
+    p = MyPoint(1.4, 2.6)
+    assert isinstance(p.round(), MyPoint)
+    assert p.round().label() == "(1, 3)"
+    assert isinstance(p.lerp((3, 4), 0.5), MyPoint)
+    assert isinstance(p + Vec2(1, 1), MyPoint)
+    assert isinstance(p + MyVec(1, 1), MyPoint)
+    assert isinstance(p + (1, 1), MyPoint)
+    assert type(p - Point(1, 1)) is Vec2
+    assert type(Point(1, 2).round()) is Point
+    assert isinstance(-MyVec(1, 2), MyVec)
+    assert isinstance(MyVec.from_angle(0), MyVec)
+
+    class MyRect(Rect):
+        pass
+
+    r = MyRect.from_points((0, 0), (10, 10))
+    assert isinstance(r, MyRect)
+    assert isinstance(r.inflate(1, 1), MyRect)
+    assert isinstance(r + Vec2(1, 1), MyRect)
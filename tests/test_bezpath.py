@@ -1,5 +1,26 @@
-from kurbopy import Point, BezPath
+from kurbopy import (
+    Affine,
+    Arc,
+    ArcLengths,
+    Point,
+    BezPath,
+    Circle,
+    FrozenBezPath,
+    Line,
+    Ray,
+    Rect,
+    TranslateScale,
+    distribute_along_path,
+    fit_euler,
+    intersection_area,
+    iou,
+    match_paths,
+    minkowski_sum,
+    simplify_polyline,
+    split_at_intersections,
+)
 import math
+import pytest
 
 
 def test_bezpath_segments():
@@ -9,3 +30,1040 @@ def test_bezpath_segments():
     b.line_to(Point(100, 0))
     b.close_path()
     assert len(b.segments()) == 3
+
+
+def test_bezpath_from_svg():
+    b = BezPath.from_svg("M0 0 A1 1 0 0 1 2 0 Z")
+    assert len(b.segments()) > 1
+    assert not b.is_empty()
+
+
+def test_bezpath_nearest_filtered():
+    b = BezPath()
+    b.move_to(Point(0, 0))
+    b.line_to(Point(100, 0))
+    b.quad_to(Point(100, 50), Point(50, 50))
+    b.close_path()
+    b.move_to(Point(200, 0))
+    b.line_to(Point(300, 0))
+    b.line_to(Point(300, 100))
+    b.close_path()
+
+    ix, nearest = b.nearest(Point(250, 5), 1e-6)
+    assert ix == 3
+    assert math.isclose(nearest.distance, 5)
+    assert math.isclose(nearest.distance ** 2, nearest.distance_sq)
+    assert math.isclose(nearest.point.x, 250) and math.isclose(nearest.point.y, 0, abs_tol=1e-9)
+
+    ix, nearest = b.nearest_on_subpath(Point(250, 5), 0, 1e-6)
+    assert ix < 3
+
+    ix, nearest = b.nearest(Point(90, 45), 1e-6, filter=lambda s: s.as_line() is not None)
+    assert b.segments()[ix].as_line() is not None
+
+
+def test_bezpath_tuple_points():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 20))
+    b.curve_to(Point(20, 20), (30, 10), (30, 0))
+    b.close_path()
+    assert len(b.segments()) == 3
+    assert b.contains((15, 5))
+
+
+def test_bezpath_grid_fit():
+    b = BezPath()
+    b.move_to((0.4, 10.3))
+    b.curve_to((5.2, 10.3), (10.2, 5.6), (10.2, 0.2))
+    b.quad_to((10.2, -9.7), (0.4, -9.7))
+    b.close_path()
+    cubic, quad, _ = list(b.grid_fit(1).segments())
+    # On-curve points land on the grid
+    assert (cubic.p0.x, cubic.p0.y) == (0, 10)
+    assert (cubic.p3.x, cubic.p3.y) == (10, 0)
+    assert (quad.p2.x, quad.p2.y) == (0, -10)
+    # Horizontal and vertical handles stay that way
+    assert math.isclose(cubic.p1.y, 10)
+    assert math.isclose(cubic.p2.x, 10)
+    assert math.isclose(quad.p1.x, 10)
+    assert math.isclose(quad.p1.y, -10)
+
+
+def test_bezpath_intersections_with_lines():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((100, 0))
+    b.line_to((100, 100))
+    b.line_to((0, 100))
+    b.close_path()
+    lines = [Line((110, y), (-10, y)) for y in (25, 50, 150)]
+    hits = b.intersections_with_lines(lines)
+    assert len(hits) == 3
+    assert [(p.x, p.y) for p in hits[0]] == [(100, 25), (0, 25)]
+    assert len(hits[1]) == 2
+    assert hits[2] == []
+    # A line through a corner hits it once, not once per adjacent edge
+    (corners,) = b.intersections_with_lines([Line((-10, -10), (110, 110))])
+    assert [(p.x, p.y) for p in corners] == [(0, 0), (100, 100)]
+
+
+def test_bezpath_dash_spans():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 0))
+    b.move_to((0, 10))
+    b.line_to((5, 10))
+    spans = b.dash_spans([4, 2], 1)
+    expected = [(0, 3), (5, 9), (10, 13)]
+    assert len(spans) == len(expected)
+    for (s, e), (es, ee) in zip(spans, expected):
+        assert math.isclose(s, es) and math.isclose(e, ee)
+
+
+def test_bezpath_round_corners_smooth():
+    square = BezPath()
+    square.move_to((0, 0))
+    square.line_to((100, 0))
+    square.line_to((100, 100))
+    square.line_to((0, 100))
+    square.close_path()
+    rounded = square.round_corners_smooth(10, 0.6)
+    # Each corner takes up (1 + smoothness) * radius of each edge
+    first = rounded.segments()[0].as_line()
+    assert math.isclose(first.p0.x, 16) and math.isclose(first.p1.x, 84)
+    assert math.isclose(rounded.area(), square.area(), rel_tol=0.01)
+    assert rounded.area() < square.area()
+    assert square.round_corners_smooth(0).area() == square.area()
+
+
+def test_bezpath_clip_lines():
+    b = BezPath()
+    b.move_to((-10, 5))
+    b.line_to((20, 5))
+    b.line_to((20, 8))
+    b.line_to((5, 8))
+    clipped = b.clip_lines(Rect(0, 0, 10, 10))
+    segs = list(clipped.segments())
+    assert len(segs) == 2
+    assert (segs[0].p0.x, segs[0].p1.x) == (0, 10)
+    assert (segs[1].p0.x, segs[1].p1.x) == (10, 5)
+    with pytest.raises(ValueError):
+        b.clip_lines(Rect(0, 0, 10, 10), 0)
+    assert Line((-5, -5), (-1, 20)).clip(Rect(0, 0, 10, 10)) is None
+    clipped_line = Line((-5, 5), (5, 5)).clip(Rect(0, 0, 10, 10))
+    assert clipped_line.p0.x == 0
+
+
+def test_bezpath_resample():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 0))
+    b.line_to((10, 10))
+    points = b.resample(5)
+    assert [(p.x, p.y) for p in points] == [(0, 0), (5, 0), (10, 0), (10, 5), (10, 10)]
+    points = b.resample_by_distance(3)
+    assert len(points) == 7
+    assert math.isclose(points[4].y, 2)
+    assert len(b.resample_by_distance(5)) == 5
+    with pytest.raises(ValueError):
+        b.resample_by_distance(0)
+
+
+def test_bezpath_flatten_subpaths():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 0))
+    b.line_to((10, 10))
+    b.close_path()
+    b.move_to((20, 0))
+    b.line_to((30, 0))
+    polylines = b.flatten_subpaths()
+    assert [[(p.x, p.y) for p in line] for line in polylines] == [
+        [(0, 0), (10, 0), (10, 10), (0, 0)],
+        [(20, 0), (30, 0)],
+    ]
+    kinds = []
+    assert b.flatten(0.1, kinds.append) is None
+    assert [el.kind for el in kinds] == [
+        "MoveTo", "LineTo", "LineTo", "ClosePath", "MoveTo", "LineTo"
+    ]
+    perimeters = []
+    b.flatten(0.1, lambda el: perimeters.append(b.perimeter()))
+    assert perimeters == [b.perimeter()] * 6
+    with pytest.raises(ValueError):
+        b.flatten_subpaths(0)
+
+
+def test_simplify_polyline():
+    points = [(0, 0), (1, 0.05), (2, -0.05), (3, 0), (3, 3)]
+    simplified = simplify_polyline(points, 0.1)
+    assert [(p.x, p.y) for p in simplified] == [(0, 0), (3, 0), (3, 3)]
+    assert len(simplify_polyline(points, 0.01)) == 5
+    assert len(simplify_polyline(points, 0)) == 5
+    with pytest.raises(ValueError):
+        simplify_polyline(points, -1)
+    b = BezPath()
+    b.move_to((0, 0))
+    b.curve_to((0, 50), (50, 100), (100, 100))
+    flat = b.flatten_subpaths(0.01)[0]
+    simplified = b.flatten_simplified(0.01, 1.0)[0]
+    assert len(simplified) < len(flat)
+    assert simplified[0].x == 0 and simplified[-1].x == 100
+    with pytest.raises(ValueError):
+        b.flatten_simplified(0.1, -1)
+
+
+def test_bezpath_corners():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 0))
+    b.curve_to((15, 0), (20, 5), (20, 10))
+    b.line_to((0, 10))
+    b.close_path()
+    corners = b.corners(0.1)
+    # The join into the curve is smooth; the others are right angles
+    assert [c[0] for c in corners] == [0, 2, 3]
+    assert all(math.isclose(abs(c[3]), math.pi / 2) for c in corners)
+    assert (corners[1][2].x, corners[1][2].y) == (20, 10)
+
+    cusp = BezPath()
+    cusp.move_to((0, 0))
+    cusp.curve_to((1, 1), (0, 1), (1, 0))
+    corners = cusp.corners(0.1)
+    assert len(corners) == 1
+    index, t, point, angle = corners[0]
+    assert index == 0 and math.isclose(t, 0.5)
+    assert math.isclose(point.x, 0.5) and math.isclose(point.y, 0.75)
+
+
+def test_bezpath_curvature_comb():
+    circle = Circle((0, 0), 10).to_path(1e-6)
+    # Normals point away from the center, whichever way the circle runs
+    for path in (circle, circle.reverse_subpaths()):
+        points, normals, magnitudes = path.curvature_comb(4, 100)
+        assert len(points) == len(normals) == len(magnitudes)
+        for p, n, m in zip(points, normals, magnitudes):
+            assert math.isclose(m, 10, rel_tol=1e-2)
+            assert math.isclose(p.x * n.x + p.y * n.y, 10, rel_tol=1e-2)
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((10, 0))
+    points, normals, magnitudes = b.curvature_comb(3)
+    assert [p.x for p in points] == [0, 5, 10]
+    assert magnitudes == [0, 0, 0]
+
+
+def test_bezpath_extrema():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.curve_to((0, 10), (10, 10), (10, 0))
+    b.line_to((5, -5))
+    b.close_path()
+    extrema = b.extrema()
+    assert len(extrema) == 1
+    index, t, point = extrema[0]
+    assert index == 0 and math.isclose(t, 0.5)
+    assert math.isclose(point.x, 5) and math.isclose(point.y, 7.5)
+    global_extrema = b.extrema(global_only=True)
+    ys = sorted(p.y for _, _, p in global_extrema)
+    assert math.isclose(ys[0], -5) and math.isclose(ys[-1], 7.5)
+    # The start and the end of the curve lie on the left and right sides
+    assert {p.x for _, _, p in global_extrema} >= {0, 10}
+
+
+def test_bezpath_harmonize():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.curve_to((0, 5), (2, 9.7), (5, 10))
+    b.curve_to((8, 10), (12, 6), (15, 0))
+    b.line_to((0, 0))
+    b.close_path()
+
+    g1 = b.harmonize()
+    first, second = list(g1.segments())[:2]
+    v_in = (first.p3.x - first.p2.x, first.p3.y - first.p2.y)
+    v_out = (second.p1.x - second.p0.x, second.p1.y - second.p0.y)
+    assert abs(v_in[0] * v_out[1] - v_in[1] * v_out[0]) < 1e-9
+    # The corners at the line are left alone
+    assert (first.p1.x, first.p1.y) == (0, 5)
+
+    g2 = b.harmonize("g2")
+    first, second = list(g2.segments())[:2]
+    assert math.isclose(first.curvature(1.0), second.curvature(0.0))
+    with pytest.raises(ValueError):
+        b.harmonize("g3")
+
+
+def test_bezpath_stroke_variable():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((100, 0))
+    constant = b.stroke_variable(10)
+    assert math.isclose(abs(constant.area()), 1000)
+    tapered = b.stroke_variable(lambda t: 10 * (1 - t))
+    assert math.isclose(abs(tapered.area()), 500)
+    sampled = b.stroke_variable([0, 10, 0])
+    assert math.isclose(abs(sampled.area()), 500)
+    box = sampled.bounding_box()
+    assert math.isclose(box.y0, -5) and math.isclose(box.height(), 10)
+    with pytest.raises(ValueError):
+        b.stroke_variable(lambda t: -1)
+
+
+def test_distribute_along_path():
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((0, 20))
+    transforms = distribute_along_path(b, None, 5)
+    assert len(transforms) == 5
+    origin = transforms[2] * Point(0, 0)
+    assert math.isclose(origin.x, 0) and math.isclose(origin.y, 10)
+    # The x axis follows the path upwards
+    along = transforms[2] * Point(1, 0)
+    assert math.isclose(along.x, 0, abs_tol=1e-12) and math.isclose(along.y, 11)
+
+    stamp = Rect(-1, -1, 1, 1).to_path(0.1)
+    stamps = distribute_along_path(b, stamp, 5, align_to_tangent=False)
+    assert len(stamps) == 5
+    box = stamps[-1].bounding_box()
+    assert (box.min_x(), box.min_y(), box.max_x(), box.max_y()) == (-1, 19, 1, 21)
+
+
+def test_bezpath_lerp():
+    a = BezPath()
+    a.move_to((0, 0))
+    a.line_to((10, 0))
+    a.curve_to((10, 5), (5, 10), (0, 10))
+    a.close_path()
+    b = BezPath()
+    b.move_to((0, 0))
+    b.line_to((20, 0))
+    b.curve_to((20, 10), (10, 20), (0, 20))
+    b.close_path()
+    assert a.is_compatible(b)
+    assert a.is_compatible(a)
+    mid = a.lerp(b, 0.5)
+    cubic = list(mid.segments())[1]
+    assert (cubic.p0.x, cubic.p3.y) == (15, 15)
+    assert (cubic.p1.x, cubic.p1.y) == (15, 7.5)
+
+    c = BezPath()
+    c.move_to((0, 0))
+    c.line_to((10, 0))
+    c.line_to((0, 10))
+    c.close_path()
+    assert not a.is_compatible(c)
+    with pytest.raises(ValueError, match="element 2"):
+        a.lerp(c, 0.5)
+
+
+def test_match_paths():
+    square = Rect(0, 0, 10, 10).to_path(0.1)
+    circle = Circle((5, 5), 5).to_path(0.1)
+    a, b = match_paths(square, circle, 16)
+    assert a.is_compatible(b)
+    assert len(list(a.segments())) == 16
+    assert math.isclose(a.perimeter(), 40, rel_tol=1e-6)
+    assert math.isclose(b.perimeter(), circle.perimeter(), rel_tol=1e-3)
+    mid = a.lerp(b, 0.5)
+    assert abs(square.area()) > abs(mid.area()) > abs(circle.area())
+
+    two = square.to_svg() + " " + Rect(20, 0, 30, 10).to_path(0.1).to_svg()
+    a, b = match_paths(BezPath.from_svg(two), circle, 8)
+    assert a.is_compatible(b)
+    box = list(b.segments())[-1].bounding_box()
+    assert (box.min_x(), box.min_y(), box.max_x(), box.max_y()) == (25, 5, 25, 5)
+
+
+def test_bezpath_contains_points():
+    circle = Circle((0, 0), 10).to_path(0.1)
+    points = [(0, 0), (9, 0), (11, 0), Point(0, -5), (20, 20)]
+    expected = [True, True, False, True, False]
+    assert circle.contains_points(points) == expected
+    many = [(x - 15, y - 15) for x in range(30) for y in range(30)]
+    assert circle.contains_points(many, parallel=True) == [
+        circle.contains(p) for p in many
+    ]
+    np = pytest.importorskip("numpy")
+    result = circle.contains_points(np.array(points, dtype=float))
+    assert isinstance(result, np.ndarray)
+    assert result.tolist() == expected
+
+
+def test_bezpath_medial_axis():
+    rect = Rect(0, 0, 100, 20).to_path(0.1)
+    axis = rect.medial_axis(1.0)
+    points = [p for line in axis for p in line]
+    assert points
+    middle = [p for p in points if 15 < p.x < 85]
+    assert middle
+    assert all(math.isclose(p.y, 10, abs_tol=0.5) for p in middle)
+    assert all(rect.contains(p) for p in points)
+
+
+def test_bezpath_buffer():
+    square = Rect(0, 0, 10, 10).to_path(0.1)
+    assert math.isclose(square.buffer(1, join="miter").area(), 144)
+    assert math.isclose(square.buffer(1, join="bevel").area(), 142)
+    round_area = square.buffer(1, tolerance=0.01).area()
+    assert math.isclose(round_area, 100 + 40 + math.pi, rel_tol=1e-3)
+    assert math.isclose(square.buffer(-1).area(), 64)
+    assert square.buffer(-6).area() == 0
+    overlapping = BezPath.from_svg(
+        "M0,0 L10,0 L10,10 L0,10 Z M5,5 L15,5 L15,15 L5,15 Z"
+    )
+    # Perimeter 60, six outer corners and two inner ones.
+    grown = overlapping.buffer(1, join="miter").area()
+    assert math.isclose(grown, 175 + 60 + 6 - 2)
+    donut = BezPath.from_svg("M0,0 L10,0 L10,10 L0,10 Z M3,3 L3,7 L7,7 L7,3 Z")
+    assert math.isclose(donut.buffer(1, join="miter").area(), 140)
+    assert math.isclose(donut.buffer(2.5, join="miter").area(), 225)
+    with pytest.raises(ValueError):
+        square.buffer(1, join="square")
+
+
+def test_minkowski_sum():
+    square = Rect(0, 0, 10, 10).to_path(0.1)
+    small = Rect(-1, -1, 1, 1).to_path(0.1)
+    assert math.isclose(minkowski_sum(square, small).area(), 144)
+    ell = BezPath.from_svg("M0,0 L10,0 L10,2 L2,2 L2,10 L0,10 Z")
+    assert math.isclose(minkowski_sum(ell, small).area(), 80)
+    assert math.isclose(minkowski_sum(small, ell).area(), 80)
+    assert math.isclose(minkowski_sum(ell, ell).area(), 208)
+    disk = Circle((0, 0), 1).to_path(0.01)
+    rounded = minkowski_sum(ell, disk, 0.01).area()
+    assert math.isclose(rounded, 36 + 40 + math.pi, rel_tol=1e-2)
+
+
+def test_bezpath_clip_to_rect():
+    square = Rect(0, 0, 10, 10).to_path(0.1)
+    assert math.isclose(square.clip_to_rect(Rect(5, 5, 20, 20)).area(), 25)
+    assert square.clip_to_rect(Rect(20, 20, 30, 30)).is_empty()
+    circle = Circle((0, 0), 10).to_path(0.01)
+    quarter = circle.clip_to_rect(Rect(0, 0, 20, 20))
+    assert math.isclose(abs(quarter.area()), math.pi * 25, rel_tol=1e-3)
+    assert any(el.kind == "CurveTo" for el in quarter.elements())
+    line = BezPath.from_svg("M-5,5 L15,5")
+    (seg,) = list(line.clip_to_rect(Rect(0, 0, 10, 10), fill=False).segments())
+    assert (seg.p0.x, seg.p1.x) == (0, 10)
+    big = Rect(0, 0, 20, 20).to_path(0.1)
+    outline = big.clip_to_rect(Rect(-5, -5, 10, 10), fill=False)
+    assert [el.kind for el in outline.elements()].count("MoveTo") == 1
+    assert math.isclose(outline.perimeter(1e-9), 20)
+
+
+def test_bezpath_tessellate():
+    donut = BezPath.from_svg("M0,0 L10,0 L10,10 L0,10 Z M3,3 L3,7 L7,7 L7,3 Z")
+    vertices, indices, contours = donut.tessellate()
+    assert (vertices.typecode, indices.typecode) == ("f", "I")
+    assert len(vertices) == 16 and len(contours) == 8
+    assert sorted(set(contours)) == [0, 1]
+    assert len(indices) % 3 == 0 and max(indices) < 8
+    memoryview(vertices)
+
+    def area(i, j, k):
+        (x0, y0), (x1, y1), (x2, y2) = [vertices[2 * n : 2 * n + 2] for n in (i, j, k)]
+        return ((x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0)) / 2
+
+    triangles = [area(*indices[n : n + 3]) for n in range(0, len(indices), 3)]
+    assert all(a > 0 for a in triangles)
+    assert math.isclose(sum(triangles), 84)
+
+
+def test_bezpath_remove_overlaps():
+    squares = BezPath.from_svg("M0,0 L10,0 L10,10 L0,10 Z M5,5 L15,5 L15,15 L5,15 Z")
+    merged = squares.remove_overlaps()
+    assert math.isclose(merged.area(), 175)
+    assert [el.kind for el in merged.elements()].count("MoveTo") == 1
+    assert merged.contains((12, 12)) and not merged.contains((12, 2))
+    clockwise = squares.reverse_subpaths().remove_overlaps()
+    assert math.isclose(clockwise.area(), -175)
+    circles = BezPath.from_svg(
+        Circle((0, 0), 10).to_path(0.1).to_svg()
+        + " "
+        + Circle((10, 0), 10).to_path(0.1).to_svg()
+    )
+    union = circles.remove_overlaps()
+    assert any(el.kind == "CurveTo" for el in union.elements())
+    lens = 200 * math.acos(0.5) - 5 * math.sqrt(300)
+    assert math.isclose(union.area(), 2 * math.pi * 100 - lens, rel_tol=1e-3)
+    single = Circle((0, 0), 10).to_path(0.1)
+    assert math.isclose(single.remove_overlaps().area(), single.area())
+
+
+def test_bezpath_correct_directions():
+    # Both contours anticlockwise; the inner one is a hole.
+    donut = BezPath.from_svg("M0,0 L10,0 L10,10 L0,10 Z M3,3 L7,3 L7,7 L3,7 Z")
+    assert donut.subpath_directions() == [("anticlockwise", 0), ("anticlockwise", 1)]
+    postscript = donut.correct_directions()
+    assert postscript.subpath_directions() == [("anticlockwise", 0), ("clockwise", 1)]
+    assert math.isclose(postscript.area(), 84)
+    truetype = donut.correct_directions("truetype")
+    assert truetype.subpath_directions() == [("clockwise", 0), ("anticlockwise", 1)]
+    assert math.isclose(truetype.area(), -84)
+    assert postscript.correct_directions().to_svg() == postscript.to_svg()
+    flat = BezPath.from_svg("M0,0 L10,0 Z")
+    assert flat.subpath_directions() == [(None, 0)]
+    with pytest.raises(ValueError):
+        donut.correct_directions("metafont")
+
+
+def test_bezpath_cleanup():
+    path = BezPath.from_svg(
+        "M0,0 L0,0 L5,0 L10,0 C10,3 10,7 10,10 L10,10 L0,10 Z M20,20 Z M30,30"
+    )
+    assert path.cleanup().to_svg() == "M0,0 L10,0 L10,10 L0,10 Z"
+    # The closing line carries straight on into the first line.
+    rotated = BezPath.from_svg("M5,0 L10,0 L10,10 L0,10 L0,0 Z")
+    assert rotated.cleanup().to_svg() == "M0,0 L10,0 L10,10 L0,10 Z"
+    # Real curves are kept.
+    curve = BezPath.from_svg("M0,0 C0,5 10,5 10,0")
+    assert curve.cleanup().to_svg() == curve.to_svg()
+    # Within the tolerance, a nearly straight curve becomes a line.
+    nearly = BezPath.from_svg("M0,0 C3,0.01 7,-0.01 10,0")
+    assert nearly.cleanup().to_svg() == nearly.to_svg()
+    assert nearly.cleanup(0.1).to_svg() == "M0,0 L10,0"
+    with pytest.raises(ValueError):
+        path.cleanup(-1)
+
+
+def test_bezpath_round_coordinates():
+    path = BezPath.from_svg(
+        "M0.4,0.6 L10.2,0.3 Q12.7,5.5 10.1,9.9 C7.5,12.4 2.6,12.5 0.1,10.2 Z"
+    )
+    assert path.round_coordinates().to_svg() == "M0,1 L10,0 Q13,6 10,10 C8,12 3,13 0,10 Z"
+    assert path.round_coordinates(grid=4).to_svg() == "M0,0 L12,0 Q12,4 12,8 C8,12 4,12 0,12 Z"
+    assert path.round_coordinates(1).to_svg() == path.to_svg()
+
+
+def test_bezpath_to_svg_options():
+    path = BezPath.from_svg(
+        "M10,10 C10,20 20,20 20,10 C20,0 30,0 30,10 Q40,20 50,10 Q60,0 70,10 L70,30 Z"
+    )
+    assert path.to_svg(shorthand=True) == (
+        "M10,10 C10,20 20,20 20,10 S30,0 30,10 Q40,20 50,10 T70,10 L70,30 Z"
+    )
+    relative = path.to_svg(relative=True)
+    assert relative == "m10,10 c0,10 10,10 10,0 c0,-10 10,-10 10,0 q10,10 20,0 q10,-10 20,0 l0,20 z"
+    assert BezPath.from_svg(relative).to_svg() == path.to_svg()
+    both = path.to_svg(relative=True, shorthand=True)
+    assert BezPath.from_svg(both).to_svg() == path.to_svg()
+    precise = BezPath.from_svg("M0.123456,1 L2.5,3.14159 L-0.0001,7")
+    assert precise.to_svg(precision=2) == "M0.12,1 L2.5,3.14 L0,7"
+    # Rounding errors do not build up in relative commands.
+    steps = BezPath.from_svg("M0,0 L0.44,0 L0.88,0 L1.32,0")
+    assert steps.to_svg(precision=1, relative=True) == "m0,0 l0.4,0 l0.5,0 l0.4,0"
+
+
+def test_bezpath_json():
+    path = BezPath.from_svg("M0,0 L10,0 Q15,5 10,10 C5,15 0,5 0.1,0.2 Z")
+    assert path.to_dict()[:2] == [{"MoveTo": {"x": 0.0, "y": 0.0}}, {"LineTo": {"x": 10.0, "y": 0.0}}]
+    assert path.to_dict()[-1] == "ClosePath"
+    assert BezPath.from_json(path.to_json()).to_svg() == path.to_svg()
+    assert BezPath.from_dict(path.to_dict()).to_svg() == path.to_svg()
+    with pytest.raises(ValueError):
+        BezPath.from_json("[{\"MoveTo\": 1}]")
+
+
+def test_bezpath_arcs():
+    path = BezPath()
+    with pytest.raises(ValueError):
+        path.arc_to(5, 5, 0, False, True, (20, 0))
+    path.move_to((0, 0))
+    path.line_to((10, 0))
+    path.arc_to(5, 5, 0, False, True, (20, 0))
+    kinds = [el.kind for el in path.elements()]
+    assert kinds[:2] == ["MoveTo", "LineTo"]
+    assert set(kinds[2:]) == {"CurveTo"}
+    end = list(path.elements())[-1].end_point()
+    assert math.isclose(end.x, 20) and math.isclose(end.y, 0, abs_tol=1e-9)
+    # The arc bulges out by its radius.
+    bounds = path.bounding_box()
+    assert math.isclose(bounds.height(), 5, rel_tol=1e-3)
+    # A zero radius gives a straight line.
+    path.arc_to(0, 0, 0, False, True, (30, 0))
+    assert list(path.elements())[-1].kind == "LineTo"
+
+    arcs = BezPath()
+    arcs.append_arc(Arc((0, 0), (1, 1), 0, math.pi / 2, 0))
+    assert list(arcs.elements())[0].kind == "MoveTo"
+    assert math.isclose(list(arcs.elements())[0].end_point().x, 1)
+    count = len(list(arcs.elements()))
+    # A second arc which does not start where the first ends is joined by a line.
+    arcs.append_arc(Arc((0, 0), (2, 2), math.pi / 2, math.pi / 2, 0))
+    assert list(arcs.elements())[count].kind == "LineTo"
+    end = list(arcs.elements())[-1].end_point()
+    assert math.isclose(end.x, -2) and math.isclose(end.y, 0, abs_tol=1e-9)
+
+
+def test_bezpath_from_polygon():
+    square = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    assert square.to_svg() == "M0,0 L10,0 L10,10 L0,10 Z"
+    assert math.isclose(abs(square.area()), 100)
+    open_path = BezPath.from_polygon([Point(0, 0), (5, 5)], closed=False)
+    assert open_path.to_svg() == "M0,0 L5,5"
+    assert BezPath.from_polygon([]).is_empty()
+
+
+def test_bezpath_regular_polygon_and_star():
+    hexagon = BezPath.regular_polygon((0, 0), 6, 10)
+    elements = list(hexagon.elements())
+    assert len(elements) == 7 and elements[-1].kind == "ClosePath"
+    assert math.isclose(elements[0].end_point().x, 10)
+    assert math.isclose(abs(hexagon.area()), 3 * math.sqrt(3) / 2 * 100)
+    rotated = BezPath.regular_polygon((0, 0), 4, 1, rotation=math.pi / 2)
+    first = list(rotated.elements())[0].end_point()
+    assert math.isclose(first.x, 0, abs_tol=1e-12) and math.isclose(first.y, 1)
+    with pytest.raises(ValueError):
+        BezPath.regular_polygon((0, 0), 2, 1)
+
+    star = BezPath.star((5, 5), 5, 10, 4)
+    points = [el.end_point() for el in list(star.elements())[:-1]]
+    assert len(points) == 10
+    distances = [math.hypot(p.x - 5, p.y - 5) for p in points]
+    assert all(math.isclose(d, 10 if i % 2 == 0 else 4) for i, d in enumerate(distances))
+
+
+def test_bezpath_to_monotonic():
+    path = BezPath.from_svg("M0,0 C0,10 10,10 10,0 Z M20,0 Q25,10 30,0 L40,0")
+    monotonic = path.to_monotonic()
+    kinds = [el.kind for el in monotonic.elements()]
+    assert kinds == [
+        "MoveTo", "CurveTo", "CurveTo", "ClosePath",
+        "MoveTo", "QuadTo", "QuadTo", "LineTo",
+    ]
+    assert math.isclose(monotonic.area(), path.area())
+
+
+def test_fit_euler():
+    # Points on a circle are joined by circular arcs.
+    samples = [
+        (10 * math.cos(math.radians(a)), 10 * math.sin(math.radians(a)))
+        for a in range(0, 181, 30)
+    ]
+    path = fit_euler(samples, 0.001)
+    ends = [el.end_point() for el in path.elements()]
+    for x, y in samples:
+        assert any(math.isclose(p.x, x, abs_tol=1e-9) and math.isclose(p.y, y, abs_tol=1e-9) for p in ends)
+    for seg in path.segments():
+        for t in (0.25, 0.5, 0.75):
+            p = seg.eval(t)
+            assert math.isclose(math.hypot(p.x, p.y), 10, abs_tol=0.01)
+
+    # Collinear points give straight lines.
+    line = fit_euler([(0, 0), (5, 0), (10, 0)])
+    assert line.to_svg() == "M0,0 L5,0 L10,0"
+
+    closed = fit_euler([(0, 0), (10, 0), (10, 10), (0, 10)], closed=True)
+    assert list(closed.elements())[-1].kind == "ClosePath"
+    assert abs(closed.area()) > 100
+    for tolerance in (0, float("nan"), float("inf")):
+        with pytest.raises(ValueError):
+            fit_euler(samples, tolerance)
+
+
+def test_bezpath_min_distance():
+    long_line = BezPath.from_svg("M0,0 L100,0")
+    square = BezPath.from_polygon([(50, 3), (51, 3), (51, 4), (50, 4)])
+    assert math.isclose(long_line.min_distance(square), 3, abs_tol=1e-6)
+    assert math.isclose(square.min_distance(long_line), 3, abs_tol=1e-6)
+
+    big = Circle((0, 0), 10).to_path(1e-3)
+    small = Circle((30, 0), 5).to_path(1e-3)
+    assert math.isclose(big.min_distance(small, accuracy=1e-4), 15, abs_tol=0.01)
+    assert BezPath().min_distance(big) > 1e300
+
+
+def test_bezpath_intersects():
+    a = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    b = BezPath.from_polygon([(5, 5), (15, 5), (15, 15), (5, 15)])
+    hits = a.intersects(b)
+    assert [(ia, ib) for ia, _, ib, _, _ in hits] == [(1, 0), (2, 3)]
+    for _, ta, _, tb, _ in hits:
+        assert math.isclose(ta, 0.5) and math.isclose(tb, 0.5)
+    assert math.isclose(hits[0][4].x, 10) and math.isclose(hits[0][4].y, 5)
+    assert math.isclose(hits[1][4].x, 5) and math.isclose(hits[1][4].y, 10)
+    # A path can be tested against itself; its segments meet at the corners.
+    corners = sorted((p.x, p.y) for *_, p in a.intersects(a))
+    assert corners == [(0, 0), (0, 10), (10, 0), (10, 10)]
+
+    # A crossing at a vertex is only reported once.
+    vee = BezPath.from_svg("M0,0 L10,10 L20,0")
+    post = BezPath.from_svg("M10,0 L10,20")
+    assert len(vee.intersects(post)) == 1
+
+    circle = Circle((0, 0), 10).to_path(0.01)
+    line = BezPath.from_svg("M-20,0 L20,0")
+    xs = sorted(hit[4].x for hit in circle.intersects(line))
+    assert len(xs) == 2
+    assert math.isclose(xs[0], -10, abs_tol=1e-6) and math.isclose(xs[1], 10, abs_tol=1e-6)
+    # Curves against curves are found by subdivision.
+    other = Circle((10, 0), 10).to_path(0.01)
+    ys = sorted(hit[4].y for hit in circle.intersects(other, 1e-6))
+    assert len(ys) == 2
+    assert math.isclose(ys[1], math.sqrt(75), abs_tol=0.02)
+    with pytest.raises(ValueError):
+        circle.intersects(other, float("nan"))
+
+
+def test_bezpath_intersect_line():
+    square = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    hits = square.intersect_line(Line((-5, 5), (15, 5)))
+    assert [h.segment_index for h in hits] == [3, 1]
+    assert math.isclose(hits[0].line_t, 0.25) and math.isclose(hits[1].line_t, 0.75)
+    assert math.isclose(hits[0].segment_t, 0.5)
+    assert math.isclose(hits[0].point.x, 0, abs_tol=1e-9) and math.isclose(hits[0].point.y, 5)
+    unsorted = square.intersect_line(Line((-5, 5), (15, 5)), sort=False)
+    assert [h.segment_index for h in unsorted] == [1, 3]
+
+    # The diagonal passes through two corners, each shared by two segments.
+    diagonal = square.intersect_line(Line((-5, -5), (15, 15)))
+    assert len(diagonal) == 2
+    assert math.isclose(diagonal[0].point.x, 0, abs_tol=1e-9)
+    assert math.isclose(diagonal[1].point.x, 10)
+
+
+def test_bezpath_intersect_path():
+    a = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    b = BezPath.from_polygon([(5, 5), (15, 5), (15, 15), (5, 15)])
+    records = a.intersect_path(b)
+    assert [(r.segment_index, r.other_index) for r in records] == [(1, 0), (2, 3)]
+    assert math.isclose(records[0].segment_t, 0.5) and math.isclose(records[0].other_t, 0.5)
+    assert math.isclose(records[1].point.x, 5) and math.isclose(records[1].point.y, 10)
+    assert [(r.segment_index, r.segment_t) for r in records] == [h[:2] for h in a.intersects(b)]
+    assert repr(records[0]).startswith("PathIntersection(1, ")
+    assert a.intersect_path(BezPath.from_svg("M20,20 L30,30")) == []
+    assert len(a.intersect_path(a)) == len(a.intersects(a))
+    with pytest.raises(ValueError):
+        a.intersect_path(b, float("nan"))
+
+
+def test_split_at_intersections():
+    a = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    b = BezPath.from_polygon([(5, 5), (15, 5), (15, 15), (5, 15)])
+    split_a, split_b = split_at_intersections(a, b)
+    assert split_a.round_coordinates(6).to_svg() == "M0,0 L10,0 L10,5 L10,10 L5,10 L0,10 Z"
+    assert split_b.round_coordinates(6).to_svg() == "M5,5 L10,5 L15,5 L15,15 L5,15 L5,10 Z"
+    assert math.isclose(split_a.area(), a.area())
+    # Meeting only at its own corners, a path split against itself is unchanged
+    self_a, _ = split_at_intersections(a, a)
+    assert self_a.to_svg() == a.to_svg()
+
+    circle = Circle((0, 0), 10).to_path(0.01)
+    line = BezPath.from_svg("M-20,0 L20,0")
+    split_circle, split_line = split_at_intersections(circle, line)
+    assert len(list(split_line.segments())) == 3
+    ends = [el.end_point() for el in split_circle.elements() if el.kind != "ClosePath"]
+    for x in (10, -10):
+        assert any(math.hypot(p.x - x, p.y) < 1e-5 for p in ends)
+    assert math.isclose(split_circle.area(), circle.area(), rel_tol=1e-6)
+    with pytest.raises(ValueError):
+        split_at_intersections(a, b, float("nan"))
+
+
+def test_bezpath_contains_operator():
+    square = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    assert (5, 5) in square
+    assert Point(15, 5) not in square
+
+
+def test_bezpath_len_and_bool():
+    path = BezPath()
+    assert len(path) == 0
+    assert not path
+    path.move_to((0, 0))
+    assert path and len(path) == 1
+    path.line_to((10, 0))
+    path.close_path()
+    assert len(path) == 3
+    assert len(path) == len(list(path.elements()))
+
+
+def test_bezpath_concatenation():
+    a = BezPath.from_svg("M0,0 L10,0 L10,10 Z")
+    b = BezPath.from_svg("M20,0 L30,0")
+    c = a + b
+    assert c.to_svg() == "M0,0 L10,0 L10,10 Z M20,0 L30,0"
+    assert len(a) == 4
+
+    a += b
+    assert a.to_svg() == c.to_svg()
+    b += b
+    assert b.to_svg() == "M20,0 L30,0 M20,0 L30,0"
+
+    path = BezPath()
+    path.extend(BezPath.from_svg("M0,0 L1,1").elements())
+    assert path.to_svg() == "M0,0 L1,1"
+
+
+def test_bezpath_transform_operators():
+    path = BezPath.from_svg("M0,0 L10,0 L10,10 Z")
+    moved = path * Affine.translate((5, 0))
+    assert moved.to_svg() == "M5,0 L15,0 L15,10 Z"
+    assert path.to_svg() == "M0,0 L10,0 L10,10 Z"
+
+    original = path
+    path *= TranslateScale.scale(2)
+    assert path is original
+    assert path.to_svg() == "M0,0 L20,0 L20,20 Z"
+    path *= Affine.translate((1, 1))
+    assert path.to_svg() == "M1,1 L21,1 L21,21 Z"
+
+
+def test_frozen_bezpath():
+    path = BezPath.from_svg("M0,0 L10,0 L10,10 Z")
+    frozen = path.freeze()
+    path.line_to((20, 20))
+    assert frozen.to_svg() == "M0,0 L10,0 L10,10 Z"
+    assert len(frozen) == 4
+
+    same = FrozenBezPath(BezPath.from_svg("M0,0 L10,0 L10,10 Z"))
+    assert frozen == same
+    assert hash(frozen) == hash(same)
+    assert {frozen: 1}[same] == 1
+    assert frozen != path.freeze()
+
+    assert math.isclose(frozen.area(), 50)
+    assert frozen.contains((8, 2))
+    thawed = frozen.thaw()
+    thawed.close_path()
+    assert frozen.to_svg() == "M0,0 L10,0 L10,10 Z"
+    assert not FrozenBezPath()
+
+
+def test_bezpath_control_points():
+    path = BezPath.from_svg("M0,0 Q5,10 10,0 C15,0 20,5 20,10 Z")
+    points = [(p.x, p.y) for p in path.control_points()]
+    assert points == [(0, 0), (5, 10), (10, 0), (15, 0), (20, 5), (20, 10)]
+    grouped = path.control_points(grouped=True)
+    assert [len(g) for g in grouped] == [1, 2, 3, 0]
+    assert [len(el.points()) for el in path.elements()] == [1, 2, 3, 0]
+
+    polygon = path.control_polygon()
+    assert polygon.to_svg() == "M0,0 L5,10 L10,0 L15,0 L20,5 L20,10 Z"
+
+
+def test_bezpath_hausdorff_distance():
+    a = BezPath.from_svg("M0,0 L10,0")
+    b = BezPath.from_svg("M0,1 L10,1 L10,4")
+    assert math.isclose(a.hausdorff_distance(b), 4, abs_tol=1e-5)
+    assert math.isclose(b.hausdorff_distance(a), 4, abs_tol=1e-5)
+    assert a.hausdorff_distance(a) == 0
+    broken = BezPath()
+    broken.move_to((0, 0))
+    broken.line_to((float("nan"), 0))
+    with pytest.raises(ValueError):
+        a.hausdorff_distance(broken)
+
+    inner = Circle((0, 0), 10).to_path(1e-4)
+    outer = Circle((0, 0), 11).to_path(1e-4)
+    assert math.isclose(inner.hausdorff_distance(outer, 1e-4), 1, abs_tol=1e-3)
+
+
+def test_intersection_area():
+    a = Rect(0, 0, 10, 10).to_path()
+    b = Rect(5, 5, 15, 15).to_path()
+    assert math.isclose(intersection_area(a, b), 25)
+    assert math.isclose(intersection_area(b, a.reverse_subpaths()), 25)
+    assert intersection_area(a, Rect(20, 20, 30, 30).to_path()) == 0
+
+    circle = Circle((0, 0), 10).to_path(0.01)
+    overlap = intersection_area(circle, circle, 0.01)
+    assert math.isclose(overlap, math.pi * 100, rel_tol=1e-2)
+
+
+def test_iou():
+    a = Rect(0, 0, 10, 10)
+    b = Rect(5, 0, 15, 10)
+    assert math.isclose(iou(a, b), 50 / 150)
+    assert math.isclose(iou(a.to_path(), b), 50 / 150)
+    assert math.isclose(iou(a.to_path(), b.to_path()), 50 / 150)
+    assert iou(a, Rect(20, 20, 30, 30)) == 0
+    assert math.isclose(iou(a, a.to_path()), 1)
+    assert iou(BezPath(), BezPath()) == 0
+    with pytest.raises(TypeError):
+        iou(a, Circle((0, 0), 1))
+
+
+def test_bezpath_flatten_with_t():
+    path = BezPath.from_svg("M0,0 L10,0 Q20,0 20,10 Z")
+    flat = path.flatten_with_t(0.01)
+    assert flat[0][1:] == (0, 0.0)
+    assert flat[1][1:] == (0, 1.0)
+    assert flat[-1][1:] == (2, 1.0)
+    assert (flat[-1][0].x, flat[-1][0].y) == (0, 0)
+    segments = list(path.segments())
+    for point, ix, t in flat:
+        expected = segments[ix].eval(t)
+        assert math.isclose(point.x, expected.x) and math.isclose(point.y, expected.y)
+    curve = [t for _, ix, t in flat if ix == 1]
+    assert len(curve) > 4 and curve == sorted(curve)
+
+
+def test_arc_lengths():
+    path = BezPath.from_svg("M0,0 L10,0 L10,20")
+    lengths = ArcLengths(path)
+    assert len(lengths) == 2
+    assert math.isclose(lengths.total, 30)
+    assert lengths.t_at_length(5) == (0, 0.5)
+    ix, t = lengths.t_at_length(20)
+    assert ix == 1 and math.isclose(t, 0.5)
+    p = lengths.point_at_length(25)
+    assert math.isclose(p.x, 10) and math.isclose(p.y, 15)
+    assert math.isclose(lengths.length_at(1, 0.25), 15)
+    assert lengths.t_at_length(-1) == (0, 0.0)
+    assert lengths.t_at_length(100) == (1, 1.0)
+    with pytest.raises(IndexError):
+        lengths.length_at(2, 0.5)
+
+    circle = Circle((0, 0), 10).to_path(1e-3)
+    lengths = ArcLengths(circle)
+    assert math.isclose(lengths.total, circle.perimeter(), rel_tol=1e-6)
+    with pytest.raises(ValueError):
+        ArcLengths(BezPath()).point_at_length(0)
+
+
+def test_bezpath_nearest_many():
+    path = Circle((0, 0), 10).to_path(0.01)
+    path.move_to((20, 0))
+    path.line_to((30, 0))
+    queries = [(0, 0.5), (25, 3), (12, 1), (-4, -4), (50, 50)]
+    for parallel in (False, True):
+        results = path.nearest_many(queries, parallel=parallel)
+        assert len(results) == len(queries)
+        for query, (ix, nearest) in zip(queries, results):
+            expected_ix, expected = path.nearest(query)
+            assert ix == expected_ix
+            assert math.isclose(nearest.distance_sq, expected.distance_sq, rel_tol=1e-9)
+    ix, nearest = path.nearest_many([(25, 3)])[0]
+    assert (nearest.point.x, nearest.point.y) == (25, 0)
+    assert BezPath().nearest_many([(0, 0)]) == [None]
+
+
+def test_bezpath_raycast():
+    square = BezPath.from_polygon([(0, 0), (10, 0), (10, 10), (0, 10)])
+    hit = square.raycast(Ray((5, 5), (2, 0)))
+    assert math.isclose(hit.distance, 5)
+    assert math.isclose(hit.point.x, 10) and math.isclose(hit.point.y, 5)
+    assert hit.segment_index == 1 and math.isclose(hit.segment_t, 0.5)
+    assert square.raycast(Ray((20, 20), (1, 0))) is None
+    assert square.raycast(Ray((5, 5), (0, 1))).segment_index == 2
+
+    hits = square.raycast_all(Ray((-5, 5), (1, 0)))
+    assert [h.segment_index for h in hits] == [3, 1]
+    assert [round(h.distance, 9) for h in hits] == [5, 15]
+
+    # Through opposite corners, each of which joins two segments.
+    hits = square.raycast_all(Ray((-5, -5), (1, 1)))
+    assert len(hits) == 2
+    assert math.isclose(hits[1].distance, 15 * math.sqrt(2))
+
+    circle = Circle((0, 0), 10).to_path(1e-3)
+    hit = circle.raycast(Ray((0, 0), (1, 1)))
+    assert math.isclose(hit.distance, 10, rel_tol=1e-3)
+
+
+def test_find_mirror_axes():
+    triangle = BezPath()
+    triangle.move_to((0, 0))
+    triangle.line_to((10, 0))
+    triangle.line_to((5, 20))
+    triangle.close_path()
+    axes = triangle.find_mirror_axes(0.01)
+    assert len(axes) == 1
+    axis, error = axes[0]
+    assert error < 0.01
+    assert (axis.p0.x, axis.p1.x) == pytest.approx((5, 5))
+    assert sorted((axis.p0.y, axis.p1.y)) == pytest.approx([0, 20])
+
+    rect = Rect(0, 0, 20, 10).to_path(0.1)
+    axes = rect.find_mirror_axes(0.01)
+    assert len(axes) == 2
+    directions = sorted(
+        (abs(axis.p1.x - axis.p0.x) > 1, abs(axis.p1.y - axis.p0.y) > 1)
+        for axis, _ in axes
+    )
+    assert directions == [(False, True), (True, False)]
+
+    skewed = BezPath()
+    skewed.move_to((0, 0))
+    skewed.line_to((10, 0))
+    skewed.line_to((2, 20))
+    skewed.close_path()
+    assert skewed.find_mirror_axes(0.01) == []
+
+
+def test_rotational_symmetry():
+    order, center = Rect(0, 0, 10, 10).to_path(0.1).rotational_symmetry(0.01)
+    assert order == 4
+    assert (center.x, center.y) == pytest.approx((5, 5))
+    order, _ = Rect(0, 0, 20, 10).to_path(0.1).rotational_symmetry(0.01)
+    assert order == 2
+
+    triangle = BezPath()
+    triangle.move_to((0, 0))
+    triangle.line_to((10, 0))
+    triangle.line_to((5, 5 * math.sqrt(3)))
+    triangle.close_path()
+    order, center = triangle.rotational_symmetry(0.01)
+    assert order == 3
+    assert (center.x, center.y) == pytest.approx((5, 5 / math.sqrt(3)))
+
+    triangle = BezPath()
+    triangle.move_to((0, 0))
+    triangle.line_to((10, 0))
+    triangle.line_to((5, 20))
+    triangle.close_path()
+    assert triangle.rotational_symmetry(0.01)[0] == 1
+    with pytest.raises(ValueError):
+        BezPath().rotational_symmetry()
+
+
+def test_canonicalize():
+    a = BezPath()
+    a.move_to((0, 0))
+    a.line_to((10, 0))
+    a.line_to((10, 10))
+    a.line_to((0, 10))
+    a.close_path()
+    a.move_to((20, 0))
+    a.curve_to((25, 5), (25, 5), (30, 0))
+    a.close_path()
+
+    b = BezPath()
+    b.move_to((30, 0))
+    b.curve_to((25, 5), (25, 5), (20, 0))
+    b.line_to((30, 0))
+    b.close_path()
+    b.move_to((10, 10))
+    b.line_to((10, 0))
+    b.line_to((0, 0))
+    b.line_to((0, 10))
+    b.close_path()
+
+    assert a.to_svg() != b.to_svg()
+    assert a.canonicalize().to_svg() == b.canonicalize().to_svg()
+    assert a.canonicalize().freeze() == b.canonicalize().freeze()
+    assert hash(a.canonicalize().freeze()) == hash(b.canonicalize().freeze())
+    assert a.canonicalize().canonicalize().to_svg() == a.canonicalize().to_svg()
+
+
+def test_approx_eq():
+    path = Rect(0, 0, 10, 10).to_path(0.1)
+    moved = path * Affine.translate((1e-12, 0))
+    assert path.approx_eq(moved)
+    assert path.approx_eq(path)
+    assert not path.approx_eq(path * Affine.translate((1, 0)))
+    assert not path.approx_eq(path.reverse_subpaths())
+
+
+def test_subclass_preserved():
+    class Glyph(BezPath):
+        pass
+
+    g = Glyph.from_svg("M0 0L10 0L10 10Z")
+    assert isinstance(g, Glyph)
+    assert isinstance(g.reverse_subpaths(), Glyph)
+    assert isinstance(g * Affine.scale(2), Glyph)
+    assert isinstance(g.round_coordinates(), Glyph)
@@ -0,0 +1,115 @@
+import math
+import pytest
+
+from kurbopy import BezPath, Circle, CircleSegment, Ellipse, Line, Point, min_bounding_ellipse
+
+
+def test_circle_intersect_line():
+    circle = Circle((0, 0), 5)
+    hits = circle.intersect_line(Line((-10, 3), (10, 3)))
+    assert len(hits) == 2
+    (p0, t0, a0), (p1, t1, a1) = hits
+    assert t0 < t1
+    assert math.isclose(p0.x, -4) and math.isclose(p0.y, 3)
+    assert math.isclose(p1.x, 4) and math.isclose(p1.y, 3)
+    assert math.isclose(t0, 0.3) and math.isclose(t1, 0.7)
+    assert math.isclose(a1, math.atan2(3, 4))
+    # Only points within the segment count.
+    assert len(circle.intersect_line(Line((0, 3), (10, 3)))) == 1
+    assert circle.intersect_line(Line((-10, 6), (10, 6))) == []
+    tangent = circle.intersect_line(Line((-10, 5), (10, 5)))
+    assert len(tangent) == 1
+    assert math.isclose(tangent[0][0].x, 0, abs_tol=1e-9)
+
+
+def test_circle_intersect_circle():
+    circle = Circle((0, 0), 5)
+    kind, points = circle.intersect_circle(Circle((8, 0), 5))
+    assert kind == "intersecting"
+    assert sorted((round(p.x, 9), round(p.y, 9)) for p in points) == [(4, -3), (4, 3)]
+    kind, points = circle.intersect_circle(Circle((10, 0), 5))
+    assert kind == "tangent"
+    assert (points[0].x, points[0].y) == (5, 0)
+    kind, points = Circle((2, 0), 3).intersect_circle(circle)
+    assert kind == "tangent"
+    assert (points[0].x, points[0].y) == (5, 0)
+    assert circle.intersect_circle(Circle((20, 0), 5)) == ("disjoint", [])
+    assert circle.intersect_circle(Circle((1, 0), 1)) == ("contained", [])
+    assert circle.intersect_circle(Circle((0, 0), 1)) == ("contained", [])
+    assert circle.intersect_circle(Circle((0, 0), 5)) == ("coincident", [])
+
+
+def test_ellipse_intersect_line():
+    ellipse = Ellipse((10, 0), (4, 2), 0.0)
+    hits = ellipse.intersect_line(Line((0, 0), (20, 0)))
+    assert [round(t, 9) for _, t, _ in hits] == [0.3, 0.7]
+    assert math.isclose(hits[0][0].x, 6) and math.isclose(hits[1][0].x, 14)
+    assert math.isclose(abs(hits[0][2]), math.pi)
+    assert math.isclose(hits[1][2], 0, abs_tol=1e-9)
+    # A rotated ellipse, crossed along its major axis.
+    rotated = Ellipse((0, 0), (4, 2), math.pi / 2)
+    hits = rotated.intersect_line(Line((0, -10), (0, 10)))
+    assert [round(p.y, 9) for p, _, _ in hits] == [-4, 4]
+    assert rotated.intersect_line(Line((3, -10), (3, 10))) == []
+
+
+def test_tangent_lines():
+    circle = Circle((0, 0), 5)
+    tangents = circle.tangent_lines((10, 0))
+    assert len(tangents) == 2
+    for line, point in tangents:
+        assert (line.p0.x, line.p0.y) == (10, 0)
+        assert math.isclose(point.x, 2.5)
+        assert math.isclose(abs(point.y), 5 * math.sqrt(3) / 2)
+        # The radius is perpendicular to the tangent.
+        radius = point.to_vec2()
+        direction = line.p1 - line.p0
+        assert math.isclose(radius.dot(direction), 0, abs_tol=1e-9)
+    assert circle.tangent_lines((1, 1)) == []
+    assert len(circle.tangent_lines((5, 0))) == 1
+    ellipse = Ellipse((0, 0), (4, 2), 0.0)
+    tangents = ellipse.tangent_lines((0, 4))
+    points = sorted((round(p.x, 9), round(p.y, 9)) for _, p in tangents)
+    # From (0, 4), the tangents touch where y = ry^2 / 4 = 1.
+    assert points == [(round(-2 * math.sqrt(3), 9), 1), (round(2 * math.sqrt(3), 9), 1)]
+
+
+def test_contains_operator():
+    circle = Circle((0, 0), 10)
+    assert (3, 4) in circle
+    assert Point(8, 8) not in circle
+    ellipse = Ellipse((0, 0), (10, 2), 0.0)
+    assert (9, 0) in ellipse and (0, 3) not in ellipse
+    ring = CircleSegment((0, 0), 10, 5, 0.0, 2 * math.pi)
+    assert (7, 0) in ring
+    assert (1, 0) not in ring
+
+
+def test_min_bounding_ellipse():
+    corners = [(0, 0), (20, 0), (20, 10), (0, 10)]
+    ellipse = min_bounding_ellipse(corners)
+    assert math.isclose(ellipse.center.x, 10) and math.isclose(ellipse.center.y, 5)
+    assert math.isclose(ellipse.radii.x, 10 * math.sqrt(2))
+    assert math.isclose(ellipse.radii.y, 5 * math.sqrt(2))
+
+    # The smallest ellipse around a triangle is its Steiner circumellipse.
+    ellipse = min_bounding_ellipse([(0, 0), (10, 0), (0, 10), (2, 2)])
+    area = math.pi * ellipse.radii.x * ellipse.radii.y
+    assert math.isclose(area, 4 * math.pi / (3 * math.sqrt(3)) * 50, rel_tol=1e-6)
+
+    points = [(i * 7 % 13, i * i % 11) for i in range(40)]
+    ellipse = min_bounding_ellipse(points)
+    c, s = math.cos(ellipse.rotation), math.sin(ellipse.rotation)
+    for x, y in points:
+        dx, dy = x - ellipse.center.x, y - ellipse.center.y
+        u, v = dx * c + dy * s, dy * c - dx * s
+        assert (u / ellipse.radii.x) ** 2 + (v / ellipse.radii.y) ** 2 <= 1 + 1e-9
+
+    line = min_bounding_ellipse([(0, 0), (5, 5), (10, 10)])
+    assert math.isclose(line.radii.x, math.hypot(5, 5))
+    assert math.isclose(line.radii.y, 0, abs_tol=1e-6)
+
+    path = BezPath.from_polygon(corners)
+    assert math.isclose(path.bounding_ellipse().radii.x, 10 * math.sqrt(2))
+    with pytest.raises(ValueError):
+        path.bounding_ellipse(0)
@@ -1,5 +1,6 @@
-from kurbopy import Point, CubicBez
+from kurbopy import Arc, BezPath, Line, Nearest, Point, CubicBez, PathSeg, QuadBez
 import math
+import pytest
 
 
 def test_cubicbez_deriv():
@@ -164,3 +165,148 @@ def test_cubicbez_extrema():
 #                 err = (p.y - p.x.powi(3)).abs();
 #                 worst = worst.max(err);
 #                 assert!(err < accuracy, "got { wanted {", err, accuracy);
+
+
+def test_cubicbez_default_accuracy():
+    c = CubicBez(
+        Point(0.0, 0.0),
+        Point(1.0 / 3.0, 0.0),
+        Point(2.0 / 3.0, 1.0 / 3.0),
+        Point(1.0, 1.0),
+    )
+    true_arclen = 0.5 * math.sqrt(5.0) + 0.25 * math.log(2.0 + math.sqrt(5.0))
+    assert abs(c.arclen() - true_arclen) < 1e-6
+    assert abs(c.nearest(Point(1.0, 1.0)).get_t() - 1.0) < 1e-6
+    assert len(c.to_quads()) >= 1
+
+
+def test_cubicbez_return_error():
+    c = CubicBez((0, 0), (30, 100), (70, 100), (100, 0))
+    length, error = c.arclen(1e-3, return_error=True)
+    assert math.isclose(length, c.arclen(1e-3))
+    assert error <= 1e-3
+    quads, error = c.to_quads(0.1, return_error=True)
+    assert len(quads) == len(c.to_quads(0.1))
+    assert error <= 0.1
+    nearest, error = c.nearest((50, 50), return_error=True)
+    assert error < 1e-6
+
+
+def test_cubicbez_try_approx_quad():
+    # An exact degree elevation of a quadratic
+    c = CubicBez((0, 0), (20, 40), (40, 40), (60, 0))
+    q = c.try_approx_quad(1e-9)
+    assert (q.p1.x, q.p1.y) == (30, 60)
+    s_curve = CubicBez((0, 0), (0, 100), (100, -100), (100, 0))
+    assert s_curve.try_approx_quad(0.1) is None
+
+
+def test_is_linear_and_flatness():
+    straight = CubicBez((0, 0), (3, 0.01), (7, -0.02), (10, 0))
+    assert math.isclose(straight.flatness(), 0.02)
+    assert straight.is_linear(0.05)
+    assert not straight.is_linear(0.01)
+    # Control points beyond the ends make the curve double back.
+    overshoot = CubicBez((0, 0), (-5, 0), (15, 0), (10, 0))
+    assert math.isclose(overshoot.flatness(), 5)
+    assert not overshoot.is_linear()
+
+    assert QuadBez((0, 0), (5, 0), (10, 0)).is_linear()
+    arch = QuadBez((0, 0), (5, 4), (10, 0))
+    assert math.isclose(arch.flatness(), 4)
+    assert not arch.is_linear(1)
+
+
+def test_split_at_extrema():
+    arch = CubicBez((0, 0), (0, 10), (10, 10), (10, 0))
+    pieces = arch.split_at_extrema()
+    assert len(pieces) == 2
+    assert math.isclose(pieces[0].p3.x, 5) and math.isclose(pieces[0].p3.y, 7.5)
+    end = pieces[1].p3
+    assert math.isclose(end.x, 10) and math.isclose(end.y, 0, abs_tol=1e-9)
+    # Each piece rises or falls, but not both.
+    first, second = [[p.y for p in (c.p0, c.p1, c.p2, c.p3)] for c in pieces]
+    assert all(a <= b + 1e-9 for a, b in zip(first, first[1:]))
+    assert all(a >= b - 1e-9 for a, b in zip(second, second[1:]))
+
+    quad = QuadBez((0, 0), (5, 10), (10, 0))
+    first, second = quad.split_at_extrema()
+    assert math.isclose(first.p2.y, 5) and math.isclose(second.p0.x, 5)
+    assert len(QuadBez((0, 0), (5, 5), (10, 10)).split_at_extrema()) == 1
+
+
+def test_curvature_extrema():
+    arch = CubicBez((0, 0), (0, 10), (10, 10), (10, 0))
+    (t,) = arch.curvature_extrema()
+    assert math.isclose(t, 0.5, abs_tol=1e-6)
+    # A cusp has infinite curvature.
+    cusp = CubicBez((0, 0), (1, 1), (0, 1), (1, 0))
+    (t,) = cusp.curvature_extrema()
+    assert math.isclose(t, 0.5, abs_tol=1e-6)
+    assert CubicBez((0, 0), (1, 0), (2, 0), (3, 0)).curvature_extrema() == []
+
+    assert QuadBez((0, 0), (5, 10), (10, 0)).curvature_extrema() == [0.5]
+    # This curve bends most tightly at its start.
+    assert QuadBez((0, 0), (10, 0), (20, 5)).curvature_extrema() == []
+    assert PathSeg(arch).curvature_extrema() == arch.curvature_extrema()
+
+
+def _ends(piece):
+    if isinstance(piece, Line):
+        return piece.p0, piece.p1
+    c, r = piece.center, piece.radii.x
+    a0, a1 = piece.start_angle, piece.start_angle + piece.sweep_angle
+    return (
+        Point(c.x + r * math.cos(a0), c.y + r * math.sin(a0)),
+        Point(c.x + r * math.cos(a1), c.y + r * math.sin(a1)),
+    )
+
+
+def test_to_biarcs():
+    k = 0.5522847498
+    quarter = CubicBez((10, 0), (10, 10 * k), (10 * k, 10), (0, 10))
+    arcs = quarter.to_biarcs(0.01)
+    assert arcs and all(isinstance(a, Arc) for a in arcs)
+    for arc in arcs:
+        assert math.isclose(arc.radii.x, 10, rel_tol=1e-2)
+        assert arc.sweep_angle > 0
+    ends = [_ends(a) for a in arcs]
+    assert (ends[0][0] - Point(10, 0)).hypot() < 1e-9
+    assert (ends[-1][1] - Point(0, 10)).hypot() < 1e-9
+    for (_, end), (start, _) in zip(ends, ends[1:]):
+        assert (end - start).hypot() < 1e-9
+
+    # An S-curve needs arcs bending both ways.
+    s_curve = CubicBez((0, 0), (10, 10), (0, 10), (10, 20))
+    sweeps = [a.sweep_angle for a in s_curve.to_biarcs(0.01) if isinstance(a, Arc)]
+    assert any(s > 0 for s in sweeps) and any(s < 0 for s in sweeps)
+
+    assert [type(p) for p in CubicBez((0, 0), (1, 0), (2, 0), (3, 0)).to_biarcs()] == [Line]
+
+
+def test_bezpath_to_arcs():
+    path = BezPath.from_svg("M0,0 L10,0 Q20,0 20,10 Z M30,0 L40,0")
+    first, second = path.to_arcs(0.01)
+    assert isinstance(first[0], Line) and isinstance(first[-1], Line)
+    assert any(isinstance(p, Arc) for p in first)
+    assert (_ends(first[-1])[1] - Point(0, 0)).hypot() < 1e-9
+    assert [type(p) for p in second] == [Line]
+    for tolerance in (0, float("nan")):
+        with pytest.raises(ValueError):
+            path.to_arcs(tolerance)
+        with pytest.raises(ValueError):
+            CubicBez((0, 0), (1, 2), (3, 2), (4, 0)).to_biarcs(tolerance)
+
+
+def test_nearest_point():
+    c = CubicBez((0, 0), (1, 2), (3, 2), (4, 0))
+    n = c.nearest((2, 3))
+    assert math.isclose(n.t, 0.5, abs_tol=1e-6)
+    assert math.isclose(n.point.x, 2, abs_tol=1e-6) and math.isclose(n.point.y, 1.5)
+    assert math.isclose(n.distance, 1.5)
+    assert repr(n).startswith("Nearest(") and "Point(" in repr(n)
+    # The accessor methods from before the properties still work
+    with pytest.warns(DeprecationWarning):
+        assert n.get_t() == n.t
+    bare = Nearest(2.25, 0.5)
+    assert bare.point is None and bare.distance == 1.5
@@ -58,3 +58,18 @@ def test_rect_rect():
     assert insets2.y1 == insets.y1
     assert insets2.x_value() == insets.x_value()
     assert insets2.y_value() == insets.y_value()
+
+
+def test_insets_arithmetic():
+    a = Insets(1.0, 2.0, 3.0, 4.0)
+    b = Insets.uniform(1.0)
+    total = a + b
+    assert (total.x0, total.y0, total.x1, total.y1) == (2.0, 3.0, 4.0, 5.0)
+    diff = a - b
+    assert (diff.x0, diff.y1) == (0.0, 3.0)
+    scaled = 2 * a
+    assert (scaled.x0, scaled.y1) == (2.0, 8.0)
+    size = a + Size(10.0, 10.0)
+    assert (size.width, size.height) == (14.0, 16.0)
+    assert repr(a * 0.5) == "Insets(0.5, 1.0, 1.5, 2.0)"
+    assert repr(a / 2) == "Insets(0.5, 1.0, 1.5, 2.0)"
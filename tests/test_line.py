@@ -0,0 +1,67 @@
+from kurbopy import Line, Line2, PathSeg, QuadBez, Rect
+import pytest
+
+
+def test_line_constructors():
+    line = Line((0, 0), (10, 0))
+    offset = line.offset(2)
+    assert (offset.p0.y, offset.p1.y) == (2, 2)
+    perp = line.perpendicular_at(0.5, 3)
+    assert (perp.p0.x, perp.p0.y, perp.p1.x, perp.p1.y) == (5, 0, 5, 3)
+    longer = line.extend(1)
+    assert (longer.p0.x, longer.p1.x) == (-1, 11)
+    with pytest.raises(ValueError):
+        Line((1, 1), (1, 1)).offset(1)
+
+
+def test_min_dist_points():
+    line = PathSeg(Line((0, 0), (10, 0)))
+    arch = PathSeg(QuadBez((0, 5), (5, 1), (10, 5)))
+    d = line.min_dist(arch)
+    assert abs(d.distance - 3) < 1e-6
+    assert abs(d.p1.x - 5) < 0.05 and abs(d.p1.y) < 1e-9
+    assert abs(d.p2.x - 5) < 0.05 and abs(d.p2.y - 3) < 1e-3
+
+
+def test_line2():
+    axis = Line2.from_points((0, 0), (1, 1))
+    assert axis.side_of((0, 5)) == 1
+    assert axis.side_of((5, 0)) == -1
+    assert axis.side_of((3, 3)) == 0
+    assert axis.signed_distance((0, 2)) == pytest.approx(2 ** 0.5)
+    foot = axis.project((0, 2))
+    assert (foot.x, foot.y) == pytest.approx((1, 1))
+    hits = axis.intersect(Rect(2, 0, 4, 10).to_path())
+    assert [c for p in hits for c in p] == pytest.approx([2, 2, 4, 4])
+    crossing = axis.intersect(Line2((0, 4), (1, -1)))
+    assert [c for p in crossing for c in p] == pytest.approx([2, 2])
+    assert axis.intersect(Line2((0, 1), (2, 2))) == []
+    far = Line2.from_line(Line((0, 0), (1, 0))).intersect(Line((100, -1), (100, 1)))
+    assert [c for p in far for c in p] == pytest.approx([100, 0])
+    with pytest.raises(ValueError):
+        Line2((0, 0), (0, 0))
+
+
+def test_project():
+    line = Line((0, 0), (10, 0))
+    t, foot, distance = line.project((5, 3))
+    assert (t, foot.x, foot.y, distance) == (0.5, 5, 0, 3)
+    t, foot, distance = line.project((15, 0))
+    assert (t, foot.x, distance) == (1, 10, 5)
+    t, foot, distance = line.project((15, 0), clamp=False)
+    assert (t, foot.x, distance) == (1.5, 15, 0)
+    arch = QuadBez((0, 0), (5, 10), (10, 0))
+    t, foot, distance = arch.project((5, 10))
+    assert t == pytest.approx(0.5)
+    assert (foot.x, foot.y) == pytest.approx((5, 5))
+    assert distance == pytest.approx(5)
+    t, foot, distance = PathSeg(arch).project((5, 10))
+    assert distance == pytest.approx(5)
+
+
+def test_approx_eq():
+    assert Line((0, 0), (1, 1)).approx_eq(Line((0, 1e-12), (1, 1)))
+    assert not Line((0, 0), (1, 1)).approx_eq(Line((1, 1), (0, 0)))
+    arch = QuadBez((0, 0), (5, 10), (10, 0))
+    assert arch.approx_eq(QuadBez((0, 0), (5, 10 + 1e-12), (10, 0)))
+    assert not PathSeg(arch).approx_eq(PathSeg(Line((0, 0), (10, 0))))
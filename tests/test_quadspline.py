@@ -0,0 +1,38 @@
+from kurbopy import CubicBez, Point, QuadSpline, cubics_to_quadratic_splines
+
+
+def test_cubics_to_quadratic_splines():
+    curves = [
+        CubicBez(Point(0, 0), Point(10, 20), Point(30, 20), Point(40, 0)),
+        CubicBez(Point(0, 0), Point(20, 40), Point(60, 40), Point(80, 0)),
+    ]
+    result = cubics_to_quadratic_splines(curves, 0.1)
+    assert result.is_complete()
+    assert result.compatible
+    assert result.succeeded() == [0, 1]
+    assert len(result.splines[0].points()) == len(result.splines[1].points())
+
+
+def test_cubics_to_quadratic_splines_partial_failure():
+    nan = float("nan")
+    curves = [
+        CubicBez(Point(0, 0), Point(10, 20), Point(30, 20), Point(40, 0)),
+        CubicBez(Point(0, 0), Point(nan, 40), Point(60, 40), Point(80, 0)),
+    ]
+    result = cubics_to_quadratic_splines(curves, 0.1)
+    assert not result.is_complete()
+    assert [s is None for s in result.splines] == [False, True]
+    assert result.succeeded() == [0]
+    assert result.failures == [(1, "curve has non-finite coordinates")]
+
+
+def test_quadspline_json():
+    spline = QuadSpline([(0, 0), (10, 20), (30, 20), (40, 0)])
+    assert spline.to_dict() == [
+        {"x": 0.0, "y": 0.0},
+        {"x": 10.0, "y": 20.0},
+        {"x": 30.0, "y": 20.0},
+        {"x": 40.0, "y": 0.0},
+    ]
+    back = QuadSpline.from_json(spline.to_json())
+    assert [(p.x, p.y) for p in back.points()] == [(0, 0), (10, 20), (30, 20), (40, 0)]
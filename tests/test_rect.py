@@ -0,0 +1,75 @@
+from kurbopy import BezPath, Circle, CubicBez, Line, PathEl, Point, Rect, union_bounding_box
+import math
+
+import pytest
+
+
+def test_rect_path_elements():
+    rect = Rect(0, 0, 10, 10)
+    els = list(rect.path_elements(0.1))
+    assert len(els) == 5
+    assert all(isinstance(el, PathEl) for el in els)
+    assert els[0].end_point().x == 0
+
+
+def test_circle_path_elements():
+    circle = Circle(Point(0, 0), 10)
+    it = circle.path_elements(0.1)
+    first = next(it)
+    assert first.end_point().x == 10
+
+
+def test_rect_from_tuples():
+    r = Rect.from_points((0, 0), (5, 5))
+    assert r.area() == 25
+    assert r.union_pt((10, 5)).width() == 10
+
+
+def test_rect_to_rounded_rect():
+    rect = Rect(0, 0, 100, 50)
+    rr = rect.to_rounded_rect(10)
+    assert rr.radii().as_single_radius() == 10
+    assert rr.area() < rect.area()
+    rr = rect.to_rounded_rect((1, 2, 3, 4))
+    assert rr.radii().bottom_left == 4
+    assert rr.rect().width() == 100
+
+
+def test_rect_predicates():
+    outer = Rect(0, 0, 10, 10)
+    assert outer.contains_rect(Rect(2, 2, 10, 5))
+    assert not outer.contains_rect(Rect(2, 2, 11, 5))
+    # Touching edges and degenerate rectangles still overlap
+    assert outer.overlaps(Rect(10, 0, 20, 10))
+    assert outer.overlaps(Rect(5, 5, 5, 20))
+    assert not outer.overlaps(Rect(11, 0, 20, 10))
+    assert Point(10, 10) in outer
+    assert (5, 5) in outer
+    assert Rect(1, 1, 2, 2) in outer
+    assert (11, 5) not in outer
+
+
+def test_rect_scalar_arithmetic():
+    rect = Rect(1, 2, 3, 4)
+    assert repr(rect * 2) == "Rect(2.0, 4.0, 6.0, 8.0)"
+    assert repr(2 * rect) == "Rect(2.0, 4.0, 6.0, 8.0)"
+    assert repr(rect / 2) == "Rect(0.5, 1.0, 1.5, 2.0)"
+
+
+def test_union_bounding_box():
+    shapes = [
+        Rect(0, 0, 10, 10),
+        Circle((20, 5), 2),
+        Line((-5, 3), (0, 4)),
+        CubicBez((0, 0), (0, 20), (10, 20), (10, 0)),
+        BezPath.from_svg("M0,0 L3,-7"),
+        Point(1, 1),
+    ]
+    bbox = union_bounding_box(shapes)
+    assert (bbox.x0, bbox.y0, bbox.x1) == (-5, -7, 22)
+    assert math.isclose(bbox.max_y(), 15)
+    assert union_bounding_box(iter([Point(1, 2)])).area() == 0
+    with pytest.raises(ValueError):
+        union_bounding_box([])
+    with pytest.raises(TypeError):
+        union_bounding_box([Rect(0, 0, 1, 1), "not a shape"])
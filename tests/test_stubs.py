@@ -0,0 +1,67 @@
+import ast
+import os
+import subprocess
+import sys
+
+import kurbopy.kurbopy
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+STUB = os.path.join(ROOT, "kurbopy", "kurbopy.pyi")
+
+
+def test_stubs_up_to_date():
+    result = subprocess.run(
+        [sys.executable, os.path.join(ROOT, "tools", "generate_stubs.py"), "--check"],
+        stdout=subprocess.PIPE,
+        stderr=subprocess.PIPE,
+        universal_newlines=True,
+    )
+    assert result.returncode == 0, result.stderr
+
+
+def test_stubs_cover_extension():
+    with open(STUB, encoding="utf-8") as fh:
+        tree = ast.parse(fh.read())
+    def member_name(item):
+        if isinstance(item, ast.FunctionDef):
+            return item.name
+        if isinstance(item, ast.AnnAssign):
+            return item.target.id
+
+    classes = {
+        node.name: {member_name(item) for item in node.body} - {None}
+        for node in tree.body
+        if isinstance(node, ast.ClassDef)
+    }
+    for name, members in classes.items():
+        cls = getattr(kurbopy.kurbopy, name, None)
+        if cls is None:
+            continue
+        for member in members:
+            assert hasattr(cls, member), "%s.%s" % (name, member)
+        public = {
+            attr for attr in vars(cls) if not attr.startswith("_") and attr != "raise"
+        }
+        assert public <= members, "%s: %s" % (name, sorted(public - members))
+
+
+def test_stubs_annotated():
+    with open(STUB, encoding="utf-8") as fh:
+        source = fh.read()
+    tree = ast.parse(source)
+    aliases = {node.targets[0].id for node in tree.body if isinstance(node, ast.Assign)}
+    assert {"PointLike", "Vec2Like"} <= aliases
+    for node in ast.walk(tree):
+        if not isinstance(node, ast.FunctionDef):
+            continue
+        assert node.returns is not None, node.name
+        args = node.args.args + node.args.kwonlyargs
+        args += [a for a in (node.args.vararg, node.args.kwarg) if a is not None]
+        for arg in args:
+            if arg.arg not in ("self", "cls"):
+                assert arg.annotation is not None, "%s(%s)" % (node.name, arg.arg)
+
+    assert "def perimeter(self, accuracy: float = ...) -> float:" in source
+    assert "def distance(self, other: PointLike) -> float:" in source
+    assert "def __sub__(self, rhs: Point) -> Vec2: ..." in source
+    assert "def x(self, x: float) -> None: ..." in source
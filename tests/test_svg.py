@@ -0,0 +1,59 @@
+import io
+
+from kurbopy import Affine, BezPath, Rect, svg
+
+
+def test_svg_write():
+    square = BezPath.from_svg("M0,0 L10,0 L10,10 L0,10 Z")
+    line = BezPath.from_svg("M5,-5 L20,5")
+    out = io.StringIO()
+    svg.write(out, [square, line], attributes=[None, {"stroke": "red", "stroke-width": 0.5}])
+    assert out.getvalue() == (
+        '<svg xmlns="http://www.w3.org/2000/svg" width="20" height="15" viewBox="0 -5 20 15">\n'
+        '  <path d="M0,0 L10,0 L10,10 L0,10 Z"/>\n'
+        '  <path d="M5,-5 L20,5" stroke="red" stroke-width="0.5"/>\n'
+        "</svg>\n"
+    )
+    out = io.StringIO()
+    svg.write(out, [square], size=(100, 50), attributes={"fill": "none"})
+    assert 'viewBox="0 0 100 50"' in out.getvalue()
+    assert 'fill="none"' in out.getvalue()
+
+
+def test_svg_write_file(tmp_path):
+    filename = tmp_path / "square.svg"
+    svg.write(filename, [Rect(0, 0, 10, 10).to_path(0.1)], size=Rect(-5, -5, 15, 15))
+    assert 'viewBox="-5 -5 20 20"' in filename.read_text()
+
+
+def test_svg_read():
+    document = io.StringIO(
+        """<svg xmlns="http://www.w3.org/2000/svg">
+  <defs><path d="M0,0 L1,1"/></defs>
+  <g transform="translate(10, 20) scale(2)">
+    <rect x="1" y="2" width="3" height="4"/>
+    <rect width="10" height="10" rx="2"/>
+    <circle cx="5" cy="5" r="5" transform="translate(1)"/>
+  </g>
+  <ellipse cx="0" cy="0" rx="4" ry="2"/>
+  <line x1="0" y1="0" x2="10px" y2="5"/>
+  <polygon points="0,0 10,0 10,10"/>
+  <polyline points="0 0, 5 5 -5,10"/>
+  <path d="M0,0 L10,0 L10,10 Z" transform="matrix(1 0 0 -1 0 100)"/>
+</svg>"""
+    )
+    shapes = svg.read(document)
+    kinds = [type(shape).__name__ for shape, _ in shapes]
+    assert kinds == [
+        "Rect", "RoundedRect", "Circle", "Ellipse", "Line", "BezPath", "BezPath", "BezPath",
+    ]
+    group = Affine.translate((10, 20)) * Affine.scale(2)
+    rect, transform = shapes[0]
+    assert (rect.min_x(), rect.min_y(), rect.max_x(), rect.max_y()) == (1, 2, 4, 6)
+    assert transform == group
+    assert shapes[2][1] == group * Affine.translate((1, 0))
+    assert shapes[3][1] == Affine.IDENTITY()
+    assert shapes[4][0].p1.x == 10
+    assert shapes[5][0].to_svg() == "M0,0 L10,0 L10,10 Z"
+    assert shapes[6][0].to_svg() == "M0,0 L5,5 L-5,10"
+    assert shapes[7][1] == Affine(1, 0, 0, -1, 0, 100)
@@ -0,0 +1,25 @@
+import pytest
+
+from kurbopy import BezPath, Point, Rect, sweep
+
+
+def test_sweep_straight():
+    profile = Rect(-1, -1, 1, 1).to_path(0.1)
+    spine = BezPath()
+    spine.move_to(Point(0, 0))
+    spine.line_to(Point(10, 0))
+    swept = sweep(profile, spine, 0.01)
+    bbox = swept.bounding_box()
+    assert abs(bbox.x0 + 1) < 1e-9 and abs(bbox.x1 - 11) < 1e-9
+    assert abs(bbox.y0 + 1) < 1e-9 and abs(bbox.max_y() - 1) < 1e-9
+    assert abs(abs(swept.area()) - 24) < 1e-9
+
+
+def test_sweep_accuracy():
+    profile = Rect(-1, -1, 1, 1).to_path(0.1)
+    spine = BezPath()
+    spine.move_to(Point(0, 0))
+    spine.line_to(Point(10, 0))
+    for tolerance in (0, -1, float("nan"), float("inf")):
+        with pytest.raises(ValueError):
+            sweep(profile, spine, tolerance)
@@ -0,0 +1,1058 @@
+#!/usr/bin/env python3
+"""Generate the type stubs for kurbopy.
+
+The stubs describe the built extension module: this script imports
+``kurbopy.kurbopy`` and walks its classes, functions and the ``common``
+submodule, taking each parameter list from ``__text_signature__`` and each
+docstring from ``__doc__``, so that nothing is declared which was not built.
+pyo3 records no types there, so the annotations come from the Rust sources:
+the parameter and return types of each ``#[pymethods]`` method and
+``#[pyfunction]`` in ``src/*.rs``, the getters and setters of each property,
+and the overloads of the operators declared with ``polymorphic!``. Anything
+the Rust sources do not describe is annotated as ``Any``.
+
+Build and install kurbopy (for instance with ``maturin develop``), then run
+it from the root of the repository after changing the Python API::
+
+    python3 tools/generate_stubs.py
+
+With ``--check``, the stubs are not written, and the script exits with an
+error if they are out of date.
+"""
+import argparse
+import inspect
+import keyword
+import os
+import re
+import sys
+import types
+
+import kurbopy.kurbopy
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+SRC = os.path.join(ROOT, "src")
+OUTPUTS = {
+    "kurbopy": os.path.join(ROOT, "kurbopy", "kurbopy.pyi"),
+    "common": os.path.join(ROOT, "kurbopy", "common", "__init__.pyi"),
+}
+
+HEADER = """\
+# This file is generated from the built extension module and the Rust
+# sources by tools/generate_stubs.py. Do not edit it by hand: rebuild
+# kurbopy and run the script again.
+"""
+
+# Argument types which accept several kinds of Python value.
+ALIASES = {
+    "PointLike": "Union[Point, Tuple[float, float]]",
+    "Vec2Like": "Union[Vec2, Tuple[float, float]]",
+    "AffineLike": "Union[Affine, TranslateScale]",
+    "RadiiLike": (
+        "Union[RoundedRectRadii, float, Tuple[float, float, float, float]]"
+    ),
+    # Also accepts a NumPy array of shape (n, 2).
+    "PointArray": "Union[Sequence[PointLike], Any]",
+    "Region": "Union[BezPath, Rect]",
+}
+
+# Types which are converted to Python by hand, rather than being classes.
+INLINE = {
+    "Piece": "Union[Arc, Line]",
+}
+
+# Signatures of the methods added to classes by kurbopy/__init__.py.
+PYTHON_EXTRAS = {
+    ("BezPath", "from_drawable"): (
+        ["drawable: Any", "*penArgs: Any", "**penKwargs: Any"],
+        "List[BezPath]",
+    ),
+    ("BezPath", "to_matplot"): (["self"], "Any"),
+    ("BezPath", "plot"): (["self", "ax: Any", "**kwargs: Any"], "None"),
+}
+
+SCALARS = {
+    "f64": "float",
+    "f32": "float",
+    "bool": "bool",
+    "String": "str",
+    "str": "str",
+    "char": "str",
+    "PyObject": "Any",
+    "PyAny": "Any",
+    "PyList": "list",
+    "PyTuple": "tuple",
+    "PyDict": "dict",
+    "PyBytes": "bytes",
+    "PyString": "str",
+    "PyType": "type",
+    "PyIterator": "Iterator[Any]",
+}
+INTS = {"usize", "isize", "u8", "u16", "u32", "u64", "i8", "i16", "i32", "i64"}
+WRAPPERS = {"PyResult", "PyRef", "PyRefMut", "Py", "Bound", "Box", "Cow"}
+LISTS = {"Vec", "VecDeque"}
+
+COMPARISONS = ["__eq__", "__ne__", "__lt__", "__le__", "__gt__", "__ge__"]
+OPERATORS = {"add": "__add__", "sub": "__sub__", "mul": "__mul__"}
+
+# Members which every class has, or which the stubs express another way.
+SKIPPED = {"__doc__", "__module__", "__new__", "__dict__", "__weakref__"}
+
+
+# --- Tokenizing ------------------------------------------------------------
+
+TOKEN_RE = re.compile(
+    r"""
+    (?P<space>\s+)
+  | (?P<doc>///(?!/)[^\n]*)
+  | (?P<comment>//[^\n]*|/\*.*?\*/)
+  | (?P<str>b?"(?:\\.|[^"\\])*")
+  | (?P<rawstr>r\#*")
+  | (?P<char>'(?:\\.|[^'\\])')
+  | (?P<lifetime>'[A-Za-z_]\w*)
+  | (?P<num>\d[\w.]*(?:[eE][-+]?\d+)?)
+  | (?P<ident>[A-Za-z_]\w*)
+  | (?P<punct>->|=>|::|\S)
+    """,
+    re.VERBOSE | re.DOTALL,
+)
+
+
+class Token:
+    __slots__ = ("kind", "text")
+
+    def __init__(self, kind, text):
+        self.kind = kind
+        self.text = text
+
+    def __repr__(self):
+        return "%s(%r)" % (self.kind, self.text)
+
+
+def tokenize(source):
+    tokens = []
+    pos = 0
+    while pos < len(source):
+        m = TOKEN_RE.match(source, pos)
+        kind = m.lastgroup
+        if kind == "rawstr":
+            hashes = m.group()[1:-1]
+            end = source.index('"' + hashes, m.end()) + 1 + len(hashes)
+            tokens.append(Token("str", source[pos:end]))
+            pos = end
+            continue
+        pos = m.end()
+        if kind in ("space", "comment"):
+            continue
+        text = m.group()
+        if kind == "doc":
+            text = text[3:]
+            if text.startswith(" "):
+                text = text[1:]
+        tokens.append(Token(kind, text))
+    return tokens
+
+
+CLOSERS = {"(": ")", "[": "]", "{": "}"}
+
+
+def matching(tokens, i):
+    """The index of the bracket closing the one at ``tokens[i]``."""
+    depth = 0
+    for j in range(i, len(tokens)):
+        t = tokens[j]
+        if t.kind != "punct":
+            continue
+        if t.text in CLOSERS:
+            depth += 1
+        elif t.text in (")", "]", "}"):
+            depth -= 1
+            if depth == 0:
+                return j
+    raise ValueError("unbalanced brackets")
+
+
+def split_commas(tokens):
+    """Split tokens at the commas which are not inside brackets."""
+    parts, current, depth = [], [], 0
+    for t in tokens:
+        if t.kind == "punct":
+            if t.text in ("(", "[", "{", "<"):
+                depth += 1
+            elif t.text in (")", "]", "}", ">"):
+                depth -= 1
+            elif t.text == "," and depth == 0:
+                parts.append(current)
+                current = []
+                continue
+        current.append(t)
+    if current:
+        parts.append(current)
+    return parts
+
+
+def is_punct(t, text):
+    return t.kind == "punct" and t.text == text
+
+
+def is_ident(t, text):
+    return t.kind == "ident" and t.text == text
+
+
+# --- Types -------------------------------------------------------------------
+
+
+def parse_type(tokens, i=0):
+    """Parse a Rust type starting at ``tokens[i]``. Returns a tree of
+    ``(name, [arguments])`` tuples, where tuples are named ``()`` and slices
+    and arrays ``[]``, and the index after the type."""
+    t = tokens[i]
+    if is_punct(t, "&"):
+        i += 1
+        if tokens[i].kind == "lifetime":
+            i += 1
+        if is_ident(tokens[i], "mut"):
+            i += 1
+        return parse_type(tokens, i)
+    if is_punct(t, "("):
+        end = matching(tokens, i)
+        items = [parse_type(part)[0] for part in split_commas(tokens[i + 1 : end])]
+        return ("()", items), end + 1
+    if is_punct(t, "["):
+        end = matching(tokens, i)
+        inner, _ = parse_type(tokens, i + 1)
+        return ("[]", [inner]), end + 1
+    if t.kind == "ident" and t.text in ("dyn", "impl"):
+        depth, j = 0, i + 1
+        while j < len(tokens):
+            u = tokens[j]
+            if is_punct(u, "<"):
+                depth += 1
+            elif is_punct(u, ">"):
+                if depth == 0:
+                    break
+                depth -= 1
+            elif is_punct(u, ",") and depth == 0:
+                break
+            j += 1
+        return ("Any", []), j
+    if is_punct(t, "$"):
+        i += 1
+    # A path, of which only the last segment matters.
+    name = tokens[i].text
+    i += 1
+    while i + 1 < len(tokens) and is_punct(tokens[i], "::"):
+        name = tokens[i + 1].text
+        i += 2
+    args = []
+    if i < len(tokens) and is_punct(tokens[i], "<"):
+        depth, j, start = 1, i + 1, i + 1
+        while depth:
+            if is_punct(tokens[j], "<"):
+                depth += 1
+            elif is_punct(tokens[j], ">"):
+                depth -= 1
+            j += 1
+        for part in split_commas(tokens[start : j - 1]):
+            if part and part[0].kind != "lifetime":
+                args.append(parse_type(part)[0])
+        i = j
+    return (name, args), i
+
+
+class Context:
+    def __init__(self, classes, type_aliases):
+        self.classes = classes
+        self.type_aliases = type_aliases
+
+
+def render_type(tree, ctx, self_name=None):
+    name, args = tree
+    if name == "()":
+        if not args:
+            return "None"
+        return "Tuple[%s]" % ", ".join(render_type(a, ctx, self_name) for a in args)
+    if name == "[]":
+        return "List[%s]" % render_type(args[0], ctx, self_name)
+    if name in ctx.type_aliases:
+        params, body = ctx.type_aliases[name]
+        return render_type(substitute(body, dict(zip(params, args))), ctx, self_name)
+    if name == "Self":
+        return self_name or "Any"
+    if name in WRAPPERS:
+        return render_type(args[0], ctx, self_name) if args else "Any"
+    if name == "Option":
+        return "Optional[%s]" % render_type(args[0], ctx, self_name)
+    if name in LISTS:
+        return "List[%s]" % render_type(args[0], ctx, self_name)
+    if name in ("HashMap", "BTreeMap"):
+        return "Dict[%s, %s]" % tuple(render_type(a, ctx, self_name) for a in args)
+    if name in ("HashSet", "BTreeSet"):
+        return "Set[%s]" % render_type(args[0], ctx, self_name)
+    if name in INTS:
+        return "int"
+    if name in SCALARS:
+        return SCALARS[name]
+    if name in ALIASES:
+        return name
+    if name in INLINE:
+        return INLINE[name]
+    if name in ctx.classes:
+        return name
+    return "Any"
+
+
+def substitute(tree, mapping):
+    name, args = tree
+    if name in mapping and not args:
+        return mapping[name]
+    return (name, [substitute(a, mapping) for a in args])
+
+
+# --- Items -------------------------------------------------------------------
+
+
+class Function:
+    def __init__(self, name, docs, attrs, params, ret):
+        self.name = name
+        self.docs = docs
+        self.attrs = attrs
+        self.params = params  # [(name, type tree or None)]
+        self.ret = ret
+
+
+class PyClass:
+    def __init__(self, name, docs):
+        self.name = name
+        self.docs = docs
+        self.methods = []
+        self.operators = {}  # magic name -> [(rhs, returns)]
+
+
+def attr_name(attr):
+    """The name of an attribute, such as ``pyclass`` or ``pyo3``."""
+    names = []
+    for t in attr:
+        if t.kind == "ident":
+            names.append(t.text)
+        elif not is_punct(t, "::"):
+            break
+    return names[-1] if names else ""
+
+
+def parse_fn(tokens, i, docs, attrs):
+    """Parse a function whose ``fn`` keyword is at ``tokens[i]``."""
+    name = tokens[i + 1].text
+    j = i + 2
+    if is_punct(tokens[j], "<"):
+        depth = 0
+        while True:
+            if is_punct(tokens[j], "<"):
+                depth += 1
+            elif is_punct(tokens[j], ">"):
+                depth -= 1
+                if depth == 0:
+                    break
+            j += 1
+        j += 1
+    end = matching(tokens, j)
+    params = []
+    for part in split_commas(tokens[j + 1 : end]):
+        part = [t for t in part if not is_ident(t, "mut")]
+        if any(is_ident(t, "self") for t in part[:2]):
+            params.append(("self", None))
+            continue
+        colon = next(k for k, t in enumerate(part) if is_punct(t, ":"))
+        pname = part[colon - 1].text
+        if pname == ")":
+            # A tuple pattern, such as (t0, t1), which pyo3 names after
+            # its parts.
+            pname = "_".join(t.text for t in part[:colon] if t.kind == "ident")
+        params.append((pname, parse_type(part, colon + 1)[0]))
+    k = end + 1
+    ret = ("()", [])
+    if k < len(tokens) and is_punct(tokens[k], "->"):
+        ret, k = parse_type(tokens, k + 1)
+    while not (is_punct(tokens[k], "{") or is_punct(tokens[k], ";")):
+        k += 1
+    if is_punct(tokens[k], "{"):
+        k = matching(tokens, k)
+    return Function(name, docs, attrs, params, ret), k + 1
+
+
+class Parser:
+    def __init__(self):
+        self.classes = {}
+        self.functions = {}  # (module, name) -> Function
+        self.macros = {}
+        self.type_aliases = {}
+        self.order = []
+
+    def cls(self, name):
+        if name not in self.classes:
+            self.classes[name] = PyClass(name, [])
+        return self.classes[name]
+
+    def parse(self, tokens, module):
+        i = 0
+        docs, attrs = [], []
+        while i < len(tokens):
+            t = tokens[i]
+            if t.kind == "doc":
+                docs.append(t.text)
+                i += 1
+                continue
+            if is_punct(t, "#"):
+                inner = 1 if is_punct(tokens[i + 1], "!") else 0
+                end = matching(tokens, i + 1 + inner)
+                if not inner:
+                    attrs.append(tokens[i + 2 : end])
+                i = end + 1
+                continue
+            if t.kind == "ident" and t.text in ("pub", "unsafe", "const", "async"):
+                if t.text == "pub" and is_punct(tokens[i + 1], "("):
+                    i = matching(tokens, i + 1)
+                i += 1
+                continue
+            names = [attr_name(a) for a in attrs]
+            if is_ident(t, "macro_rules"):
+                i = self.parse_macro(tokens, i)
+            elif t.kind == "ident" and is_punct(tokens[i + 1], "!") and is_punct(
+                tokens[i + 2], "("
+            ):
+                end = matching(tokens, i + 2)
+                self.expand(t.text, tokens[i + 3 : end], module)
+                i = end + 1
+            elif is_ident(t, "struct") or is_ident(t, "enum"):
+                name = tokens[i + 1].text
+                if "pyclass" in names:
+                    self.cls(name).docs = docs
+                    self.order.append(name)
+                i = self.skip_item(tokens, i)
+            elif is_ident(t, "type"):
+                i = self.parse_type_alias(tokens, i)
+            elif is_ident(t, "impl"):
+                i = self.parse_impl(tokens, i, "pymethods" in names)
+            elif is_ident(t, "fn"):
+                func, i = parse_fn(tokens, i, docs, attrs)
+                if "pyfunction" in names:
+                    self.functions[(module, func.name)] = func
+            else:
+                i = self.skip_item(tokens, i)
+            docs, attrs = [], []
+
+    def skip_item(self, tokens, i):
+        while i < len(tokens):
+            t = tokens[i]
+            if is_punct(t, ";"):
+                return i + 1
+            if is_punct(t, "{"):
+                return matching(tokens, i) + 1
+            if t.kind == "punct" and t.text in ("(", "["):
+                i = matching(tokens, i)
+            i += 1
+        return i
+
+    def parse_type_alias(self, tokens, i):
+        name = tokens[i + 1].text
+        j, params = i + 2, []
+        if is_punct(tokens[j], "<"):
+            while not is_punct(tokens[j], ">"):
+                if tokens[j].kind == "ident":
+                    params.append(tokens[j].text)
+                j += 1
+            j += 1
+        body, j = parse_type(tokens, j + 1)
+        self.type_aliases[name] = (params, body)
+        return j + 1
+
+    def parse_impl(self, tokens, i, exposed):
+        j = i + 1
+        target, j = parse_type(tokens, j)
+        if is_ident(tokens[j], "for"):
+            target, j = parse_type(tokens, j + 1)
+        while not is_punct(tokens[j], "{"):
+            j += 1
+        end = matching(tokens, j)
+        if exposed:
+            cls = self.cls(target[0])
+            k, docs, attrs = j + 1, [], []
+            while k < end:
+                t = tokens[k]
+                if t.kind == "doc":
+                    docs.append(t.text)
+                    k += 1
+                elif is_punct(t, "#"):
+                    close = matching(tokens, k + 1)
+                    attrs.append(tokens[k + 2 : close])
+                    k = close + 1
+                elif is_ident(t, "fn"):
+                    func, k = parse_fn(tokens, k, docs, attrs)
+                    cls.methods.append(func)
+                    docs, attrs = [], []
+                else:
+                    k += 1
+        return end + 1
+
+    def parse_macro(self, tokens, i):
+        name = tokens[i + 2].text
+        start = i + 3
+        end = matching(tokens, start)
+        arms = []
+        k = start + 1
+        while k < end:
+            if not is_punct(tokens[k], "("):
+                k += 1
+                continue
+            pattern_end = matching(tokens, k)
+            pattern = tokens[k + 1 : pattern_end]
+            body_start = pattern_end + 2
+            body_end = matching(tokens, body_start)
+            params = [
+                pattern[n + 1].text
+                for n in range(len(pattern) - 1)
+                if is_punct(pattern[n], "$") and pattern[n + 1].kind == "ident"
+            ]
+            arms.append((params, tokens[body_start + 1 : body_end]))
+            k = body_end + 1
+        self.macros[name] = arms
+        return end + 1
+
+    def expand(self, name, args, module):
+        if name == "polymorphic":
+            self.expand_polymorphic(args)
+            return
+        arms = self.macros.get(name)
+        if not arms or len(arms) != 1:
+            return
+        params, body = arms[0]
+        values = split_commas(args)
+        mapping = dict(zip(params, values))
+        expanded = []
+        n = 0
+        while n < len(body):
+            t = body[n]
+            if is_punct(t, "$") and n + 1 < len(body):
+                key = body[n + 1].text
+                if key == "crate":
+                    expanded.append(Token("ident", "crate"))
+                    n += 2
+                    continue
+                if key in mapping:
+                    expanded.extend(mapping[key])
+                    n += 2
+                    continue
+            expanded.append(t)
+            n += 1
+        self.parse(expanded, module)
+
+    def expand_polymorphic(self, args):
+        op = args[0].text
+        target, k = parse_type(args, 1)
+        k += 1  # =>
+        cls = self.cls(target[0])
+        ops = cls.operators.setdefault(OPERATORS[op], [])
+        for part in split_commas(args[k:]):
+            if not part or not is_punct(part[0], "("):
+                continue
+            fields = split_commas(part[1:-1])
+            ops.append((parse_type(fields[1])[0], parse_type(fields[2])[0]))
+
+
+
+# --- Types from the Rust sources ---------------------------------------------
+
+
+def python_name(func):
+    """The Python name of a function, which ``#[pyo3(name = "...")]``
+    may change."""
+    for attr in func.attrs:
+        if attr_name(attr) != "pyo3":
+            continue
+        for item in split_commas(attr[2:-1]):
+            if len(item) == 3 and is_ident(item[0], "name") and item[2].kind == "str":
+                return item[2].text.strip('"')
+    return func.name
+
+
+def is_receiver(pname, ptype):
+    if pname == "self" or ptype is None:
+        return True
+    name, args = ptype
+    if name in ("PyRef", "PyRefMut") or (
+        name in ("Bound", "Py") and args and args[0][0] == "Self"
+    ):
+        return True
+    if name == "Bound" and args and args[0][0] == "PyType":
+        return True
+    return False
+
+
+def rust_params(func, method):
+    """The Python parameters of a Rust function, as ``(name, type tree)``
+    pairs, leaving out the receiver and the ``Python`` token."""
+    params = [(n, t) for n, t in func.params if not (t and t[0] == "Python")]
+    if method and params and is_receiver(*params[0]):
+        params.pop(0)
+    return params
+
+
+def property_name(func, kind):
+    for attr in func.attrs:
+        if attr_name(attr) == kind and len(attr) > 1 and is_punct(attr[1], "("):
+            return attr[2].text
+    prefix = kind[:3] + "_"
+    if func.name.startswith(prefix):
+        return func.name[len(prefix) :]
+    return func.name
+
+
+class RustClass:
+    """What the Rust sources say about the members of a class."""
+
+    def __init__(self, cls):
+        self.cls = cls
+        self.methods = {}
+        self.getters = {}
+        self.setters = {}
+        self.new = None
+        for func in cls.methods:
+            names = [attr_name(a) for a in func.attrs]
+            if "new" in names:
+                self.new = func
+            elif "getter" in names:
+                self.getters[property_name(func, "getter")] = func
+            elif "setter" in names:
+                self.setters[property_name(func, "setter")] = func
+            else:
+                self.methods.setdefault(python_name(func), func)
+
+
+def read_sources():
+    parser = Parser()
+    files = sorted(f for f in os.listdir(SRC) if f.endswith(".rs"))
+    sources = {}
+    for f in files:
+        with open(os.path.join(SRC, f), encoding="utf-8") as fh:
+            sources[f[:-3]] = fh.read()
+    # Macros are defined before they are used, so read them first.
+    for module, source in sources.items():
+        tokens = tokenize(source)
+        for i, t in enumerate(tokens):
+            if is_ident(t, "macro_rules"):
+                parser.parse_macro(tokens, i)
+    for module, source in sources.items():
+        parser.parse(tokenize(source), module)
+    return parser
+
+
+# --- Rendering ---------------------------------------------------------------
+
+
+def docstring(obj, indent):
+    """The docstring of ``obj``, indented for a stub, or None."""
+    return format_doc(inspect.getdoc(obj), indent)
+
+
+def format_doc(doc, indent):
+    if not doc:
+        return None
+    text = doc.replace("\\", "\\\\").replace('"""', '\\"\\"\\"')
+    lines = text.split("\n")
+    pad = " " * indent
+    if len(lines) == 1:
+        return '%s"""%s"""' % (pad, lines[0])
+    body = "\n".join((pad + line) if line else "" for line in lines)
+    return '%s"""\n%s\n%s"""' % (pad, body, pad)
+
+
+def render_def(name, params, ret, doc, indent, decorators=()):
+    pad = " " * indent
+    lines = [pad + d for d in decorators]
+    head = "%sdef %s(%s) -> %s:" % (pad, name, ", ".join(params), ret)
+    if len(head) > 88:
+        inner = "".join("%s    %s,\n" % (pad, p) for p in params)
+        head = "%sdef %s(\n%s%s) -> %s:" % (pad, name, inner, pad, ret)
+    if doc is None:
+        lines.append(head + " ...")
+    else:
+        lines.append(head)
+        lines.append(doc)
+        lines.append(pad + "    ...")
+    return "\n".join(lines)
+
+
+def split_top(text):
+    """Split a parameter list at the commas which are not inside brackets
+    or strings."""
+    parts, current, depth, quote = [], "", 0, None
+    for c in text:
+        if quote:
+            quote = None if c == quote else quote
+        elif c in "\"'":
+            quote = c
+        elif c in "([{":
+            depth += 1
+        elif c in ")]}":
+            depth -= 1
+        elif c == "," and depth == 0:
+            parts.append(current.strip())
+            current = ""
+            continue
+        current += c
+    if current.strip():
+        parts.append(current.strip())
+    return parts
+
+
+def built_params(obj, receiver=None):
+    """The parameters of a built-in function, as ``(prefix, name, default)``
+    tuples, from its ``__text_signature__``, leaving out the receiver, which
+    a hand-written signature may name ``receiver``."""
+    text = getattr(obj, "__text_signature__", None)
+    if not text:
+        return [("*", "args", None), ("**", "kwargs", None)]
+    params = []
+    parts = split_top(text.strip()[1:-1])
+    if parts and (parts[0].startswith("$") or parts[0] == receiver):
+        parts.pop(0)
+    for part in parts:
+        # Stubs must parse on Python 3.7, which has no positional-only
+        # marker.
+        if part == "/":
+            continue
+        name, _, default = part.partition("=")
+        prefix = re.match(r"\**", name).group(0)
+        name = name[len(prefix) :]
+        if name.startswith("("):
+            # A Rust argument destructured as a tuple, such as (t0, t1),
+            # is named after its pattern, which is not a Python identifier.
+            name = "_".join(re.findall(r"\w+", name))
+        params.append((prefix, name or None, default or None))
+    return params
+
+
+def annotate(params, rust, ctx, self_name):
+    """Render ``(prefix, name, default)`` parameters with the types of the
+    Rust parameters of the same names. A hand-written text signature may
+    rename the parameters, so if the names do not match, but the number of
+    parameters does, they are matched by position instead."""
+    types_ = dict(rust)
+    named = [p for p in params if p[1] is not None]
+    by_position = len(named) == len(rust) and not all(p[1] in types_ for p in named)
+    out = []
+    for prefix, name, default in params:
+        if name is None:
+            out.append(prefix)
+            continue
+        if by_position:
+            tree = rust[named.index((prefix, name, default))][1]
+        else:
+            tree = types_.get(name)
+        annotation = "Any" if prefix or tree is None else render_type(tree, ctx, self_name)
+        text = "%s%s: %s" % (prefix, name, annotation)
+        if default is not None:
+            text += " = " + default
+        out.append(text)
+    return out
+
+
+def return_type(func, ctx):
+    # Methods which return their own class return the class of the object
+    # or class they are called on, which may be a Python subclass.
+    if func.name == "__next__" and func.ret[0] == "Option":
+        return render_type(func.ret[1][0], ctx, "Self")
+    return render_type(func.ret, ctx, "Self")
+
+
+def render_python_function(cls_name, name, member, indent):
+    """Render a Python function which kurbopy/__init__.py adds to a class."""
+    decorators = []
+    if (cls_name, name) in PYTHON_EXTRAS:
+        params, ret = PYTHON_EXTRAS[(cls_name, name)]
+    else:
+        params = []
+        for param in inspect.signature(member).parameters.values():
+            text = str(param.replace(annotation=param.empty, default=param.empty))
+            if param.name not in ("self", "cls"):
+                text += ": Any"
+            if param.default is not param.empty:
+                text += " = ..."
+            params.append(text)
+        ret = "Any"
+    if not params or params[0] != "self":
+        decorators.append("@staticmethod")
+    return render_def(name, params, ret, docstring(member, indent + 4), indent, decorators)
+
+
+def render_slot(name, rust, ctx):
+    """Render a special method, such as ``__add__``, which Python exposes
+    as a slot wrapper. Returns None for slots which pyo3 fills in but which
+    the class does not implement, such as the reflected operators."""
+    cls = rust.cls
+    if name in COMPARISONS:
+        if "__richcmp__" in rust.methods or (
+            name in ("__eq__", "__ne__") and "__eq__" in rust.methods
+        ):
+            return [render_def(name, ["self", "other: object"], "bool", None, 4)]
+        return None
+    if name in cls.operators:
+        overloads = cls.operators[name]
+        decorators = ["@overload"] if len(overloads) > 1 else []
+        out = []
+        for rhs, ret in overloads:
+            params = ["self", "rhs: %s" % render_type(rhs, ctx, cls.name)]
+            if ret[0] == cls.name:
+                ret = ("Self", [])
+            out.append(
+                render_def(name, params, render_type(ret, ctx, "Self"), None, 4, decorators)
+            )
+        return out
+    func = rust.methods.get(name)
+    if func is None:
+        return None
+    params = ["self"] + [
+        "%s: %s" % (n, render_type(t, ctx, cls.name)) for n, t in rust_params(func, True)
+    ]
+    return [render_def(name, params, return_type(func, ctx), None, 4)]
+
+
+def render_property(name, member, rust, ctx):
+    getter = rust.getters.get(name)
+    ret = render_type(getter.ret, ctx, rust.cls.name) if getter else "Any"
+    out = [render_def(name, ["self"], ret, docstring(member, 8), 4, ["@property"])]
+    setter = rust.setters.get(name)
+    if setter is not None:
+        params = ["self"] + [
+            "%s: %s" % (n, render_type(t, ctx, rust.cls.name))
+            for n, t in rust_params(setter, True)
+        ]
+        out.append(render_def(name, params, "None", None, 4, ["@%s.setter" % name]))
+    return out
+
+
+def render_member(name, member, rust, ctx):
+    cls_name = rust.cls.name
+    if member is None:
+        # An unhashable class sets __hash__ to None.
+        return ["    %s: ClassVar[None]" % name]
+    if isinstance(member, types.GetSetDescriptorType):
+        return render_property(name, member, rust, ctx)
+    if isinstance(member, types.WrapperDescriptorType):
+        return render_slot(name, rust, ctx)
+    if isinstance(member, types.FunctionType):
+        return [render_python_function(cls_name, name, member, 4)]
+    if not callable(member):
+        return ["    %s: %s" % (name, value_type(member))]
+    func = rust.methods.get(name)
+    rust_types = rust_params(func, True) if func else []
+    classmethod = isinstance(member, types.ClassMethodDescriptorType)
+    receiver = "cls" if classmethod else "self"
+    params = annotate(built_params(member, receiver), rust_types, ctx, cls_name)
+    ret = return_type(func, ctx) if func else "Any"
+    if name in ("__eq__", "__ne__"):
+        params, ret = ["other: object"], "bool"
+    decorators = []
+    if classmethod:
+        decorators.append("@classmethod")
+        params = ["cls"] + params
+    elif isinstance(member, types.BuiltinFunctionType):
+        decorators.append("@staticmethod")
+    else:
+        params = ["self"] + params
+    return [render_def(name, params, ret, docstring(member, 8), 4, decorators)]
+
+
+def render_class(cls, rust, ctx):
+    lines = ["class %s:" % cls.__name__]
+    doc = docstring(cls, 4)
+    members = []
+    if cls.__text_signature__:
+        params = annotate(
+            built_params(cls),
+            rust_params(rust.new, True) if rust.new else [],
+            ctx,
+            cls.__name__,
+        )
+        # pyo3 does not keep the docs of #[new] methods.
+        doc_new = format_doc(inspect.cleandoc("\n".join(rust.new.docs)), 8) if rust.new else None
+        members.append(render_def("__init__", ["self"] + params, "None", doc_new, 4))
+    for name in sorted(vars(cls)):
+        private = name.startswith("_") and not name.startswith("__")
+        if name in SKIPPED or private or keyword.iskeyword(name):
+            # Methods named after keywords, such as QuadBez.raise, can only
+            # be called with getattr, and cannot be declared in a stub.
+            continue
+        rendered = render_member(name, vars(cls)[name], rust, ctx)
+        if rendered:
+            members.extend(rendered)
+    if doc:
+        lines.append(doc)
+    if not members and not doc:
+        lines[0] += " ..."
+        return "\n".join(lines)
+    body = "\n\n".join(members)
+    if body:
+        lines.append(("\n" if doc else "") + body)
+    return "\n".join(lines)
+
+
+def render_hidden_class(rust, ctx):
+    """Render a class which the module does not expose by name, such as the
+    iterator returned by ``BezPath.segments``, from the Rust sources."""
+    lines = ["class %s:" % rust.cls.name]
+    doc = format_doc(inspect.cleandoc("\n".join(rust.cls.docs)), 4)
+    if doc:
+        lines += [doc, ""]
+    members = []
+    for name, func in sorted(rust.methods.items()):
+        params = ["self"] + [
+            "%s: %s" % (n, render_type(t, ctx, rust.cls.name))
+            for n, t in rust_params(func, True)
+        ]
+        members.append(render_def(name, params, return_type(func, ctx), None, 4))
+    lines.append("\n\n".join(members))
+    return "\n".join(lines)
+
+
+def render_function(name, func, rust, ctx):
+    params = annotate(built_params(func), rust_params(rust, False) if rust else [], ctx, None)
+    ret = render_type(rust.ret, ctx) if rust else "Any"
+    return render_def(name, params, ret, docstring(func, 4), 0)
+
+
+def value_type(value):
+    """The annotation for a constant."""
+    if isinstance(value, tuple) and value:
+        return "Tuple[%s]" % ", ".join(value_type(v) for v in value)
+    if isinstance(value, list) and value:
+        items = {value_type(v) for v in value}
+        return "List[%s]" % (items.pop() if len(items) == 1 else "Any")
+    return type(value).__name__
+
+
+TYPING = [
+    "Any", "ClassVar", "Dict", "Iterator", "List", "Optional", "Sequence", "Set", "Tuple",
+    "Union", "overload",
+]
+
+
+def typing_import(text):
+    used = [name for name in TYPING if re.search(r"\b%s\b" % name, text)]
+    out = "from typing import %s\n" % ", ".join(used) if used else ""
+    if re.search(r"(-> |\[)Self\b", text):
+        out += "from typing_extensions import Self\n"
+    return out
+
+
+# --- Main --------------------------------------------------------------------
+
+
+def module_functions(mod, parser, ctx, rust_module=None):
+    """The functions of a built module, annotated from the Rust functions
+    behind them, which are in ``src/<rust_module>.rs`` if it is given."""
+    rust = {
+        python_name(f): f
+        for (module, _), f in parser.functions.items()
+        if rust_module in (None, module)
+    }
+    return [
+        render_function(name, value, rust.get(name), ctx)
+        for name, value in sorted(vars(mod).items())
+        if callable(value) and not isinstance(value, type) and not name.startswith("_")
+    ]
+
+
+def generate():
+    parser = read_sources()
+    ctx = Context(set(parser.classes), parser.type_aliases)
+    mod = kurbopy.kurbopy
+
+    # The main extension module.
+    constants = [
+        "%s: %s" % (name, value_type(value))
+        for name, value in sorted(vars(mod).items())
+        if not name.startswith("_")
+        and not callable(value)
+        and not isinstance(value, types.ModuleType)
+    ]
+    aliases = ["%s = %s" % (k, v) for k, v in ALIASES.items()]
+    classes = []
+    for name, cls in sorted(vars(mod).items(), key=lambda item: item[0].lower()):
+        if isinstance(cls, type):
+            rust = RustClass(parser.classes.get(name) or PyClass(name, []))
+            classes.append(render_class(cls, rust, ctx))
+    functions = module_functions(mod, parser, ctx)
+    hidden = set()
+    while True:
+        text = "\n".join(classes + functions)
+        found = {
+            name
+            for name in parser.classes
+            if name not in vars(mod) and name not in hidden and re.search(r"\b%s\b" % name, text)
+        }
+        if not found:
+            break
+        hidden |= found
+        for name in sorted(found):
+            classes.append(render_hidden_class(RustClass(parser.classes[name]), ctx))
+    text = "\n\n".join(["\n".join(constants), "\n".join(aliases)] + classes + functions)
+    main = (
+        HEADER
+        + typing_import(text)
+        + "\n"
+        + "from . import common as common\n"
+        + "\n"
+        + text
+        + "\n"
+    )
+    main = re.sub(r"\n(class |def )", r"\n\n\1", main)
+
+    # The common submodule.
+    constants = [
+        "%s: %s" % (name, value_type(value))
+        for name, value in sorted(vars(mod.common).items())
+        if not name.startswith("_") and not callable(value)
+    ]
+    blocks = ["\n".join(constants)] if constants else []
+    text = "\n\n\n".join(blocks + module_functions(mod.common, parser, ctx, "common"))
+    used = sorted(
+        name
+        for name in list(ALIASES) + list(parser.classes)
+        if re.search(r"\b%s\b" % name, text)
+    )
+    common = HEADER + typing_import(text)
+    if used:
+        common += "from kurbopy.kurbopy import %s\n" % ", ".join(used)
+    common += "\n" + text + "\n"
+    return {"kurbopy": main, "common": common}
+
+
+def main():
+    parser = argparse.ArgumentParser(description=__doc__.split("\n")[0])
+    parser.add_argument(
+        "--check",
+        action="store_true",
+        help="exit with an error if the stubs are out of date, without writing them",
+    )
+    args = parser.parse_args()
+    stale = []
+    for name, text in generate().items():
+        path = OUTPUTS[name]
+        current = None
+        if os.path.exists(path):
+            with open(path, encoding="utf-8") as fh:
+                current = fh.read()
+        if current == text:
+            continue
+        stale.append(os.path.relpath(path, ROOT))
+        if not args.check:
+            with open(path, "w", encoding="utf-8") as fh:
+                fh.write(text)
+    if args.check and stale:
+        sys.exit(
+            "stubs out of date: %s; run tools/generate_stubs.py" % ", ".join(stale)
+        )
+
+
+if __name__ == "__main__":
+    main()